@@ -1,5 +1,5 @@
-use anyhow::Result;
+use std::process::ExitCode;
 
-fn main() -> Result<()> {
-    opencode_cloud::run()
+fn main() -> ExitCode {
+    opencode_cloud::run_with_exit_code()
 }
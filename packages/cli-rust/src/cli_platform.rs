@@ -1,9 +1,12 @@
+use crate::release_binary::release_target;
 use std::process::Command;
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum CliInstallMethod {
     Cargo,
     Npm,
+    /// A prebuilt binary downloaded from a GitHub release
+    Binary,
 }
 
 impl CliInstallMethod {
@@ -11,10 +14,18 @@ impl CliInstallMethod {
         match self {
             CliInstallMethod::Cargo => "cargo install",
             CliInstallMethod::Npm => "npm install -g",
+            CliInstallMethod::Binary => "GitHub release binary",
         }
     }
 
-    pub(crate) fn run_update(&self, target: Option<&str>) -> Result<(), String> {
+    /// Program and arguments that update the CLI, pinned to `target` when known
+    ///
+    /// `None` for release binaries, which update themselves instead of
+    /// running a package manager.
+    pub(crate) fn update_command(
+        &self,
+        target: Option<&str>,
+    ) -> Option<(&'static str, Vec<String>)> {
         let mut args: Vec<String> = match self {
             CliInstallMethod::Cargo => vec!["install".to_string(), "opencode-cloud".to_string()],
             CliInstallMethod::Npm => vec![
@@ -22,6 +33,7 @@ impl CliInstallMethod {
                 "-g".to_string(),
                 "opencode-cloud".to_string(),
             ],
+            CliInstallMethod::Binary => return None,
         };
 
         if let Some(version) = target {
@@ -34,12 +46,23 @@ impl CliInstallMethod {
                     let _ = args.pop();
                     args.push(format!("opencode-cloud@{version}"));
                 }
+                CliInstallMethod::Binary => {}
             }
         }
 
-        let program = match self {
-            CliInstallMethod::Cargo => "cargo",
-            CliInstallMethod::Npm => "npm",
+        let program = if matches!(self, CliInstallMethod::Npm) {
+            "npm"
+        } else {
+            "cargo"
+        };
+        Some((program, args))
+    }
+
+    pub(crate) async fn run_update(&self, target: Option<&str>) -> Result<(), String> {
+        let Some((program, args)) = self.update_command(target) else {
+            return crate::release_binary::install_release(target)
+                .await
+                .map_err(|e| format!("{e:#}"));
         };
 
         let status = Command::new(program)
@@ -67,9 +90,35 @@ pub(crate) fn detect_install_method() -> Option<CliInstallMethod> {
         return Some(CliInstallMethod::Cargo);
     }
 
+    if release_target().is_some() && !is_managed_install_path(&exe_str) {
+        return Some(CliInstallMethod::Binary);
+    }
+
     None
 }
 
+/// Paths owned by package managers or local builds, which must not self-replace
+///
+/// Distro packages install into `/usr/bin` and friends; replacing those with
+/// a release download would fight the package manager.
+fn is_managed_install_path(exe_path: &str) -> bool {
+    const MANAGED_PREFIXES: &[&str] = &["/usr/bin/", "/usr/sbin/", "/bin/", "/sbin/", "/usr/lib/"];
+    const MANAGED_MARKERS: &[&str] = &[
+        "/Cellar/",
+        "/homebrew/",
+        "/nix/store/",
+        "/snap/",
+        "/flatpak/",
+        "/target/",
+    ];
+    MANAGED_PREFIXES
+        .iter()
+        .any(|prefix| exe_path.starts_with(prefix))
+        || MANAGED_MARKERS
+            .iter()
+            .any(|marker| exe_path.contains(marker))
+}
+
 pub(crate) fn is_dev_binary() -> bool {
     let exe_path = match std::env::current_exe() {
         Ok(path) => path,
@@ -86,8 +135,26 @@ pub(crate) fn cli_platform_label() -> &'static str {
     }
 
     match detect_install_method() {
-        Some(CliInstallMethod::Cargo) => "Rust CLI",
+        Some(CliInstallMethod::Cargo | CliInstallMethod::Binary) => "Rust CLI",
         Some(CliInstallMethod::Npm) => "Node.js CLI",
         None => "CLI",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_binaries_outside_managed_paths_can_self_update() {
+        assert!(!is_managed_install_path("/usr/local/bin/occ"));
+        assert!(!is_managed_install_path("/home/me/bin/occ"));
+        assert!(is_managed_install_path("/opt/homebrew/bin/occ"));
+        assert!(is_managed_install_path("/usr/bin/occ"));
+        assert!(is_managed_install_path("/snap/occ/12/bin/occ"));
+        assert!(is_managed_install_path("/usr/local/Cellar/occ/1.0/bin/occ"));
+        assert!(is_managed_install_path(
+            "/home/me/src/opencode-cloud/target/release/occ"
+        ));
+    }
+}
@@ -1,18 +1,37 @@
 //! Cockpit command implementation
 //!
-//! Opens the Cockpit web console in the default browser.
+//! Opens the Cockpit web console in the default browser, or toggles
+//! `cockpit_enabled` with `occ cockpit enable` / `occ cockpit disable`.
 
+use crate::commands::{StartArgs, StopArgs, cmd_start, cmd_stop};
+use crate::confirm::confirm;
 use crate::constants::COCKPIT_EXPOSED;
-use crate::output::localhost_display_addr;
+use crate::error::CliError;
+use crate::output::format_cockpit_url;
 use anyhow::{Result, bail};
-use clap::Args;
+use clap::{Args, Subcommand};
 use console::style;
-use opencode_cloud_core::config::load_config_or_default;
-use opencode_cloud_core::docker::{CONTAINER_NAME, container_is_running};
+use opencode_cloud_core::docker::{
+    CONTAINER_NAME, container_exists, container_is_running, docker_supports_systemd,
+};
+use opencode_cloud_core::{load_config_or_default, save_config};
+use std::io::IsTerminal;
 
 /// Arguments for the cockpit command
 #[derive(Args)]
-pub struct CockpitArgs {}
+pub struct CockpitArgs {
+    #[command(subcommand)]
+    pub command: Option<CockpitCommand>,
+}
+
+/// Cockpit subcommands (opens the console when omitted)
+#[derive(Subcommand)]
+pub enum CockpitCommand {
+    /// Enable Cockpit (takes effect when the container is recreated)
+    Enable,
+    /// Disable Cockpit (takes effect when the container is recreated)
+    Disable,
+}
 
 /// Open Cockpit web console in browser
 ///
@@ -20,7 +39,22 @@ pub struct CockpitArgs {}
 /// 1. Checks if Cockpit is enabled in config
 /// 2. Checks if the container is running
 /// 3. Opens the Cockpit URL in the default browser
-pub async fn cmd_cockpit(_args: &CockpitArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+pub async fn cmd_cockpit(
+    args: &CockpitArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
+    match args.command {
+        Some(CockpitCommand::Enable) => {
+            return cmd_cockpit_toggle(true, maybe_host, quiet, verbose).await;
+        }
+        Some(CockpitCommand::Disable) => {
+            return cmd_cockpit_toggle(false, maybe_host, quiet, verbose).await;
+        }
+        None => {}
+    }
+
     if !COCKPIT_EXPOSED {
         if !quiet {
             println!(
@@ -40,13 +74,10 @@ pub async fn cmd_cockpit(_args: &CockpitArgs, maybe_host: Option<&str>, quiet: b
              Cockpit is disabled in configuration.\n\n\
              {}: Cockpit requires Linux host with native Docker.\n\
              It does NOT work on macOS Docker Desktop.\n\n\
-             To enable (Linux only):\n\
-             1. {}\n\
-             2. {}",
+             To enable (Linux only): {}",
             style("Cockpit is disabled").yellow().bold(),
             style("Note").yellow(),
-            style("occ config set cockpit_enabled true").cyan(),
-            style("occ start --cached-rebuild-sandbox-image").cyan()
+            style("occ cockpit enable").cyan()
         );
     }
 
@@ -56,11 +87,10 @@ pub async fn cmd_cockpit(_args: &CockpitArgs, maybe_host: Option<&str>, quiet: b
     client
         .verify_connection()
         .await
-        .map_err(|e| anyhow::anyhow!("{e}"))?;
+        .map_err(|e| CliError::docker(&e, e.to_string()))?;
 
     let running = container_is_running(&client, CONTAINER_NAME).await?;
     if !running {
-        let display_addr = localhost_display_addr(&config.bind_address);
         bail!(
             "{}\n\n\
              The container is not running. Cockpit runs inside the container.\n\n\
@@ -68,13 +98,17 @@ pub async fn cmd_cockpit(_args: &CockpitArgs, maybe_host: Option<&str>, quiet: b
              Then access Cockpit:  {}",
             style("Container not running").yellow().bold(),
             style("occ start").cyan(),
-            style(format!("http://{}:{}", display_addr, config.cockpit_port)).cyan()
+            style(format_cockpit_url(
+                None,
+                &config.bind_address,
+                config.cockpit_port
+            ))
+            .cyan()
         );
     }
 
     // Build URL
-    let browser_addr = localhost_display_addr(&config.bind_address);
-    let url = format!("http://{}:{}", browser_addr, config.cockpit_port);
+    let url = format_cockpit_url(None, &config.bind_address, config.cockpit_port);
 
     if !quiet {
         println!("Opening Cockpit at: {}", style(&url).cyan());
@@ -100,3 +134,105 @@ pub async fn cmd_cockpit(_args: &CockpitArgs, maybe_host: Option<&str>, quiet: b
 
     Ok(())
 }
+
+/// Set `cockpit_enabled` and offer to recreate the container
+///
+/// The Cockpit port mapping is fixed when the container is created, so a
+/// plain restart does not pick up the change.
+async fn cmd_cockpit_toggle(
+    enable: bool,
+    maybe_host: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
+    let mut config = load_config_or_default()?;
+    let state = if enable { "enabled" } else { "disabled" };
+    if config.cockpit_enabled == enable {
+        if !quiet {
+            println!("Cockpit is already {state}.");
+        }
+        return Ok(());
+    }
+
+    let maybe_client = match crate::resolve_docker_client(maybe_host).await {
+        Ok((client, _host_name)) => client.verify_connection().await.ok().map(|_| client),
+        Err(_) => None,
+    };
+
+    if enable && !quiet {
+        match maybe_client.as_ref() {
+            Some(client) => {
+                if !docker_supports_systemd(client).await.unwrap_or(false) {
+                    eprintln!(
+                        "{} This Docker host cannot run systemd inside the container, which Cockpit needs.\n\
+                         Cockpit requires a Linux host with native Docker; it does not work on macOS Docker Desktop.",
+                        style("Warning:").yellow().bold()
+                    );
+                }
+            }
+            None => eprintln!(
+                "{} Could not reach Docker to check systemd support; Cockpit needs a Linux host with native Docker.",
+                style("Warning:").yellow().bold()
+            ),
+        }
+    }
+
+    config.cockpit_enabled = enable;
+    save_config(&config)?;
+    if quiet {
+        return Ok(());
+    }
+    println!("Cockpit {state}.");
+
+    if !COCKPIT_EXPOSED {
+        println!(
+            "{}",
+            style(
+                "Note: Cockpit is not exposed in this release, so the setting has no effect yet."
+            )
+            .dim()
+        );
+        return Ok(());
+    }
+
+    let container_present = match maybe_client.as_ref() {
+        Some(client) => container_exists(client, CONTAINER_NAME)
+            .await
+            .unwrap_or(false),
+        None => false,
+    };
+    if !container_present {
+        println!(
+            "{}",
+            style("The change applies the next time the container is created.").dim()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        style("The Cockpit port is set when the container is created, so it must be recreated.")
+            .dim()
+    );
+    // Without a terminal, only OPENCODE_CLOUD_ASSUME_YES recreates
+    let non_interactive = !std::io::stdin().is_terminal();
+    let recreate = confirm(
+        "Recreate the container now?",
+        !non_interactive,
+        non_interactive,
+    )?;
+    if !recreate {
+        println!(
+            "Not recreated. Apply later with: {}",
+            style("occ stop --remove && occ start").cyan()
+        );
+        return Ok(());
+    }
+
+    let stop_args = StopArgs {
+        remove: true,
+        ..StopArgs::default()
+    };
+    cmd_stop(&stop_args, maybe_host, quiet, verbose).await?;
+    cmd_start(&StartArgs::default(), maybe_host, quiet, verbose).await
+}
@@ -0,0 +1,64 @@
+//! Config edit subcommand
+//!
+//! Opens config.json in the user's editor and only saves it once it loads
+//! and validates, so a typo can't leave every command failing on startup.
+
+use super::set::check_container_running;
+use crate::editor::{EditOutcome, edit_validated};
+use anyhow::{Result, bail};
+use console::style;
+use opencode_cloud_core::config::{
+    display_validation_warning, get_config_path, parse_config_file, validate_config,
+};
+use std::io::IsTerminal;
+use tokio::runtime::Runtime;
+
+/// Edit the config file in `$VISUAL`/`$EDITOR`, rejecting invalid results
+pub fn cmd_config_edit(rt: &Runtime, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        bail!("occ config edit needs an interactive terminal. Use 'occ config set' instead.");
+    }
+
+    let config_path =
+        get_config_path().ok_or_else(|| anyhow::anyhow!("Could not determine config file path"))?;
+
+    let outcome = edit_validated(&config_path, |path| {
+        let config = parse_config_file(path)?;
+        let warnings = validate_config(&config).map_err(|e| {
+            anyhow::anyhow!("{}: {}\n  To fix: {}", e.field, e.message, e.fix_command)
+        })?;
+        Ok(warnings)
+    })?;
+
+    match outcome {
+        EditOutcome::Saved(warnings) => {
+            if !quiet {
+                for warning in &warnings {
+                    display_validation_warning(warning);
+                }
+                println!(
+                    "{} Saved {}",
+                    style("Success:").green().bold(),
+                    config_path.display()
+                );
+                if let Ok(true) = check_container_running(rt, maybe_host) {
+                    eprintln!(
+                        "{} Restart required for changes to take effect",
+                        style("Warning:").yellow().bold()
+                    );
+                }
+            }
+        }
+        EditOutcome::Unchanged => {
+            if !quiet {
+                println!("No changes to save.");
+            }
+        }
+        EditOutcome::Discarded => {
+            if !quiet {
+                println!("Edit discarded; {} was not changed.", config_path.display());
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Effective configuration view
+//!
+//! `occ config show --effective` explains where commands will act: the
+//! Docker target after `--remote-host`/`--local`/`default_host` resolution,
+//! the runtime mode, the sandbox instance resource names, and the loaded config.
+
+use super::show::{Redaction, config_display_json, show_table};
+use anyhow::Result;
+use console::style;
+use opencode_cloud_core::docker::{DockerResourceNames, active_resource_names};
+use opencode_cloud_core::{Config, config, load_hosts};
+use serde_json::json;
+
+/// How the CLI resolved its target and runtime for this invocation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedContext {
+    /// Remote host name, or `None` for local Docker
+    pub target_host: Option<String>,
+    /// What picked the target (e.g. `--remote-host`, `default_host`)
+    pub target_source: &'static str,
+    /// Runtime mode in effect (`host` or `container`)
+    pub runtime_mode: &'static str,
+    /// What picked the runtime mode (e.g. `--runtime`, `OPENCODE_RUNTIME`)
+    pub runtime_source: &'static str,
+}
+
+/// Print the fully resolved view of where and how commands will run
+pub fn cmd_config_effective(config: &Config, context: &ResolvedContext, json: bool) -> Result<()> {
+    let resources = active_resource_names();
+    let connection = describe_connection(context.target_host.as_deref());
+    let config_path = config::paths::get_config_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    if json {
+        let value = json!({
+            "target": {
+                "host": context.target_host,
+                "source": context.target_source,
+                "connection": connection,
+            },
+            "runtime": {
+                "mode": context.runtime_mode,
+                "source": context.runtime_source,
+            },
+            "sandbox": resources_json(&resources),
+            "config_path": config_path,
+            "config": config_display_json(config)?,
+        });
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        return Ok(());
+    }
+
+    print_header("Target");
+    print_kv(
+        "Docker:",
+        context
+            .target_host
+            .as_deref()
+            .map(|name| format!("remote host {}", style(name).cyan()))
+            .unwrap_or_else(|| "local".to_string()),
+    );
+    print_kv("Connection:", connection);
+    print_kv("Source:", context.target_source);
+
+    print_header("Runtime");
+    print_kv("Mode:", context.runtime_mode);
+    print_kv("Source:", context.runtime_source);
+
+    print_header("Sandbox");
+    print_kv(
+        "Instance:",
+        resources.instance_id.as_deref().unwrap_or("(shared)"),
+    );
+    print_kv("Container:", &resources.container_name);
+    print_kv("Image tag:", &resources.image_tag);
+    print_kv("Volumes:", resources.volume_names().join(", "));
+
+    print_header("Config");
+    print_kv("Path:", &config_path);
+    println!();
+    show_table(config, Redaction::Secrets)
+}
+
+/// How the Docker daemon is reached, e.g. `ssh -p 2222 user@host` or a local socket
+fn describe_connection(target_host: Option<&str>) -> String {
+    let Some(name) = target_host else {
+        return std::env::var("DOCKER_HOST")
+            .map(|docker_host| format!("DOCKER_HOST={docker_host}"))
+            .unwrap_or_else(|_| "default Docker socket".to_string());
+    };
+
+    match load_hosts()
+        .ok()
+        .as_ref()
+        .and_then(|hosts| hosts.get_host(name))
+    {
+        Some(host) => host.format_ssh_command(),
+        None => format!("unknown host '{name}' (not in hosts.json)"),
+    }
+}
+
+fn resources_json(resources: &DockerResourceNames) -> serde_json::Value {
+    json!({
+        "instance": resources.instance_id,
+        "container": resources.container_name,
+        "image_tag": resources.image_tag,
+        "volumes": resources.volume_names(),
+        "image_state_file": resources.image_state_file,
+    })
+}
+
+fn print_header(title: &str) {
+    println!();
+    println!("{}", style(title).bold());
+}
+
+fn print_kv(label: &str, value: impl std::fmt::Display) {
+    println!("  {label:<12} {value}");
+}
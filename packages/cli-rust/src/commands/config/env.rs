@@ -1,11 +1,19 @@
 //! Config env subcommand
 //!
 //! Manages container environment variables.
+//!
+//! Docker fixes a container's environment when it is created, so changes only
+//! take effect once `occ restart` recreates the container. `--apply` runs that
+//! restart right away.
 
-use anyhow::{Result, bail};
+use crate::commands::{RestartArgs, cmd_restart};
+use anyhow::{Context, Result, bail};
 use clap::Subcommand;
 use console::style;
+use opencode_cloud_core::docker::RESERVED_ENV_KEYS;
 use opencode_cloud_core::{load_config_or_default, save_config};
+use std::path::{Path, PathBuf};
+use tokio::runtime::Runtime;
 
 /// Environment variable management subcommands
 #[derive(Subcommand)]
@@ -14,6 +22,9 @@ pub enum EnvCommands {
     Set {
         /// Environment variable in KEY=value format
         env_var: String,
+        /// Restart the service to recreate the container with the new environment
+        #[arg(long)]
+        apply: bool,
     },
     /// List all configured environment variables
     List,
@@ -21,20 +32,83 @@ pub enum EnvCommands {
     Remove {
         /// Environment variable key to remove
         key: String,
+        /// Restart the service to recreate the container with the new environment
+        #[arg(long)]
+        apply: bool,
+    },
+    /// Import variables from a .env file (KEY=value per line)
+    Import {
+        /// Path to the .env file
+        path: PathBuf,
+        /// Restart the service to recreate the container with the new environment
+        #[arg(long)]
+        apply: bool,
+    },
+    /// Export configured variables in .env format
+    Export {
+        /// Write to this file instead of stdout
+        path: Option<PathBuf>,
     },
 }
 
 /// Handle config env subcommand
 ///
-/// Routes to the appropriate handler based on the env subcommand.
-pub fn cmd_config_env(cmd: EnvCommands, quiet: bool) -> Result<()> {
-    match cmd {
-        EnvCommands::Set { env_var } => cmd_env_set(&env_var, quiet),
-        EnvCommands::List => cmd_env_list(quiet),
-        EnvCommands::Remove { key } => cmd_env_remove(&key, quiet),
+/// Routes to the appropriate handler based on the env subcommand, then
+/// restarts the service when `--apply` was given.
+pub fn cmd_config_env(
+    rt: &Runtime,
+    cmd: EnvCommands,
+    maybe_host: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
+    let apply = match cmd {
+        EnvCommands::Set { env_var, apply } => {
+            cmd_env_set(&env_var, quiet)?;
+            apply
+        }
+        EnvCommands::List => return cmd_env_list(quiet),
+        EnvCommands::Remove { key, apply } => {
+            cmd_env_remove(&key, quiet)?;
+            apply
+        }
+        EnvCommands::Import { path, apply } => {
+            cmd_env_import(&path, quiet)?;
+            apply
+        }
+        EnvCommands::Export { path } => return cmd_env_export(path.as_deref(), quiet),
+    };
+
+    if apply {
+        apply_env_changes(rt, maybe_host, quiet, verbose)
+    } else {
+        if !quiet {
+            println!(
+                "{}",
+                style("Note: Run `occ restart` (or pass --apply) to recreate the container with the new environment.").dim()
+            );
+        }
+        Ok(())
     }
 }
 
+/// Restart the service so the container is recreated with the saved environment
+///
+/// Passing `--apply` is the consent, so this never prompts.
+fn apply_env_changes(
+    rt: &Runtime,
+    maybe_host: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
+    rt.block_on(cmd_restart(
+        &RestartArgs::default(),
+        maybe_host,
+        quiet,
+        verbose,
+    ))
+}
+
 /// Set or update an environment variable
 fn cmd_env_set(env_var: &str, quiet: bool) -> Result<()> {
     // Validate format: must contain '='
@@ -127,8 +201,188 @@ fn cmd_env_remove(key: &str, quiet: bool) -> Result<()> {
     Ok(())
 }
 
+/// Import environment variables from a .env file
+fn cmd_env_import(path: &Path, quiet: bool) -> Result<()> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let entries =
+        parse_env_file(&contents).with_context(|| format!("Failed to parse {}", path.display()))?;
+
+    if entries.is_empty() {
+        if !quiet {
+            println!("No environment variables found in {}", path.display());
+        }
+        return Ok(());
+    }
+
+    let mut config = load_config_or_default()?;
+    for (key, value) in &entries {
+        if !quiet && RESERVED_ENV_KEYS.contains(&key.as_str()) {
+            eprintln!(
+                "{} {} is set by opencode-cloud by default; the imported value overrides it.",
+                style("Warning:").yellow().bold(),
+                key
+            );
+        }
+        merge_env_entry(&mut config.container_env, key, value);
+    }
+    save_config(&config)?;
+
+    if !quiet {
+        println!(
+            "{} Imported {} environment variable(s) from {}",
+            style("Success:").green().bold(),
+            entries.len(),
+            path.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Export configured environment variables in .env format
+fn cmd_env_export(path: Option<&Path>, quiet: bool) -> Result<()> {
+    let config = load_config_or_default()?;
+    let contents = format_env_file(&config.container_env);
+
+    let Some(path) = path else {
+        print!("{contents}");
+        return Ok(());
+    };
+
+    std::fs::write(path, &contents)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    if !quiet {
+        println!(
+            "{} Exported {} environment variable(s) to {}",
+            style("Success:").green().bold(),
+            config.container_env.len(),
+            path.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Parse .env file contents into key/value pairs
+///
+/// Skips blank lines and `#` comments, accepts an optional `export ` prefix,
+/// and strips one pair of matching quotes around the value (see [`unquote`]).
+/// Later duplicates win, as they would when sourced by a shell.
+fn parse_env_file(contents: &str) -> Result<Vec<(String, String)>> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+
+    for (index, raw) in contents.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+
+        let Some((key, value)) = line.split_once('=') else {
+            bail!(
+                "Line {}: expected KEY=value, got '{}'",
+                index + 1,
+                raw.trim()
+            );
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            bail!(
+                "Line {}: environment variable key cannot be empty",
+                index + 1
+            );
+        }
+        if key.chars().any(char::is_whitespace) {
+            bail!("Line {}: key '{key}' contains whitespace", index + 1);
+        }
+
+        let value = unquote(value.trim());
+        entries.retain(|(existing, _)| existing != key);
+        entries.push((key.to_string(), value));
+    }
+
+    Ok(entries)
+}
+
+/// Strip one pair of matching quotes, unescaping `\"`, `\\`, `\n` and `\r`
+/// inside double quotes; single-quoted values are taken literally
+fn unquote(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        return value[1..value.len() - 1].to_string();
+    }
+    if !(value.len() >= 2 && value.starts_with('"') && value.ends_with('"')) {
+        return value.to_string();
+    }
+
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value[1..value.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(escaped @ ('"' | '\\')) => unescaped.push(escaped),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Double-quote a value when it would not survive a .env round trip bare
+fn quote_env_value(value: &str) -> String {
+    let needs_quotes = value != value.trim()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '#' | '"' | '\'' | '\\'));
+    if !needs_quotes {
+        return value.to_string();
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Replace any existing entry for `key`, keeping the rest in order
+fn merge_env_entry(env: &mut Vec<String>, key: &str, value: &str) {
+    let key_prefix = format!("{key}=");
+    env.retain(|e| !e.starts_with(&key_prefix));
+    env.push(format!("{key}={value}"));
+}
+
+/// Render config entries as .env lines, quoting values that need it
+fn format_env_file(env: &[String]) -> String {
+    env.iter()
+        .map(|entry| match entry.split_once('=') {
+            Some((key, value)) => format!("{key}={}\n", quote_env_value(value)),
+            None => format!("{entry}\n"),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_key_extraction_from_env_var() {
         let env_var = "FOO=bar";
@@ -156,4 +410,63 @@ mod tests {
         assert_eq!(remaining.len(), 1);
         assert_eq!(remaining[0], "BAR=baz");
     }
+
+    #[test]
+    fn parse_env_file_skips_comments_and_blanks() {
+        let contents = "# comment\n\nFOO=bar\n  export BAZ = \"qux quux\"\nURL=a=b\n";
+        let entries = parse_env_file(contents).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("FOO".to_string(), "bar".to_string()),
+                ("BAZ".to_string(), "qux quux".to_string()),
+                ("URL".to_string(), "a=b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_env_file_reports_line_numbers() {
+        let err = parse_env_file("FOO=bar\nnot-a-pair\n").unwrap_err();
+        assert!(err.to_string().contains("Line 2"));
+        let err = parse_env_file("=value\n").unwrap_err();
+        assert!(err.to_string().contains("Line 1"));
+    }
+
+    #[test]
+    fn parse_env_file_last_duplicate_wins() {
+        let entries = parse_env_file("FOO=1\nFOO='2'\n").unwrap();
+        assert_eq!(entries, vec![("FOO".to_string(), "2".to_string())]);
+    }
+
+    #[test]
+    fn merge_and_export_round_trip() {
+        let mut env = vec!["FOO=old".to_string(), "BAR=keep".to_string()];
+        merge_env_entry(&mut env, "FOO", "new");
+        assert_eq!(env, vec!["BAR=keep", "FOO=new"]);
+
+        let exported = format_env_file(&env);
+        assert_eq!(exported, "BAR=keep\nFOO=new\n");
+        let parsed = parse_env_file(&exported).unwrap();
+        assert_eq!(parsed.len(), 2);
+    }
+
+    #[test]
+    fn export_quotes_values_that_need_it_and_round_trips() {
+        let value = "two words # not a comment \"quoted\" C:\\dir\nINJECTED=1";
+        let env = vec![format!("TRICKY={value}"), "PLAIN=ok".to_string()];
+
+        let exported = format_env_file(&env);
+        assert_eq!(exported.lines().count(), 2);
+        assert!(exported.ends_with("PLAIN=ok\n"));
+
+        let parsed = parse_env_file(&exported).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                ("TRICKY".to_string(), value.to_string()),
+                ("PLAIN".to_string(), "ok".to_string()),
+            ]
+        );
+    }
 }
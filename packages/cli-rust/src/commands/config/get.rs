@@ -1,25 +1,45 @@
 //! Config get subcommand
 //!
-//! Retrieves a single configuration value by key.
+//! Retrieves a single configuration value by key, or by a dotted path such
+//! as `mounts.0` walked through the serialized config.
 
+use super::show::config_display_json;
 use anyhow::{Result, bail};
 use opencode_cloud_core::Config;
+use serde_json::Value;
 
 /// Get a single configuration value
 ///
 /// Outputs just the value (no formatting) for scripting.
 /// Passwords are always masked for security.
 pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
+    if key.contains('.') {
+        let root = config_display_json(config)?;
+        let leaf = lookup_path(&root, key)?;
+        println!("{}", format_leaf(leaf)?);
+        return Ok(());
+    }
+
     // Normalize key (support both short and full forms)
     let value = match key.to_lowercase().as_str() {
         "version" => config.version.to_string(),
         "port" | "opencode_web_port" => config.opencode_web_port.to_string(),
-        "bind" | "hostname" => config.bind.clone(),
-        "bind_address" | "host" => config.bind_address.clone(),
+        "bind_address" | "host" | "bind" | "hostname" => config.bind_address.clone(),
         "auto_restart" => config.auto_restart.to_string(),
         "boot_mode" => config.boot_mode.clone(),
+        "image_source" => config.image_source.clone(),
+        "platform" => format_optional(&config.platform),
+        "build_args" => serde_json::to_string(&config.build_args)?,
+        "container_labels" | "labels" => serde_json::to_string(&config.container_labels)?,
+        "check_opencode_updates" => config.check_opencode_updates.to_string(),
+        "build_log_tail" => config.build_log_tail.to_string(),
+        "build_error_tail" => config.build_error_tail.to_string(),
+        "pull_retries" => config.pull_retries.to_string(),
+        "rollback_depth" => config.rollback_depth.to_string(),
+        "pull_retry_delay" => config.pull_retry_delay.to_string(),
         "restart_retries" => config.restart_retries.to_string(),
         "restart_delay" => config.restart_delay.to_string(),
+        "stop_timeout_seconds" | "stop_timeout" => config.stop_timeout_seconds.to_string(),
         "username" | "auth_username" => format_optional(&config.auth_username),
         "password" | "auth_password" => {
             // Never reveal actual password
@@ -47,20 +67,38 @@ pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
                 config.users.join(",")
             }
         }
+        "mounts" => {
+            if config.mounts.is_empty() {
+                "(none)".to_string()
+            } else {
+                config.mounts.join(",")
+            }
+        }
         "cockpit_enabled" | "cockpit" => config.cockpit_enabled.to_string(),
         "cockpit_port" => config.cockpit_port.to_string(),
+        "container_prefix" => format_optional(&config.container_prefix),
+        "sandbox_instance" => format_optional(&config.sandbox_instance),
         _ => {
             bail!(
                 "Unknown configuration key: {key}\n\n\
                 Valid keys:\n  \
                   version\n  \
                   port / opencode_web_port\n  \
-                  bind / hostname\n  \
-                  bind_address / host\n  \
+                  bind_address / host / bind / hostname\n  \
                   auto_restart\n  \
                   boot_mode\n  \
+                  image_source\n  \
+                  platform\n  \
+                  build_args\n  \
+                  check_opencode_updates\n  \
+                  build_log_tail\n  \
+                  build_error_tail\n  \
+                  pull_retries\n  \
+                  rollback_depth\n  \
+                  pull_retry_delay\n  \
                   restart_retries\n  \
                   restart_delay\n  \
+                  stop_timeout_seconds / stop_timeout\n  \
                   username / auth_username\n  \
                   password / auth_password\n  \
                   env / container_env\n  \
@@ -68,7 +106,11 @@ pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
                   allow_unauthenticated_network / allow_unauth\n  \
                   rate_limit_attempts / rate_attempts\n  \
                   rate_limit_window_seconds / rate_window\n  \
-                  users"
+                  users\n  \
+                  mounts\n  \
+                  container_prefix\n  \
+                  sandbox_instance\n  \
+                  container_labels / labels"
             );
         }
     };
@@ -77,6 +119,93 @@ pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
     Ok(())
 }
 
+/// Walk a dotted path (`mounts.0`, `rate_limit.attempts`) through a JSON value
+///
+/// Segments index objects by key and arrays by position. When a segment is
+/// not a key, it is joined with the following segments by `_`, so
+/// `rate_limit.attempts` also reaches the flat `rate_limit_attempts` field.
+/// Failing that, the rest of the path is tried as one key, which reaches
+/// dotted map keys like `container_labels.com.example.team`.
+fn lookup_path<'a>(root: &'a Value, path: &str) -> Result<&'a Value> {
+    let segments: Vec<&str> = path.split('.').collect();
+    if segments.iter().any(|s| s.is_empty()) {
+        bail!("Invalid configuration path: {path}");
+    }
+
+    let mut current = root;
+    let mut i = 0;
+    while i < segments.len() {
+        let walked = segments[..i].join(".");
+        match current {
+            Value::Object(map) => {
+                let found = map
+                    .get(segments[i])
+                    .map(|value| (value, i + 1))
+                    .or_else(|| {
+                        (i + 1..=segments.len()).find_map(|end| {
+                            let key = segments[i..end].join("_").to_lowercase();
+                            map.get(&key).map(|value| (value, end))
+                        })
+                    })
+                    .or_else(|| {
+                        // Map keys such as labels may contain dots themselves
+                        let key = segments[i..].join(".");
+                        map.get(&key).map(|value| (value, segments.len()))
+                    });
+                let Some((value, end)) = found else {
+                    let keys: Vec<&str> = map.keys().map(String::as_str).collect();
+                    bail!(
+                        "Unknown configuration path: {path}\n\n\
+                         Valid keys{}:\n  {}",
+                        location_suffix(&walked),
+                        keys.join("\n  ")
+                    );
+                };
+                current = value;
+                i = end;
+            }
+            Value::Array(items) => {
+                let index: usize = segments[i].parse().map_err(|_| {
+                    anyhow::anyhow!(
+                        "Unknown configuration path: {path}\n\n\
+                         '{walked}' is a list; use an index from 0 to {}",
+                        items.len().saturating_sub(1)
+                    )
+                })?;
+                let Some(value) = items.get(index) else {
+                    bail!(
+                        "Unknown configuration path: {path}\n\n\
+                         '{walked}' has {} item(s)",
+                        items.len()
+                    );
+                };
+                current = value;
+                i += 1;
+            }
+            _ => bail!("Unknown configuration path: {path}\n\n'{walked}' is not a list or object"),
+        }
+    }
+    Ok(current)
+}
+
+fn location_suffix(walked: &str) -> String {
+    if walked.is_empty() {
+        String::new()
+    } else {
+        format!(" under '{walked}'")
+    }
+}
+
+/// Scalars print bare; lists and objects print as compact JSON
+pub(super) fn format_leaf(value: &Value) -> Result<String> {
+    Ok(match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(_) | Value::Object(_) => serde_json::to_string(value)?,
+    })
+}
+
 /// Format an optional string, returning empty string if None
 fn format_optional(value: &Option<String>) -> String {
     value.clone().unwrap_or_default()
@@ -86,6 +215,61 @@ fn format_optional(value: &Option<String>) -> String {
 mod tests {
     use super::*;
 
+    fn sample() -> Value {
+        serde_json::json!({
+            "rate_limit_attempts": 5,
+            "mounts": ["/a:/b", "/c:/d"],
+            "users": [],
+            "auth_username": null,
+            "container_labels": {
+                "com.example.team": "web",
+                "Owner_Name": "ops"
+            }
+        })
+    }
+
+    #[test]
+    fn test_lookup_path_indexes_arrays() {
+        let root = sample();
+        assert_eq!(lookup_path(&root, "mounts.1").unwrap(), "/c:/d");
+        assert!(lookup_path(&root, "mounts.2").is_err());
+        assert!(lookup_path(&root, "mounts.x").is_err());
+    }
+
+    #[test]
+    fn test_lookup_path_joins_segments_for_flat_keys() {
+        let root = sample();
+        assert_eq!(lookup_path(&root, "rate_limit.attempts").unwrap(), 5);
+    }
+
+    #[test]
+    fn test_lookup_path_reaches_dotted_map_keys() {
+        let root = sample();
+        assert_eq!(
+            lookup_path(&root, "container_labels.com.example.team").unwrap(),
+            "web"
+        );
+        assert_eq!(
+            lookup_path(&root, "container_labels.Owner_Name").unwrap(),
+            "ops"
+        );
+        assert!(lookup_path(&root, "container_labels.com.example").is_err());
+    }
+
+    #[test]
+    fn test_lookup_path_unknown_key_lists_valid_keys() {
+        let err = lookup_path(&sample(), "rate_limit.nope").unwrap_err();
+        assert!(err.to_string().contains("rate_limit_attempts"));
+    }
+
+    #[test]
+    fn test_format_leaf() {
+        assert_eq!(format_leaf(&serde_json::json!("x")).unwrap(), "x");
+        assert_eq!(format_leaf(&serde_json::json!(true)).unwrap(), "true");
+        assert_eq!(format_leaf(&Value::Null).unwrap(), "");
+        assert_eq!(format_leaf(&serde_json::json!(["a"])).unwrap(), r#"["a"]"#);
+    }
+
     #[test]
     fn test_format_optional_with_value() {
         assert_eq!(format_optional(&Some("test".to_string())), "test");
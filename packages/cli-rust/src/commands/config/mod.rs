@@ -2,6 +2,8 @@
 //!
 //! Provides `occ config` subcommands for viewing and managing configuration.
 
+mod edit;
+mod effective;
 mod env;
 mod get;
 mod reset;
@@ -11,12 +13,16 @@ mod show;
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use opencode_cloud_core::Config;
+use tokio::runtime::Runtime;
 
+pub use edit::cmd_config_edit;
+pub use effective::{ResolvedContext, cmd_config_effective};
 pub use env::{EnvCommands, cmd_config_env};
 pub use get::cmd_config_get;
 pub use reset::cmd_config_reset;
 pub use set::cmd_config_set;
-pub use show::cmd_config_show;
+pub(crate) use show::config_display_json;
+pub use show::{Redaction, cmd_config_show};
 
 /// Configuration command arguments
 #[derive(Args)]
@@ -29,6 +35,17 @@ pub struct ConfigArgs {
     command: Option<ConfigSubcommands>,
 }
 
+impl ConfigArgs {
+    /// Whether this invocation prints JSON (`occ config --json` or `config show --json`)
+    pub(crate) fn json_output(&self) -> bool {
+        self.json
+            || matches!(
+                self.command,
+                Some(ConfigSubcommands::Show { json: true, .. })
+            )
+    }
+}
+
 /// Configuration management subcommands
 #[derive(Subcommand)]
 pub enum ConfigSubcommands {
@@ -37,24 +54,42 @@ pub enum ConfigSubcommands {
         /// Output as JSON instead of table format
         #[arg(long)]
         json: bool,
+
+        /// Also show the resolved target host, runtime mode, and sandbox resource names
+        #[arg(long)]
+        effective: bool,
+
+        /// Print secrets such as auth_password instead of masking them
+        #[arg(long, conflicts_with = "redact_all")]
+        show_secrets: bool,
+
+        /// Also mask usernames, bind addresses, and container env values (safe to paste publicly)
+        #[arg(long)]
+        redact_all: bool,
     },
     /// Get a single configuration value
     Get {
-        /// Configuration key (e.g., "port", "auth_username", "bind")
+        /// Configuration key (e.g., "port", "auth_username", "bind_address")
         key: String,
     },
     /// Set a configuration value
     Set {
         /// Configuration key to set (e.g., "port", "username", "password")
         key: String,
-        /// Value to set (omit for password to prompt securely)
+        /// Value to set (omit for password to prompt securely; +item/-item for lists)
+        #[arg(allow_hyphen_values = true)]
         value: Option<String>,
         /// Skip confirmation prompts (use with care)
         #[arg(long)]
         force: bool,
     },
-    /// Reset configuration to defaults
+    /// Open the config file in $VISUAL or $EDITOR, saving only if it validates
+    Edit,
+    /// Reset configuration to defaults, or a single key
     Reset {
+        /// Configuration key to reset (e.g., "port"); resets everything when omitted
+        key: Option<String>,
+
         /// Skip confirmation prompt
         #[arg(long, short)]
         force: bool,
@@ -68,18 +103,56 @@ pub enum ConfigSubcommands {
 ///
 /// Routes to the appropriate handler based on the subcommand.
 /// If no subcommand is given, defaults to Show.
-pub fn cmd_config(args: ConfigArgs, config: &Config, quiet: bool) -> Result<()> {
+pub fn cmd_config(
+    args: ConfigArgs,
+    config: &Config,
+    maybe_host: Option<&str>,
+    context: &ResolvedContext,
+    rt: &Runtime,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
     match args.command {
-        Some(ConfigSubcommands::Show { json }) => cmd_config_show(config, json, quiet),
+        Some(ConfigSubcommands::Show {
+            json,
+            effective: true,
+            ..
+        }) => cmd_config_effective(config, context, json),
+        Some(ConfigSubcommands::Show {
+            json,
+            show_secrets,
+            redact_all,
+            ..
+        }) => {
+            let redaction = if show_secrets {
+                Redaction::None
+            } else if redact_all {
+                Redaction::All
+            } else {
+                Redaction::Secrets
+            };
+            cmd_config_show(config, json, redaction, quiet)
+        }
         Some(ConfigSubcommands::Get { key }) => cmd_config_get(config, &key, quiet),
-        Some(ConfigSubcommands::Set { key, value, force }) => {
-            cmd_config_set(&key, value.as_deref(), quiet, force)
+        Some(ConfigSubcommands::Set { key, value, force }) => cmd_config_set(
+            rt,
+            maybe_host,
+            &key,
+            value.as_deref(),
+            force,
+            quiet,
+            verbose,
+        ),
+        Some(ConfigSubcommands::Edit) => cmd_config_edit(rt, maybe_host, quiet),
+        Some(ConfigSubcommands::Reset { key, force }) => {
+            cmd_config_reset(key.as_deref(), force, quiet)
+        }
+        Some(ConfigSubcommands::Env(env_cmd)) => {
+            cmd_config_env(rt, env_cmd, maybe_host, quiet, verbose)
         }
-        Some(ConfigSubcommands::Reset { force }) => cmd_config_reset(force, quiet),
-        Some(ConfigSubcommands::Env(env_cmd)) => cmd_config_env(env_cmd, quiet),
         None => {
             // Default to show when no subcommand given
-            cmd_config_show(config, args.json, quiet)
+            cmd_config_show(config, args.json, Redaction::Secrets, quiet)
         }
     }
 }
@@ -1,16 +1,24 @@
 //! Config reset subcommand
 //!
-//! Resets configuration to default values.
+//! Resets configuration to default values, either entirely or one key at a time.
 
-use anyhow::Result;
+use super::get::format_leaf;
+use anyhow::{Result, bail};
 use console::style;
 use dialoguer::Confirm;
-use opencode_cloud_core::{Config, save_config};
+use opencode_cloud_core::{Config, load_config_or_default, save_config};
+use serde_json::Value;
 
 /// Reset configuration to defaults
 ///
-/// Prompts for confirmation unless --force is specified.
-pub fn cmd_config_reset(force: bool, quiet: bool) -> Result<()> {
+/// With a key, only that field is restored from `Config::default()` and no
+/// confirmation is asked. Without one, the whole config is replaced, which
+/// prompts for confirmation unless --force is specified.
+pub fn cmd_config_reset(key: Option<&str>, force: bool, quiet: bool) -> Result<()> {
+    if let Some(key) = key {
+        return reset_key(key, quiet);
+    }
+
     // Prompt for confirmation unless forced
     if !force {
         let confirmed = Confirm::new()
@@ -39,3 +47,112 @@ pub fn cmd_config_reset(force: bool, quiet: bool) -> Result<()> {
 
     Ok(())
 }
+
+fn reset_key(key: &str, quiet: bool) -> Result<()> {
+    let field = resolve_field(key)?;
+    let config = load_config_or_default()?;
+    let updated = reset_field(&config, &field)?;
+    save_config(&updated)?;
+
+    if !quiet {
+        println!(
+            "{} Reset {}: {} → {}",
+            style("Success:").green().bold(),
+            field,
+            display_field(&config, &field)?,
+            style(display_field(&updated, &field)?).cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// Map a key or one of its `occ config get/set` aliases to the config field name
+fn resolve_field(key: &str) -> Result<String> {
+    let normalized = key.to_lowercase();
+    let field = match normalized.as_str() {
+        "port" => "opencode_web_port",
+        "host" | "hostname" | "bind" => "bind_address",
+        "username" => "auth_username",
+        "password" => "auth_password",
+        "env" => "container_env",
+        "proxy" => "trust_proxy",
+        "allow_unauth" | "unauth_network" => "allow_unauthenticated_network",
+        "rate_attempts" => "rate_limit_attempts",
+        "rate_window" | "rate_limit_window" => "rate_limit_window_seconds",
+        "stop_timeout" => "stop_timeout_seconds",
+        "cockpit" => "cockpit_enabled",
+        other => other,
+    };
+
+    let fields = resettable_fields()?;
+    if !fields.iter().any(|f| f == field) {
+        bail!(
+            "Unknown configuration key: {key}\n\nValid keys:\n  {}",
+            fields.join("\n  ")
+        );
+    }
+    Ok(field.to_string())
+}
+
+/// Every config field except the schema version
+fn resettable_fields() -> Result<Vec<String>> {
+    let Value::Object(defaults) = serde_json::to_value(Config::default())? else {
+        bail!("Config should serialize to an object");
+    };
+    Ok(defaults
+        .into_iter()
+        .map(|(field, _)| field)
+        .filter(|field| field != "version")
+        .collect())
+}
+
+/// Copy of `config` with `field` restored to its default value
+fn reset_field(config: &Config, field: &str) -> Result<Config> {
+    let mut value = serde_json::to_value(config)?;
+    let defaults = serde_json::to_value(Config::default())?;
+    value[field] = defaults[field].clone();
+    Ok(serde_json::from_value(value)?)
+}
+
+fn display_field(config: &Config, field: &str) -> Result<String> {
+    let value = serde_json::to_value(config)?;
+    let value = &value[field];
+    if field == "auth_password" && !value.is_null() {
+        return Ok("********".to_string());
+    }
+    let display = format_leaf(value)?;
+    Ok(if display.is_empty() {
+        "(none)".to_string()
+    } else {
+        display
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_field_accepts_aliases_and_rejects_unknown_keys() {
+        assert_eq!(resolve_field("PORT").unwrap(), "opencode_web_port");
+        assert_eq!(resolve_field("build_args").unwrap(), "build_args");
+        assert!(resolve_field("version").is_err());
+        assert!(resolve_field("nope").is_err());
+    }
+
+    #[test]
+    fn reset_field_only_touches_that_field() {
+        let config = Config {
+            opencode_web_port: 4000,
+            restart_retries: 9,
+            ..Config::default()
+        };
+        let updated = reset_field(&config, "opencode_web_port").unwrap();
+        assert_eq!(
+            updated.opencode_web_port,
+            Config::default().opencode_web_port
+        );
+        assert_eq!(updated.restart_retries, 9);
+    }
+}
@@ -1,21 +1,43 @@
 //! Config set subcommand
 //!
-//! Sets a single configuration value.
+//! Sets a single configuration value. List keys (`users`, `mounts`) accept
+//! `+item` to add, `-item` to remove, or a comma-separated list to replace.
 
+use crate::commands::{RestartArgs, cmd_restart};
+use crate::confirm::{confirm, skip_confirmation};
+use crate::error::CliError;
 use anyhow::{Result, bail};
 use console::style;
 use dialoguer::{Confirm, Password};
-use opencode_cloud_core::config::validate_bind_address;
-use opencode_cloud_core::docker::{CONTAINER_NAME, DockerClient, container_is_running};
-use opencode_cloud_core::{load_config_or_default, save_config};
+use opencode_cloud_core::config::{
+    validate_bind_address, validate_build_arg_key, validate_container_label_key,
+};
+use opencode_cloud_core::docker::{
+    BUILD_LOG_TAIL_RANGE, CONTAINER_NAME, PULL_RETRIES_RANGE, PULL_RETRY_DELAY_RANGE, ParsedMount,
+    ROLLBACK_DEPTH_RANGE, container_is_running, validate_container_prefix, validate_platform,
+};
+use opencode_cloud_core::{Config, load_config_or_default, save_config};
+use std::collections::{BTreeMap, HashSet};
+use std::io::IsTerminal;
+use tokio::runtime::Runtime;
 
 /// Set a configuration value
 ///
 /// Special handling for password: prompts interactively if value is None.
 /// Returns error if password value is provided on command line (security risk).
-pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool) -> Result<()> {
+pub fn cmd_config_set(
+    rt: &Runtime,
+    maybe_host: Option<&str>,
+    key: &str,
+    value: Option<&str>,
+    force: bool,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
     let mut config = load_config_or_default()?;
     let normalized_key = key.to_lowercase();
+    let was_open_without_auth = open_without_auth(&config);
+    let previous_binding = ServiceBinding::of(&config);
 
     // Display value for output (password is masked)
     let display_value: String;
@@ -49,25 +71,26 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
             display_value = port.to_string();
         }
 
-        "bind" | "hostname" => {
-            let val = require_value(value, key)?;
-            config.bind = val.to_string();
-            display_value = val.to_string();
-        }
-
-        "bind_address" | "host" => {
+        "bind_address" | "host" | "bind" | "hostname" => {
             let val = require_value(value, key)?;
 
             // Validate the address
-            validate_bind_address(val).map_err(|_| {
+            let ip = validate_bind_address(val).map_err(|_| {
                 anyhow::anyhow!(
                     "Invalid address: {val}\n\
-                     Valid examples: 127.0.0.1, ::1, 0.0.0.0, ::, or localhost"
+                     Valid examples: 127.0.0.1, ::1, 0.0.0.0, ::, fd00::10, or localhost"
                 )
             })?;
 
+            // Store IPv6 literals without brackets; they are added back for URLs
+            let val = if ip.is_ipv6() {
+                ip.to_string()
+            } else {
+                val.to_string()
+            };
+
             // Check for network exposure and show warning
-            if val == "0.0.0.0" || val == "::" {
+            if ip.is_unspecified() {
                 eprintln!();
                 eprintln!(
                     "{} {}",
@@ -77,7 +100,7 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
                 eprintln!();
                 eprintln!(
                     "Binding to {} exposes the service to all network interfaces.",
-                    style(val).cyan()
+                    style(&val).cyan()
                 );
                 eprintln!("Anyone on your network can access the opencode web UI.");
                 eprintln!();
@@ -91,10 +114,19 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
                     style("occ config set bind_address localhost").cyan()
                 );
                 eprintln!();
+            } else if ip.is_ipv6() && !ip.is_loopback() {
+                eprintln!();
+                eprintln!(
+                    "{} Binding to {} exposes the service to the network reachable from that address.",
+                    style("WARNING:").yellow().bold(),
+                    style(&val).cyan()
+                );
+                eprintln!("  - Ensure strong authentication is configured (occ user add)");
+                eprintln!();
             }
 
-            config.bind_address = val.to_string();
-            display_value = val.to_string();
+            display_value = val.clone();
+            config.bind_address = val;
         }
 
         "username" | "auth_username" => {
@@ -131,6 +163,78 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
             display_value = retries.to_string();
         }
 
+        "build_log_tail" | "build_error_tail" => {
+            let val = require_value(value, key)?;
+            let lines: usize = val
+                .parse()
+                .ok()
+                .filter(|lines| BUILD_LOG_TAIL_RANGE.contains(lines))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Invalid {normalized_key}: {val}. Must be a number between {} and {}.",
+                        BUILD_LOG_TAIL_RANGE.start(),
+                        BUILD_LOG_TAIL_RANGE.end()
+                    )
+                })?;
+            if normalized_key == "build_log_tail" {
+                config.build_log_tail = lines;
+            } else {
+                config.build_error_tail = lines;
+            }
+            display_value = lines.to_string();
+        }
+
+        "pull_retries" => {
+            let val = require_value(value, key)?;
+            let retries: u32 = val
+                .parse()
+                .ok()
+                .filter(|retries| PULL_RETRIES_RANGE.contains(retries))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Invalid pull_retries: {val}. Must be a number between {} and {}.",
+                        PULL_RETRIES_RANGE.start(),
+                        PULL_RETRIES_RANGE.end()
+                    )
+                })?;
+            config.pull_retries = retries;
+            display_value = retries.to_string();
+        }
+
+        "rollback_depth" => {
+            let val = require_value(value, key)?;
+            let depth: usize = val
+                .parse()
+                .ok()
+                .filter(|depth| ROLLBACK_DEPTH_RANGE.contains(depth))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Invalid rollback_depth: {val}. Must be a number between {} and {}.",
+                        ROLLBACK_DEPTH_RANGE.start(),
+                        ROLLBACK_DEPTH_RANGE.end()
+                    )
+                })?;
+            config.rollback_depth = depth;
+            display_value = depth.to_string();
+        }
+
+        "pull_retry_delay" => {
+            let val = require_value(value, key)?;
+            let delay: u64 = val
+                .parse()
+                .ok()
+                .filter(|delay| PULL_RETRY_DELAY_RANGE.contains(delay))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Invalid pull_retry_delay: {val}. Must be between {} and {} seconds.",
+                        PULL_RETRY_DELAY_RANGE.start(),
+                        PULL_RETRY_DELAY_RANGE.end()
+                    )
+                })?;
+            config.pull_retry_delay = delay;
+            display_value = delay.to_string();
+        }
+
         "restart_delay" => {
             let val = require_value(value, key)?;
             let delay: u32 = val.parse().map_err(|_| {
@@ -142,6 +246,20 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
             display_value = delay.to_string();
         }
 
+        "stop_timeout_seconds" | "stop_timeout" => {
+            let val = require_value(value, key)?;
+            let timeout: u32 = val.parse().map_err(|_| {
+                anyhow::anyhow!(
+                    "Invalid stop_timeout_seconds: {val}. Must be a positive integer (seconds)."
+                )
+            })?;
+            if timeout == 0 {
+                bail!("Stop timeout must be at least 1 second");
+            }
+            config.stop_timeout_seconds = timeout;
+            display_value = timeout.to_string();
+        }
+
         "trust_proxy" | "proxy" => {
             let val = require_value(value, key)?;
             let trust = parse_bool(val).ok_or_else(|| {
@@ -233,6 +351,18 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
                     println!("This means ANYONE on your network can access the opencode web UI");
                     println!("without logging in.");
                     println!();
+                    if config.is_network_exposed() {
+                        println!(
+                            "{}",
+                            style(format!(
+                                "bind_address is {}, so the service is reachable from the network right now.",
+                                config.bind_address
+                            ))
+                            .red()
+                            .bold()
+                        );
+                        println!();
+                    }
                     println!("This is typically only appropriate for:");
                     println!("  - Development environments on trusted networks");
                     println!("  - Services behind an authenticating reverse proxy");
@@ -303,6 +433,32 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
             display_value = enabled.to_string();
         }
 
+        "users" => {
+            let val = require_value(value, key)?;
+            let users = apply_list_edit(&config.users, parse_list_edit(val), |entry, item| {
+                entry == item
+            })?;
+            validate_users_list(&users)?;
+            config.users = users;
+            display_value = format_list(&config.users);
+
+            if !quiet {
+                println!(
+                    "{} This edits the tracked user list only. Use {} to create accounts.",
+                    style("Note:").dim(),
+                    style("occ user add").cyan()
+                );
+            }
+        }
+
+        "mounts" => {
+            let val = require_value(value, key)?;
+            let mounts = apply_list_edit(&config.mounts, parse_list_edit(val), mount_matches_item)?;
+            validate_mounts_list(&mounts)?;
+            config.mounts = mounts;
+            display_value = format_list(&config.mounts);
+        }
+
         "cockpit_port" => {
             let val = require_value(value, key)?;
             let port: u16 = val.parse().map_err(|_| {
@@ -312,33 +468,176 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
             display_value = port.to_string();
         }
 
+        "build_args" => {
+            let val = require_value(value, key)?;
+            config.build_args = apply_build_args_edit(&config.build_args, parse_list_edit(val))?;
+            display_value = format_key_values(&config.build_args);
+        }
+
+        "container_labels" | "labels" => {
+            let val = require_value(value, key)?;
+            config.container_labels =
+                apply_container_labels_edit(&config.container_labels, parse_list_edit(val))?;
+            display_value = format_key_values(&config.container_labels);
+        }
+
+        "check_opencode_updates" => {
+            let val = require_value(value, key)?;
+            let enabled = parse_bool(val).ok_or_else(|| {
+                anyhow::anyhow!("Invalid boolean value: {val}. Use: true/false, yes/no, or 1/0")
+            })?;
+            config.check_opencode_updates = enabled;
+            display_value = enabled.to_string();
+        }
+
+        "container_prefix" => {
+            let val = require_value(value, key)?.trim();
+            if !val.is_empty() {
+                validate_container_prefix(val).map_err(|msg| anyhow::anyhow!(msg))?;
+            }
+            // The running container keeps its old name, so later commands would lose track of it
+            if matches!(check_container_running(rt, maybe_host), Ok(true)) {
+                bail!(
+                    "Stop the service before changing container_prefix.\n\
+                     Run: occ stop, then set container_prefix and run occ start"
+                );
+            }
+            config.container_prefix = (!val.is_empty()).then(|| val.to_string());
+            display_value = config
+                .container_prefix
+                .clone()
+                .unwrap_or_else(|| "(default names)".to_string());
+        }
+
+        "platform" => {
+            let val = require_value(value, key)?.trim();
+            if !val.is_empty() {
+                validate_platform(val).map_err(|msg| anyhow::anyhow!(msg))?;
+            }
+            config.platform = (!val.is_empty()).then(|| val.to_string());
+            display_value = config
+                .platform
+                .clone()
+                .unwrap_or_else(|| "(daemon default)".to_string());
+        }
+
+        "sandbox_instance" => {
+            let val = require_value(value, key)?.trim().to_ascii_lowercase();
+            config.sandbox_instance = crate::sandbox_profile::persisted_selection(&val)?;
+            display_value = config
+                .sandbox_instance
+                .clone()
+                .unwrap_or_else(|| "(shared sandbox)".to_string());
+        }
+
+        "image_source" => {
+            let val = parse_image_source(require_value(value, key)?)?;
+            if !quiet {
+                print_image_source_guidance(val);
+            }
+            config.image_source = val.to_string();
+            display_value = val.to_string();
+        }
+
         _ => {
             bail!(
                 "Unknown configuration key: {key}\n\n\
                 Valid keys:\n  \
                   port / opencode_web_port\n  \
-                  bind / hostname\n  \
-                  bind_address / host\n  \
+                  bind_address / host / bind / hostname\n  \
                   username / auth_username\n  \
                   password / auth_password\n  \
                   auto_restart\n  \
                   boot_mode\n  \
+                  image_source\n  \
+                  platform\n  \
+                  build_args (+KEY=value, -KEY, or K=v,K2=v to replace)\n  \
+                  check_opencode_updates\n  \
+                  build_log_tail / build_error_tail\n  \
+                  pull_retries / pull_retry_delay\n  \
+                  rollback_depth\n  \
                   restart_retries\n  \
                   restart_delay\n  \
+                  stop_timeout_seconds / stop_timeout\n  \
                   trust_proxy / proxy\n  \
                   rate_limit_attempts / rate_attempts\n  \
                   rate_limit_window_seconds / rate_window\n  \
-                  allow_unauthenticated_network / allow_unauth\n\n\
+                  allow_unauthenticated_network / allow_unauth\n  \
+                  users (+name, -name, or a,b to replace)\n  \
+                  mounts (+spec, -spec, or a,b to replace)\n  \
+                  container_prefix ('' for the default names)\n  \
+                  sandbox_instance (name or auto; '' for the shared sandbox)\n  \
+                  container_labels / labels (+KEY=value, -KEY, or a=1,b=2 to replace)\n\n\
                 For environment variables, use: occ config env set KEY=value"
             );
         }
     }
 
+    // Guard against ending up network-exposed with no authentication,
+    // whichever key completed that combination
+    let is_allow_unauth_key = matches!(
+        normalized_key.as_str(),
+        "allow_unauthenticated_network" | "allow_unauth" | "unauth_network"
+    );
+    if !was_open_without_auth && open_without_auth(&config) {
+        // The allow_unauthenticated_network prompt already showed this unless forced
+        if force || !is_allow_unauth_key {
+            print_open_without_auth_warning(&config.bind_address);
+        }
+        if !skip_confirmation(force) && !is_allow_unauth_key {
+            let non_interactive = quiet || !std::io::stdin().is_terminal();
+            if non_interactive {
+                return Err(CliError::Failed(
+                    "Refusing to expose the service without authentication non-interactively.\n\
+                     Re-run with --force to save it anyway."
+                        .to_string(),
+                )
+                .into());
+            }
+            if !confirm("Save this configuration anyway?", false, non_interactive)? {
+                return Err(CliError::Failed("Aborted. Setting not changed.".to_string()).into());
+            }
+        }
+    }
+
     // Save the config
     save_config(&config)?;
 
-    // Check if service is running and warn
-    if !quiet && let Ok(true) = check_container_running() {
+    // Port and bind address are fixed when the container is created
+    let binding_changed = ServiceBinding::of(&config) != previous_binding;
+    if binding_changed
+        && !quiet
+        && let Ok(true) = check_container_running(rt, maybe_host)
+    {
+        offer_recreate_for_binding(
+            rt,
+            maybe_host,
+            &previous_binding,
+            &ServiceBinding::of(&config),
+            force,
+            verbose,
+        )?;
+    }
+
+    // Check if service is running and warn (image_source and platform apply on
+    // the next update and check_opencode_updates on the next status instead)
+    if !quiet
+        && !binding_changed
+        && !matches!(
+            normalized_key.as_str(),
+            "image_source"
+                | "check_opencode_updates"
+                | "container_prefix"
+                | "sandbox_instance"
+                | "platform"
+                | "build_log_tail"
+                | "build_error_tail"
+                | "pull_retries"
+                | "pull_retry_delay"
+                | "rollback_depth"
+        )
+        && let Ok(true) = check_container_running(rt, maybe_host)
+    {
         eprintln!(
             "{} Restart required for changes to take effect",
             style("Warning:").yellow().bold()
@@ -357,6 +656,158 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool, force: bool)
     Ok(())
 }
 
+/// Host-side settings baked into the container's port bindings
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ServiceBinding {
+    bind_address: String,
+    port: u16,
+    cockpit_port: u16,
+}
+
+impl ServiceBinding {
+    fn of(config: &Config) -> Self {
+        Self {
+            bind_address: config.bind_address.clone(),
+            port: config.opencode_web_port,
+            cockpit_port: config.cockpit_port,
+        }
+    }
+}
+
+/// Lines explaining that the running container still uses the old binding
+fn binding_change_lines(previous: &ServiceBinding, current: &ServiceBinding) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{} The running container still listens on {}:{}.",
+        style("Note:").yellow().bold(),
+        previous.bind_address,
+        previous.port
+    )];
+    lines.push(format!(
+        "{}:{} takes effect once the container is recreated (volumes are kept).",
+        current.bind_address, current.port
+    ));
+    if previous.cockpit_port != current.cockpit_port {
+        lines.push(format!(
+            "Cockpit moves from port {} to {} at the same time.",
+            previous.cockpit_port, current.cockpit_port
+        ));
+    }
+    lines
+}
+
+/// Offer to recreate the running container so a new port or bind address applies
+///
+/// Prompts only on a terminal; `--force` or `OPENCODE_CLOUD_ASSUME_YES` recreate
+/// without asking, and otherwise the exact command is printed.
+fn offer_recreate_for_binding(
+    rt: &Runtime,
+    maybe_host: Option<&str>,
+    previous: &ServiceBinding,
+    current: &ServiceBinding,
+    force: bool,
+    verbose: u8,
+) -> Result<()> {
+    eprintln!();
+    for line in binding_change_lines(previous, current) {
+        eprintln!("{line}");
+    }
+
+    let recreate = if skip_confirmation(force) {
+        true
+    } else if std::io::stdin().is_terminal() {
+        Confirm::new()
+            .with_prompt("Recreate the container now?")
+            .default(true)
+            .interact()?
+    } else {
+        false
+    };
+
+    if !recreate {
+        eprintln!(
+            "Run {} to apply it.",
+            style("occ restart --recreate").cyan()
+        );
+        eprintln!();
+        return Ok(());
+    }
+
+    let args = RestartArgs {
+        recreate: true,
+        ..Default::default()
+    };
+    // Only offered when not --quiet, so the restart shows its progress
+    rt.block_on(cmd_restart(&args, maybe_host, false, verbose))
+}
+
+/// Whether the config exposes the service to the network without requiring login
+fn open_without_auth(config: &Config) -> bool {
+    config.is_network_exposed() && config.allow_unauthenticated_network
+}
+
+fn print_open_without_auth_warning(bind_address: &str) {
+    eprintln!();
+    eprintln!(
+        "{}",
+        style("WARNING: NETWORK EXPOSED WITHOUT AUTHENTICATION")
+            .red()
+            .bold()
+    );
+    eprintln!();
+    eprintln!(
+        "bind_address is {} and allow_unauthenticated_network is true.",
+        style(bind_address).cyan()
+    );
+    eprintln!("Anyone who can reach this address can use the opencode web UI without logging in.");
+    eprintln!();
+    eprintln!(
+        "To require login: {}",
+        style("occ config set allow_unauthenticated_network false").cyan()
+    );
+    eprintln!(
+        "To bind to localhost only: {}",
+        style("occ config set bind_address localhost").cyan()
+    );
+    eprintln!();
+}
+
+/// Accepted values for `image_source`
+const IMAGE_SOURCES: &[&str] = &["prebuilt", "build"];
+
+fn parse_image_source(value: &str) -> Result<&'static str> {
+    let normalized = value.trim().to_lowercase();
+    IMAGE_SOURCES
+        .iter()
+        .find(|source| **source == normalized)
+        .copied()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid image_source: {value}. Must be one of: {}",
+                IMAGE_SOURCES.join(", ")
+            )
+        })
+}
+
+fn print_image_source_guidance(source: &str) {
+    let action = if source == "build" {
+        "build the image from source"
+    } else {
+        "pull the prebuilt image"
+    };
+    println!(
+        "{} The running container is unchanged. {} will {action}; {} does the same when no image exists yet.",
+        style("Note:").dim(),
+        style("occ update container").cyan(),
+        style("occ start").cyan()
+    );
+    if source == "build" {
+        eprintln!(
+            "{} Building requires Docker BuildKit and takes a while with plenty of CPU, memory, and disk.",
+            style("Warning:").yellow().bold()
+        );
+    }
+}
+
 /// Require a value for non-password keys
 fn require_value<'a>(value: Option<&'a str>, key: &str) -> Result<&'a str> {
     value.ok_or_else(|| {
@@ -384,6 +835,194 @@ fn validate_username(username: &str) -> Result<()> {
     Ok(())
 }
 
+/// Edit to a list-valued key
+#[derive(Debug, PartialEq, Eq)]
+enum ListEdit {
+    Add(Vec<String>),
+    Remove(Vec<String>),
+    Replace(Vec<String>),
+}
+
+/// Parse `+a`, `-a`, or `a,b` into a list edit
+///
+/// Empty input replaces the list with nothing.
+fn parse_list_edit(value: &str) -> ListEdit {
+    let trimmed = value.trim();
+    if let Some(rest) = trimmed.strip_prefix('+') {
+        ListEdit::Add(split_list(rest))
+    } else if let Some(rest) = trimmed.strip_prefix('-') {
+        ListEdit::Remove(split_list(rest))
+    } else {
+        ListEdit::Replace(split_list(trimmed))
+    }
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Apply a list edit, failing if a removed item is not present
+fn apply_list_edit(
+    current: &[String],
+    edit: ListEdit,
+    matches: impl Fn(&str, &str) -> bool,
+) -> Result<Vec<String>> {
+    match edit {
+        ListEdit::Add(items) => {
+            if items.is_empty() {
+                bail!("Nothing to add. Usage: occ config set <key> +value");
+            }
+            let mut updated = current.to_vec();
+            updated.extend(items);
+            Ok(updated)
+        }
+        ListEdit::Remove(items) => {
+            if items.is_empty() {
+                bail!("Nothing to remove. Usage: occ config set <key> -value");
+            }
+            let mut updated = current.to_vec();
+            for item in &items {
+                let before = updated.len();
+                updated.retain(|entry| !matches(entry, item));
+                if updated.len() == before {
+                    bail!("'{item}' is not in the list");
+                }
+            }
+            Ok(updated)
+        }
+        ListEdit::Replace(items) => Ok(items),
+    }
+}
+
+/// Apply a list edit to the build args map
+fn apply_build_args_edit(
+    current: &BTreeMap<String, String>,
+    edit: ListEdit,
+) -> Result<BTreeMap<String, String>> {
+    apply_map_edit(
+        current,
+        edit,
+        "build_args",
+        "build arg",
+        validate_build_arg_key,
+    )
+}
+
+/// Apply a list edit to the container labels map
+fn apply_container_labels_edit(
+    current: &BTreeMap<String, String>,
+    edit: ListEdit,
+) -> Result<BTreeMap<String, String>> {
+    apply_map_edit(
+        current,
+        edit,
+        "container_labels",
+        "container label",
+        validate_container_label_key,
+    )
+}
+
+/// Apply a list edit to a `KEY=value` map setting
+///
+/// Added and replacing items are `KEY=value`; removed items are keys.
+fn apply_map_edit(
+    current: &BTreeMap<String, String>,
+    edit: ListEdit,
+    setting: &str,
+    item_label: &str,
+    validate_key: fn(&str) -> Result<(), String>,
+) -> Result<BTreeMap<String, String>> {
+    let parse_entry = |item: &str| -> Result<(String, String)> {
+        let Some((key, value)) = item.split_once('=') else {
+            bail!("Invalid {item_label}: {item}. Use KEY=value");
+        };
+        let key = key.trim();
+        validate_key(key).map_err(|msg| anyhow::anyhow!(msg))?;
+        Ok((key.to_string(), value.trim().to_string()))
+    };
+
+    match edit {
+        ListEdit::Add(items) => {
+            if items.is_empty() {
+                bail!("Nothing to add. Usage: occ config set {setting} +KEY=value");
+            }
+            let mut updated = current.clone();
+            for item in &items {
+                let (key, value) = parse_entry(item)?;
+                updated.insert(key, value);
+            }
+            Ok(updated)
+        }
+        ListEdit::Remove(keys) => {
+            if keys.is_empty() {
+                bail!("Nothing to remove. Usage: occ config set {setting} -KEY");
+            }
+            let mut updated = current.clone();
+            for key in &keys {
+                if updated.remove(key).is_none() {
+                    bail!("'{key}' is not a configured {item_label}");
+                }
+            }
+            Ok(updated)
+        }
+        ListEdit::Replace(items) => items.iter().map(|item| parse_entry(item)).collect(),
+    }
+}
+
+fn format_key_values(entries: &BTreeMap<String, String>) -> String {
+    if entries.is_empty() {
+        return "(none)".to_string();
+    }
+    entries
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Match a configured mount by its full spec or its container path
+fn mount_matches_item(entry: &str, item: &str) -> bool {
+    entry == item || ParsedMount::parse(entry).is_ok_and(|parsed| parsed.container_path == item)
+}
+
+fn validate_users_list(users: &[String]) -> Result<()> {
+    let mut seen = HashSet::new();
+    for user in users {
+        validate_username(user)?;
+        if !seen.insert(user.as_str()) {
+            bail!("Duplicate user: {user}");
+        }
+    }
+    Ok(())
+}
+
+fn validate_mounts_list(mounts: &[String]) -> Result<()> {
+    let mut targets = HashSet::new();
+    for mount in mounts {
+        let parsed = ParsedMount::parse(mount)?;
+        if !targets.insert(parsed.container_path.clone()) {
+            bail!(
+                "Duplicate mount target: {} (from {mount})",
+                parsed.container_path
+            );
+        }
+    }
+    Ok(())
+}
+
+fn format_list(items: &[String]) -> String {
+    if items.is_empty() {
+        "(none)".to_string()
+    } else {
+        items.join(",")
+    }
+}
+
 /// Parse boolean from various string representations
 fn parse_bool(s: &str) -> Option<bool> {
     match s.to_lowercase().as_str() {
@@ -393,11 +1032,10 @@ fn parse_bool(s: &str) -> Option<bool> {
     }
 }
 
-/// Check if the container is running (synchronous wrapper)
-fn check_container_running() -> Result<bool> {
-    let rt = tokio::runtime::Runtime::new()?;
+/// Check if the container is running on the target host (synchronous wrapper)
+pub(super) fn check_container_running(rt: &Runtime, maybe_host: Option<&str>) -> Result<bool> {
     rt.block_on(async {
-        let client = DockerClient::new()?;
+        let (client, _) = crate::resolve_docker_client(maybe_host).await?;
         container_is_running(&client, CONTAINER_NAME)
             .await
             .map_err(|e| anyhow::anyhow!("{e}"))
@@ -408,6 +1046,57 @@ fn check_container_running() -> Result<bool> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_binding_change_lines() {
+        let previous = ServiceBinding {
+            bind_address: "127.0.0.1".to_string(),
+            port: 3000,
+            cockpit_port: 9090,
+        };
+        let current = ServiceBinding {
+            port: 3100,
+            ..previous.clone()
+        };
+        let lines = binding_change_lines(&previous, &current).join("\n");
+        assert!(lines.contains("still listens on 127.0.0.1:3000"));
+        assert!(lines.contains("127.0.0.1:3100 takes effect"));
+        assert!(!lines.contains("Cockpit"));
+    }
+
+    #[test]
+    fn test_apply_build_args_edit() {
+        let current = BTreeMap::from([("A".to_string(), "1".to_string())]);
+
+        let added = apply_build_args_edit(&current, parse_list_edit("+B=2,A=3")).unwrap();
+        assert_eq!(format_key_values(&added), "A=3,B=2");
+
+        let removed = apply_build_args_edit(&added, parse_list_edit("-A")).unwrap();
+        assert_eq!(format_key_values(&removed), "B=2");
+        assert!(apply_build_args_edit(&removed, parse_list_edit("-A")).is_err());
+
+        let cleared = apply_build_args_edit(&current, parse_list_edit("")).unwrap();
+        assert!(cleared.is_empty());
+
+        assert!(apply_build_args_edit(&current, parse_list_edit("+NOVALUE")).is_err());
+        assert!(apply_build_args_edit(&current, parse_list_edit("+BAD-KEY=1")).is_err());
+
+        let labels = apply_container_labels_edit(
+            &BTreeMap::new(),
+            parse_list_edit("+com.example.team=infra"),
+        )
+        .unwrap();
+        assert_eq!(format_key_values(&labels), "com.example.team=infra");
+        assert!(apply_container_labels_edit(&labels, parse_list_edit("+managed-by=me")).is_err());
+    }
+
+    #[test]
+    fn test_parse_image_source() {
+        assert_eq!(parse_image_source("build").unwrap(), "build");
+        assert_eq!(parse_image_source(" Prebuilt ").unwrap(), "prebuilt");
+        let err = parse_image_source("prebuild").unwrap_err().to_string();
+        assert!(err.contains("prebuilt, build"));
+    }
+
     #[test]
     fn test_validate_username_valid() {
         assert!(validate_username("admin").is_ok());
@@ -459,6 +1148,74 @@ mod tests {
         assert_eq!(parse_bool("0"), Some(false));
     }
 
+    fn list(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_list_edit() {
+        assert_eq!(parse_list_edit("+alice"), ListEdit::Add(list(&["alice"])));
+        assert_eq!(
+            parse_list_edit("-bob, carol"),
+            ListEdit::Remove(list(&["bob", "carol"]))
+        );
+        assert_eq!(
+            parse_list_edit("alice,carol"),
+            ListEdit::Replace(list(&["alice", "carol"]))
+        );
+        assert_eq!(parse_list_edit(""), ListEdit::Replace(Vec::new()));
+    }
+
+    #[test]
+    fn test_apply_list_edit_add_and_remove() {
+        let current = list(&["alice", "bob"]);
+        let eq = |entry: &str, item: &str| entry == item;
+
+        let added = apply_list_edit(&current, ListEdit::Add(list(&["carol"])), eq).unwrap();
+        assert_eq!(added, list(&["alice", "bob", "carol"]));
+
+        let removed = apply_list_edit(&current, ListEdit::Remove(list(&["bob"])), eq).unwrap();
+        assert_eq!(removed, list(&["alice"]));
+
+        assert!(apply_list_edit(&current, ListEdit::Remove(list(&["dave"])), eq).is_err());
+    }
+
+    #[test]
+    fn test_validate_users_list_rejects_duplicates() {
+        assert!(validate_users_list(&list(&["alice", "carol"])).is_ok());
+        assert!(validate_users_list(&list(&["alice", "alice"])).is_err());
+        assert!(validate_users_list(&list(&["bad name"])).is_err());
+    }
+
+    #[test]
+    fn test_validate_mounts_list() {
+        assert!(validate_mounts_list(&list(&["/a:/workspace", "/b:/data:ro"])).is_ok());
+        assert!(validate_mounts_list(&list(&["/a:/workspace", "/b:/workspace"])).is_err());
+        assert!(validate_mounts_list(&list(&["not-a-mount"])).is_err());
+    }
+
+    #[test]
+    fn test_mount_matches_item_by_spec_or_target() {
+        assert!(mount_matches_item("/a:/workspace:ro", "/a:/workspace:ro"));
+        assert!(mount_matches_item("/a:/workspace:ro", "/workspace"));
+        assert!(!mount_matches_item("/a:/workspace", "/data"));
+    }
+
+    #[test]
+    fn test_open_without_auth_requires_exposure_and_flag() {
+        let mut config = Config {
+            bind_address: "0.0.0.0".to_string(),
+            ..Config::default()
+        };
+        assert!(!open_without_auth(&config));
+
+        config.allow_unauthenticated_network = true;
+        assert!(open_without_auth(&config));
+
+        config.bind_address = "127.0.0.1".to_string();
+        assert!(!open_without_auth(&config));
+    }
+
     #[test]
     fn test_parse_bool_invalid() {
         assert_eq!(parse_bool("maybe"), None);
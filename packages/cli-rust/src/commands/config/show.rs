@@ -2,17 +2,25 @@
 //!
 //! Displays current configuration in table or JSON format.
 //! Uses serde serialization to automatically include all Config fields.
+//! `--show-secrets` and `--redact-all` control what is masked.
+//! Each value is marked as `default` or `set` by comparing it with
+//! `Config::default()`.
 
 use anyhow::Result;
-use comfy_table::{Cell, Color, ContentArrangement, Table};
+use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
 use opencode_cloud_core::{Config, config};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 /// Fields that should have their values masked in output
 const SENSITIVE_FIELDS: &[&str] = &["auth_password"];
+/// Fields masked by `--redact-all` because they identify users or hosts
+const IDENTIFYING_FIELDS: &[&str] = &["auth_username", "bind_address"];
 /// Fields that should be omitted from display output
 const HIDDEN_FIELDS: &[&str] = &["cockpit_enabled", "cockpit_port"];
 
+/// Key in `config show --json` output that maps each field to its source
+const SOURCES_KEY: &str = "_sources";
+
 /// Fields that should be highlighted when they indicate security concerns
 const SECURITY_FIELDS: &[(&str, &str)] = &[
     ("bind_address", "0.0.0.0"),               // Network exposed
@@ -20,44 +28,115 @@ const SECURITY_FIELDS: &[(&str, &str)] = &[
     ("allow_unauthenticated_network", "true"), // No auth required
 ];
 
+/// How much of the configuration `config show` masks
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Redaction {
+    /// Mask passwords only (default)
+    #[default]
+    Secrets,
+    /// Print every value as stored (`--show-secrets`)
+    None,
+    /// Also mask usernames, bind addresses, and container env values (`--redact-all`)
+    All,
+}
+
 /// Show current configuration
 ///
 /// Displays all configuration values in a formatted table.
 /// Uses serde serialization to automatically include all fields.
-/// Passwords are masked for security.
-pub fn cmd_config_show(config: &Config, json: bool, _quiet: bool) -> Result<()> {
+/// Passwords are masked unless `redaction` says otherwise.
+pub fn cmd_config_show(
+    config: &Config,
+    json: bool,
+    redaction: Redaction,
+    _quiet: bool,
+) -> Result<()> {
     if json {
-        return show_json(config);
+        return show_json(config, redaction);
     }
 
-    show_table(config)
+    show_table(config, redaction)
 }
 
-fn show_json(config: &Config) -> Result<()> {
-    let mut value = serde_json::to_value(config)?;
-    remove_hidden_fields(&mut value);
-    mask_sensitive_fields(&mut value);
+fn show_json(config: &Config, redaction: Redaction) -> Result<()> {
+    let mut value = config_display_json_with(config, redaction)?;
+    if let Value::Object(obj) = &mut value {
+        obj.insert(
+            SOURCES_KEY.to_string(),
+            Value::Object(value_sources(config)?),
+        );
+    }
     println!("{}", serde_json::to_string_pretty(&value)?);
     Ok(())
 }
 
-fn show_table(config: &Config) -> Result<()> {
-    let value = serde_json::to_value(config)?;
+/// Config as JSON with hidden fields removed and passwords masked
+pub(crate) fn config_display_json(config: &Config) -> Result<Value> {
+    config_display_json_with(config, Redaction::Secrets)
+}
+
+fn config_display_json_with(config: &Config, redaction: Redaction) -> Result<Value> {
+    let mut value = serde_json::to_value(config)?;
+    remove_hidden_fields(&mut value);
+    if redaction != Redaction::None {
+        mask_sensitive_fields(&mut value);
+    }
+    if redaction == Redaction::All {
+        mask_identifying_fields(&mut value);
+    }
+    Ok(value)
+}
+
+/// Map each displayed field to "default" or "set"
+///
+/// A field counts as set when its value differs from `Config::default()`, so
+/// it covers values from the config file and from environment overrides alike.
+fn value_sources(config: &Config) -> Result<Map<String, Value>> {
+    let current = serde_json::to_value(config)?;
+    let defaults = serde_json::to_value(Config::default())?;
+    let (Value::Object(current), Value::Object(defaults)) = (current, defaults) else {
+        return Ok(Map::new());
+    };
+
+    Ok(current
+        .into_iter()
+        .filter(|(key, _)| !HIDDEN_FIELDS.contains(&key.as_str()))
+        .map(|(key, val)| {
+            let source = if defaults.get(&key) == Some(&val) {
+                "default"
+            } else {
+                "set"
+            };
+            (key, Value::String(source.to_string()))
+        })
+        .collect())
+}
+
+pub(super) fn show_table(config: &Config, redaction: Redaction) -> Result<()> {
+    let mut value = serde_json::to_value(config)?;
+    if redaction == Redaction::All {
+        mask_identifying_fields(&mut value);
+    }
     let obj = value
         .as_object()
         .expect("Config should serialize to object");
+    let sources = value_sources(config)?;
 
     let mut table = Table::new();
-    table.set_header(vec!["Key", "Value"]);
+    table.set_header(vec!["Key", "Value", "Source"]);
     table.set_content_arrangement(ContentArrangement::Dynamic);
 
     for (key, val) in obj {
         if HIDDEN_FIELDS.contains(&key.as_str()) {
             continue;
         }
-        let display_value = format_value(key, val);
+        let display_value = format_value_with(key, val, redaction);
         let cell = apply_cell_styling(key, val, display_value);
-        table.add_row(vec![Cell::new(key), cell]);
+        let source = sources
+            .get(key)
+            .and_then(Value::as_str)
+            .unwrap_or("default");
+        table.add_row(vec![Cell::new(key), cell, source_cell(source)]);
     }
 
     println!("{table}");
@@ -70,10 +149,25 @@ fn show_table(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Dim "(default)" so explicitly set values stand out
+fn source_cell(source: &str) -> Cell {
+    let cell = Cell::new(format!("({source})"));
+    if source == "default" {
+        cell.add_attribute(Attribute::Dim)
+    } else {
+        cell
+    }
+}
+
 /// Format a JSON value for display
+#[cfg(test)]
 fn format_value(key: &str, value: &Value) -> String {
+    format_value_with(key, value, Redaction::Secrets)
+}
+
+fn format_value_with(key: &str, value: &Value, redaction: Redaction) -> String {
     // Handle sensitive fields first
-    if SENSITIVE_FIELDS.contains(&key) {
+    if redaction != Redaction::None && SENSITIVE_FIELDS.contains(&key) {
         return format_sensitive(value);
     }
 
@@ -181,6 +275,36 @@ fn mask_sensitive_fields(value: &mut Value) {
     }
 }
 
+/// Mask usernames, addresses, and env values for `--redact-all`
+fn mask_identifying_fields(value: &mut Value) {
+    let Value::Object(obj) = value else {
+        return;
+    };
+
+    for (key, val) in obj.iter_mut() {
+        match (key.as_str(), val) {
+            (key, Value::String(s)) if IDENTIFYING_FIELDS.contains(&key) && !s.is_empty() => {
+                *s = "********".to_string();
+            }
+            ("users", Value::Array(users)) => {
+                for user in users.iter_mut() {
+                    *user = Value::String("********".to_string());
+                }
+            }
+            ("container_env", Value::Array(entries)) => {
+                for entry in entries.iter_mut() {
+                    if let Value::String(s) = entry
+                        && let Some((key, _)) = s.split_once('=')
+                    {
+                        *s = format!("{key}=********");
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Remove hidden fields from a JSON Value (for JSON output)
 fn remove_hidden_fields(value: &mut Value) {
     let Value::Object(obj) = value else {
@@ -210,7 +334,7 @@ mod tests {
     #[test]
     fn test_format_value_preserves_normal_strings() {
         let val = Value::String("localhost".to_string());
-        assert_eq!(format_value("bind", &val), "localhost");
+        assert_eq!(format_value("bind_address", &val), "localhost");
     }
 
     #[test]
@@ -245,14 +369,60 @@ mod tests {
         let mut value = serde_json::json!({
             "auth_username": "admin",
             "auth_password": "secret123",
-            "bind": "localhost"
+            "bind_address": "localhost"
         });
         mask_sensitive_fields(&mut value);
 
         let obj = value.as_object().unwrap();
         assert_eq!(obj["auth_username"], "admin");
         assert_eq!(obj["auth_password"], "********");
-        assert_eq!(obj["bind"], "localhost");
+        assert_eq!(obj["bind_address"], "localhost");
+    }
+
+    #[test]
+    fn test_redaction_levels() {
+        let config = Config {
+            auth_username: Some("admin".to_string()),
+            auth_password: Some("secret123".to_string()),
+            bind_address: "10.0.0.5".to_string(),
+            users: vec!["alice".to_string()],
+            container_env: vec!["API_KEY=abc".to_string()],
+            ..Config::default()
+        };
+
+        let shown = config_display_json_with(&config, Redaction::None).unwrap();
+        assert_eq!(shown["auth_password"], "secret123");
+
+        let default = config_display_json_with(&config, Redaction::Secrets).unwrap();
+        assert_eq!(default["auth_password"], "********");
+        assert_eq!(default["auth_username"], "admin");
+        assert_eq!(default["bind_address"], "10.0.0.5");
+
+        let all = config_display_json_with(&config, Redaction::All).unwrap();
+        assert_eq!(all["auth_password"], "********");
+        assert_eq!(all["auth_username"], "********");
+        assert_eq!(all["bind_address"], "********");
+        assert_eq!(all["users"][0], "********");
+        assert_eq!(all["container_env"][0], "API_KEY=********");
+
+        let val = Value::String("secret".to_string());
+        assert_eq!(
+            format_value_with("auth_password", &val, Redaction::None),
+            "secret"
+        );
+    }
+
+    #[test]
+    fn test_value_sources_compare_with_defaults() {
+        let config = Config {
+            opencode_web_port: Config::default().opencode_web_port + 1,
+            ..Config::default()
+        };
+
+        let sources = value_sources(&config).unwrap();
+        assert_eq!(sources["opencode_web_port"], "set");
+        assert_eq!(sources["bind_address"], "default");
+        assert!(!sources.contains_key("cockpit_port"));
     }
 
     #[test]
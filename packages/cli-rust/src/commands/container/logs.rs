@@ -2,13 +2,19 @@
 
 use crate::commands::LogsArgs;
 use crate::commands::container::systemd_available;
-use crate::commands::logs::emit_log_line;
+use crate::commands::logs::{LogService, emit_log_line, journalctl_since_arg};
 use anyhow::{Result, anyhow};
 use console::style;
 use std::io::{BufRead, BufReader};
 use std::process::{Command, Stdio};
 
 pub async fn cmd_logs_container(args: &LogsArgs, quiet: bool) -> Result<()> {
+    if args.export.is_some() {
+        return Err(anyhow!(
+            "--export is not supported in container runtime.\n\
+Redirect output instead: occ logs --no-follow > occ.log"
+        ));
+    }
     if !systemd_available() {
         return Err(anyhow!(
             "Logs unavailable in container runtime without systemd.\n\
@@ -16,18 +22,14 @@ Use host runtime instead: occ --runtime host logs"
         ));
     }
 
-    let service = if args.broker {
-        "opencode-broker"
-    } else {
-        "opencode"
-    };
-    let cmd = build_journalctl_command(args, service)?;
+    let maybe_service = args.selected_service();
+    let unit = maybe_service.map_or("opencode", LogService::unit);
+    let cmd = build_journalctl_command(args, unit)?;
 
     if !quiet && !args.no_follow {
-        let label = if args.broker {
-            "Following broker logs (Ctrl+C to exit)..."
-        } else {
-            "Following logs (Ctrl+C to exit)..."
+        let label = match maybe_service {
+            Some(service) => format!("Following {} logs (Ctrl+C to exit)...", service.label()),
+            None => "Following logs (Ctrl+C to exit)...".to_string(),
         };
         eprintln!("{}", style(label).dim());
         eprintln!();
@@ -76,16 +78,20 @@ fn build_journalctl_command(args: &LogsArgs, service: &str) -> Result<Vec<String
         cmd.push("cat".to_string());
     }
 
-    let lines = args.lines.trim();
+    let lines = args.tail();
     if lines.eq_ignore_ascii_case("all") {
         // no -n flag
     } else if !lines.is_empty() && lines.chars().all(|c| c.is_ascii_digit()) {
         cmd.push("-n".to_string());
-        cmd.push(lines.to_string());
+        cmd.push(lines);
     } else {
         return Err(anyhow!("Invalid value for --lines. Use a number or 'all'."));
     }
 
+    if let Some(since) = args.since {
+        cmd.push(journalctl_since_arg(since));
+    }
+
     if !args.no_follow {
         cmd.push("-f".to_string());
     }
@@ -96,14 +102,22 @@ fn build_journalctl_command(args: &LogsArgs, service: &str) -> Result<Vec<String
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commands::logs::LogStreams;
 
     fn base_args() -> LogsArgs {
         LogsArgs {
-            lines: "50".to_string(),
+            lines: None,
             no_follow: false,
             timestamps: false,
+            local: false,
             grep: None,
             broker: false,
+            service: None,
+            since: None,
+            export: None,
+            bundle: false,
+            streams: LogStreams::All,
+            prefix: false,
         }
     }
 
@@ -121,7 +135,7 @@ mod tests {
     #[test]
     fn journalctl_command_all_lines() {
         let mut args = base_args();
-        args.lines = "all".to_string();
+        args.lines = Some("all".to_string());
         let cmd = build_journalctl_command(&args, "opencode").unwrap();
         assert!(!cmd.contains(&"-n".to_string()));
     }
@@ -133,4 +147,12 @@ mod tests {
         let cmd = build_journalctl_command(&args, "opencode").unwrap();
         assert!(!cmd.contains(&"-f".to_string()));
     }
+
+    #[test]
+    fn journalctl_command_since() {
+        let mut args = base_args();
+        args.since = Some(std::time::Duration::from_secs(7200));
+        let cmd = build_journalctl_command(&args, "opencode").unwrap();
+        assert!(cmd.contains(&"--since=-7200s".to_string()));
+    }
 }
@@ -1,23 +1,46 @@
 //! Container-mode status command implementation.
 
 use crate::commands::container::{exec_command_with_status, systemd_available};
-use crate::commands::runtime_shared::backend::{ContainerBackend, default_container_port};
+use crate::commands::runtime_shared::backend::{
+    ContainerBackend, default_container_port, probe_opencode_http,
+};
 use crate::commands::runtime_shared::collect_status_view;
 use crate::commands::runtime_shared::status_model::{
-    OpencodeHealthStatus, format_broker_health_label,
+    OpencodeHealthStatus, OpencodeHttpProbe, format_broker_health_label,
 };
+use crate::commands::status::probe_result;
 use crate::output::{format_service_url, state_style};
-use anyhow::Result;
+use anyhow::{Result, bail};
 use console::style;
 use opencode_cloud_core::docker::get_cli_version;
 
 const STATUS_LABEL_WIDTH: usize = 15;
 
 pub async fn cmd_status_container(
-    _args: &crate::commands::StatusArgs,
+    args: &crate::commands::StatusArgs,
     quiet: bool,
     _verbose: u8,
 ) -> Result<()> {
+    if args.instances {
+        bail!(
+            "--instances needs Docker access. Run it on the host: occ --runtime host status --instances"
+        );
+    }
+    if args.history {
+        bail!(
+            "Health history is recorded on the host. Run it there: occ --runtime host status --history"
+        );
+    }
+    if args.probe {
+        let probe = tokio::time::timeout(
+            args.probe_timeout(),
+            probe_opencode_http("127.0.0.1", default_container_port()),
+        )
+        .await
+        .unwrap_or(OpencodeHttpProbe::Timeout);
+        return probe_result(probe);
+    }
+
     let systemd = systemd_available();
 
     let opencode_running = if systemd {
@@ -36,8 +59,14 @@ pub async fn cmd_status_container(
 
     let backend = ContainerBackend::new(systemd);
     let host_port = default_container_port();
-    let status_view =
-        collect_status_view(&backend, opencode_running, "127.0.0.1", host_port).await?;
+    let status_view = collect_status_view(
+        &backend,
+        opencode_running,
+        "127.0.0.1",
+        host_port,
+        args.probe_timeout(),
+    )
+    .await?;
 
     let state_label = if opencode_running {
         match status_view.opencode_health {
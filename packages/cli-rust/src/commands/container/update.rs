@@ -5,10 +5,10 @@ use crate::commands::UpdateCommand;
 use crate::commands::UpdateOpencodeArgs;
 use crate::commands::container::{exec_command, exec_command_with_status, systemd_available};
 use crate::commands::update::{build_opencode_update_script, short_commit};
+use crate::confirm::confirm;
 use crate::output::CommandSpinner;
 use anyhow::{Result, anyhow};
 use console::style;
-use dialoguer::Confirm;
 use std::fs;
 
 pub async fn cmd_update_container(args: &UpdateArgs, quiet: bool, _verbose: u8) -> Result<()> {
@@ -21,7 +21,7 @@ Use host runtime instead: occ --runtime host update --rollback"
 
     match args.command {
         Some(UpdateCommand::Opencode(ref op_args)) => {
-            cmd_update_opencode_container(op_args, quiet).await
+            cmd_update_opencode_container(op_args, args.dry_run, quiet).await
         }
         _ => Err(anyhow!(
             "Only `occ update opencode` is supported in container runtime.\n\
@@ -30,7 +30,18 @@ To use host runtime: occ --runtime host update <target>"
     }
 }
 
-async fn cmd_update_opencode_container(args: &UpdateOpencodeArgs, quiet: bool) -> Result<()> {
+async fn cmd_update_opencode_container(
+    args: &UpdateOpencodeArgs,
+    dry_run: bool,
+    quiet: bool,
+) -> Result<()> {
+    if args.rollback {
+        return Err(anyhow!(
+            "Opencode rollback is not supported in container runtime.\n\
+Use host runtime instead: occ --runtime host update opencode --rollback"
+        ));
+    }
+
     if !systemd_available() {
         return Err(anyhow!(
             "Opencode update requires systemd in container runtime.\n\
@@ -85,11 +96,16 @@ Run the update from the host instead: occ --runtime host update opencode"
         eprintln!();
     }
 
+    if dry_run {
+        let next_hash = next_commit.as_deref().unwrap_or("unknown");
+        println!("{}", style("Dry run: no changes made").bold());
+        println!("  - Would stop opencode and check out {target_ref} (hash {next_hash})");
+        println!("  - Would rebuild opencode and restart its service");
+        return Ok(());
+    }
+
     if !args.yes {
-        let confirmed = Confirm::new()
-            .with_prompt("Continue with opencode update?")
-            .default(true)
-            .interact()?;
+        let confirmed = confirm("Continue with opencode update?", true, quiet)?;
 
         if !confirmed {
             if !quiet {
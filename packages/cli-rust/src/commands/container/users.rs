@@ -3,14 +3,16 @@
 use crate::commands::container::{exec_command, exec_command_with_status, exec_command_with_stdin};
 use crate::commands::user::{
     UserAddArgs, UserArgs, UserCommands, UserDisableArgs, UserEnableArgs, UserListArgs,
-    UserPasswdArgs, UserRemoveArgs,
+    UserPasswdArgs, UserRemoveArgs, bulk,
+    list::{UserListEntry, print_user_list},
 };
 use crate::passwords::{generate_random_password, print_generated_password};
 use anyhow::{Result, anyhow, bail};
-use comfy_table::{Cell, Color, Table};
 use console::style;
 use dialoguer::{Confirm, Input, Password};
-use opencode_cloud_core::docker::{MOUNT_USERS, UserInfo};
+use opencode_cloud_core::docker::{
+    MOUNT_USERS, UserInfo, is_builtin_system_user, parse_lastlog_output,
+};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::os::unix::fs::PermissionsExt;
@@ -45,6 +47,12 @@ pub async fn cmd_user_container(args: &UserArgs, quiet: bool, verbose: u8) -> Re
         UserCommands::Disable(disable_args) => {
             cmd_user_disable_container(disable_args, quiet, verbose).await
         }
+        UserCommands::LockAll(lock_args) => {
+            cmd_user_bulk_container(true, &lock_args.except, quiet).await
+        }
+        UserCommands::UnlockAll(unlock_args) => {
+            cmd_user_bulk_container(false, &unlock_args.except, quiet).await
+        }
     }
 }
 
@@ -200,44 +208,16 @@ async fn cmd_user_remove_container(args: &UserRemoveArgs, quiet: bool, _verbose:
     Ok(())
 }
 
-async fn cmd_user_list_container(_args: &UserListArgs, quiet: bool, _verbose: u8) -> Result<()> {
+async fn cmd_user_list_container(args: &UserListArgs, quiet: bool, _verbose: u8) -> Result<()> {
     let users = list_users().await?;
 
-    if users.is_empty() {
-        if !quiet {
-            println!("No users configured.");
-        }
-        return Ok(());
+    let mut entries = Vec::with_capacity(users.len());
+    for user in users {
+        let login = last_login(&user.username).await;
+        entries.push(UserListEntry::new(user, login));
     }
 
-    if quiet {
-        for user in &users {
-            println!("{}", user.username);
-        }
-        return Ok(());
-    }
-
-    let mut table = Table::new();
-    table.set_header(vec!["Username", "Status", "UID", "Home", "Shell"]);
-
-    for user in &users {
-        let status_cell = if user.locked {
-            Cell::new("disabled").fg(Color::Yellow)
-        } else {
-            Cell::new("enabled").fg(Color::Green)
-        };
-
-        table.add_row(vec![
-            Cell::new(&user.username),
-            status_cell,
-            Cell::new(user.uid.to_string()),
-            Cell::new(&user.home),
-            Cell::new(&user.shell),
-        ]);
-    }
-
-    println!("{table}");
-    Ok(())
+    print_user_list(&entries, args.json, quiet)
 }
 
 async fn cmd_user_passwd_container(args: &UserPasswdArgs, quiet: bool, _verbose: u8) -> Result<()> {
@@ -247,6 +227,10 @@ async fn cmd_user_passwd_container(args: &UserPasswdArgs, quiet: bool, _verbose:
 
     let username = &args.username;
 
+    if is_builtin_system_user(username) {
+        bail!("User '{username}' is built-in; its password cannot be changed with occ");
+    }
+
     if !user_exists(username).await? {
         bail!("User '{username}' does not exist in the container");
     }
@@ -342,6 +326,32 @@ async fn cmd_user_disable_container(
     Ok(())
 }
 
+async fn cmd_user_bulk_container(lock: bool, except: &[String], quiet: bool) -> Result<()> {
+    let users = list_users().await?;
+    let plan = bulk::plan_bulk(&users, lock, except)?;
+
+    let mut failed = Vec::new();
+    for username in &plan.targets {
+        let result = if lock {
+            lock_user(username).await
+        } else {
+            unlock_user(username).await
+        };
+        let result = match result {
+            Ok(()) => persist_user(username).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            failed.push(username.clone());
+            if !quiet {
+                eprintln!("{} {username}: {e}", style("Error:").red().bold());
+            }
+        }
+    }
+
+    bulk::report_bulk(&plan, &failed, lock, quiet)
+}
+
 fn ensure_root() -> Result<()> {
     let output = Command::new("id")
         .arg("-u")
@@ -432,6 +442,13 @@ async fn unlock_user(username: &str) -> Result<()> {
     Ok(())
 }
 
+async fn last_login(username: &str) -> Option<String> {
+    let (output, _status) = exec_command_with_status("lastlog", &["-u", username])
+        .await
+        .ok()?;
+    parse_lastlog_output(&output)
+}
+
 async fn user_exists(username: &str) -> Result<bool> {
     let (_output, status) = exec_command_with_status("id", &["-u", username]).await?;
     Ok(status == 0)
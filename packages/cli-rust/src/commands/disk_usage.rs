@@ -22,6 +22,14 @@ pub struct DiskUsageReport {
     pub total: Option<i64>,
 }
 
+/// Size of one managed volume as reported by `/system/df`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VolumeUsage {
+    pub name: String,
+    /// `None` when Docker did not report a size (e.g. the volume is missing)
+    pub size: Option<i64>,
+}
+
 #[derive(Clone, Copy)]
 pub struct HostDiskReport {
     pub total: u64,
@@ -49,6 +57,22 @@ pub fn format_disk_usage_report(
     lines
 }
 
+/// Format a per-volume breakdown, largest first.
+pub fn format_volume_breakdown(volumes: &[VolumeUsage]) -> Vec<String> {
+    let mut sorted: Vec<&VolumeUsage> = volumes.iter().collect();
+    sorted.sort_by_key(|volume| std::cmp::Reverse(volume.size.unwrap_or(-1)));
+    let width = sorted.iter().map(|v| v.name.len()).max().unwrap_or(0);
+    let mut lines = vec!["Managed volumes:".to_string()];
+    for volume in sorted {
+        lines.push(format!(
+            "  {:<width$}  {}",
+            volume.name,
+            format_usage_value(volume.size)
+        ));
+    }
+    lines
+}
+
 /// Format a host disk report, optionally including a delta vs. a baseline.
 pub fn format_host_disk_report(
     stage: &str,
@@ -83,14 +107,33 @@ pub fn format_host_disk_report(
 /// match the Docker API v1.52+ response shape, which otherwise yields "unknown"
 /// values in the CLI.
 pub async fn get_disk_usage_report(client: &DockerClient) -> Result<DiskUsageReport> {
-    let data_usage = match fetch_system_df_json(client, true).await {
-        Ok(payload) => payload,
+    let data_usage = fetch_disk_usage_json(client).await?;
+    Ok(build_disk_usage_report(&data_usage))
+}
+
+/// Fetch Docker disk usage plus the sizes of the given volumes.
+///
+/// Uses the same `/system/df` payload as [`get_disk_usage_report`], so the
+/// breakdown costs no extra request.
+pub async fn get_disk_usage_with_volumes(
+    client: &DockerClient,
+    volume_names: &[&str],
+) -> Result<(DiskUsageReport, Vec<VolumeUsage>)> {
+    let data_usage = fetch_disk_usage_json(client).await?;
+    Ok((
+        build_disk_usage_report(&data_usage),
+        parse_volume_usage(&data_usage, volume_names),
+    ))
+}
+
+async fn fetch_disk_usage_json(client: &DockerClient) -> Result<Value> {
+    match fetch_system_df_json(client, true).await {
+        Ok(payload) => Ok(payload),
         Err(err) => {
             tracing::debug!("Verbose /system/df failed, retrying without verbose: {err}");
-            fetch_system_df_json(client, false).await?
+            fetch_system_df_json(client, false).await
         }
-    };
-    Ok(build_disk_usage_report(&data_usage))
+    }
 }
 
 /// Compute host disk report for local Docker; returns `None` for remote.
@@ -179,7 +222,7 @@ fn format_delta_u64(after: Option<u64>, before: Option<u64>) -> Option<String> {
 }
 
 /// Format a byte count into a human-friendly string.
-fn format_bytes_u64(value: u64) -> String {
+pub fn format_bytes_u64(value: u64) -> String {
     let units = ["B", "KB", "MB", "GB", "TB", "PB"];
     let mut size = value as f64;
     let mut index = 0usize;
@@ -230,7 +273,7 @@ async fn fetch_system_df_json(client: &DockerClient, verbose: bool) -> Result<Va
 
 /// Build a reqwest client configured for the Docker endpoint.
 ///
-/// We need custom transport (Unix socket vs HTTP) because we bypass Bollard
+/// We need custom transport (Unix socket, HTTP, or TLS) because we bypass Bollard
 /// for `/system/df`. Unix sockets are only supported on Unix platforms.
 fn build_reqwest_client(endpoint: &DockerEndpoint) -> Result<Client> {
     let mut builder = Client::builder().timeout(Duration::from_secs(30));
@@ -248,6 +291,16 @@ fn build_reqwest_client(endpoint: &DockerEndpoint) -> Result<Client> {
             }
         }
         DockerEndpoint::Http(_) => {}
+        DockerEndpoint::Tls { ca, cert, key, .. } => {
+            let read = |path: &std::path::Path| {
+                std::fs::read(path).map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))
+            };
+            let mut identity_pem = read(cert)?;
+            identity_pem.extend(read(key)?);
+            builder = builder
+                .tls_certs_only([reqwest::Certificate::from_pem(&read(ca)?)?])
+                .identity(reqwest::Identity::from_pem(&identity_pem)?);
+        }
     }
     builder
         .build()
@@ -262,7 +315,9 @@ fn build_reqwest_client(endpoint: &DockerEndpoint) -> Result<Client> {
 fn system_df_url(endpoint: &DockerEndpoint, verbose: bool) -> Result<String> {
     let base = match endpoint {
         DockerEndpoint::Unix(_) => "http://localhost".to_string(),
-        DockerEndpoint::Http(base) => base.trim_end_matches('/').to_string(),
+        DockerEndpoint::Http(base) | DockerEndpoint::Tls { url: base, .. } => {
+            base.trim_end_matches('/').to_string()
+        }
     };
     let suffix = if verbose { "?verbose=true" } else { "" };
     Ok(format!("{base}/system/df{suffix}"))
@@ -300,6 +355,40 @@ fn sum_build_cache_sizes(data_usage: &Value) -> Option<i64> {
     sum_array_sizes(data_usage, "BuildCache", &["Size"])
 }
 
+/// Look up each named volume's size in `Volumes[]` (legacy) or
+/// `VolumeUsage.Items[]` (API v1.52+).
+fn parse_volume_usage(data_usage: &Value, volume_names: &[&str]) -> Vec<VolumeUsage> {
+    let items = data_usage
+        .get("Volumes")
+        .and_then(Value::as_array)
+        .or_else(|| {
+            data_usage
+                .get("VolumeUsage")
+                .and_then(|usage| usage.get("Items"))
+                .and_then(Value::as_array)
+        });
+
+    volume_names
+        .iter()
+        .map(|name| {
+            let size = items
+                .and_then(|items| {
+                    items
+                        .iter()
+                        .find(|item| item.get("Name").and_then(Value::as_str) == Some(name))
+                })
+                .and_then(|item| item.get("UsageData"))
+                .and_then(|usage| usage.get("Size"))
+                .and_then(Value::as_i64)
+                .filter(|size| *size >= 0);
+            VolumeUsage {
+                name: (*name).to_string(),
+                size,
+            }
+        })
+        .collect()
+}
+
 /// Sum sizes from a legacy array response using a JSON field path.
 fn sum_array_sizes(data_usage: &Value, key: &str, path: &[&str]) -> Option<i64> {
     let array = data_usage.get(key)?.as_array()?;
@@ -366,4 +455,48 @@ mod tests {
         assert_eq!(report.build_cache, Some(24));
         assert_eq!(report.total, Some(41));
     }
+
+    #[test]
+    fn parse_volume_usage_matches_names_in_both_shapes() {
+        let legacy = json!({
+            "Volumes": [
+                { "Name": "opencode-data", "UsageData": { "Size": 2048 } },
+                { "Name": "other", "UsageData": { "Size": 1 } },
+                { "Name": "opencode-cache", "UsageData": { "Size": -1 } }
+            ]
+        });
+        let volumes = parse_volume_usage(&legacy, &["opencode-data", "opencode-cache", "gone"]);
+        assert_eq!(volumes[0].size, Some(2048));
+        assert_eq!(volumes[1].size, None);
+        assert_eq!(volumes[2].size, None);
+
+        let verbose = json!({
+            "VolumeUsage": {
+                "TotalSize": 10,
+                "Items": [{ "Name": "opencode-data", "UsageData": { "Size": 10 } }]
+            }
+        });
+        let volumes = parse_volume_usage(&verbose, &["opencode-data"]);
+        assert_eq!(volumes[0].size, Some(10));
+    }
+
+    #[test]
+    fn format_volume_breakdown_lists_largest_first() {
+        let lines = format_volume_breakdown(&[
+            VolumeUsage {
+                name: "small".to_string(),
+                size: Some(1),
+            },
+            VolumeUsage {
+                name: "missing".to_string(),
+                size: None,
+            },
+            VolumeUsage {
+                name: "big".to_string(),
+                size: Some(4096),
+            },
+        ]);
+        assert!(lines[1].contains("big") && lines[1].contains("4.00 KB"));
+        assert!(lines[3].contains("missing") && lines[3].contains("unknown"));
+    }
 }
@@ -0,0 +1,499 @@
+//! Doctor command implementation
+//!
+//! Runs the environment checks that other commands do piecemeal (Docker
+//! connectivity, config validation, port availability, drift, disk space,
+//! orphaned volumes) and
+//! prints them as one checklist with remediation hints.
+
+use crate::commands::disk_usage::{
+    VolumeUsage, format_bytes_i64, get_disk_usage_report, get_disk_usage_with_volumes,
+    get_host_disk_report,
+};
+use crate::commands::runtime_shared::drift::{REBUILD_CACHED_COMMAND, detect_runtime_asset_drift};
+use crate::commands::start::check_port_available;
+use crate::commands::status::render_daemon_info_lines;
+use crate::error::CliError;
+use anyhow::{Result, anyhow};
+use clap::Args;
+use console::style;
+use opencode_cloud_core::Config;
+use opencode_cloud_core::config::validate_config;
+use opencode_cloud_core::docker::{
+    CONTAINER_NAME, DockerClient, DockerError, container_is_running, daemon_info,
+    docker_supports_systemd, find_orphaned_volumes, get_container_ports,
+};
+
+/// Free host disk below which a warning is shown (the sandbox image alone is several GB)
+const DISK_WARN_BYTES: u64 = 10 * 1024 * 1024 * 1024;
+
+/// Free host disk below which image pulls and builds are likely to fail
+const DISK_FAIL_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// Arguments for the doctor command
+#[derive(Args, Default)]
+pub struct DoctorArgs {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+    Skip,
+}
+
+/// Outcome of a single check
+#[derive(Debug)]
+struct Check {
+    name: &'static str,
+    status: CheckStatus,
+    detail: String,
+    /// Remediation shown under warnings and failures
+    hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Pass, detail, None)
+    }
+
+    fn skip(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Skip, detail, None)
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Warn, detail, Some(hint.into()))
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Fail, detail, Some(hint.into()))
+    }
+
+    fn new(
+        name: &'static str,
+        status: CheckStatus,
+        detail: impl Into<String>,
+        hint: Option<String>,
+    ) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            hint,
+        }
+    }
+}
+
+/// Run environment diagnostics
+///
+/// Docker-dependent checks are skipped when Docker cannot be reached. Returns
+/// an error (non-zero exit) when any check fails; warnings do not fail.
+pub async fn cmd_doctor(
+    _args: &DoctorArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
+    // Ports and bind address come from the target host's overrides
+    let config = &crate::load_config_for_host(maybe_host)?;
+    let mut checks = vec![check_config(config)];
+
+    let client = match connect_docker(maybe_host).await {
+        Ok((client, check)) => {
+            checks.push(check);
+            Some(client)
+        }
+        Err(check) => {
+            checks.push(check);
+            None
+        }
+    };
+    let docker_failed = client.is_none();
+
+    match &client {
+        Some(client) => {
+            checks.push(check_systemd(client, config).await);
+            checks.push(check_port(client, config).await);
+            checks.push(check_drift(client).await);
+            checks.push(check_disk(client).await);
+            checks.push(check_orphaned_volumes(client, config).await);
+        }
+        None => {
+            checks.push(Check::skip("Cockpit support", "Docker not reachable"));
+            // A local port conflict is worth reporting even before Docker is fixed
+            checks.push(match maybe_host {
+                None => port_check(
+                    &config.bind_address,
+                    config.opencode_web_port,
+                    check_port_available(&config.bind_address, config.opencode_web_port),
+                ),
+                Some(_) => Check::skip("Web port", "Docker not reachable"),
+            });
+            checks.push(Check::skip("Runtime assets", "Docker not reachable"));
+            checks.push(Check::skip("Disk space", "Docker not reachable"));
+            checks.push(Check::skip("Orphaned volumes", "Docker not reachable"));
+        }
+    }
+
+    if !quiet {
+        let title = crate::format_host_message(maybe_host, "opencode-cloud doctor");
+        println!("{}", style(title).bold());
+        println!();
+        for check in &checks {
+            print_check(check);
+        }
+        println!();
+        if verbose > 0
+            && let Some(client) = &client
+        {
+            print_daemon_info(client).await;
+        }
+    }
+
+    let (failures, warnings) = count_problems(&checks);
+    if failures == 0 {
+        if !quiet {
+            if warnings == 0 {
+                println!("{}", style("All checks passed.").green());
+            } else {
+                println!(
+                    "{}",
+                    style(format!("No failures, {warnings} warning(s).")).yellow()
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let message = format!("{failures} check(s) failed");
+    if docker_failed {
+        Err(CliError::DockerUnreachable(message).into())
+    } else {
+        Err(anyhow!(message))
+    }
+}
+
+/// Daemon details for bug reports, shown with --verbose
+async fn print_daemon_info(client: &DockerClient) {
+    println!("{}", style("Docker daemon").bold());
+    match daemon_info(client).await {
+        Ok(info) => {
+            for line in render_daemon_info_lines(&info) {
+                println!("  {line}");
+            }
+        }
+        Err(e) => println!("  {}", style(format!("Unavailable: {e}")).yellow()),
+    }
+    println!();
+}
+
+async fn connect_docker(maybe_host: Option<&str>) -> Result<(DockerClient, Check), Check> {
+    const NAME: &str = "Docker";
+    let target = maybe_host.unwrap_or("local");
+
+    let (client, _) = crate::resolve_docker_client(maybe_host)
+        .await
+        .map_err(|e| Check::fail(NAME, format!("{e}"), docker_hint(maybe_host)))?;
+    if let Err(e) = client.verify_connection().await {
+        let hint = if matches!(e, DockerError::DesktopPaused) {
+            "Resume Docker Desktop from the whale menu or the Dashboard".to_string()
+        } else {
+            docker_hint(maybe_host)
+        };
+        return Err(Check::fail(NAME, format!("{e}"), hint));
+    }
+
+    let detail = match client.version().await {
+        Ok(version) => format!("reachable on {target} (Docker {version})"),
+        Err(_) => format!("reachable on {target}"),
+    };
+    Ok((client, Check::pass(NAME, detail)))
+}
+
+fn docker_hint(maybe_host: Option<&str>) -> String {
+    match maybe_host {
+        Some(name) => format!("Check the remote host with: occ host test {name}"),
+        None => "Start Docker Desktop or the Docker daemon, and make sure your user can access the Docker socket".to_string(),
+    }
+}
+
+fn check_config(config: &Config) -> Check {
+    const NAME: &str = "Config";
+    match validate_config(config) {
+        Ok(warnings) if warnings.is_empty() => Check::pass(NAME, "valid"),
+        Ok(warnings) => {
+            let detail = warnings
+                .iter()
+                .map(|w| format!("{}: {}", w.field, w.message))
+                .collect::<Vec<_>>()
+                .join("; ");
+            let hint = warnings
+                .iter()
+                .map(|w| w.fix_command.as_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            Check::warn(NAME, detail, hint)
+        }
+        Err(e) => Check::fail(NAME, format!("{}: {}", e.field, e.message), e.fix_command),
+    }
+}
+
+async fn check_systemd(client: &DockerClient, config: &Config) -> Check {
+    const NAME: &str = "Cockpit support";
+    if !config.cockpit_enabled {
+        return Check::skip(NAME, "Cockpit disabled");
+    }
+    match docker_supports_systemd(client).await {
+        Ok(true) => Check::pass(NAME, "Docker host supports systemd in containers"),
+        Ok(false) => Check::warn(
+            NAME,
+            "Docker host does not support systemd in containers (Docker Desktop or rootless)",
+            "Cockpit needs a native Linux Docker host; disable it with: occ config set cockpit_enabled false",
+        ),
+        Err(e) => Check::warn(
+            NAME,
+            format!("could not query Docker info: {e}"),
+            "Re-run with Docker reachable to check systemd support",
+        ),
+    }
+}
+
+async fn check_port(client: &DockerClient, config: &Config) -> Check {
+    const NAME: &str = "Web port";
+    let port = config.opencode_web_port;
+    let bind_addr = config.bind_address.as_str();
+
+    if client.is_remote() {
+        return Check::skip(NAME, format!("{port} not checked on remote hosts"));
+    }
+
+    // Our own running container holding the port is expected
+    if container_is_running(client, CONTAINER_NAME)
+        .await
+        .unwrap_or(false)
+        && let Ok(ports) = get_container_ports(client, CONTAINER_NAME).await
+        && ports.opencode_port == Some(port)
+    {
+        return Check::pass(NAME, format!("{bind_addr}:{port} in use by opencode-cloud"));
+    }
+
+    port_check(bind_addr, port, check_port_available(bind_addr, port))
+}
+
+fn port_check(bind_addr: &str, port: u16, available: bool) -> Check {
+    const NAME: &str = "Web port";
+    if available {
+        Check::pass(NAME, format!("{bind_addr}:{port} available"))
+    } else {
+        Check::fail(
+            NAME,
+            format!("{bind_addr}:{port} is in use by another process"),
+            format!(
+                "Stop the process using port {port}, or pick another with: occ config set opencode_web_port <port>"
+            ),
+        )
+    }
+}
+
+async fn check_drift(client: &DockerClient) -> Check {
+    const NAME: &str = "Runtime assets";
+    let running = container_is_running(client, CONTAINER_NAME)
+        .await
+        .unwrap_or(false);
+    if !running {
+        return Check::skip(NAME, "container not running");
+    }
+
+    let report = detect_runtime_asset_drift(client).await;
+    if report.drift_detected {
+        Check::warn(
+            NAME,
+            format!(
+                "container out of sync: {}",
+                report.mismatched_assets.join(", ")
+            ),
+            format!("Rebuild with: {REBUILD_CACHED_COMMAND}"),
+        )
+    } else if !report.diagnostics.is_empty() {
+        Check::skip(NAME, report.diagnostics.join("; "))
+    } else {
+        Check::pass(NAME, "container matches this CLI")
+    }
+}
+
+async fn check_disk(client: &DockerClient) -> Check {
+    const NAME: &str = "Disk space";
+    let docker_usage = get_disk_usage_report(client)
+        .await
+        .ok()
+        .and_then(|report| report.total)
+        .map(|total| format!("Docker using {}", format_bytes_i64(total)));
+
+    match get_host_disk_report(client) {
+        Ok(Some(report)) => disk_check(report.available, docker_usage),
+        Ok(None) => Check::skip(
+            NAME,
+            docker_usage.unwrap_or_else(|| "host disk not checked on remote hosts".to_string()),
+        ),
+        Err(e) => Check::skip(NAME, format!("could not read host disk: {e}")),
+    }
+}
+
+fn disk_check(available: u64, docker_usage: Option<String>) -> Check {
+    const NAME: &str = "Disk space";
+    let free = format_bytes_i64(i64::try_from(available).unwrap_or(i64::MAX));
+    let detail = match docker_usage {
+        Some(usage) => format!("{free} free, {usage}"),
+        None => format!("{free} free"),
+    };
+    let hint = "Free up space, or reclaim unused Docker data with: docker system prune";
+
+    if available < DISK_FAIL_BYTES {
+        Check::fail(NAME, detail, hint)
+    } else if available < DISK_WARN_BYTES {
+        Check::warn(NAME, detail, hint)
+    } else {
+        Check::pass(NAME, detail)
+    }
+}
+
+async fn check_orphaned_volumes(client: &DockerClient, config: &Config) -> Check {
+    const NAME: &str = "Orphaned volumes";
+    let unattached = match find_orphaned_volumes(client, &config.mounts).await {
+        Ok(Some(unattached)) => unattached,
+        Ok(None) => return Check::skip(NAME, "no container; volumes kept for next start"),
+        Err(e) => return Check::skip(NAME, format!("could not inspect volumes: {e}")),
+    };
+
+    let names: Vec<&str> = unattached.orphaned.iter().map(String::as_str).collect();
+    let sizes = get_disk_usage_with_volumes(client, &names)
+        .await
+        .map(|(_, volumes)| volumes)
+        .unwrap_or_default();
+    orphaned_volumes_check(&sizes, &names, unattached.shadowed.len())
+}
+
+fn orphaned_volumes_check(sizes: &[VolumeUsage], orphaned: &[&str], shadowed: usize) -> Check {
+    const NAME: &str = "Orphaned volumes";
+    if orphaned.is_empty() {
+        let detail = if shadowed == 0 {
+            "all managed volumes attached".to_string()
+        } else {
+            format!("none; {shadowed} volume(s) shadowed by bind mounts")
+        };
+        return Check::pass(NAME, detail);
+    }
+
+    let listed: Vec<String> = orphaned
+        .iter()
+        .map(
+            |name| match sizes.iter().find(|v| v.name == *name).and_then(|v| v.size) {
+                Some(size) => format!("{name} ({})", format_bytes_i64(size)),
+                None => name.to_string(),
+            },
+        )
+        .collect();
+    Check::warn(
+        NAME,
+        format!("not attached to the container: {}", listed.join(", ")),
+        "Review and remove them with: occ prune orphans",
+    )
+}
+
+fn count_problems(checks: &[Check]) -> (usize, usize) {
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    (count(CheckStatus::Fail), count(CheckStatus::Warn))
+}
+
+fn print_check(check: &Check) {
+    let marker = match check.status {
+        CheckStatus::Pass => style("✓").green(),
+        CheckStatus::Warn => style("!").yellow(),
+        CheckStatus::Fail => style("✗").red(),
+        CheckStatus::Skip => style("-").dim(),
+    };
+    println!("  {marker} {:<16} {}", check.name, check.detail);
+    if let Some(hint) = &check.hint {
+        for line in hint.lines() {
+            println!("    {} {}", style("→").dim(), style(line).dim());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disk_check_thresholds() {
+        assert_eq!(
+            disk_check(DISK_FAIL_BYTES - 1, None).status,
+            CheckStatus::Fail
+        );
+        assert_eq!(
+            disk_check(DISK_WARN_BYTES - 1, None).status,
+            CheckStatus::Warn
+        );
+        let ok = disk_check(DISK_WARN_BYTES, Some("Docker using 1 GB".to_string()));
+        assert_eq!(ok.status, CheckStatus::Pass);
+        assert!(ok.detail.ends_with("Docker using 1 GB"));
+    }
+
+    #[test]
+    fn port_check_fails_with_hint_when_taken() {
+        let check = port_check("127.0.0.1", 3000, false);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.hint.unwrap().contains("opencode_web_port"));
+        assert_eq!(
+            port_check("127.0.0.1", 3000, true).status,
+            CheckStatus::Pass
+        );
+    }
+
+    #[test]
+    fn config_check_reports_validation_errors() {
+        let mut config = Config::default();
+        assert_ne!(check_config(&config).status, CheckStatus::Fail);
+
+        config.opencode_web_port = 80;
+        let check = check_config(&config);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.hint.unwrap().starts_with("occ config set"));
+    }
+
+    #[test]
+    fn orphaned_volumes_check_lists_sizes() {
+        assert_eq!(
+            orphaned_volumes_check(&[], &[], 0).status,
+            CheckStatus::Pass
+        );
+        let shadowed = orphaned_volumes_check(&[], &[], 6);
+        assert_eq!(shadowed.status, CheckStatus::Pass);
+        assert!(
+            shadowed
+                .detail
+                .contains("6 volume(s) shadowed by bind mounts")
+        );
+
+        let sizes = [VolumeUsage {
+            name: "opencode-cache".to_string(),
+            size: Some(2048),
+        }];
+        let check = orphaned_volumes_check(&sizes, &["opencode-cache", "opencode-ssh"], 0);
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.detail.contains("opencode-cache (2.00 KB)"));
+        assert!(check.detail.contains("opencode-ssh"));
+        assert!(check.hint.unwrap().contains("occ prune orphans"));
+    }
+
+    #[test]
+    fn count_problems_ignores_pass_and_skip() {
+        let checks = vec![
+            Check::pass("a", ""),
+            Check::skip("b", ""),
+            Check::warn("c", "", ""),
+            Check::fail("d", "", ""),
+            Check::fail("e", "", ""),
+        ];
+        assert_eq!(count_problems(&checks), (2, 1));
+    }
+}
@@ -6,9 +6,9 @@ use console::style;
 use dialoguer::Confirm;
 use indicatif::{ProgressBar, ProgressStyle};
 use opencode_cloud_core::{
-    HostConfig, HostError, detect_distro, get_docker_install_commands, host_exists_in_ssh_config,
-    install_docker, load_hosts, query_ssh_config, save_hosts, test_connection,
-    verify_docker_installed, write_ssh_config_entry,
+    HostConfig, HostError, SshConfigMatch, detect_distro, get_docker_install_commands,
+    host_exists_in_ssh_config, install_docker, load_hosts, query_ssh_config, save_hosts,
+    test_connection, verify_docker_installed, write_ssh_config_entry,
 };
 
 /// Arguments for host add command
@@ -17,7 +17,7 @@ pub struct HostAddArgs {
     /// Name to identify this host (e.g., "prod-1", "staging")
     pub name: String,
 
-    /// SSH hostname or IP address
+    /// SSH hostname or IP address; `user@host:port` and `ssh://user@host:port` also work
     pub hostname: String,
 
     /// SSH username (default: from SSH config or current user)
@@ -40,12 +40,16 @@ pub struct HostAddArgs {
     #[arg(short, long)]
     pub group: Vec<String>,
 
+    /// Tag for filtering (can be specified multiple times)
+    #[arg(long)]
+    pub tag: Vec<String>,
+
     /// Description for this host
     #[arg(short, long)]
     pub description: Option<String>,
 
     /// Skip connection verification
-    #[arg(long)]
+    #[arg(long, alias = "no-test")]
     pub no_verify: bool,
 
     /// Overwrite if host already exists
@@ -55,6 +59,22 @@ pub struct HostAddArgs {
     /// Don't prompt to add host to SSH config
     #[arg(long)]
     pub no_ssh_config: bool,
+
+    /// CA certificate (PEM) to reach Docker directly over TLS instead of SSH
+    #[arg(long, requires_all = ["tls_cert", "tls_key"])]
+    pub tls_ca: Option<String>,
+
+    /// Client certificate (PEM) for Docker over TLS
+    #[arg(long, requires_all = ["tls_ca", "tls_key"])]
+    pub tls_cert: Option<String>,
+
+    /// Client private key (PEM) for Docker over TLS
+    #[arg(long, requires_all = ["tls_ca", "tls_cert"])]
+    pub tls_key: Option<String>,
+
+    /// Docker TLS port (default: 2376); --port stays the SSH port
+    #[arg(long, value_name = "PORT", requires = "tls_ca")]
+    pub docker_port: Option<u16>,
 }
 
 pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Result<()> {
@@ -69,8 +89,14 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
         );
     }
 
+    // Split `user@host:port` forms before anything is looked up or saved
+    let target = parse_host_target(&args.hostname)?;
+    let user = merge_target_field("user", args.user.clone(), target.user)?;
+    let port = merge_target_field("port", args.port, target.port)?;
+    let hostname = target.hostname.as_str();
+
     // Query SSH config for this hostname to auto-fill settings
-    let ssh_config_match = query_ssh_config(&args.hostname).unwrap_or_default();
+    let ssh_config_match = query_ssh_config(hostname).unwrap_or_default();
 
     if !quiet && ssh_config_match.has_settings() {
         println!(
@@ -81,62 +107,56 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
     }
 
     // Build host config, preferring explicit args > SSH config > defaults
-    let mut config = HostConfig::new(&args.hostname);
-
-    // User: explicit arg > SSH config > current user (HostConfig default)
-    let effective_user = args.user.clone().or_else(|| ssh_config_match.user.clone());
-    if let Some(user) = &effective_user {
-        config = config.with_user(user);
-    }
-
-    // Port: explicit arg > SSH config > default (22)
-    let effective_port = args.port.or(ssh_config_match.port);
-    if let Some(port) = effective_port {
-        config = config.with_port(port);
-    }
-
-    // Identity file: explicit arg > SSH config
-    let effective_identity = args
-        .identity_file
-        .clone()
-        .or_else(|| ssh_config_match.identity_file.clone());
-    if let Some(key) = &effective_identity {
-        config = config.with_identity_file(key);
-    }
-
-    // Jump host: explicit arg > SSH config
-    let effective_jump = args
-        .jump_host
-        .clone()
-        .or_else(|| ssh_config_match.proxy_jump.clone());
-    if let Some(jump) = &effective_jump {
-        config = config.with_jump_host(jump);
-    }
+    let mut config = build_host_config(
+        hostname,
+        user.as_deref(),
+        port,
+        args.identity_file.as_deref(),
+        args.jump_host.as_deref(),
+        &ssh_config_match,
+    );
 
-    // Groups and description (no SSH config equivalent)
+    // Groups, tags, and description (no SSH config equivalent)
     for group in &args.group {
         config = config.with_group(group);
     }
+    for tag in &args.tag {
+        config = config.with_tag(tag);
+    }
     if let Some(desc) = &args.description {
         config = config.with_description(desc);
     }
+    if let (Some(ca), Some(cert), Some(key)) = (&args.tls_ca, &args.tls_cert, &args.tls_key) {
+        config = config.with_tls(
+            resolve_tls_file("--tls-ca", ca)?,
+            resolve_tls_file("--tls-cert", cert)?,
+            resolve_tls_file("--tls-key", key)?,
+        );
+        if let Some(port) = args.docker_port {
+            config = config.with_docker_port(port);
+        }
+    }
 
     // Track if user provided custom settings that aren't in SSH config
-    let has_custom_settings = args.user.is_some()
-        || args.identity_file.is_some()
-        || args.port.is_some()
-        || args.jump_host.is_some();
+    let has_custom_settings = !config.uses_tls()
+        && (user.is_some()
+            || args.identity_file.is_some()
+            || port.is_some()
+            || args.jump_host.is_some());
 
     // Test connection unless --no-verify
     let mut verification_succeeded = false;
     if !args.no_verify {
         if !quiet {
-            // Show the effective SSH command being used
-            println!(
-                "{} {}",
-                style("SSH Command:").cyan(),
-                style(config.format_ssh_command()).dim()
-            );
+            // Show the effective SSH command (or TLS endpoint) being used
+            match config.docker_tls_url() {
+                Some(url) => println!("{} {}", style("Docker TLS:").cyan(), style(url).dim()),
+                None => println!(
+                    "{} {}",
+                    style("SSH Command:").cyan(),
+                    style(config.format_ssh_command()).dim()
+                ),
+            }
 
             let spinner = ProgressBar::new_spinner();
             spinner.set_style(
@@ -146,7 +166,7 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
             );
             spinner.set_message(format!(
                 "Testing connection to {}@{}...",
-                config.user, args.hostname
+                config.user, hostname
             ));
             spinner.enable_steady_tick(std::time::Duration::from_millis(100));
 
@@ -167,9 +187,7 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
                     eprintln!();
 
                     // Offer to install Docker
-                    if let Some(installed) =
-                        offer_docker_installation(&config, &args.hostname, quiet)?
-                    {
+                    if let Some(installed) = offer_docker_installation(&config, hostname, quiet)? {
                         if installed {
                             verification_succeeded = true;
                         }
@@ -186,8 +204,8 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
                     // Provide helpful tips based on the error
                     print_connection_failure_tips(
                         &config,
-                        &args.hostname,
-                        args.user.is_none(),
+                        hostname,
+                        user.is_none(),
                         args.identity_file.is_none(),
                     );
 
@@ -214,14 +232,14 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
                 "{} Host '{}' updated ({}).",
                 style("Updated:").yellow(),
                 style(&args.name).cyan(),
-                args.hostname
+                hostname
             );
         } else {
             println!(
                 "{} Host '{}' added ({}).",
                 style("Added:").green(),
                 style(&args.name).cyan(),
-                args.hostname
+                hostname
             );
         }
 
@@ -255,9 +273,9 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
             if should_add {
                 match write_ssh_config_entry(
                     &args.name,
-                    &args.hostname,
-                    args.user.as_deref(),
-                    args.port,
+                    hostname,
+                    user.as_deref(),
+                    port,
                     args.identity_file.as_deref(),
                     args.jump_host.as_deref(),
                 ) {
@@ -288,6 +306,149 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
     Ok(())
 }
 
+/// A host argument split into its SSH parts
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct HostTarget {
+    pub(super) hostname: String,
+    pub(super) user: Option<String>,
+    pub(super) port: Option<u16>,
+}
+
+const HOST_TARGET_EXAMPLES: &str = "Valid forms:\n  \
+    203.0.113.10\n  \
+    server.example.com\n  \
+    deploy@server.example.com:2222\n  \
+    ssh://deploy@server.example.com:2222\n  \
+    [2001:db8::1]:22";
+
+/// Parse `host`, `user@host:port`, or `ssh://user@host:port`
+///
+/// Any scheme other than `ssh://` (such as `tcp://` or `unix://`) is rejected
+/// as a host argument rather than saved.
+pub(super) fn parse_host_target(input: &str) -> Result<HostTarget> {
+    let input = input.trim();
+    let rest = match input.split_once("://") {
+        Some(("ssh", rest)) => rest.trim_end_matches('/'),
+        Some((scheme, _)) => bail!(
+            "Unsupported scheme '{scheme}://' in '{input}'. Remote hosts are reached over SSH; \
+             give the SSH host instead (add --tls-ca/--tls-cert/--tls-key for Docker over TLS).\n\n{HOST_TARGET_EXAMPLES}"
+        ),
+        None => input,
+    };
+
+    let (user, host_port) = match rest.split_once('@') {
+        Some((user, host_port)) => (Some(user), host_port),
+        None => (None, rest),
+    };
+    if user.is_some_and(|u| u.is_empty() || u.contains(char::is_whitespace)) {
+        bail!("Invalid user in '{input}'.\n\n{HOST_TARGET_EXAMPLES}");
+    }
+
+    let (hostname, port) = if let Some(bracketed) = host_port.strip_prefix('[') {
+        let (addr, after) = bracketed.split_once(']').ok_or_else(|| {
+            anyhow::anyhow!("Unclosed '[' in '{input}'.\n\n{HOST_TARGET_EXAMPLES}")
+        })?;
+        match after {
+            "" => (addr, None),
+            _ => match after.strip_prefix(':') {
+                Some(port) => (addr, Some(port)),
+                None => bail!("Invalid host '{input}'.\n\n{HOST_TARGET_EXAMPLES}"),
+            },
+        }
+    } else if host_port.matches(':').count() == 1 {
+        let (host, port) = host_port.split_once(':').unwrap_or((host_port, ""));
+        (host, Some(port))
+    } else {
+        // Zero colons, or a bare IPv6 address
+        (host_port, None)
+    };
+
+    let valid_host = !hostname.is_empty()
+        && hostname
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | ':'));
+    if !valid_host {
+        bail!("Invalid host '{input}'.\n\n{HOST_TARGET_EXAMPLES}");
+    }
+
+    let port = match port {
+        Some(port) => match port.parse::<u16>() {
+            Ok(port) if port != 0 => Some(port),
+            _ => bail!("Invalid port '{port}' in '{input}' (expected 1-65535)."),
+        },
+        None => None,
+    };
+
+    Ok(HostTarget {
+        hostname: hostname.to_string(),
+        user: user.map(str::to_string),
+        port,
+    })
+}
+
+/// Check that a `--tls-*` file exists and return its absolute path
+///
+/// Paths are stored absolute so the host keeps working from any directory.
+/// A leading `~` is expanded, since shells leave `--tls-ca=~/...` alone.
+fn resolve_tls_file(flag: &str, path: &str) -> Result<String> {
+    let path = opencode_cloud_core::expand_home(path);
+    if !path.is_file() {
+        bail!("{flag} file not found: {}", path.display());
+    }
+    let absolute = std::fs::canonicalize(&path)?;
+    Ok(absolute.display().to_string())
+}
+
+/// Combine a `--user`/`--port` flag with the same value from the host argument
+fn merge_target_field<T: PartialEq + std::fmt::Display>(
+    field: &str,
+    flag: Option<T>,
+    from_host: Option<T>,
+) -> Result<Option<T>> {
+    match (flag, from_host) {
+        (Some(flag), Some(from_host)) if flag != from_host => {
+            bail!("Conflicting {field}: --{field} {flag} but the host argument says {from_host}.")
+        }
+        (flag, from_host) => Ok(flag.or(from_host)),
+    }
+}
+
+/// Build a host config from explicit settings, falling back to SSH config values
+///
+/// Precedence for each field: explicit value > SSH config > `HostConfig` default.
+pub(crate) fn build_host_config(
+    hostname: &str,
+    user: Option<&str>,
+    port: Option<u16>,
+    identity_file: Option<&str>,
+    jump_host: Option<&str>,
+    ssh_config_match: &SshConfigMatch,
+) -> HostConfig {
+    let mut config = HostConfig::new(hostname);
+
+    // User: explicit arg > SSH config > current user (HostConfig default)
+    if let Some(user) = user.or(ssh_config_match.user.as_deref()) {
+        config = config.with_user(user);
+    }
+
+    // Port: explicit arg > SSH config > default (22)
+    if let Some(port) = port.or(ssh_config_match.port) {
+        config = config.with_port(port);
+    }
+
+    // Identity file: explicit arg > SSH config
+    if let Some(key) = identity_file.or(ssh_config_match.identity_file.as_deref()) {
+        config = config.with_identity_file(key);
+    }
+
+    // Jump host: explicit arg > SSH config
+    if let Some(jump) = jump_host.or(ssh_config_match.proxy_jump.as_deref()) {
+        config = config.with_jump_host(jump);
+    }
+
+    config
+}
+
 /// Offer to install Docker on a remote host
 ///
 /// Returns:
@@ -609,3 +770,75 @@ fn find_ssh_keys() -> Vec<String> {
     keys.sort();
     keys
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(hostname: &str, user: Option<&str>, port: Option<u16>) -> HostTarget {
+        HostTarget {
+            hostname: hostname.to_string(),
+            user: user.map(str::to_string),
+            port,
+        }
+    }
+
+    #[test]
+    fn parse_host_target_normalizes_ssh_forms() {
+        assert_eq!(
+            parse_host_target("example.com").unwrap(),
+            target("example.com", None, None)
+        );
+        assert_eq!(
+            parse_host_target("ssh://deploy@example.com:2222/").unwrap(),
+            target("example.com", Some("deploy"), Some(2222))
+        );
+        assert_eq!(
+            parse_host_target("[2001:db8::1]:22").unwrap(),
+            target("2001:db8::1", None, Some(22))
+        );
+        assert_eq!(
+            parse_host_target("2001:db8::1").unwrap(),
+            target("2001:db8::1", None, None)
+        );
+    }
+
+    #[test]
+    fn parse_host_target_rejects_malformed_input() {
+        let err = parse_host_target("tcp://example.com:2375").unwrap_err();
+        assert!(err.to_string().contains("Valid forms"));
+        assert!(parse_host_target("unix:///var/run/docker.sock").is_err());
+        assert!(parse_host_target("example.com:99999").is_err());
+        assert!(parse_host_target("example.com:").is_err());
+        assert!(parse_host_target("@example.com").is_err());
+        assert!(parse_host_target("exa mple.com").is_err());
+        assert!(parse_host_target("").is_err());
+    }
+
+    #[test]
+    fn resolve_tls_file_requires_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let ca = dir.path().join("ca.pem");
+        std::fs::write(&ca, "pem").unwrap();
+
+        let resolved = resolve_tls_file("--tls-ca", ca.to_str().unwrap()).unwrap();
+        assert!(std::path::Path::new(&resolved).is_absolute());
+
+        let missing = dir.path().join("missing.pem");
+        let err = resolve_tls_file("--tls-key", missing.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("--tls-key file not found"));
+    }
+
+    #[test]
+    fn merge_target_field_rejects_conflicts() {
+        assert_eq!(
+            merge_target_field("port", None, Some(22)).unwrap(),
+            Some(22)
+        );
+        assert_eq!(
+            merge_target_field("port", Some(22), Some(22)).unwrap(),
+            Some(22)
+        );
+        assert!(merge_target_field("port", Some(2222), Some(22)).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! occ host clone - Duplicate an existing host entry
+
+use super::add::parse_host_target;
+use anyhow::{Result, bail};
+use clap::Args;
+use console::style;
+use opencode_cloud_core::{HostConfig, load_hosts, save_hosts};
+
+/// Arguments for host clone command
+#[derive(Args)]
+pub struct HostCloneArgs {
+    /// Name of the host to copy from
+    pub source: String,
+
+    /// Name for the new host entry
+    pub new_name: String,
+
+    /// Override SSH hostname or IP address (user@host:port and ssh:// forms accepted)
+    #[arg(long, alias = "endpoint")]
+    pub hostname: Option<String>,
+
+    /// Override SSH username
+    #[arg(short, long)]
+    pub user: Option<String>,
+
+    /// Override SSH port
+    #[arg(short, long)]
+    pub port: Option<u16>,
+
+    /// Override identity file path (use empty string to clear)
+    #[arg(short, long)]
+    pub identity_file: Option<String>,
+
+    /// Override jump host (use empty string to clear)
+    #[arg(short = 'J', long)]
+    pub jump_host: Option<String>,
+
+    /// Override description (use empty string to clear)
+    #[arg(short, long)]
+    pub description: Option<String>,
+
+    /// Overwrite if the new host name already exists
+    #[arg(long)]
+    pub force: bool,
+}
+
+pub async fn cmd_host_clone(args: &HostCloneArgs, quiet: bool, _verbose: u8) -> Result<()> {
+    let mut hosts = load_hosts()?;
+
+    if args.source == args.new_name {
+        bail!("Source and new host name must be different.");
+    }
+
+    let source = hosts
+        .get_host(&args.source)
+        .ok_or_else(|| anyhow::anyhow!("Host '{}' not found.", args.source))?;
+
+    if hosts.has_host(&args.new_name) && !args.force {
+        bail!(
+            "Host '{}' already exists. Use --force to overwrite, or choose a different name.",
+            args.new_name
+        );
+    }
+
+    let config = apply_overrides(source.clone(), args)?;
+    let is_overwrite = hosts.has_host(&args.new_name);
+    hosts.add_host(&args.new_name, config.clone());
+    save_hosts(&hosts)?;
+
+    if !quiet {
+        let label = if is_overwrite {
+            style("Updated:").yellow()
+        } else {
+            style("Cloned:").green()
+        };
+        println!(
+            "{} Host '{}' created from '{}' ({}).",
+            label,
+            style(&args.new_name).cyan(),
+            style(&args.source).cyan(),
+            config.hostname
+        );
+        println!(
+            "  {} Connection not verified. Run {} to test.",
+            style("Note:").dim(),
+            style(format!("occ host test {}", args.new_name)).yellow()
+        );
+    }
+
+    Ok(())
+}
+
+/// Apply override flags to a copy of the source host config
+///
+/// Explicit `--user`/`--port` win over parts of the `--hostname` value.
+fn apply_overrides(mut config: HostConfig, args: &HostCloneArgs) -> Result<HostConfig> {
+    if let Some(hostname) = &args.hostname {
+        let target = parse_host_target(hostname)?;
+        config.hostname = target.hostname;
+        if let Some(user) = target.user {
+            config.user = user;
+        }
+        if let Some(port) = target.port {
+            config.port = Some(port);
+        }
+    }
+
+    if let Some(user) = &args.user {
+        config.user = user.clone();
+    }
+
+    if let Some(port) = args.port {
+        config.port = Some(port);
+    }
+
+    if let Some(key) = &args.identity_file {
+        config.identity_file = (!key.is_empty()).then(|| key.clone());
+    }
+
+    if let Some(jump) = &args.jump_host {
+        config.jump_host = (!jump.is_empty()).then(|| jump.clone());
+    }
+
+    if let Some(desc) = &args.description {
+        config.description = (!desc.is_empty()).then(|| desc.clone());
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clone_args() -> HostCloneArgs {
+        HostCloneArgs {
+            source: "staging".to_string(),
+            new_name: "prod".to_string(),
+            hostname: None,
+            user: None,
+            port: None,
+            identity_file: None,
+            jump_host: None,
+            description: None,
+            force: false,
+        }
+    }
+
+    #[test]
+    fn apply_overrides_keeps_source_fields_without_flags() {
+        let source = HostConfig::new("staging.example.com")
+            .with_user("deploy")
+            .with_port(2222)
+            .with_group("web");
+        let cloned = apply_overrides(source.clone(), &clone_args()).unwrap();
+        assert_eq!(cloned, source);
+    }
+
+    #[test]
+    fn apply_overrides_replaces_and_clears_fields() {
+        let source = HostConfig::new("staging.example.com")
+            .with_user("deploy")
+            .with_jump_host("bastion")
+            .with_description("staging box");
+        let args = HostCloneArgs {
+            hostname: Some("prod.example.com".to_string()),
+            jump_host: Some(String::new()),
+            description: Some("prod box".to_string()),
+            ..clone_args()
+        };
+        let cloned = apply_overrides(source, &args).unwrap();
+        assert_eq!(cloned.hostname, "prod.example.com");
+        assert_eq!(cloned.user, "deploy");
+        assert!(cloned.jump_host.is_none());
+        assert_eq!(cloned.description.as_deref(), Some("prod box"));
+    }
+
+    #[test]
+    fn apply_overrides_validates_hostname() {
+        let source = HostConfig::new("staging.example.com").with_user("deploy");
+        let args = HostCloneArgs {
+            hostname: Some("root@[2001:db8::1]:2200".to_string()),
+            port: Some(22),
+            ..clone_args()
+        };
+        let cloned = apply_overrides(source.clone(), &args).unwrap();
+        assert_eq!(cloned.hostname, "2001:db8::1");
+        assert_eq!(cloned.user, "root");
+        assert_eq!(cloned.port, Some(22));
+
+        for bad in [
+            "exa mple.com",
+            "tcp://example.com:2375",
+            "example.com:0",
+            "",
+        ] {
+            let args = HostCloneArgs {
+                hostname: Some(bad.to_string()),
+                ..clone_args()
+            };
+            assert!(apply_overrides(source.clone(), &args).is_err(), "{bad}");
+        }
+    }
+}
@@ -1,18 +1,32 @@
 //! occ host edit - Edit host configuration
+//!
+//! Flags update individual fields for scripting; with no field flags on a
+//! terminal, each field is prompted for with its current value as default.
+//! `--editor` opens hosts.json in `$VISUAL`/`$EDITOR` instead.
 
-use anyhow::Result;
+use super::add::parse_host_target;
+use crate::editor::{EditOutcome, edit_validated};
+use anyhow::{Context, Result, bail};
 use clap::Args;
 use console::style;
-use opencode_cloud_core::{load_hosts, save_hosts};
+use dialoguer::{Confirm, Input};
+use indicatif::{ProgressBar, ProgressStyle};
+use opencode_cloud_core::config::validate_bind_address;
+use opencode_cloud_core::{
+    HostConfig, HostsFile, get_hosts_path, load_hosts, save_hosts, test_connection,
+};
+use std::io::IsTerminal;
+use std::time::Duration;
 
 /// Arguments for host edit command
 #[derive(Args)]
 pub struct HostEditArgs {
     /// Name of the host to edit
-    pub name: String,
+    #[arg(required_unless_present = "editor")]
+    pub name: Option<String>,
 
-    /// New hostname
-    #[arg(long)]
+    /// New hostname, user@host:port, or ssh://[user@]host[:port] endpoint
+    #[arg(long, alias = "endpoint")]
     pub hostname: Option<String>,
 
     /// New SSH username
@@ -23,7 +37,7 @@ pub struct HostEditArgs {
     #[arg(short, long)]
     pub port: Option<u16>,
 
-    /// New identity file path
+    /// New identity file path (use empty string to clear)
     #[arg(short, long)]
     pub identity_file: Option<String>,
 
@@ -39,99 +53,501 @@ pub struct HostEditArgs {
     #[arg(long)]
     pub remove_group: Vec<String>,
 
+    /// Replace tags (can be specified multiple times; use empty string to clear)
+    #[arg(long)]
+    pub tag: Vec<String>,
+
     /// New description (use empty string to clear)
     #[arg(short, long)]
     pub description: Option<String>,
+
+    /// SSH connect timeout in seconds (0 restores the default)
+    #[arg(long, value_name = "SECS")]
+    pub connect_timeout: Option<u32>,
+
+    /// opencode web port to use when this host is the target (0 falls back to the config)
+    #[arg(long, value_name = "PORT")]
+    pub web_port: Option<u16>,
+
+    /// Bind address to use when this host is the target (use empty string to clear)
+    #[arg(long, value_name = "ADDRESS")]
+    pub bind_address: Option<String>,
+
+    /// Docker TLS port for hosts reached over TLS (0 falls back to 2376)
+    #[arg(long, value_name = "PORT")]
+    pub docker_port: Option<u16>,
+
+    /// Test the connection with the new settings and only save if it succeeds
+    #[arg(long)]
+    pub test: bool,
+
+    /// Open hosts.json in $VISUAL or $EDITOR, saving only if it still parses
+    #[arg(long, conflicts_with_all = [
+        "hostname", "user", "port", "identity_file", "jump_host", "add_group",
+        "remove_group", "tag", "description", "connect_timeout", "web_port", "bind_address",
+        "docker_port", "test",
+    ])]
+    pub editor: bool,
+}
+
+impl HostEditArgs {
+    fn has_field_changes(&self) -> bool {
+        self.hostname.is_some()
+            || self.user.is_some()
+            || self.port.is_some()
+            || self.identity_file.is_some()
+            || self.jump_host.is_some()
+            || !self.add_group.is_empty()
+            || !self.remove_group.is_empty()
+            || !self.tag.is_empty()
+            || self.description.is_some()
+            || self.connect_timeout.is_some()
+            || self.web_port.is_some()
+            || self.bind_address.is_some()
+            || self.docker_port.is_some()
+    }
 }
 
 pub async fn cmd_host_edit(args: &HostEditArgs, quiet: bool, _verbose: u8) -> Result<()> {
+    if args.editor {
+        return edit_hosts_file(args.name.as_deref(), quiet);
+    }
+
+    let name = args.name.as_deref().unwrap_or_default();
     let mut hosts = load_hosts()?;
 
-    let config = hosts
-        .get_host_mut(&args.name)
-        .ok_or_else(|| anyhow::anyhow!("Host '{}' not found.", args.name))?;
+    let original = hosts
+        .get_host(name)
+        .ok_or_else(|| anyhow::anyhow!("Host '{name}' not found."))?
+        .clone();
 
-    let mut changed = false;
+    let (updated, test) = if args.has_field_changes() {
+        (apply_edits(original.clone(), args)?, args.test)
+    } else {
+        if quiet || !std::io::stdin().is_terminal() {
+            if !quiet {
+                println!("No changes specified. Use --help to see available options.");
+            }
+            return Ok(());
+        }
+        let updated = prompt_host_edit(&original)?;
+        let test = updated != original
+            && (args.test
+                || Confirm::new()
+                    .with_prompt("Test the connection before saving?")
+                    .default(true)
+                    .interact()?);
+        (updated, test)
+    };
 
-    // Apply changes
+    if updated == original {
+        if !quiet {
+            println!("No changes to save.");
+        }
+        return Ok(());
+    }
+
+    if test {
+        verify_connection(name, &updated, quiet).await?;
+    }
+
+    // Save
+    hosts.add_host(name, updated);
+    save_hosts(&hosts)?;
+
+    if !quiet {
+        println!(
+            "{} Host '{}' updated.",
+            style("Updated:").green(),
+            style(name).cyan()
+        );
+        println!(
+            "  {} {}",
+            style("View changes:").dim(),
+            style(format!("occ host show {name}")).yellow()
+        );
+    }
+
+    Ok(())
+}
+
+/// Edit hosts.json directly, keeping the original when the result doesn't parse
+fn edit_hosts_file(name: Option<&str>, quiet: bool) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        bail!("occ host edit --editor needs an interactive terminal.");
+    }
+
+    if let Some(name) = name
+        && load_hosts()?.get_host(name).is_none()
+    {
+        bail!("Host '{name}' not found.");
+    }
+
+    let hosts_path =
+        get_hosts_path().ok_or_else(|| anyhow::anyhow!("Could not determine hosts file path"))?;
+    if !hosts_path.exists() {
+        save_hosts(&HostsFile::new())?;
+    }
+
+    let outcome = edit_validated(&hosts_path, |path| {
+        let contents = std::fs::read_to_string(path)?;
+        let hosts: HostsFile = serde_json::from_str(&contents)
+            .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+        validate_hosts_file(&hosts)
+    })?;
+
+    if quiet {
+        return Ok(());
+    }
+    match outcome {
+        EditOutcome::Saved(()) => println!(
+            "{} Saved {}",
+            style("Updated:").green(),
+            hosts_path.display()
+        ),
+        EditOutcome::Unchanged => println!("No changes to save."),
+        EditOutcome::Discarded => {
+            println!("Edit discarded; {} was not changed.", hosts_path.display())
+        }
+    }
+    Ok(())
+}
+
+/// Checks serde can't express: the default host must exist and hostnames can't be blank
+fn validate_hosts_file(hosts: &HostsFile) -> Result<()> {
+    if let Some(default) = &hosts.default_host
+        && !hosts.hosts.contains_key(default)
+    {
+        bail!("default_host '{default}' is not one of the configured hosts");
+    }
+    if let Some((name, _)) = hosts
+        .hosts
+        .iter()
+        .find(|(_, host)| host.hostname.trim().is_empty())
+    {
+        bail!("Host '{name}' has an empty hostname");
+    }
+    Ok(())
+}
+
+/// Apply flag-based edits to a copy of the host config
+fn apply_edits(mut config: HostConfig, args: &HostEditArgs) -> Result<HostConfig> {
     if let Some(hostname) = &args.hostname {
-        config.hostname = hostname.clone();
-        changed = true;
+        apply_endpoint(&mut config, hostname)?;
     }
 
+    // Explicit flags win over parts of an ssh:// endpoint
     if let Some(user) = &args.user {
         config.user = user.clone();
-        changed = true;
     }
 
     if let Some(port) = args.port {
         config.port = Some(port);
-        changed = true;
     }
 
     if let Some(key) = &args.identity_file {
-        config.identity_file = if key.is_empty() {
-            None
-        } else {
-            Some(key.clone())
-        };
-        changed = true;
+        config.identity_file = (!key.is_empty()).then(|| key.clone());
     }
 
     if let Some(jump) = &args.jump_host {
-        config.jump_host = if jump.is_empty() {
-            None
-        } else {
-            Some(jump.clone())
-        };
-        changed = true;
+        config.jump_host = (!jump.is_empty()).then(|| jump.clone());
     }
 
     for group in &args.add_group {
         if !config.groups.contains(group) {
             config.groups.push(group.clone());
-            changed = true;
         }
     }
 
-    for group in &args.remove_group {
-        if let Some(pos) = config.groups.iter().position(|g| g == group) {
-            config.groups.remove(pos);
-            changed = true;
+    config.groups.retain(|g| !args.remove_group.contains(g));
+
+    if !args.tag.is_empty() {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in args.tag.iter().filter(|t| !t.is_empty()) {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
         }
+        config.tags = tags;
     }
 
     if let Some(desc) = &args.description {
-        config.description = if desc.is_empty() {
-            None
-        } else {
-            Some(desc.clone())
-        };
-        changed = true;
+        config.description = (!desc.is_empty()).then(|| desc.clone());
     }
 
-    if !changed {
-        if !quiet {
-            println!("No changes specified. Use --help to see available options.");
+    if let Some(timeout) = args.connect_timeout {
+        config.connect_timeout = (timeout > 0).then_some(timeout);
+    }
+
+    if let Some(port) = args.web_port {
+        config.web_port = (port > 0).then_some(port);
+    }
+
+    if let Some(port) = args.docker_port {
+        config.docker_port = (port > 0).then_some(port);
+    }
+
+    if let Some(address) = &args.bind_address {
+        let address = address.trim();
+        if !address.is_empty() {
+            validate_bind_address(address)
+                .map_err(|e| anyhow::anyhow!("Invalid bind address '{address}': {e}"))?;
+        }
+        config.bind_address = (!address.is_empty()).then(|| address.to_string());
+    }
+
+    Ok(config)
+}
+
+/// Set the hostname, taking user and port from the endpoint when given
+fn apply_endpoint(config: &mut HostConfig, value: &str) -> Result<()> {
+    let target = parse_host_target(value)?;
+    config.hostname = target.hostname;
+    if let Some(user) = target.user {
+        config.user = user;
+    }
+    if let Some(port) = target.port {
+        config.port = Some(port);
+    }
+    Ok(())
+}
+
+/// Prompt for every field, keeping the current value on Enter
+fn prompt_host_edit(current: &HostConfig) -> Result<HostConfig> {
+    let mut config = current.clone();
+
+    let hostname: String = Input::new()
+        .with_prompt("Hostname or ssh:// endpoint")
+        .default(current.hostname.clone())
+        .interact_text()?;
+    apply_endpoint(&mut config, &hostname)?;
+
+    config.user = Input::new()
+        .with_prompt("SSH user")
+        .default(config.user.clone())
+        .interact_text()?;
+
+    let port: String = Input::new()
+        .with_prompt("SSH port (empty for 22)")
+        .default(config.port.map(|p| p.to_string()).unwrap_or_default())
+        .allow_empty(true)
+        .validate_with(|input: &String| -> Result<(), String> {
+            if input.is_empty() || input.parse::<u16>().is_ok() {
+                Ok(())
+            } else {
+                Err("Enter a port number between 1 and 65535".to_string())
+            }
+        })
+        .interact_text()?;
+    config.port = port.parse().ok();
+
+    config.identity_file = prompt_optional("Identity file", &config.identity_file)?;
+    config.jump_host = prompt_optional("Jump host", &config.jump_host)?;
+    config.groups = prompt_list("Groups (comma-separated)", &config.groups)?;
+    config.tags = prompt_list("Tags (comma-separated)", &config.tags)?;
+    config.description = prompt_optional("Description", &config.description)?;
+
+    let timeout: String = Input::new()
+        .with_prompt("Connect timeout in seconds (empty for default)")
+        .default(
+            config
+                .connect_timeout
+                .map(|t| t.to_string())
+                .unwrap_or_default(),
+        )
+        .allow_empty(true)
+        .validate_with(|input: &String| -> Result<(), String> {
+            if input.is_empty() || input.parse::<u32>().is_ok_and(|t| t > 0) {
+                Ok(())
+            } else {
+                Err("Enter a positive number of seconds".to_string())
+            }
+        })
+        .interact_text()?;
+    config.connect_timeout = timeout.parse().ok();
+
+    Ok(config)
+}
+
+fn prompt_optional(prompt: &str, current: &Option<String>) -> Result<Option<String>> {
+    let value: String = Input::new()
+        .with_prompt(format!("{prompt} (empty for none)"))
+        .default(current.clone().unwrap_or_default())
+        .allow_empty(true)
+        .interact_text()?;
+    let value = value.trim();
+    Ok((!value.is_empty()).then(|| value.to_string()))
+}
+
+fn prompt_list(prompt: &str, current: &[String]) -> Result<Vec<String>> {
+    let value: String = Input::new()
+        .with_prompt(prompt)
+        .default(current.join(", "))
+        .allow_empty(true)
+        .interact_text()?;
+    let mut items: Vec<String> = Vec::new();
+    for item in value.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+        if !items.iter().any(|existing| existing == item) {
+            items.push(item.to_string());
         }
+    }
+    Ok(items)
+}
+
+/// Reconnect with the edited settings; an error leaves hosts.json untouched
+async fn verify_connection(name: &str, config: &HostConfig, quiet: bool) -> Result<()> {
+    if quiet {
+        test_connection(config).await?;
         return Ok(());
     }
 
-    // Save
-    save_hosts(&hosts)?;
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.cyan} {msg}")
+            .expect("valid template"),
+    );
+    spinner.set_message(format!(
+        "Testing connection to {}@{}...",
+        config.user, config.hostname
+    ));
+    spinner.enable_steady_tick(Duration::from_millis(100));
 
-    if !quiet {
-        println!(
-            "{} Host '{}' updated.",
-            style("Updated:").green(),
-            style(&args.name).cyan()
-        );
-        println!(
-            "  {} {}",
-            style("View changes:").dim(),
-            style(format!("occ host show {}", args.name)).yellow()
-        );
+    match test_connection(config).await {
+        Ok(docker_version) => {
+            spinner.finish_with_message(format!(
+                "{} Connected (Docker {})",
+                style("✓").green(),
+                docker_version
+            ));
+            Ok(())
+        }
+        Err(e) => {
+            spinner.finish_with_message(format!("{} Connection failed", style("✗").red()));
+            bail!(
+                "{e}\n\nHost '{name}' was not changed. Fix the settings or drop --test to save anyway."
+            )
+        }
     }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit_args(name: &str) -> HostEditArgs {
+        HostEditArgs {
+            name: Some(name.to_string()),
+            hostname: None,
+            user: None,
+            port: None,
+            identity_file: None,
+            jump_host: None,
+            add_group: Vec::new(),
+            remove_group: Vec::new(),
+            tag: Vec::new(),
+            description: None,
+            connect_timeout: None,
+            web_port: None,
+            bind_address: None,
+            docker_port: None,
+            test: false,
+            editor: false,
+        }
+    }
+
+    #[test]
+    fn apply_endpoint_accepts_urls_and_bare_hostnames() {
+        let mut config = HostConfig::new("old.example.com");
+        apply_endpoint(&mut config, "ssh://deploy@prod.example.com:2222").unwrap();
+        assert_eq!(config.hostname, "prod.example.com");
+        assert_eq!(config.user, "deploy");
+        assert_eq!(config.port, Some(2222));
+
+        let mut config = HostConfig::new("old.example.com");
+        apply_endpoint(&mut config, "deploy@10.0.0.5:2200").unwrap();
+        assert_eq!(config.hostname, "10.0.0.5");
+        assert_eq!(config.user, "deploy");
+        assert_eq!(config.port, Some(2200));
+    }
+
+    #[test]
+    fn apply_endpoint_handles_ipv6() {
+        let mut config = HostConfig::new("old.example.com");
+        apply_endpoint(&mut config, "ssh://[2001:db8::1]:22").unwrap();
+        assert_eq!(config.hostname, "2001:db8::1");
+        assert_eq!(config.port, Some(22));
+
+        let mut config = HostConfig::new("old.example.com");
+        apply_endpoint(&mut config, "ssh://2001:db8::1").unwrap();
+        assert_eq!(config.hostname, "2001:db8::1");
+        assert!(config.port.is_none());
+    }
+
+    #[test]
+    fn apply_endpoint_rejects_malformed_input() {
+        let mut config = HostConfig::new("old.example.com");
+        assert!(apply_endpoint(&mut config, "ssh://host:notaport").is_err());
+        assert!(apply_endpoint(&mut config, "ssh://host:0").is_err());
+        assert!(apply_endpoint(&mut config, "http://host").is_err());
+        assert!(apply_endpoint(&mut config, "ssh://").is_err());
+        assert_eq!(config.hostname, "old.example.com");
+    }
+
+    #[test]
+    fn apply_edits_prefers_flags_over_endpoint_parts() {
+        let original = HostConfig::new("old.example.com")
+            .with_user("ubuntu")
+            .with_identity_file("~/.ssh/old");
+        let mut args = edit_args("prod");
+        args.hostname = Some("ssh://root@new.example.com:2200".to_string());
+        args.port = Some(22);
+        args.identity_file = Some(String::new());
+        args.connect_timeout = Some(30);
+
+        let updated = apply_edits(original, &args).unwrap();
+        assert_eq!(updated.hostname, "new.example.com");
+        assert_eq!(updated.user, "root");
+        assert_eq!(updated.port, Some(22));
+        assert!(updated.identity_file.is_none());
+        assert_eq!(updated.connect_timeout, Some(30));
+    }
+
+    #[test]
+    fn apply_edits_sets_and_clears_overrides() {
+        let mut args = edit_args("prod");
+        args.web_port = Some(3100);
+        args.bind_address = Some("0.0.0.0".to_string());
+        args.docker_port = Some(2377);
+        let updated = apply_edits(HostConfig::new("prod.example.com"), &args).unwrap();
+        assert_eq!(updated.web_port, Some(3100));
+        assert_eq!(updated.docker_port, Some(2377));
+        assert!(updated.port.is_none());
+        assert_eq!(updated.bind_address.as_deref(), Some("0.0.0.0"));
+
+        args.web_port = Some(0);
+        args.bind_address = Some(String::new());
+        args.docker_port = Some(0);
+        let cleared = apply_edits(updated, &args).unwrap();
+        assert!(!cleared.has_overrides());
+        assert!(cleared.docker_port.is_none());
+
+        args.bind_address = Some("not-an-ip".to_string());
+        assert!(apply_edits(cleared, &args).is_err());
+    }
+
+    #[test]
+    fn validate_hosts_file_rejects_dangling_default() {
+        let mut hosts = HostsFile::new();
+        hosts.add_host("prod", HostConfig::new("prod.example.com"));
+        hosts.default_host = Some("prod".to_string());
+        assert!(validate_hosts_file(&hosts).is_ok());
+
+        hosts.default_host = Some("staging".to_string());
+        assert!(validate_hosts_file(&hosts).is_err());
+
+        hosts.default_host = None;
+        hosts.add_host("blank", HostConfig::new(" "));
+        assert!(validate_hosts_file(&hosts).is_err());
+    }
 }
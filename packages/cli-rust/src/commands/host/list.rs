@@ -1,10 +1,23 @@
 //! occ host list - List all configured hosts
 
 use anyhow::Result;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use comfy_table::{Cell, Color, Table};
 use console::style;
-use opencode_cloud_core::{get_hosts_path, load_hosts};
+use opencode_cloud_core::{HostConfig, get_hosts_path, load_hosts};
+use serde_json::{Value, json};
+
+/// Output format for `occ host list`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum HostListFormat {
+    /// Human-readable table
+    #[default]
+    Table,
+    /// JSON array of host configs
+    Json,
+    /// YAML list of host configs
+    Yaml,
+}
 
 /// Arguments for host list command
 #[derive(Args)]
@@ -13,15 +26,31 @@ pub struct HostListArgs {
     #[arg(short, long)]
     pub group: Option<String>,
 
+    /// Filter by tag
+    #[arg(long)]
+    pub tag: Option<String>,
+
     /// Show only host names (for scripting)
     #[arg(long)]
     pub names_only: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = HostListFormat::Table)]
+    pub format: HostListFormat,
+}
+
+impl HostListArgs {
+    /// Whether this invocation prints JSON (`--format json` without `--names-only`)
+    pub(crate) fn json_output(&self) -> bool {
+        self.format == HostListFormat::Json && !self.names_only
+    }
 }
 
 pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Result<()> {
     let hosts = load_hosts()?;
+    let structured = args.format != HostListFormat::Table && !args.names_only;
 
-    if hosts.hosts.is_empty() {
+    if hosts.hosts.is_empty() && !structured {
         if !quiet && !args.names_only {
             println!("No hosts configured.");
             println!();
@@ -34,8 +63,8 @@ pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Re
         return Ok(());
     }
 
-    // Filter by group if specified
-    let filtered: Vec<_> = hosts
+    // Filter by group and tag if specified, sorted so output is stable
+    let mut filtered: Vec<_> = hosts
         .hosts
         .iter()
         .filter(|(_, config)| {
@@ -44,14 +73,37 @@ pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Re
                 .map(|g| config.groups.contains(g))
                 .unwrap_or(true)
         })
+        .filter(|(_, config)| {
+            args.tag
+                .as_deref()
+                .map(|t| config.has_tag(t))
+                .unwrap_or(true)
+        })
         .collect();
+    filtered.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    if structured {
+        let entries: Vec<Value> = filtered
+            .iter()
+            .map(|(name, config)| {
+                host_entry(
+                    name,
+                    config,
+                    hosts.default_host.as_deref() == Some(name.as_str()),
+                )
+            })
+            .collect::<Result<_>>()?;
+        let value = Value::Array(entries);
+        match args.format {
+            HostListFormat::Json => println!("{}", serde_json::to_string_pretty(&value)?),
+            _ => print!("{}", to_yaml(&value)),
+        }
+        return Ok(());
+    }
 
     if filtered.is_empty() {
         if !quiet && !args.names_only {
-            println!(
-                "No hosts found in group '{}'.",
-                args.group.as_deref().unwrap_or("")
-            );
+            println!("No hosts found {}.", describe_filters(args));
         }
         return Ok(());
     }
@@ -67,7 +119,7 @@ pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Re
     // Build table
     let mut table = Table::new();
     table.set_header(vec![
-        "Name", "Hostname", "User", "Port", "Groups", "Default",
+        "Name", "Hostname", "User", "Port", "Groups", "Tags", "Default",
     ]);
 
     for (name, config) in filtered {
@@ -88,6 +140,11 @@ pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Re
         } else {
             config.groups.join(", ")
         };
+        let tags_str = if config.tags.is_empty() {
+            "-".to_string()
+        } else {
+            config.tags.join(", ")
+        };
         let default_str = if is_default { "*" } else { "" };
 
         table.add_row(vec![
@@ -96,6 +153,7 @@ pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Re
             Cell::new(&config.user),
             Cell::new(port_str),
             Cell::new(groups_str),
+            Cell::new(tags_str),
             Cell::new(default_str),
         ]);
     }
@@ -123,3 +181,134 @@ pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Re
 
     Ok(())
 }
+
+/// One host for `--format json|yaml`: its name, default marker, and config
+///
+/// hosts.json holds no credentials (only the identity file path), so the
+/// config is emitted as stored.
+fn host_entry(name: &str, config: &HostConfig, is_default: bool) -> Result<Value> {
+    let mut entry = json!({ "name": name, "default": is_default });
+    if let (Value::Object(map), Value::Object(fields)) = (&mut entry, serde_json::to_value(config)?)
+    {
+        map.extend(fields);
+    }
+    Ok(entry)
+}
+
+/// Render a JSON value as block-style YAML
+///
+/// Strings are always double-quoted (JSON escaping is valid YAML), so values
+/// such as `yes` or `22` never change type when read back.
+fn to_yaml(value: &Value) -> String {
+    let mut out = String::new();
+    match value {
+        Value::Array(items) if items.is_empty() => out.push_str("[]\n"),
+        Value::Object(map) if map.is_empty() => out.push_str("{}\n"),
+        Value::Array(_) | Value::Object(_) => write_yaml_block(value, 0, &mut out),
+        scalar => {
+            out.push_str(&yaml_scalar(scalar));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn write_yaml_block(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                out.push_str(&pad);
+                out.push('-');
+                write_yaml_child(item, indent + 2, true, out);
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map {
+                out.push_str(&pad);
+                out.push_str(key);
+                out.push(':');
+                write_yaml_child(item, indent + 2, false, out);
+            }
+        }
+        scalar => {
+            out.push_str(&pad);
+            out.push_str(&yaml_scalar(scalar));
+            out.push('\n');
+        }
+    }
+}
+
+/// Write the value after `- ` or `key:`, nesting non-empty collections
+fn write_yaml_child(value: &Value, indent: usize, in_list: bool, out: &mut String) {
+    match value {
+        Value::Array(items) if !items.is_empty() => {
+            out.push('\n');
+            write_yaml_block(value, indent, out);
+        }
+        Value::Object(map) if !map.is_empty() => {
+            if in_list {
+                // Start the first key on the dash line, as YAML usually reads
+                let mut nested = String::new();
+                write_yaml_block(value, indent, &mut nested);
+                out.push(' ');
+                out.push_str(nested.trim_start());
+            } else {
+                out.push('\n');
+                write_yaml_block(value, indent, out);
+            }
+        }
+        Value::Array(_) => out.push_str(" []\n"),
+        Value::Object(_) => out.push_str(" {}\n"),
+        scalar => {
+            out.push(' ');
+            out.push_str(&yaml_scalar(scalar));
+            out.push('\n');
+        }
+    }
+}
+
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::String(s) => Value::String(s.clone()).to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Describe active filters for the "no hosts found" message
+fn describe_filters(args: &HostListArgs) -> String {
+    match (&args.group, &args.tag) {
+        (Some(group), Some(tag)) => format!("in group '{group}' with tag '{tag}'"),
+        (Some(group), None) => format!("in group '{group}'"),
+        (None, Some(tag)) => format!("with tag '{tag}'"),
+        (None, None) => "matching filters".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_entry_includes_name_and_default_marker() {
+        let config = HostConfig::new("10.0.0.5").with_user("deploy");
+        let entry = host_entry("prod", &config, true).unwrap();
+        assert_eq!(entry["name"], "prod");
+        assert_eq!(entry["default"], true);
+        assert_eq!(entry["hostname"], "10.0.0.5");
+        assert_eq!(entry["user"], "deploy");
+    }
+
+    #[test]
+    fn to_yaml_renders_lists_of_maps() {
+        let value = json!([
+            { "name": "prod", "port": 22, "tags": ["gpu", "yes"], "groups": [], "jump_host": null }
+        ]);
+        assert_eq!(
+            to_yaml(&value),
+            "- groups: []\n  jump_host: null\n  name: \"prod\"\n  port: 22\n  tags:\n    - \"gpu\"\n    - \"yes\"\n"
+        );
+        assert_eq!(to_yaml(&json!([])), "[]\n");
+    }
+}
@@ -3,6 +3,7 @@
 //! Provides `occ host` subcommands for managing remote Docker hosts.
 
 mod add;
+mod clone;
 mod default;
 mod edit;
 mod list;
@@ -13,7 +14,9 @@ mod test;
 use anyhow::Result;
 use clap::{Args, Subcommand};
 
+pub(crate) use add::build_host_config;
 pub use add::cmd_host_add;
+pub use clone::cmd_host_clone;
 pub use default::cmd_host_default;
 pub use edit::cmd_host_edit;
 pub use list::cmd_host_list;
@@ -41,6 +44,8 @@ pub enum HostCommands {
     Show(show::HostShowArgs),
     /// Edit host configuration
     Edit(edit::HostEditArgs),
+    /// Duplicate an existing host under a new name
+    Clone(clone::HostCloneArgs),
     /// Test connection to a host
     Test(test::HostTestArgs),
     /// Set or show the default host
@@ -57,6 +62,7 @@ pub async fn cmd_host(args: &HostArgs, quiet: bool, verbose: u8) -> Result<()> {
         HostCommands::List(list_args) => cmd_host_list(list_args, quiet, verbose).await,
         HostCommands::Show(show_args) => cmd_host_show(show_args, quiet, verbose).await,
         HostCommands::Edit(edit_args) => cmd_host_edit(edit_args, quiet, verbose).await,
+        HostCommands::Clone(clone_args) => cmd_host_clone(clone_args, quiet, verbose).await,
         HostCommands::Test(test_args) => cmd_host_test(test_args, quiet, verbose).await,
         HostCommands::Default(default_args) => cmd_host_default(default_args, quiet, verbose).await,
     }
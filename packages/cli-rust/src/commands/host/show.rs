@@ -50,6 +50,13 @@ pub async fn cmd_host_show(args: &HostShowArgs, quiet: bool, _verbose: u8) -> Re
             .unwrap_or_else(|| "22 (default)".to_string())
     );
 
+    if let (Some(url), Some((ca, cert, key))) = (config.docker_tls_url(), config.tls_paths()) {
+        println!("  {:<15} {}", style("Docker TLS:").dim(), url);
+        println!("  {:<15} {}", style("TLS CA:").dim(), ca.display());
+        println!("  {:<15} {}", style("TLS cert:").dim(), cert.display());
+        println!("  {:<15} {}", style("TLS key:").dim(), key.display());
+    }
+
     if let Some(key) = &config.identity_file {
         println!("  {:<15} {}", style("Identity:").dim(), key);
     }
@@ -66,10 +73,26 @@ pub async fn cmd_host_show(args: &HostShowArgs, quiet: bool, _verbose: u8) -> Re
         );
     }
 
+    if !config.tags.is_empty() {
+        println!("  {:<15} {}", style("Tags:").dim(), config.tags.join(", "));
+    }
+
     if let Some(desc) = &config.description {
         println!("  {:<15} {}", style("Description:").dim(), desc);
     }
 
+    if let Some(timeout) = config.connect_timeout {
+        println!("  {:<15} {}s", style("Timeout:").dim(), timeout);
+    }
+
+    if let Some(port) = config.web_port {
+        println!("  {:<15} {}", style("Web port:").dim(), port);
+    }
+
+    if let Some(address) = &config.bind_address {
+        println!("  {:<15} {}", style("Bind address:").dim(), address);
+    }
+
     println!();
     println!(
         "  {} {}",
@@ -1,31 +1,53 @@
 //! occ host test - Test connection to a host
 
+use crate::error::CliError;
+use crate::exit_code::ExitCode;
+use crate::output::CommandSpinner;
 use anyhow::{Result, bail};
 use clap::Args;
+use comfy_table::{Cell, Color, Table};
 use console::style;
+use futures_util::future::join_all;
 use indicatif::{ProgressBar, ProgressStyle};
+use opencode_cloud_core::docker::DockerClient;
 use opencode_cloud_core::{load_hosts, test_connection};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Arguments for host test command
 #[derive(Args)]
 pub struct HostTestArgs {
     /// Name of the host to test
-    pub name: String,
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    pub name: Option<String>,
+
+    /// Test every configured host concurrently
+    #[arg(long)]
+    pub all: bool,
+}
+
+/// Connection check result for one host in `--all` mode
+struct HostTestResult {
+    name: String,
+    latency: Duration,
+    /// Docker version on success, error text on failure
+    outcome: Result<String, String>,
 }
 
 pub async fn cmd_host_test(args: &HostTestArgs, quiet: bool, _verbose: u8) -> Result<()> {
     let hosts = load_hosts()?;
 
+    let Some(name) = args.name.as_deref() else {
+        return test_all_hosts(&hosts, quiet).await;
+    };
     let config = hosts
-        .get_host(&args.name)
-        .ok_or_else(|| anyhow::anyhow!("Host '{}' not found.", args.name))?;
+        .get_host(name)
+        .ok_or_else(|| anyhow::anyhow!("Host '{name}' not found."))?;
 
     if quiet {
         // Quiet mode: exit 0 on success, 1 on failure
         match test_connection(config).await {
             Ok(_) => return Ok(()),
-            Err(_) => std::process::exit(1),
+            Err(_) => return Err(CliError::Silent(ExitCode::Failure).into()),
         }
     }
 
@@ -37,7 +59,7 @@ pub async fn cmd_host_test(args: &HostTestArgs, quiet: bool, _verbose: u8) -> Re
     );
     spinner.set_message(format!(
         "Testing connection to {} ({}@{})...",
-        style(&args.name).cyan(),
+        style(name).cyan(),
         config.user,
         config.hostname
     ));
@@ -50,7 +72,7 @@ pub async fn cmd_host_test(args: &HostTestArgs, quiet: bool, _verbose: u8) -> Re
                 style("✓").green().bold()
             ));
             println!();
-            println!("  {:<15} {}", style("Host:").dim(), args.name);
+            println!("  {:<15} {}", style("Host:").dim(), name);
             println!(
                 "  {:<15} {}@{}",
                 style("SSH:").dim(),
@@ -89,3 +111,110 @@ pub async fn cmd_host_test(args: &HostTestArgs, quiet: bool, _verbose: u8) -> Re
         }
     }
 }
+
+/// Connect to every configured host at once and print a pass/fail table
+///
+/// Fails when any host fails; in quiet mode only the exit code is reported.
+async fn test_all_hosts(hosts: &opencode_cloud_core::HostsFile, quiet: bool) -> Result<()> {
+    let mut names: Vec<&String> = hosts.hosts.keys().collect();
+    names.sort();
+    if names.is_empty() {
+        if !quiet {
+            println!("No hosts configured. Add one with: occ host add <name> <hostname>");
+        }
+        return Ok(());
+    }
+
+    let spinner = CommandSpinner::new_maybe(&format!("Testing {} host(s)...", names.len()), quiet);
+    let results = join_all(
+        names
+            .iter()
+            .map(|name| test_host(name, &hosts.hosts[name.as_str()])),
+    )
+    .await;
+    let failed = results.iter().filter(|r| r.outcome.is_err()).count();
+
+    if quiet {
+        if failed > 0 {
+            return Err(CliError::Silent(ExitCode::Failure).into());
+        }
+        return Ok(());
+    }
+
+    if failed == 0 {
+        spinner.success(&format!("All {} host(s) reachable", results.len()));
+    } else {
+        spinner.fail(&format!("{failed} of {} host(s) failed", results.len()));
+    }
+    println!();
+    println!("{}", results_table(&results));
+
+    if failed > 0 {
+        bail!(
+            "{failed} of {} host(s) failed the connection test",
+            results.len()
+        );
+    }
+    Ok(())
+}
+
+async fn test_host(name: &str, config: &opencode_cloud_core::HostConfig) -> HostTestResult {
+    let started = Instant::now();
+    let outcome = async {
+        let client = DockerClient::connect_remote(config, name).await?;
+        client.verify_connection().await?;
+        client.version().await
+    }
+    .await
+    .map_err(|e| e.to_string());
+    HostTestResult {
+        name: name.to_string(),
+        latency: started.elapsed(),
+        outcome,
+    }
+}
+
+fn results_table(results: &[HostTestResult]) -> Table {
+    let mut table = Table::new();
+    table.set_header(vec!["Host", "Result", "Latency", "Details"]);
+    for result in results {
+        let (status, details) = match &result.outcome {
+            Ok(version) => (Cell::new("pass").fg(Color::Green), version.as_str()),
+            Err(err) => (Cell::new("FAIL").fg(Color::Red), err.as_str()),
+        };
+        table.add_row(vec![
+            Cell::new(&result.name),
+            status,
+            Cell::new(format!("{} ms", result.latency.as_millis())),
+            Cell::new(details),
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_table_shows_pass_and_failure_details() {
+        let results = vec![
+            HostTestResult {
+                name: "prod".to_string(),
+                latency: Duration::from_millis(42),
+                outcome: Ok("Docker 27.0.1 (API 1.46)".to_string()),
+            },
+            HostTestResult {
+                name: "staging".to_string(),
+                latency: Duration::from_millis(5000),
+                outcome: Err("SSH tunnel failed: connection refused".to_string()),
+            },
+        ];
+        let rendered = results_table(&results).to_string();
+        assert!(rendered.contains("prod"));
+        assert!(rendered.contains("42 ms"));
+        assert!(rendered.contains("Docker 27.0.1"));
+        assert!(rendered.contains("FAIL"));
+        assert!(rendered.contains("connection refused"));
+    }
+}
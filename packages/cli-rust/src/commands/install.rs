@@ -4,11 +4,11 @@
 //! (systemd on Linux, launchd on macOS) to start automatically on boot/login.
 
 use crate::output::CommandSpinner;
-use anyhow::{Result, anyhow};
-use clap::Args;
+use anyhow::{Result, anyhow, bail};
+use clap::{Args, ValueEnum};
 use console::style;
 use dialoguer::Confirm;
-use opencode_cloud_core::config::load_config_or_default;
+use opencode_cloud_core::config::{load_config_or_default, save_config};
 use opencode_cloud_core::platform::{
     ServiceConfig, get_service_manager, is_service_registration_supported,
 };
@@ -23,6 +23,47 @@ pub struct InstallArgs {
     /// Show what would be done without making changes
     #[arg(long)]
     dry_run: bool,
+
+    /// Service manager backend (default: detected from the platform and boot_mode)
+    #[arg(long, value_enum, default_value_t = ServiceBackend::Auto)]
+    backend: ServiceBackend,
+}
+
+/// Service manager backend selectable with `--backend`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum ServiceBackend {
+    /// The platform's service manager, scoped by the configured boot_mode
+    #[default]
+    Auto,
+    /// systemd, scoped by the configured boot_mode (Linux only)
+    Systemd,
+    /// launchd, scoped by the configured boot_mode (macOS only)
+    Launchd,
+    /// The platform's service manager as a per-user service (systemd user unit or LaunchAgent)
+    User,
+}
+
+/// Name of the service manager on this platform
+pub(super) fn platform_backend_name() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "launchd"
+    } else {
+        "systemd"
+    }
+}
+
+/// Boot mode to install with for `backend`, or an error if it is unavailable here
+fn resolve_boot_mode(backend: ServiceBackend, configured: &str) -> Result<String> {
+    match backend {
+        ServiceBackend::Systemd if !cfg!(target_os = "linux") => {
+            bail!("The systemd backend is only available on Linux.")
+        }
+        ServiceBackend::Launchd if !cfg!(target_os = "macos") => {
+            bail!("The launchd backend is only available on macOS.")
+        }
+        ServiceBackend::User => Ok("user".to_string()),
+        _ => Ok(configured.to_string()),
+    }
 }
 
 /// Register the service with the platform's service manager
@@ -44,10 +85,17 @@ pub async fn cmd_install(args: &InstallArgs, quiet: bool, _verbose: u8) -> Resul
     }
 
     // 2. Load config first to get boot_mode
-    let config = load_config_or_default()?;
+    let mut config = load_config_or_default()?;
 
-    // 3. Get service manager with correct boot_mode
-    let manager = get_service_manager(&config.boot_mode)?;
+    // 3. Get service manager for the selected backend
+    let boot_mode = resolve_boot_mode(args.backend, &config.boot_mode)?;
+    let manager = get_service_manager(&boot_mode)?;
+    if !quiet {
+        println!(
+            "Backend: {} ({boot_mode})",
+            style(platform_backend_name()).cyan()
+        );
+    }
 
     // 4. Check if already installed
     if manager.is_installed()? {
@@ -87,6 +135,15 @@ pub async fn cmd_install(args: &InstallArgs, quiet: bool, _verbose: u8) -> Resul
         return Ok(());
     }
 
+    // Uninstall and status look the service up by boot_mode, so keep it in sync
+    if boot_mode != config.boot_mode {
+        config.boot_mode = boot_mode.clone();
+        save_config(&config)?;
+        if !quiet {
+            println!("Set boot_mode to {boot_mode} to match the selected backend.");
+        }
+    }
+
     // 5. Show spinner during install
     let spinner = CommandSpinner::new_maybe("Installing service...", quiet);
 
@@ -98,7 +155,7 @@ pub async fn cmd_install(args: &InstallArgs, quiet: bool, _verbose: u8) -> Resul
         executable_path,
         restart_retries: config.restart_retries,
         restart_delay: config.restart_delay,
-        boot_mode: config.boot_mode.clone(),
+        boot_mode: boot_mode.clone(),
     };
 
     // 8. Perform install
@@ -118,7 +175,7 @@ pub async fn cmd_install(args: &InstallArgs, quiet: bool, _verbose: u8) -> Resul
             println!("Status:       {}", style("running").green());
         }
         println!();
-        let boot_desc = if config.boot_mode == "system" {
+        let boot_desc = if boot_mode == "system" {
             "boot"
         } else {
             "login"
@@ -128,3 +185,24 @@ pub async fn cmd_install(args: &InstallArgs, quiet: bool, _verbose: u8) -> Resul
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_backend_forces_user_boot_mode() {
+        let mode = resolve_boot_mode(ServiceBackend::User, "system").unwrap();
+        assert_eq!(mode, "user");
+        let mode = resolve_boot_mode(ServiceBackend::Auto, "system").unwrap();
+        assert_eq!(mode, "system");
+    }
+
+    #[test]
+    fn platform_specific_backends_error_elsewhere() {
+        let systemd = resolve_boot_mode(ServiceBackend::Systemd, "user");
+        assert_eq!(systemd.is_ok(), cfg!(target_os = "linux"));
+        let launchd = resolve_boot_mode(ServiceBackend::Launchd, "user");
+        assert_eq!(launchd.is_ok(), cfg!(target_os = "macos"));
+    }
+}
@@ -1,25 +1,33 @@
 //! Logs command implementation
 //!
-//! Streams container logs with optional filtering, timestamps, and follow mode.
+//! Streams container logs with optional filtering, timestamps, and follow mode,
+//! or exports a one-shot capture to a file with `--export`.
 
+use crate::commands::logs_export::{
+    bundle_config_json, bundle_status_json, write_log_file, write_support_bundle,
+};
+use crate::error::CliError;
 use crate::output::{format_docker_error_anyhow, log_level_style};
 use anyhow::{Result, anyhow};
-use clap::Args;
+use clap::{Args, ValueEnum};
 use console::style;
-use futures_util::StreamExt;
+use futures_util::{Stream, StreamExt};
 use opencode_cloud_core::bollard::container::LogOutput;
 use opencode_cloud_core::bollard::exec::{CreateExecOptions, StartExecOptions, StartExecResults};
 use opencode_cloud_core::bollard::query_parameters::LogsOptions;
 use opencode_cloud_core::docker::{
     DockerClient, active_resource_names, container_is_running, exec_command_exit_code,
 };
+use opencode_cloud_core::load_config_or_default;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Arguments for the logs command
 #[derive(Args)]
 pub struct LogsArgs {
-    /// Number of lines to show (default: 50)
-    #[arg(short = 'n', long = "lines", default_value = "50")]
-    pub lines: String,
+    /// Number of lines to show, or `all` (default: 50 when following, 200 otherwise)
+    #[arg(short = 'n', long = "lines", value_parser = parse_lines)]
+    pub lines: Option<String>,
 
     /// Don't follow (one-shot dump)
     #[arg(long = "no-follow")]
@@ -29,23 +37,171 @@ pub struct LogsArgs {
     #[arg(long)]
     pub timestamps: bool,
 
+    /// With --timestamps, show them in the local timezone instead of UTC
+    #[arg(long, requires = "timestamps")]
+    pub local: bool,
+
     /// Filter lines containing pattern
     #[arg(long)]
     pub grep: Option<String>,
 
-    /// Show opencode-broker logs (requires systemd/journald in container)
-    #[arg(long)]
+    /// Show opencode-broker logs (same as --service broker)
+    #[arg(long, conflicts_with = "service")]
     pub broker: bool,
+
+    /// Only show logs from one component (its journald unit when the container runs systemd)
+    #[arg(long, value_enum)]
+    pub service: Option<LogService>,
+
+    /// Only show logs newer than this (e.g. 30m, 2h, 1d)
+    #[arg(long, value_parser = humantime::parse_duration)]
+    pub since: Option<Duration>,
+
+    /// Write logs to a file instead of the terminal (gzip if it ends in .gz); implies --no-follow
+    #[arg(long, value_name = "PATH")]
+    pub export: Option<PathBuf>,
+
+    /// With --export, write a .tar.gz bundle of logs, status, and config for bug reports
+    #[arg(long, requires = "export")]
+    pub bundle: bool,
+
+    /// Which container output streams to show
+    #[arg(long, value_enum, default_value_t = LogStreams::All)]
+    pub streams: LogStreams,
+
+    /// With --streams all, prefix each line with [out] or [err]
+    #[arg(long)]
+    pub prefix: bool,
+}
+
+impl LogsArgs {
+    /// Line limit passed to Docker's `tail` and journalctl's `-n`
+    pub(crate) fn tail(&self) -> String {
+        match &self.lines {
+            Some(lines) => lines.clone(),
+            None if self.no_follow || self.export.is_some() => DEFAULT_DUMP_LINES.to_string(),
+            None => DEFAULT_FOLLOW_LINES.to_string(),
+        }
+    }
+
+    /// Component selected by `--service`, or by the older `--broker` flag
+    pub(crate) fn selected_service(&self) -> Option<LogService> {
+        if self.broker {
+            Some(LogService::Broker)
+        } else {
+            self.service
+        }
+    }
+
+    /// Whether lines should be tagged with the stream they came from
+    fn tag_streams(&self) -> bool {
+        self.prefix && self.streams == LogStreams::All
+    }
+
+    /// Whether leading timestamps should be converted to the local timezone
+    fn localize_timestamps(&self) -> bool {
+        self.timestamps && self.local
+    }
+}
+
+/// Container output streams selected by `--streams`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogStreams {
+    /// Both stdout and stderr
+    #[default]
+    All,
+    /// Only stdout
+    Stdout,
+    /// Only stderr
+    Stderr,
+}
+
+impl LogStreams {
+    fn includes_stdout(self) -> bool {
+        matches!(self, Self::All | Self::Stdout)
+    }
+
+    fn includes_stderr(self) -> bool {
+        matches!(self, Self::All | Self::Stderr)
+    }
 }
 
+/// Container component whose logs `--service` selects
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LogService {
+    /// The opencode web server
+    Opencode,
+    /// The opencode-broker authentication helper
+    Broker,
+    /// The Cockpit web console
+    Cockpit,
+}
+
+impl LogService {
+    /// systemd unit that runs the component inside the container
+    pub(crate) fn unit(self) -> &'static str {
+        match self {
+            LogService::Opencode => "opencode",
+            LogService::Broker => "opencode-broker",
+            LogService::Cockpit => "cockpit",
+        }
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            LogService::Opencode => "opencode",
+            LogService::Broker => "broker",
+            LogService::Cockpit => "cockpit",
+        }
+    }
+
+    /// Whether a line of the combined container output comes from this component
+    ///
+    /// Used without journald, where only the log prefixes tell components apart.
+    fn matches_line(self, line: &str) -> bool {
+        match self {
+            LogService::Broker => broker_log_matches(line),
+            LogService::Cockpit => line.contains("cockpit"),
+            LogService::Opencode => {
+                !LogService::Broker.matches_line(line) && !LogService::Cockpit.matches_line(line)
+            }
+        }
+    }
+}
+
+/// Lines shown before following new output when `--lines` is omitted
+const DEFAULT_FOLLOW_LINES: &str = "50";
+
+/// Lines shown by one-shot dumps (`--no-follow`, `--export`) when `--lines` is omitted
+const DEFAULT_DUMP_LINES: &str = "200";
+
+/// Accept a line count or `all` for `--lines`
+fn parse_lines(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("all") {
+        Ok("all".to_string())
+    } else if value.parse::<u64>().is_ok() {
+        Ok(value.to_string())
+    } else {
+        Err(format!("'{value}' is not a number or 'all'"))
+    }
+}
+
+/// Checks for a running container before `occ logs -f` gives up after the stream ends
+const MAX_RECONNECT_ATTEMPTS: u32 = 15;
+
+/// Delay between reconnect checks
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(2);
+
 fn active_container_name() -> String {
     active_resource_names().container_name
 }
 
 /// Stream logs from the opencode container
 ///
-/// By default, shows the last 50 lines and follows new output.
-/// Use --no-follow for one-shot dump.
+/// By default, shows the last 50 lines and follows new output, re-attaching
+/// when the container restarts.
+/// Use --no-follow for one-shot dump (last 200 lines unless --lines is given).
 /// Use --grep to filter lines.
 ///
 /// In quiet mode, outputs raw lines without status messages or colors.
@@ -67,20 +223,18 @@ pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) ->
         .map_err(|e| format_docker_error_anyhow(&e))?;
 
     // Check if container exists
-    let inspect_result = client
-        .inner()
-        .inspect_container(&container_name, None)
-        .await;
+    let inspect_result = client.inspect_container(&container_name).await;
 
     match inspect_result {
         Err(opencode_cloud_core::bollard::errors::Error::DockerResponseServerError {
             status_code: 404,
             ..
         }) => {
-            return Err(anyhow!(
+            return Err(CliError::ContainerMissing(format!(
                 "No container found. Run '{}' first.",
                 style("occ start").cyan()
-            ));
+            ))
+            .into());
         }
         Err(e) => {
             return Err(anyhow!("Failed to inspect container: {e}"));
@@ -88,19 +242,27 @@ pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) ->
         Ok(_) => {}
     }
 
+    if let Some(path) = args.export.as_deref() {
+        return export_logs(args, &client, path, host_name.as_deref(), quiet).await;
+    }
+
     // Determine follow mode
     let follow = !args.no_follow;
 
-    if args.broker {
-        // Show status message if following broker logs
+    if let Some(service) = args.selected_service() {
+        // Show status message if following one component's logs
         if !quiet && follow {
             eprintln!(
                 "{}",
-                style("Following broker logs (Ctrl+C to exit)...").dim()
+                style(format!(
+                    "Following {} logs (Ctrl+C to exit)...",
+                    service.label()
+                ))
+                .dim()
             );
             eprintln!();
         }
-        return stream_broker_logs(args, &client, line_prefix.as_deref(), quiet).await;
+        return stream_service_logs(args, service, &client, line_prefix.as_deref(), quiet).await;
     }
 
     // Show status message if following container logs
@@ -109,57 +271,254 @@ pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) ->
         eprintln!();
     }
 
-    // Create log options
-    let options = LogsOptions {
-        stdout: true,
-        stderr: true,
-        follow,
-        tail: args.lines.clone(),
-        timestamps: args.timestamps,
-        ..Default::default()
+    stream_container_logs(args, &client, line_prefix.as_deref(), quiet, |_| true).await
+}
+
+/// Follow container logs with the `occ logs` defaults until the stream ends
+///
+/// Used by `occ start --attach` after the service is ready.
+pub(crate) async fn follow_container_logs(
+    client: &DockerClient,
+    line_prefix: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let args = LogsArgs {
+        lines: None,
+        no_follow: false,
+        timestamps: false,
+        local: false,
+        grep: None,
+        broker: false,
+        service: None,
+        since: None,
+        export: None,
+        bundle: false,
+        streams: LogStreams::All,
+        prefix: false,
     };
+    stream_container_logs(&args, client, line_prefix, quiet, |_| true).await
+}
 
-    // Get log stream
-    let mut stream = client.inner().logs(&container_name, Some(options));
+/// Stream container logs, re-attaching in follow mode when the stream ends
+///
+/// The stream ends whenever the container stops, including restarts and the
+/// recreate during `occ update`. While following, we wait for the container
+/// to run again and pick up from where the stream ended. Gives up after
+/// `MAX_RECONNECT_ATTEMPTS` checks in a row find it not running.
+async fn stream_container_logs(
+    args: &LogsArgs,
+    client: &DockerClient,
+    line_prefix: Option<&str>,
+    quiet: bool,
+    keep_line: impl Fn(&str) -> bool,
+) -> Result<()> {
+    let container_name = active_container_name();
+    let follow = !args.no_follow;
+    let mut options = logs_options(args, follow);
+    let mut failed_attempts = 0;
 
-    // Process log stream
-    while let Some(result) = stream.next().await {
-        match result {
-            Ok(output) => {
-                if let Some(line) = log_output_to_line(output) {
-                    emit_log_line(&line, args, line_prefix.as_deref(), quiet);
-                }
-            }
-            Err(_) => {
-                // Stream error - check if container stopped
-                if follow
-                    && !container_is_running(&client, &container_name)
-                        .await
-                        .unwrap_or(false)
-                    && !quiet
-                {
-                    eprintln!();
-                    eprintln!("{}", style("Container stopped").dim());
-                }
+    loop {
+        let mut stream = client.inner().logs(&container_name, Some(options));
+        while let Some(result) = stream.next().await {
+            let Ok(output) = result else {
                 break;
+            };
+            failed_attempts = 0;
+            if let Some(line) = log_output_to_line(output, args.tag_streams())
+                && keep_line(&line)
+            {
+                emit_log_line(&line, args, line_prefix, quiet);
+            }
+        }
+
+        if !follow {
+            return Ok(());
+        }
+
+        // Docker's `since` has one-second resolution, so a line logged in the
+        // same second as the disconnect may be shown twice.
+        let disconnected_at = chrono::Utc::now().timestamp();
+        if !quiet {
+            eprintln!("{}", style("Log stream ended; reconnecting...").dim());
+        }
+        if !wait_for_container_running(client, &container_name, &mut failed_attempts).await {
+            if !quiet {
+                eprintln!();
+                eprintln!("{}", style("Container stopped").dim());
             }
+            return Ok(());
+        }
+
+        options = LogsOptions {
+            since: i32::try_from(disconnected_at).unwrap_or(i32::MAX),
+            tail: "all".to_string(),
+            ..logs_options(args, follow)
+        };
+    }
+}
+
+/// Poll until the container is running again
+///
+/// Returns `false` once `failed_attempts` reaches `MAX_RECONNECT_ATTEMPTS`.
+async fn wait_for_container_running(
+    client: &DockerClient,
+    container_name: &str,
+    failed_attempts: &mut u32,
+) -> bool {
+    while *failed_attempts < MAX_RECONNECT_ATTEMPTS {
+        tokio::time::sleep(RECONNECT_BACKOFF).await;
+        *failed_attempts += 1;
+        if container_is_running(client, container_name)
+            .await
+            .unwrap_or(false)
+        {
+            return true;
         }
     }
+    false
+}
 
+/// Build Docker log options from the command arguments
+fn logs_options(args: &LogsArgs, follow: bool) -> LogsOptions {
+    let since = args
+        .since
+        .map(|since| {
+            let secs = i64::try_from(since.as_secs()).unwrap_or(i64::MAX);
+            let start = chrono::Utc::now().timestamp().saturating_sub(secs);
+            i32::try_from(start.max(0)).unwrap_or(i32::MAX)
+        })
+        .unwrap_or_default();
+
+    LogsOptions {
+        stdout: args.streams.includes_stdout(),
+        stderr: args.streams.includes_stderr(),
+        follow,
+        since,
+        tail: args.tail(),
+        timestamps: args.timestamps,
+        ..Default::default()
+    }
+}
+
+/// journalctl argument equivalent to `--since`
+pub(crate) fn journalctl_since_arg(since: Duration) -> String {
+    format!("--since=-{}s", since.as_secs())
+}
+
+/// Fetch logs without following and write them to `path`
+async fn export_logs(
+    args: &LogsArgs,
+    client: &DockerClient,
+    path: &Path,
+    host_name: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let lines = fetch_log_lines(args, client).await?;
+
+    if args.bundle {
+        let config = load_config_or_default()?;
+        let status = bundle_status_json(client, &config, host_name).await;
+        write_support_bundle(path, &lines, &status, &bundle_config_json(&config)?)?;
+    } else {
+        write_log_file(path, &lines)?;
+    }
+
+    if !quiet {
+        let what = if args.bundle {
+            "support bundle"
+        } else {
+            "logs"
+        };
+        println!(
+            "{} Wrote {} ({} line(s)) to {}",
+            style("Success:").green().bold(),
+            what,
+            lines.len(),
+            path.display()
+        );
+    }
     Ok(())
 }
 
-/// Stream opencode-broker logs from systemd journal inside the container
-async fn stream_broker_logs(
+/// Fetch a one-shot capture of the logs selected by `args`
+async fn fetch_log_lines(args: &LogsArgs, client: &DockerClient) -> Result<Vec<String>> {
+    let container_name = active_container_name();
+
+    let service = args.selected_service();
+    let lines = if let Some(service) = service
+        && ensure_systemd_available(client).await?
+    {
+        let cmd = build_journalctl_command(args, service, false)?;
+        let exec_id = create_journal_exec(client, cmd).await?;
+        let start_config = StartExecOptions {
+            detach: false,
+            ..Default::default()
+        };
+        match client
+            .inner()
+            .start_exec(&exec_id, Some(start_config))
+            .await
+            .map_err(|e| anyhow!("Failed to start {} log stream: {e}", service.label()))?
+        {
+            StartExecResults::Attached { output, .. } => collect_log_lines(output, false).await?,
+            StartExecResults::Detached => {
+                return Err(anyhow!(
+                    "Exec unexpectedly detached while reading {} logs",
+                    service.label()
+                ));
+            }
+        }
+    } else {
+        let stream = client
+            .inner()
+            .logs(&container_name, Some(logs_options(args, false)));
+        let mut lines = collect_log_lines(stream, args.tag_streams()).await?;
+        if let Some(service) = service {
+            lines.retain(|line| !should_skip_service_fallback_line(line, service, args));
+        }
+        lines
+    };
+
+    Ok(lines
+        .into_iter()
+        .filter(|line| matches_grep(line, args))
+        .map(|line| {
+            if args.localize_timestamps() {
+                localize_timestamp(&line)
+            } else {
+                line
+            }
+        })
+        .collect())
+}
+
+async fn collect_log_lines<E: std::fmt::Display>(
+    stream: impl Stream<Item = Result<LogOutput, E>>,
+    tag_streams: bool,
+) -> Result<Vec<String>> {
+    let mut stream = std::pin::pin!(stream);
+    let mut lines = Vec::new();
+    while let Some(result) = stream.next().await {
+        let output = result.map_err(|e| anyhow!("Failed to read logs: {e}"))?;
+        if let Some(line) = log_output_to_line(output, tag_streams) {
+            lines.push(line);
+        }
+    }
+    Ok(lines)
+}
+
+/// Stream one component's logs from the systemd journal inside the container
+async fn stream_service_logs(
     args: &LogsArgs,
+    service: LogService,
     client: &DockerClient,
     line_prefix: Option<&str>,
     quiet: bool,
 ) -> Result<()> {
     if ensure_systemd_available(client).await? {
-        let cmd = build_broker_journalctl_command(args)?;
-        let exec_id = create_broker_exec(client, cmd).await?;
-        stream_broker_exec_output(args, client, &exec_id, line_prefix, quiet).await
+        let cmd = build_journalctl_command(args, service, !args.no_follow)?;
+        let exec_id = create_journal_exec(client, cmd).await?;
+        stream_journal_exec_output(args, service, client, &exec_id, line_prefix, quiet).await
     } else {
         if !quiet {
             eprintln!(
@@ -169,7 +528,7 @@ async fn stream_broker_logs(
             );
             eprintln!();
         }
-        stream_broker_logs_from_container(args, client, line_prefix, quiet).await
+        stream_service_logs_from_container(args, service, client, line_prefix, quiet).await
     }
 }
 
@@ -187,12 +546,16 @@ async fn ensure_systemd_available(client: &DockerClient) -> Result<bool> {
     Ok(systemd_available)
 }
 
-fn build_broker_journalctl_command(args: &LogsArgs) -> Result<Vec<String>> {
+fn build_journalctl_command(
+    args: &LogsArgs,
+    service: LogService,
+    follow: bool,
+) -> Result<Vec<String>> {
     let mut cmd = vec![
         "journalctl".to_string(),
         "--no-pager".to_string(),
         "-u".to_string(),
-        "opencode-broker".to_string(),
+        service.unit().to_string(),
     ];
 
     if args.timestamps {
@@ -204,26 +567,30 @@ fn build_broker_journalctl_command(args: &LogsArgs) -> Result<Vec<String>> {
         cmd.push("cat".to_string());
     }
 
-    let lines = args.lines.trim();
+    let lines = args.tail();
     if lines.eq_ignore_ascii_case("all") {
         // no -n flag
     } else if !lines.is_empty() && lines.chars().all(|c| c.is_ascii_digit()) {
         cmd.push("-n".to_string());
-        cmd.push(lines.to_string());
+        cmd.push(lines);
     } else {
         return Err(anyhow!(
-            "Invalid value for --lines with --broker. Use a number or 'all'."
+            "Invalid value for --lines with --service. Use a number or 'all'."
         ));
     }
 
-    if !args.no_follow {
+    if let Some(since) = args.since {
+        cmd.push(journalctl_since_arg(since));
+    }
+
+    if follow {
         cmd.push("-f".to_string());
     }
 
     Ok(cmd)
 }
 
-async fn create_broker_exec(client: &DockerClient, cmd: Vec<String>) -> Result<String> {
+async fn create_journal_exec(client: &DockerClient, cmd: Vec<String>) -> Result<String> {
     let container_name = active_container_name();
     let exec_config = CreateExecOptions {
         attach_stdout: Some(true),
@@ -237,13 +604,14 @@ async fn create_broker_exec(client: &DockerClient, cmd: Vec<String>) -> Result<S
         .inner()
         .create_exec(&container_name, exec_config)
         .await
-        .map_err(|e| anyhow!("Failed to create exec for broker logs: {e}"))?;
+        .map_err(|e| anyhow!("Failed to create exec for journal logs: {e}"))?;
 
     Ok(exec.id)
 }
 
-async fn stream_broker_exec_output(
+async fn stream_journal_exec_output(
     args: &LogsArgs,
+    service: LogService,
     client: &DockerClient,
     exec_id: &str,
     line_prefix: Option<&str>,
@@ -259,7 +627,7 @@ async fn stream_broker_exec_output(
         .inner()
         .start_exec(exec_id, Some(start_config))
         .await
-        .map_err(|e| anyhow!("Failed to start broker log stream: {e}"))?
+        .map_err(|e| anyhow!("Failed to start {} log stream: {e}", service.label()))?
     {
         StartExecResults::Attached {
             output: mut stream, ..
@@ -267,7 +635,7 @@ async fn stream_broker_exec_output(
             while let Some(result) = stream.next().await {
                 match result {
                     Ok(output) => {
-                        if let Some(line) = log_output_to_line(output) {
+                        if let Some(line) = log_output_to_line(output, false) {
                             emit_log_line(&line, args, line_prefix, quiet);
                         }
                     }
@@ -288,7 +656,8 @@ async fn stream_broker_exec_output(
         }
         StartExecResults::Detached => {
             return Err(anyhow!(
-                "Exec unexpectedly detached while streaming broker logs"
+                "Exec unexpectedly detached while streaming {} logs",
+                service.label()
             ));
         }
     }
@@ -296,80 +665,89 @@ async fn stream_broker_exec_output(
     Ok(())
 }
 
-async fn stream_broker_logs_from_container(
+async fn stream_service_logs_from_container(
     args: &LogsArgs,
+    service: LogService,
     client: &DockerClient,
     line_prefix: Option<&str>,
     quiet: bool,
 ) -> Result<()> {
-    let container_name = active_container_name();
-    let follow = !args.no_follow;
-    let options = LogsOptions {
-        stdout: true,
-        stderr: true,
-        follow,
-        tail: args.lines.clone(),
-        timestamps: args.timestamps,
-        ..Default::default()
-    };
-
-    let mut stream = client.inner().logs(&container_name, Some(options));
-
-    while let Some(result) = stream.next().await {
-        match result {
-            Ok(output) => {
-                if let Some(line) = log_output_to_line(output) {
-                    if should_skip_broker_fallback_line(&line, args) {
-                        continue;
-                    }
-                    emit_log_line(&line, args, line_prefix, quiet);
-                }
-            }
-            Err(_) => {
-                if follow
-                    && !container_is_running(client, &container_name)
-                        .await
-                        .unwrap_or(false)
-                    && !quiet
-                {
-                    eprintln!();
-                    eprintln!("{}", style("Container stopped").dim());
-                }
-                break;
-            }
-        }
-    }
-
-    Ok(())
+    stream_container_logs(args, client, line_prefix, quiet, |line| {
+        !should_skip_service_fallback_line(line, service, args)
+    })
+    .await
 }
 
-fn should_skip_broker_fallback_line(line: &str, args: &LogsArgs) -> bool {
+fn should_skip_service_fallback_line(line: &str, service: LogService, args: &LogsArgs) -> bool {
     if args.grep.is_some() {
         return false;
     }
 
-    !broker_log_matches(line)
+    !service.matches_line(line)
 }
 
 fn broker_log_matches(line: &str) -> bool {
     line.contains("opencode_broker::") || line.contains("opencode-broker")
 }
 
-fn log_output_to_line(output: LogOutput) -> Option<String> {
-    match output {
-        LogOutput::StdOut { message } | LogOutput::StdErr { message } => {
-            Some(String::from_utf8_lossy(&message).to_string())
-        }
-        _ => None,
-    }
+/// Convert a log frame to text, optionally tagged with `[out]`/`[err]`
+fn log_output_to_line(output: LogOutput, tag_streams: bool) -> Option<String> {
+    let (tag, message) = match output {
+        LogOutput::StdOut { message } => ("[out] ", message),
+        LogOutput::StdErr { message } => ("[err] ", message),
+        _ => return None,
+    };
+    let text = String::from_utf8_lossy(&message);
+    Some(if tag_streams {
+        format!("{tag}{text}")
+    } else {
+        text.to_string()
+    })
+}
+
+fn matches_grep(line: &str, args: &LogsArgs) -> bool {
+    args.grep
+        .as_deref()
+        .is_none_or(|pattern| line.contains(pattern))
+}
+
+/// Rewrite a line's leading timestamp in the local timezone
+///
+/// Handles Docker's RFC3339 timestamps and journalctl's `short-iso` output,
+/// after an optional `[out]`/`[err]` tag. Other lines pass through unchanged.
+fn localize_timestamp(line: &str) -> String {
+    let (tag, rest) = match line.get(..6) {
+        Some(tag @ ("[out] " | "[err] ")) => (tag, &line[6..]),
+        _ => ("", line),
+    };
+    let (stamp, message) = rest.split_once(' ').unwrap_or((rest, ""));
+    let Some(timestamp) = parse_log_timestamp(stamp.trim_end()) else {
+        return line.to_string();
+    };
+    let local = timestamp
+        .with_timezone(&chrono::Local)
+        .format("%Y-%m-%d %H:%M:%S%.3f %:z");
+    format!("{tag}{local} {message}")
+}
+
+/// Parse a Docker (RFC3339) or journalctl `short-iso` timestamp
+fn parse_log_timestamp(stamp: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    chrono::DateTime::parse_from_rfc3339(stamp)
+        .or_else(|_| chrono::DateTime::parse_from_str(stamp, "%Y-%m-%dT%H:%M:%S%z"))
+        .ok()
 }
 
 pub(crate) fn emit_log_line(line: &str, args: &LogsArgs, prefix: Option<&str>, quiet: bool) {
-    if let Some(pattern) = args.grep.as_deref()
-        && !line.contains(pattern)
-    {
+    if !matches_grep(line, args) {
         return;
     }
+    let localized;
+    let line = if args.localize_timestamps() {
+        localized = localize_timestamp(line);
+        localized.as_str()
+    } else {
+        line
+    };
 
     if quiet {
         print_line(line, prefix);
@@ -417,19 +795,44 @@ mod tests {
         // We can't easily test clap defaults here, but we can test
         // the parsing logic
         let args = LogsArgs {
-            lines: "50".to_string(),
+            lines: None,
             no_follow: false,
             timestamps: false,
+            local: false,
             grep: None,
             broker: false,
+            service: None,
+            since: None,
+            export: None,
+            bundle: false,
+            streams: LogStreams::All,
+            prefix: false,
         };
 
-        assert_eq!(args.lines, "50");
+        assert_eq!(args.tail(), "50");
         assert!(!args.no_follow);
         assert!(!args.timestamps);
         assert!(args.grep.is_none());
     }
 
+    #[test]
+    fn tail_defaults_depend_on_follow_mode() {
+        let mut args = export_args();
+        args.lines = None;
+        assert_eq!(args.tail(), "200");
+
+        args.export = None;
+        args.no_follow = false;
+        assert_eq!(args.tail(), "50");
+
+        args.lines = Some("all".to_string());
+        assert_eq!(args.tail(), "all");
+
+        assert_eq!(parse_lines("ALL"), Ok("all".to_string()));
+        assert_eq!(parse_lines(" 20 "), Ok("20".to_string()));
+        assert!(parse_lines("lots").is_err());
+    }
+
     #[test]
     fn print_line_adds_newline_when_missing() {
         // This is a basic test - the actual print happens to stdout
@@ -456,21 +859,169 @@ mod tests {
     fn follow_mode_from_no_follow_flag() {
         // follow = !args.no_follow
         let args_follow = LogsArgs {
-            lines: "50".to_string(),
+            lines: None,
             no_follow: false,
             timestamps: false,
+            local: false,
             grep: None,
             broker: false,
+            service: None,
+            since: None,
+            export: None,
+            bundle: false,
+            streams: LogStreams::All,
+            prefix: false,
         };
         assert!(!args_follow.no_follow);
 
         let args_no_follow = LogsArgs {
-            lines: "50".to_string(),
+            lines: None,
             no_follow: true,
             timestamps: false,
+            local: false,
             grep: None,
             broker: false,
+            service: None,
+            since: None,
+            export: None,
+            bundle: false,
+            streams: LogStreams::All,
+            prefix: false,
         };
         assert!(args_no_follow.no_follow);
     }
+
+    fn export_args() -> LogsArgs {
+        LogsArgs {
+            lines: Some("all".to_string()),
+            no_follow: true,
+            timestamps: false,
+            local: false,
+            grep: None,
+            broker: false,
+            service: None,
+            since: Some(Duration::from_secs(600)),
+            export: Some(PathBuf::from("occ.log.gz")),
+            bundle: false,
+            streams: LogStreams::All,
+            prefix: false,
+        }
+    }
+
+    #[test]
+    fn logs_options_apply_since_as_unix_start() {
+        let args = export_args();
+        let options = logs_options(&args, false);
+        let expected = chrono::Utc::now().timestamp() - 600;
+        assert!((i64::from(options.since) - expected).abs() <= 2);
+        assert!(!options.follow);
+        assert_eq!(
+            logs_options(
+                &LogsArgs {
+                    since: None,
+                    ..args
+                },
+                true
+            )
+            .since,
+            0
+        );
+    }
+
+    #[test]
+    fn broker_journalctl_command_bounded_export() {
+        let args = export_args();
+        let cmd = build_journalctl_command(&args, LogService::Broker, false).unwrap();
+        assert!(cmd.contains(&"--since=-600s".to_string()));
+        assert!(!cmd.contains(&"-f".to_string()));
+        assert!(!cmd.contains(&"-n".to_string()));
+    }
+
+    #[test]
+    fn journalctl_command_reads_selected_unit() {
+        let args = export_args();
+        let cmd = build_journalctl_command(&args, LogService::Cockpit, false).unwrap();
+        assert_eq!(cmd[2..4], ["-u", "cockpit"]);
+    }
+
+    #[test]
+    fn service_line_matching_splits_combined_output() {
+        let broker = " INFO opencode_broker::server: listening";
+        let cockpit = "cockpit-ws: New connection";
+        let opencode = "opencode server listening on 3000";
+        assert!(LogService::Broker.matches_line(broker));
+        assert!(LogService::Cockpit.matches_line(cockpit));
+        assert!(LogService::Opencode.matches_line(opencode));
+        assert!(!LogService::Opencode.matches_line(broker));
+        assert!(!LogService::Opencode.matches_line(cockpit));
+    }
+
+    #[test]
+    fn broker_flag_selects_broker_service() {
+        let mut args = export_args();
+        assert_eq!(args.selected_service(), None);
+        args.service = Some(LogService::Cockpit);
+        assert_eq!(args.selected_service(), Some(LogService::Cockpit));
+        args.service = None;
+        args.broker = true;
+        assert_eq!(args.selected_service(), Some(LogService::Broker));
+    }
+
+    #[test]
+    fn matches_grep_without_pattern_keeps_everything() {
+        let mut args = export_args();
+        assert!(matches_grep("anything", &args));
+        args.grep = Some("ERROR".to_string());
+        assert!(matches_grep("x ERROR y", &args));
+        assert!(!matches_grep("INFO", &args));
+    }
+
+    #[test]
+    fn streams_select_log_options_and_prefix_lines() {
+        let mut args = export_args();
+        let options = logs_options(&args, false);
+        assert!(options.stdout && options.stderr);
+
+        args.streams = LogStreams::Stderr;
+        let options = logs_options(&args, false);
+        assert!(!options.stdout && options.stderr);
+
+        let err = || LogOutput::StdErr {
+            message: "boom\n".into(),
+        };
+        args.prefix = true;
+        assert!(!args.tag_streams());
+        args.streams = LogStreams::All;
+        assert!(args.tag_streams());
+        assert_eq!(log_output_to_line(err(), true).unwrap(), "[err] boom\n");
+        assert_eq!(log_output_to_line(err(), false).unwrap(), "boom\n");
+    }
+
+    #[test]
+    fn localize_timestamp_rewrites_leading_timestamp() {
+        let localized = localize_timestamp("2024-01-15T10:30:00.123456789Z server started\n");
+        let expected = chrono::DateTime::parse_from_rfc3339("2024-01-15T10:30:00.123Z")
+            .unwrap()
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M:%S%.3f %:z")
+            .to_string();
+        assert_eq!(localized, format!("{expected} server started\n"));
+
+        let tagged = localize_timestamp("[err] 2024-01-15T10:30:00Z boom");
+        assert!(tagged.starts_with("[err] 2024-01-1"));
+        assert!(tagged.ends_with(" boom"));
+    }
+
+    #[test]
+    fn localize_timestamp_handles_journal_short_iso() {
+        assert!(parse_log_timestamp("2024-01-15T10:30:00+0000").is_some());
+        assert!(!localize_timestamp("2024-01-15T10:30:00+0000 unit[1]: ready").contains("+0000"));
+    }
+
+    #[test]
+    fn localize_timestamp_passes_other_lines_through() {
+        for line in ["no timestamp here", "", "[out] plain output"] {
+            assert_eq!(localize_timestamp(line), line);
+        }
+    }
 }
@@ -0,0 +1,242 @@
+//! File output for `occ logs --export`
+//!
+//! Writes captured log lines to a plain or gzip file, or packs them with a
+//! status and config snapshot into a `.tar.gz` support bundle.
+
+use crate::commands::config::config_display_json;
+use crate::commands::runtime_shared::backend::HostBackend;
+use crate::commands::runtime_shared::{DEFAULT_PROBE_TIMEOUT, collect_status_view};
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use opencode_cloud_core::docker::{
+    DockerClient, active_resource_names, container_exists, container_state, get_container_ports,
+};
+use opencode_cloud_core::{Config, get_version};
+use serde_json::{Value, json};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Whether `path` should be gzip-compressed
+fn is_gzip_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz") || ext.eq_ignore_ascii_case("tgz"))
+}
+
+/// Join log lines, making sure each ends with a newline
+fn render_log_lines(lines: &[String]) -> String {
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(line);
+        if !line.ends_with('\n') {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Write log lines to `path`, gzip-compressed when it ends in `.gz`
+pub(super) fn write_log_file(path: &Path, lines: &[String]) -> Result<()> {
+    let file =
+        File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    let contents = render_log_lines(lines);
+
+    if is_gzip_path(path) {
+        let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+        encoder.write_all(contents.as_bytes())?;
+        encoder.finish()?.flush()?;
+    } else {
+        let mut writer = BufWriter::new(file);
+        writer.write_all(contents.as_bytes())?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// Write a `.tar.gz` bundle with `logs.txt`, `status.json`, and `config.json`
+///
+/// Entries sit under a directory named after the bundle so extracting it
+/// does not scatter files.
+pub(super) fn write_support_bundle(
+    path: &Path,
+    lines: &[String],
+    status: &Value,
+    config: &Value,
+) -> Result<()> {
+    let file =
+        File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    let encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+    let mut archive = tar::Builder::new(encoder);
+
+    let dir = bundle_dir_name(path);
+    let mtime = chrono::Utc::now().timestamp().max(0) as u64;
+    let entries = [
+        ("logs.txt", render_log_lines(lines)),
+        ("status.json", serde_json::to_string_pretty(status)? + "\n"),
+        ("config.json", serde_json::to_string_pretty(config)? + "\n"),
+    ];
+    for (name, contents) in entries {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(mtime);
+        header.set_cksum();
+        archive.append_data(&mut header, format!("{dir}/{name}"), contents.as_bytes())?;
+    }
+
+    archive.into_inner()?.finish()?.flush()?;
+    Ok(())
+}
+
+fn bundle_dir_name(path: &Path) -> String {
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("occ-support");
+    let name = name
+        .strip_suffix(".tar.gz")
+        .or_else(|| name.strip_suffix(".tgz"))
+        .or_else(|| name.strip_suffix(".gz"))
+        .unwrap_or(name);
+    if name.is_empty() {
+        "occ-support".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Config snapshot for a bundle
+///
+/// Same view as `occ config show --json`, with container env values masked
+/// too since they often hold API keys.
+pub(super) fn bundle_config_json(config: &Config) -> Result<Value> {
+    let mut value = config_display_json(config)?;
+    if let Some(Value::Array(entries)) = value.get_mut("container_env") {
+        for entry in entries.iter_mut() {
+            if let Value::String(s) = entry
+                && let Some((key, _)) = s.split_once('=')
+            {
+                *s = format!("{key}=********");
+            }
+        }
+    }
+    Ok(value)
+}
+
+/// Status snapshot for a bundle
+///
+/// Collection problems are recorded in the snapshot rather than failing the
+/// export, since a broken service is usually why the bundle is being made.
+pub(super) async fn bundle_status_json(
+    client: &DockerClient,
+    config: &Config,
+    host_name: Option<&str>,
+) -> Value {
+    let container_name = active_resource_names().container_name;
+    let mut status = json!({
+        "cli_version": get_version(),
+        "host": host_name,
+        "container": container_name,
+    });
+
+    let state = match container_exists(client, &container_name).await {
+        Ok(true) => container_state(client, &container_name).await.ok(),
+        _ => None,
+    };
+    status["state"] = json!(state);
+
+    if let Ok(ports) = get_container_ports(client, &container_name).await {
+        status["ports"] = json!({
+            "opencode": ports.opencode_port,
+            "cockpit": ports.cockpit_port,
+        });
+    }
+
+    if state.as_deref() == Some("running") {
+        let backend = HostBackend::new(client);
+        let port = config.opencode_web_port;
+        status["runtime"] = match collect_status_view(
+            &backend,
+            host_name.is_none(),
+            &config.bind_address,
+            port,
+            DEFAULT_PROBE_TIMEOUT,
+        )
+        .await
+        {
+            Ok(view) => serde_json::to_value(view).unwrap_or(Value::Null),
+            Err(e) => json!({ "error": e.to_string() }),
+        };
+    }
+
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    fn lines() -> Vec<String> {
+        vec!["first\n".to_string(), "second".to_string()]
+    }
+
+    #[test]
+    fn write_log_file_plain_and_gzip() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let plain = dir.path().join("occ.log");
+        write_log_file(&plain, &lines()).unwrap();
+        assert_eq!(std::fs::read_to_string(&plain).unwrap(), "first\nsecond\n");
+
+        let gz = dir.path().join("occ.log.gz");
+        write_log_file(&gz, &lines()).unwrap();
+        let mut decoded = String::new();
+        GzDecoder::new(File::open(&gz).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, "first\nsecond\n");
+    }
+
+    #[test]
+    fn support_bundle_contains_all_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.tar.gz");
+        write_support_bundle(&path, &lines(), &json!({"state": "running"}), &json!({})).unwrap();
+
+        let mut archive = tar::Archive::new(GzDecoder::new(File::open(&path).unwrap()));
+        let mut names: Vec<String> = archive
+            .entries()
+            .unwrap()
+            .map(|e| e.unwrap().path().unwrap().display().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "report/config.json",
+                "report/logs.txt",
+                "report/status.json"
+            ]
+        );
+    }
+
+    #[test]
+    fn bundle_config_masks_env_values() {
+        let config = Config {
+            container_env: vec!["OPENAI_API_KEY=sk-secret".to_string()],
+            ..Config::default()
+        };
+        let value = bundle_config_json(&config).unwrap();
+        assert_eq!(value["container_env"], json!(["OPENAI_API_KEY=********"]));
+    }
+
+    #[test]
+    fn bundle_dir_name_strips_archive_suffixes() {
+        assert_eq!(bundle_dir_name(Path::new("/tmp/bug.tar.gz")), "bug");
+        assert_eq!(bundle_dir_name(Path::new("bug.tgz")), "bug");
+        assert_eq!(bundle_dir_name(Path::new("bug")), "bug");
+    }
+}
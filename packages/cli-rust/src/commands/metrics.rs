@@ -0,0 +1,381 @@
+//! Metrics command implementation
+//!
+//! Prints service health, uptime, container resource usage, and Docker disk
+//! usage in the Prometheus text exposition format, either once to stdout or
+//! from a minimal HTTP endpoint for scraping.
+
+use crate::commands::disk_usage::{DiskUsageReport, get_disk_usage_report};
+use crate::commands::runtime_shared::backend::HostBackend;
+use crate::commands::runtime_shared::status_model::{BrokerHealthStatus, OpencodeHealthStatus};
+use crate::commands::runtime_shared::{DEFAULT_PROBE_TIMEOUT, collect_status_view};
+use crate::commands::status::parse_uptime;
+use crate::commands::status_diff::web_port_binding;
+use crate::output::{format_docker_error_anyhow, normalize_bind_addr, resolve_remote_addr};
+use anyhow::{Result, anyhow};
+use clap::Args;
+use console::style;
+use futures_util::StreamExt;
+use opencode_cloud_core::bollard::errors::Error as BollardError;
+use opencode_cloud_core::docker::{
+    ContainerStatsSample, DockerClient, active_resource_names, container_stats, get_image_version,
+};
+use std::fmt::Write as _;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Prefix shared by every exported metric
+const METRIC_PREFIX: &str = "opencode_cloud";
+
+/// Content type for the Prometheus text exposition format
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// How long a scrape client gets to send its request or receive the response
+const CLIENT_IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Arguments for the metrics command
+#[derive(Args)]
+pub struct MetricsArgs {
+    /// Serve metrics at http://127.0.0.1:<PORT>/metrics instead of printing once
+    #[arg(long, value_name = "PORT")]
+    pub port: Option<u16>,
+}
+
+/// Values gathered for one scrape
+#[derive(Default)]
+struct MetricsSnapshot {
+    up: bool,
+    opencode_healthy: bool,
+    broker_healthy: bool,
+    uptime_seconds: Option<u64>,
+    stats: Option<ContainerStatsSample>,
+    disk: Option<DiskUsageReport>,
+    image_version: String,
+    opencode_version: String,
+}
+
+/// Print metrics once, or serve them over HTTP with `--port`
+pub async fn cmd_metrics(args: &MetricsArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+    let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| format_docker_error_anyhow(&e))?;
+
+    let Some(port) = args.port else {
+        let snapshot = collect_metrics(&client, host_name.as_deref()).await?;
+        print!("{}", render_metrics(&snapshot));
+        return Ok(());
+    };
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .map_err(|e| anyhow!("Failed to listen on 127.0.0.1:{port}: {e}"))?;
+    if !quiet {
+        eprintln!(
+            "Serving metrics at {} (Ctrl+C to stop)",
+            style(format!("http://127.0.0.1:{port}/metrics")).cyan()
+        );
+    }
+
+    let _interrupt = crate::interrupt::HandlesInterrupt::new();
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+    loop {
+        let accepted = tokio::select! {
+            _ = &mut shutdown => return Ok(()),
+            accepted = listener.accept() => accepted,
+        };
+        // Errors such as EMFILE are transient; keep serving later scrapes
+        let stream = match accepted {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                tracing::warn!("Failed to accept metrics connection: {e}");
+                continue;
+            }
+        };
+        tokio::select! {
+            _ = &mut shutdown => return Ok(()),
+            served = serve_scrape(stream, &client, host_name.as_deref()) => {
+                if let Err(e) = served {
+                    tracing::debug!("Metrics request failed: {e}");
+                }
+            }
+        }
+    }
+}
+
+/// Answer one HTTP request; every path except `/` and `/metrics` is a 404
+async fn serve_scrape(
+    mut stream: TcpStream,
+    client: &DockerClient,
+    host_name: Option<&str>,
+) -> Result<()> {
+    let mut buf = [0u8; 1024];
+    let read = tokio::time::timeout(CLIENT_IO_TIMEOUT, stream.read(&mut buf))
+        .await
+        .map_err(|_| anyhow!("Timed out waiting for the scrape request"))??;
+    let request = String::from_utf8_lossy(&buf[..read]);
+    let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+    let (status, content_type, body) = if matches!(path, "/" | "/metrics") {
+        match collect_metrics(client, host_name).await {
+            Ok(snapshot) => ("200 OK", CONTENT_TYPE, render_metrics(&snapshot)),
+            Err(e) => ("500 Internal Server Error", "text/plain", format!("{e}\n")),
+        }
+    } else {
+        ("404 Not Found", "text/plain", "not found\n".to_string())
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    tokio::time::timeout(CLIENT_IO_TIMEOUT, async {
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    })
+    .await
+    .map_err(|_| anyhow!("Timed out sending the scrape response"))??;
+    Ok(())
+}
+
+async fn collect_metrics(
+    client: &DockerClient,
+    host_name: Option<&str>,
+) -> Result<MetricsSnapshot> {
+    let container_name = active_resource_names().container_name;
+    let disk = get_disk_usage_report(client).await.ok();
+    let info = match client.inspect_container(&container_name).await {
+        Ok(info) => info,
+        Err(BollardError::DockerResponseServerError {
+            status_code: 404, ..
+        }) => {
+            return Ok(MetricsSnapshot {
+                disk,
+                ..Default::default()
+            });
+        }
+        Err(e) => return Err(anyhow!("Failed to inspect {container_name}: {e}")),
+    };
+
+    let state = info.state.as_ref();
+    let up = state.and_then(|s| s.running).unwrap_or(false);
+    let image = info
+        .config
+        .as_ref()
+        .and_then(|c| c.image.clone())
+        .unwrap_or_default();
+    let mut snapshot = MetricsSnapshot {
+        up,
+        disk,
+        ..Default::default()
+    };
+
+    if !up {
+        snapshot.image_version = get_image_version(client, &image)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        return Ok(snapshot);
+    }
+
+    snapshot.uptime_seconds = state
+        .and_then(|s| s.started_at.as_deref())
+        .and_then(parse_uptime)
+        .map(|(uptime, _)| uptime.as_secs());
+
+    let (bind_ip, host_port) = web_port_binding(&info);
+    let probe_addr =
+        resolve_remote_addr(host_name).unwrap_or_else(|| normalize_bind_addr(&bind_ip).to_string());
+    let backend = HostBackend::for_container(client, &container_name);
+    let port = host_port.parse().unwrap_or(0);
+    let (view, stats) = tokio::join!(
+        collect_status_view(&backend, true, &probe_addr, port, DEFAULT_PROBE_TIMEOUT),
+        async {
+            let mut samples = std::pin::pin!(container_stats(client, &container_name, false));
+            samples.next().await.and_then(|sample| sample.ok())
+        }
+    );
+    let view = view?;
+
+    snapshot.opencode_healthy = view.opencode_health == Some(OpencodeHealthStatus::Healthy);
+    snapshot.broker_healthy = view.broker_health == BrokerHealthStatus::Healthy;
+    snapshot.image_version = view.image_version;
+    snapshot.opencode_version = view.opencode_version;
+    snapshot.stats = stats;
+    Ok(snapshot)
+}
+
+/// Render a snapshot in the Prometheus text exposition format
+fn render_metrics(snapshot: &MetricsSnapshot) -> String {
+    let mut out = String::new();
+    let flag = |value: bool| if value { 1.0 } else { 0.0 };
+
+    gauge(
+        &mut out,
+        "up",
+        "Whether the sandbox container is running",
+        &[("", flag(snapshot.up))],
+    );
+    gauge(
+        &mut out,
+        "opencode_healthy",
+        "Whether the opencode web server answers its health check",
+        &[("", flag(snapshot.opencode_healthy))],
+    );
+    gauge(
+        &mut out,
+        "broker_healthy",
+        "Whether the opencode broker is healthy",
+        &[("", flag(snapshot.broker_healthy))],
+    );
+    if let Some(uptime) = snapshot.uptime_seconds {
+        gauge(
+            &mut out,
+            "uptime_seconds",
+            "Seconds since the sandbox container started",
+            &[("", uptime as f64)],
+        );
+    }
+    if let Some(stats) = &snapshot.stats {
+        gauge(
+            &mut out,
+            "container_cpu_percent",
+            "Container CPU usage as a percentage of one core",
+            &[("", stats.cpu_percent)],
+        );
+        gauge(
+            &mut out,
+            "container_memory_usage_bytes",
+            "Container memory in use, excluding page cache",
+            &[("", stats.memory_usage_bytes as f64)],
+        );
+        gauge(
+            &mut out,
+            "container_memory_limit_bytes",
+            "Container memory limit",
+            &[("", stats.memory_limit_bytes as f64)],
+        );
+    }
+    if let Some(disk) = snapshot.disk {
+        let samples: Vec<(String, f64)> = [
+            ("images", disk.images),
+            ("containers", disk.containers),
+            ("volumes", disk.volumes),
+            ("build_cache", disk.build_cache),
+            ("total", disk.total),
+        ]
+        .into_iter()
+        .filter_map(|(kind, bytes)| {
+            bytes
+                .filter(|bytes| *bytes >= 0)
+                .map(|bytes| (format!("type=\"{kind}\""), bytes as f64))
+        })
+        .collect();
+        let samples: Vec<(&str, f64)> = samples
+            .iter()
+            .map(|(labels, value)| (labels.as_str(), *value))
+            .collect();
+        if !samples.is_empty() {
+            gauge(
+                &mut out,
+                "docker_disk_usage_bytes",
+                "Docker disk usage by type",
+                &samples,
+            );
+        }
+    }
+    let info_labels = format!(
+        "image_version=\"{}\",opencode_version=\"{}\",cli_version=\"{}\"",
+        escape_label_value(&snapshot.image_version),
+        escape_label_value(&snapshot.opencode_version),
+        escape_label_value(env!("CARGO_PKG_VERSION"))
+    );
+    gauge(
+        &mut out,
+        "build_info",
+        "Image, opencode, and CLI versions",
+        &[(info_labels.as_str(), 1.0)],
+    );
+    out
+}
+
+/// Append one gauge family; each sample is (label set, value)
+fn gauge(out: &mut String, name: &str, help: &str, samples: &[(&str, f64)]) {
+    let _ = writeln!(out, "# HELP {METRIC_PREFIX}_{name} {help}");
+    let _ = writeln!(out, "# TYPE {METRIC_PREFIX}_{name} gauge");
+    for (labels, value) in samples {
+        if labels.is_empty() {
+            let _ = writeln!(out, "{METRIC_PREFIX}_{name} {value}");
+        } else {
+            let _ = writeln!(out, "{METRIC_PREFIX}_{name}{{{labels}}} {value}");
+        }
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_metrics_for_stopped_service() {
+        let output = render_metrics(&MetricsSnapshot::default());
+        assert!(output.contains("# TYPE opencode_cloud_up gauge\nopencode_cloud_up 0\n"));
+        assert!(output.contains("opencode_cloud_broker_healthy 0\n"));
+        assert!(!output.contains("uptime_seconds"));
+        assert!(!output.contains("container_cpu_percent"));
+    }
+
+    #[test]
+    fn render_metrics_for_running_service() {
+        let snapshot = MetricsSnapshot {
+            up: true,
+            opencode_healthy: true,
+            broker_healthy: true,
+            uptime_seconds: Some(90),
+            stats: Some(ContainerStatsSample {
+                cpu_percent: 12.5,
+                memory_usage_bytes: 1024,
+                memory_limit_bytes: 4096,
+                ..Default::default()
+            }),
+            disk: Some(DiskUsageReport {
+                images: Some(100),
+                containers: None,
+                volumes: Some(-1),
+                build_cache: Some(0),
+                total: Some(100),
+            }),
+            image_version: "1.2.3".to_string(),
+            opencode_version: "0.9.0".to_string(),
+        };
+        let output = render_metrics(&snapshot);
+
+        assert!(output.contains("opencode_cloud_up 1\n"));
+        assert!(output.contains("opencode_cloud_opencode_healthy 1\n"));
+        assert!(output.contains("opencode_cloud_uptime_seconds 90\n"));
+        assert!(output.contains("opencode_cloud_container_cpu_percent 12.5\n"));
+        assert!(output.contains("opencode_cloud_container_memory_limit_bytes 4096\n"));
+        assert!(output.contains("opencode_cloud_docker_disk_usage_bytes{type=\"images\"} 100\n"));
+        assert!(output.contains("{type=\"build_cache\"} 0\n"));
+        assert!(!output.contains("type=\"containers\""));
+        assert!(!output.contains("type=\"volumes\""));
+        assert!(output.contains(
+            "opencode_cloud_build_info{image_version=\"1.2.3\",opencode_version=\"0.9.0\","
+        ));
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(escape_label_value("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+}
@@ -7,18 +7,28 @@ mod cockpit;
 mod config;
 pub(crate) mod container;
 mod disk_usage;
+mod doctor;
 mod host;
 mod install;
 mod iotp;
 mod logs;
+mod logs_export;
+mod metrics;
 mod mount;
+mod open;
+mod prune;
 mod reset;
 mod restart;
+mod rolling_restart;
 pub(crate) mod runtime_shared;
+mod sandbox;
 mod service;
+mod service_manager;
 mod setup;
 mod start;
+mod stats;
 mod status;
+mod status_diff;
 mod stop;
 mod uninstall;
 mod update;
@@ -26,17 +36,25 @@ mod update_signal;
 mod user;
 
 pub use cockpit::{CockpitArgs, cmd_cockpit};
-pub use config::{ConfigArgs, cmd_config};
-pub use host::{HostArgs, cmd_host};
+pub use config::{ConfigArgs, ResolvedContext, cmd_config};
+pub use doctor::{DoctorArgs, cmd_doctor};
+pub(crate) use host::build_host_config;
+pub use host::{HostArgs, HostCommands, cmd_host};
 pub use install::{InstallArgs, cmd_install};
 pub use logs::{LogsArgs, cmd_logs};
+pub use metrics::{MetricsArgs, cmd_metrics};
 pub use mount::{MountArgs, cmd_mount};
+pub use open::{OpenArgs, cmd_open};
+pub use prune::{PruneArgs, cmd_prune};
 pub use reset::{ResetArgs, cmd_reset};
 pub use restart::{RestartArgs, cmd_restart};
+pub use sandbox::{SandboxArgs, cmd_sandbox};
+pub use service_manager::{ServiceArgs, cmd_service};
 pub use setup::{SetupArgs, cmd_setup};
 pub use start::{StartArgs, cmd_start};
+pub use stats::{StatsArgs, cmd_stats};
 pub use status::{StatusArgs, cmd_status};
 pub use stop::{StopArgs, cmd_stop};
 pub use uninstall::{UninstallArgs, cmd_uninstall};
 pub use update::{UpdateArgs, UpdateCommand, UpdateOpencodeArgs, cmd_update};
-pub use user::{UserArgs, cmd_user};
+pub use user::{UserArgs, UserCommands, cmd_user};
@@ -4,7 +4,9 @@ use anyhow::{Result, bail};
 use clap::Args;
 use console::style;
 use opencode_cloud_core::config::{load_config_or_default, save_config};
-use opencode_cloud_core::docker::{ParsedMount, check_container_path_warning, validate_mount_path};
+use opencode_cloud_core::docker::{
+    ParsedMount, check_container_path_warning, create_mount_dir, validate_mount_path,
+};
 
 #[derive(Args)]
 pub struct MountAddArgs {
@@ -18,6 +20,10 @@ pub struct MountAddArgs {
     /// Force add even if warning about system paths
     #[arg(long, short)]
     pub force: bool,
+
+    /// Create the host directory (and parents) if it does not exist
+    #[arg(long)]
+    pub create: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -83,12 +89,7 @@ pub async fn cmd_mount_add(args: &MountAddArgs, quiet: bool, _verbose: u8) -> Re
     // Parse the mount spec
     let parsed = ParsedMount::parse(&args.mount_spec)?;
 
-    // Validate host path unless --no-validate
-    if !args.no_validate {
-        validate_mount_path(&parsed.host_path)?;
-    }
-
-    // Check for system path warning
+    // Check for system path warning before touching the host filesystem
     if let Some(warning) = check_container_path_warning(&parsed.container_path) {
         if !args.force {
             eprintln!("{}", style(&warning).yellow());
@@ -101,6 +102,36 @@ pub async fn cmd_mount_add(args: &MountAddArgs, quiet: bool, _verbose: u8) -> Re
         }
     }
 
+    if args.create && create_mount_dir(&parsed.host_path)? && !quiet {
+        println!(
+            "Created directory: {}",
+            style(parsed.host_path.display()).cyan()
+        );
+    }
+
+    let host_missing = !parsed.host_path.exists();
+
+    // Validate host path unless --no-validate
+    if !args.no_validate {
+        if let Err(e) = validate_mount_path(&parsed.host_path) {
+            if host_missing {
+                eprintln!(
+                    "Use {} to create it, or {} if it will exist later.",
+                    style("--create").cyan(),
+                    style("--no-validate").cyan()
+                );
+            }
+            return Err(e.into());
+        }
+    } else if host_missing && !quiet {
+        eprintln!(
+            "{} Host path does not exist yet: {}. The container will not start until it does; use {} to create it now.",
+            style("Warning:").yellow().bold(),
+            parsed.host_path.display(),
+            style("--create").cyan()
+        );
+    }
+
     // Load config and add mount
     let mut config = load_config_or_default()?;
     let host_str = parsed.host_path.to_string_lossy().to_string();
@@ -0,0 +1,139 @@
+//! Open command implementation
+//!
+//! Opens the running service's web UI (or Cockpit) in the default browser.
+
+use crate::constants::COCKPIT_EXPOSED;
+use crate::error::CliError;
+use crate::output::{format_cockpit_url, format_service_url, resolve_remote_addr};
+use anyhow::{Result, bail};
+use clap::Args;
+use console::style;
+use opencode_cloud_core::docker::{
+    ContainerPorts, active_resource_names, container_is_running, get_container_ports,
+};
+
+/// Arguments for the open command
+#[derive(Args, Default)]
+pub struct OpenArgs {
+    /// Open the Cockpit web console instead of the opencode web UI
+    #[arg(long, hide = !COCKPIT_EXPOSED)]
+    pub cockpit: bool,
+}
+
+/// Open the web UI of the running service in the browser
+///
+/// The URL uses the ports the container was actually started with, and the
+/// configured hostname when targeting a remote host.
+pub async fn cmd_open(args: &OpenArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+    if args.cockpit && !COCKPIT_EXPOSED {
+        bail!("Cockpit is not available in this release.");
+    }
+
+    let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
+    let config = crate::load_config_for_host(host_name.as_deref())?;
+
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| CliError::docker(&e, e.to_string()))?;
+
+    let container_name = active_resource_names().container_name;
+    if !container_is_running(&client, &container_name).await? {
+        return Err(CliError::Failed(format!(
+            "{}\n\nStart it first: {}",
+            style("The service is not running.").yellow().bold(),
+            style("occ start").cyan()
+        ))
+        .into());
+    }
+
+    let ports = get_container_ports(&client, &container_name)
+        .await
+        .unwrap_or_default();
+    let maybe_remote_addr = resolve_remote_addr(host_name.as_deref());
+    let url = open_url(
+        args.cockpit,
+        &ports,
+        maybe_remote_addr.as_deref(),
+        &config.bind_address,
+        config.opencode_web_port,
+    )?;
+
+    if !quiet {
+        let label = if args.cockpit { "Cockpit" } else { "opencode" };
+        println!("Opening {label} at: {}", style(&url).cyan());
+    }
+
+    if let Err(e) = webbrowser::open(&url)
+        && !quiet
+    {
+        eprintln!(
+            "{} Failed to open browser: {}",
+            style("Warning:").yellow(),
+            e
+        );
+        eprintln!("Open manually: {}", style(&url).cyan());
+    }
+
+    Ok(())
+}
+
+/// Pick the URL to open from the container's published ports
+fn open_url(
+    cockpit: bool,
+    ports: &ContainerPorts,
+    maybe_remote_addr: Option<&str>,
+    bind_addr: &str,
+    configured_port: u16,
+) -> Result<String> {
+    if cockpit {
+        let Some(cockpit_port) = ports.cockpit_port else {
+            bail!(
+                "Cockpit is not enabled for the running container.\n\n\
+                 Enable it with: {}\n\
+                 Then rebuild the container: {}",
+                style("occ config set cockpit_enabled true").cyan(),
+                style("occ start --cached-rebuild-sandbox-image").cyan()
+            );
+        };
+        return Ok(format_cockpit_url(
+            maybe_remote_addr,
+            bind_addr,
+            cockpit_port,
+        ));
+    }
+
+    let port = ports.opencode_port.unwrap_or(configured_port);
+    Ok(format_service_url(maybe_remote_addr, bind_addr, port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_url_prefers_published_port() {
+        let ports = ContainerPorts {
+            opencode_port: Some(3100),
+            cockpit_port: None,
+        };
+        let url = open_url(false, &ports, None, "127.0.0.1", 3000).unwrap();
+        assert_eq!(url, "http://localhost:3100");
+    }
+
+    #[test]
+    fn open_url_uses_remote_addr_and_cockpit_port() {
+        let ports = ContainerPorts {
+            opencode_port: Some(3000),
+            cockpit_port: Some(9090),
+        };
+        let url = open_url(true, &ports, Some("myserver.local"), "0.0.0.0", 3000).unwrap();
+        assert_eq!(url, "http://myserver.local:9090");
+    }
+
+    #[test]
+    fn open_url_errors_when_cockpit_not_published() {
+        let ports = ContainerPorts::default();
+        assert!(open_url(true, &ports, None, "127.0.0.1", 3000).is_err());
+    }
+}
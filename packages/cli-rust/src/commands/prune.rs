@@ -0,0 +1,375 @@
+//! Prune command implementation
+//!
+//! Frees space held by a single managed volume without touching the others.
+//! `occ prune cache` empties the opencode cache volume, which is rebuilt on
+//! demand, while sessions, state, and projects stay in place. When the cache
+//! target is bind-mounted (the default config does this), the volume is unused,
+//! so the host directory is cleared instead after confirmation.
+//! `occ prune orphans` removes managed volumes the container no longer mounts.
+//! Volumes shadowed by a configured bind mount are listed but never removed.
+
+use crate::commands::disk_usage::{format_bytes_i64, get_disk_usage_with_volumes};
+use crate::confirm::{confirm, skip_confirmation};
+use crate::output::CommandSpinner;
+use anyhow::{Result, bail};
+use clap::{Args, Subcommand};
+use console::style;
+use opencode_cloud_core::docker::{
+    DockerClient, IMAGE_NAME_GHCR, MOUNT_CACHE, ParsedMount, active_resource_names,
+    clear_volume_contents, container_is_running, find_orphaned_volumes, image_exists,
+    remove_volume, volume_exists,
+};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+/// Prune command arguments
+#[derive(Args)]
+pub struct PruneArgs {
+    #[command(subcommand)]
+    pub command: PruneCommands,
+}
+
+/// Prune command subcommands
+#[derive(Subcommand)]
+pub enum PruneCommands {
+    /// Empty the opencode cache (sessions, state, and projects are kept)
+    Cache(PruneCacheArgs),
+    /// Remove managed volumes that exist but are not attached to the container
+    Orphans(PruneOrphansArgs),
+}
+
+/// Arguments for prune orphans
+#[derive(Args)]
+pub struct PruneOrphansArgs {
+    /// Skip the confirmation prompt
+    #[arg(long)]
+    pub force: bool,
+
+    /// Only list orphaned volumes and their sizes
+    #[arg(long)]
+    pub list: bool,
+}
+
+/// Arguments for prune cache
+#[derive(Args)]
+pub struct PruneCacheArgs {
+    /// Skip the confirmation prompt
+    #[arg(long)]
+    pub force: bool,
+}
+
+pub async fn cmd_prune(args: &PruneArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+    match &args.command {
+        PruneCommands::Cache(cache_args) => cmd_prune_cache(cache_args, maybe_host, quiet).await,
+        PruneCommands::Orphans(orphan_args) => {
+            cmd_prune_orphans(orphan_args, maybe_host, quiet).await
+        }
+    }
+}
+
+async fn cmd_prune_cache(
+    args: &PruneCacheArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| crate::output::format_docker_error_anyhow(&e))?;
+
+    let names = active_resource_names();
+    let config = crate::load_config_for_host(host_name.as_deref())?;
+    if let Some(host_path) = cache_bind_mount(&config.mounts) {
+        let running = container_is_running(&client, &names.container_name).await?;
+        return prune_cache_dir(&host_path, host_name.as_deref(), running, args, quiet);
+    }
+
+    let volume = names.volume_cache.as_str();
+    if !volume_exists(&client, volume).await? {
+        if !quiet {
+            println!("Cache volume {volume} does not exist; nothing to prune.");
+        }
+        return Ok(());
+    }
+
+    // The helper container runs the sandbox image, which is already local
+    // whenever the service has been started before.
+    if !image_exists(&client, IMAGE_NAME_GHCR, &names.image_tag).await? {
+        bail!(
+            "Sandbox image {IMAGE_NAME_GHCR}:{} not found locally.\n\
+             Pull it first with: {}",
+            names.image_tag,
+            style("occ start").cyan()
+        );
+    }
+
+    let running = container_is_running(&client, &names.container_name).await?;
+    let before = cache_volume_size(&client, volume).await;
+
+    if !skip_confirmation(args.force) {
+        if !std::io::stdin().is_terminal() {
+            bail!("Refusing to prune without confirmation. Re-run with --force.");
+        }
+        println!(
+            "This deletes everything in {} ({}, {}).",
+            style(volume).cyan(),
+            MOUNT_CACHE,
+            before
+                .map(format_bytes_i64)
+                .unwrap_or_else(|| "size unknown".to_string())
+        );
+        if running {
+            println!(
+                "{} The service is running; opencode may fail requests that read the cache while it is cleared.",
+                style("Warning:").yellow().bold()
+            );
+        }
+        if !confirm("Prune the cache volume?", false, false)? {
+            bail!("Prune cancelled");
+        }
+    } else if running && !quiet {
+        println!(
+            "{} The service is running; clearing the cache underneath it.",
+            style("Warning:").yellow().bold()
+        );
+    }
+
+    let image = format!("{IMAGE_NAME_GHCR}:{}", names.image_tag);
+    let spinner = CommandSpinner::new_maybe("Clearing cache volume...", quiet);
+    if let Err(e) = clear_volume_contents(&client, volume, &image).await {
+        spinner.fail("Failed to clear cache volume");
+        return Err(e.into());
+    }
+    spinner.success("Cache volume cleared");
+
+    if !quiet {
+        let after = cache_volume_size(&client, volume).await;
+        if let Some(line) = reclaimed_line(before, after) {
+            println!("{line}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Host directory bind-mounted over the opencode cache, if any
+fn cache_bind_mount(mounts: &[String]) -> Option<PathBuf> {
+    mounts
+        .iter()
+        .filter_map(|spec| ParsedMount::parse(spec).ok())
+        .rfind(|parsed| parsed.container_path == MOUNT_CACHE)
+        .map(|parsed| parsed.host_path)
+}
+
+/// Clear a bind-mounted cache directory on this machine after confirmation
+fn prune_cache_dir(
+    host_path: &Path,
+    host_name: Option<&str>,
+    running: bool,
+    args: &PruneCacheArgs,
+    quiet: bool,
+) -> Result<()> {
+    if let Some(host) = host_name {
+        bail!(
+            "The cache is bind-mounted from {} on {host}; the cache volume is not used.\n\
+             Clear that directory on {host} instead.",
+            host_path.display()
+        );
+    }
+    if !host_path.is_dir() {
+        if !quiet {
+            println!(
+                "Cache directory {} does not exist; nothing to prune.",
+                host_path.display()
+            );
+        }
+        return Ok(());
+    }
+
+    let skip_prompt = skip_confirmation(args.force);
+    if !quiet || !skip_prompt {
+        println!(
+            "The cache is bind-mounted from {}; the cache volume is not used.",
+            style(host_path.display()).cyan()
+        );
+    }
+    if !skip_prompt {
+        if !std::io::stdin().is_terminal() {
+            bail!(
+                "Refusing to clear {} without confirmation. Re-run with --force.",
+                host_path.display()
+            );
+        }
+        println!("This deletes everything in {}.", host_path.display());
+        if running {
+            println!(
+                "{} The service is running; opencode may fail requests that read the cache while it is cleared.",
+                style("Warning:").yellow().bold()
+            );
+        }
+        if !confirm("Clear the cache directory?", false, false)? {
+            bail!("Prune cancelled");
+        }
+    } else if running && !quiet {
+        println!(
+            "{} The service is running; clearing the cache underneath it.",
+            style("Warning:").yellow().bold()
+        );
+    }
+
+    let removed = clear_dir_contents(host_path)?;
+    if !quiet {
+        println!(
+            "{} Removed {removed} entr{} from {}",
+            style("Success:").green().bold(),
+            if removed == 1 { "y" } else { "ies" },
+            host_path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Remove everything inside `dir`, keeping the directory itself
+fn clear_dir_contents(dir: &Path) -> Result<usize> {
+    let mut removed = 0;
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let result = if path.is_dir() && !path.is_symlink() {
+            std::fs::remove_dir_all(&path)
+        } else {
+            std::fs::remove_file(&path)
+        };
+        result.map_err(|e| anyhow::anyhow!("Failed to remove {}: {e}", path.display()))?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+async fn cmd_prune_orphans(
+    args: &PruneOrphansArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| crate::output::format_docker_error_anyhow(&e))?;
+    let config = crate::load_config_for_host(host_name.as_deref())?;
+
+    let Some(unattached) = find_orphaned_volumes(&client, &config.mounts).await? else {
+        if !quiet {
+            println!(
+                "No container exists; managed volumes are kept for the next {}.",
+                style("occ start").cyan()
+            );
+        }
+        return Ok(());
+    };
+    if !quiet && !unattached.shadowed.is_empty() {
+        println!("Shadowed by bind mount (kept):");
+        for name in &unattached.shadowed {
+            println!("  {}", style(name).dim());
+        }
+    }
+    if unattached.orphaned.is_empty() {
+        if !quiet {
+            println!("No orphaned volumes.");
+        }
+        return Ok(());
+    }
+
+    let names: Vec<&str> = unattached.orphaned.iter().map(String::as_str).collect();
+    let sizes = get_disk_usage_with_volumes(&client, &names)
+        .await
+        .map(|(_, volumes)| volumes)
+        .unwrap_or_default();
+    let size_of = |name: &str| {
+        sizes
+            .iter()
+            .find(|v| v.name == name)
+            .and_then(|v| v.size)
+            .map(format_bytes_i64)
+            .unwrap_or_else(|| "size unknown".to_string())
+    };
+
+    if !quiet || args.list {
+        println!("Volumes not attached to the container:");
+        for name in &names {
+            println!("  {} ({})", style(name).cyan(), size_of(name));
+        }
+    }
+    if args.list {
+        return Ok(());
+    }
+
+    if !skip_confirmation(args.force) {
+        if !std::io::stdin().is_terminal() {
+            bail!("Refusing to remove volumes without confirmation. Re-run with --force.");
+        }
+        let prompt = format!("Permanently delete {} orphaned volume(s)?", names.len());
+        if !confirm(prompt, false, false)? {
+            bail!("Prune cancelled");
+        }
+    }
+
+    let spinner = CommandSpinner::new_maybe("Removing orphaned volumes...", quiet);
+    for name in &names {
+        if let Err(e) = remove_volume(&client, name).await {
+            spinner.fail(&format!("Failed to remove {name}"));
+            return Err(e.into());
+        }
+    }
+    spinner.success(&format!("Removed {} orphaned volume(s)", names.len()));
+    Ok(())
+}
+
+async fn cache_volume_size(client: &DockerClient, volume: &str) -> Option<i64> {
+    let (_, volumes) = get_disk_usage_with_volumes(client, &[volume]).await.ok()?;
+    volumes.first().and_then(|v| v.size)
+}
+
+fn reclaimed_line(before: Option<i64>, after: Option<i64>) -> Option<String> {
+    let (before, after) = (before?, after?);
+    Some(format!(
+        "Reclaimed {} ({} -> {})",
+        format_bytes_i64((before - after).max(0)),
+        format_bytes_i64(before),
+        format_bytes_i64(after)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_bind_mount_uses_default_config() {
+        let config = opencode_cloud_core::Config::default();
+        let host_path = cache_bind_mount(&config.mounts).unwrap();
+        assert!(host_path.ends_with(".cache/opencode"));
+        assert!(cache_bind_mount(&["/host/work:/home/opencoder/workspace".to_string()]).is_none());
+    }
+
+    #[test]
+    fn clear_dir_contents_keeps_the_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("models")).unwrap();
+        std::fs::write(dir.path().join("models").join("a.bin"), b"x").unwrap();
+        std::fs::write(dir.path().join("index.json"), b"{}").unwrap();
+
+        assert_eq!(clear_dir_contents(dir.path()).unwrap(), 2);
+        assert!(dir.path().is_dir());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn reclaimed_line_needs_both_sizes() {
+        assert!(reclaimed_line(None, Some(0)).is_none());
+        assert_eq!(
+            reclaimed_line(Some(2048), Some(0)).unwrap(),
+            "Reclaimed 2.00 KB (2.00 KB -> 0 B)"
+        );
+    }
+}
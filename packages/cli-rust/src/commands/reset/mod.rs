@@ -11,24 +11,30 @@ use crate::commands::disk_usage::{
     get_disk_usage_report, get_host_disk_report,
 };
 use crate::commands::iotp::{IotpState, reset_iotp_snapshot};
-use crate::commands::service::{StopSpinnerMessages, stop_service_with_spinner};
+use crate::commands::service::{
+    StopSpinnerMessages, resolve_stop_timeout_secs, stop_service_with_spinner,
+};
 use crate::commands::start::{StartArgs, cmd_start};
+use crate::confirm::{ASSUME_YES_ENV, assume_yes, confirm};
+use crate::error::CliError;
 use crate::output::{CommandSpinner, show_docker_error};
 use anyhow::{Result, anyhow, bail};
 use clap::{Args, Subcommand};
 use console::style;
-use dialoguer::Confirm;
 use opencode_cloud_core::config::load_config_or_default;
 use opencode_cloud_core::config::paths::{get_config_dir, get_data_dir};
 use opencode_cloud_core::config::save_config;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DEFAULT_STOP_TIMEOUT_SECS, clear_state, container_exists, container_is_running,
-    remove_all_volumes, remove_images_by_name,
+    CONTAINER_NAME, VolumeRemovalOutcome, VolumeRemovalResult, clear_state, container_exists,
+    container_is_running, remove_images_by_name, remove_volumes_reporting, volume_removal_error,
 };
 use opencode_cloud_core::platform::{get_service_manager, is_service_registration_supported};
 use std::fs;
 use std::path::PathBuf;
 
+/// Phrase that must be typed to confirm `occ reset host` without --force
+const HOST_RESET_PHRASE: &str = "reset";
+
 #[derive(Clone, Copy, Default)]
 struct DiskUsageSnapshot {
     docker: Option<DiskUsageReport>,
@@ -120,10 +126,10 @@ pub async fn cmd_reset(
 
 async fn cmd_reset_iotp(args: &ResetIotpArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
     let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
-    client.verify_connection().await.map_err(|e| {
-        let msg = crate::output::format_docker_error(&e);
-        anyhow!("{msg}")
-    })?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| crate::output::format_docker_error_anyhow(&e))?;
 
     if !container_is_running(&client, CONTAINER_NAME).await? {
         bail!(
@@ -264,12 +270,13 @@ async fn remove_volumes_with_spinner(
         &crate::format_host_message(host_name, "Removing Docker volumes..."),
         quiet,
     );
-    match remove_all_volumes(client).await {
-        Ok(()) => spinner.success(&crate::format_host_message(
+    let results = remove_volumes_reporting(client).await;
+    match volume_removal_error(&results) {
+        None => spinner.success(&crate::format_host_message(
             host_name,
             "Docker volumes removed",
         )),
-        Err(err) => {
+        Some(err) => {
             spinner.fail(&crate::format_host_message(
                 host_name,
                 "Failed to remove Docker volumes",
@@ -278,6 +285,25 @@ async fn remove_volumes_with_spinner(
             errors.push(format!("Failed to remove Docker volumes: {err}"));
         }
     }
+    if !quiet {
+        for result in &results {
+            println!("  {}", format_volume_removal(result));
+        }
+    }
+}
+
+fn format_volume_removal(result: &VolumeRemovalResult) -> String {
+    match &result.outcome {
+        VolumeRemovalOutcome::Removed => {
+            format!("{} {}", style("removed").green(), result.name)
+        }
+        VolumeRemovalOutcome::Missing => {
+            format!("{} {}", style("not found").dim(), result.name)
+        }
+        VolumeRemovalOutcome::Failed(err) => {
+            format!("{} {}: {err}", style("failed").red(), result.name)
+        }
+    }
 }
 
 /// Stop/remove the service container and then remove all managed volumes.
@@ -296,7 +322,7 @@ async fn remove_container_and_volumes_for_host_reset(
             host_name,
             quiet,
             true,
-            DEFAULT_STOP_TIMEOUT_SECS,
+            resolve_stop_timeout_secs(None),
             StopSpinnerMessages {
                 action_message: "Stopping service...",
                 update_label: "Stopping service",
@@ -382,6 +408,7 @@ async fn cmd_reset_container(
     verbose: u8,
 ) -> Result<()> {
     let destructive = args.volumes || args.images || args.clean_mounts || args.purge_mounts;
+    // Deleting data needs the explicit flag; OPENCODE_CLOUD_ASSUME_YES does not count
     if destructive && !args.force {
         bail!(
             "Data-destructive flags require --force.\n\
@@ -397,10 +424,10 @@ async fn cmd_reset_container(
     }
 
     let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
-    client.verify_connection().await.map_err(|e| {
-        let msg = crate::output::format_docker_error(&e);
-        anyhow!("{msg}")
-    })?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| crate::output::format_docker_error_anyhow(&e))?;
 
     let mut errors = Vec::new();
 
@@ -410,7 +437,7 @@ async fn cmd_reset_container(
             host_name.as_deref(),
             quiet,
             true,
-            DEFAULT_STOP_TIMEOUT_SECS,
+            resolve_stop_timeout_secs(None),
             StopSpinnerMessages {
                 action_message: "Stopping service...",
                 update_label: "Stopping service",
@@ -546,13 +573,29 @@ async fn cmd_reset_host(
     _verbose: u8,
 ) -> Result<()> {
     if is_remote_host(maybe_host) {
-        bail!(
+        return Err(CliError::Unsupported(
             "Host reset is only supported on the local machine.\n\
              Run without --remote-host or use --local."
-        );
+                .to_string(),
+        )
+        .into());
     }
 
     if !args.force {
+        // Wiping everything is never implied by OPENCODE_CLOUD_ASSUME_YES
+        if assume_yes() {
+            bail!(
+                "Host reset requires --force even when {ASSUME_YES_ENV} is set.\n\
+                 Run: occ reset host --force"
+            );
+        }
+        // --quiet cannot prompt, and silently doing nothing would look like success
+        if quiet {
+            bail!(
+                "Host reset requires --force when run with --quiet.\nRun: occ reset host --force"
+            );
+        }
+
         let mut prompt =
             "This will remove all opencode-cloud data, config, mounts, and containers".to_string();
         if args.images {
@@ -560,16 +603,24 @@ async fn cmd_reset_host(
         }
         prompt.push_str(". Continue?");
 
-        let confirmed = Confirm::new()
-            .with_prompt(prompt)
-            .default(false)
-            .interact()?;
+        let confirmed = confirm(prompt, false, quiet)?;
         if !confirmed {
             if !quiet {
                 println!("Cancelled.");
             }
             return Ok(());
         }
+
+        let typed: String = dialoguer::Input::new()
+            .with_prompt(format!(
+                "Type '{HOST_RESET_PHRASE}' to permanently delete everything"
+            ))
+            .allow_empty(true)
+            .interact_text()?;
+        if !host_reset_phrase_matches(&typed) {
+            println!("Cancelled: confirmation phrase did not match.");
+            return Ok(());
+        }
     }
 
     let mut errors = Vec::new();
@@ -667,6 +718,10 @@ async fn cmd_reset_host(
     }
 }
 
+fn host_reset_phrase_matches(typed: &str) -> bool {
+    typed.trim() == HOST_RESET_PHRASE
+}
+
 fn print_browser_cache_hint(quiet: bool) {
     if quiet {
         return;
@@ -725,7 +780,7 @@ fn uninstall_service_registration(quiet: bool, errors: &mut Vec<String>) {
 
     let spinner = CommandSpinner::new_maybe("Removing service registration...", quiet);
     match manager.uninstall() {
-        Ok(()) => spinner.success("Service registration removed"),
+        Ok(_) => spinner.success("Service registration removed"),
         Err(err) => {
             spinner.fail("Failed to remove service registration");
             errors.push(format!("Failed to remove service registration: {err}"));
@@ -801,4 +856,13 @@ mod tests {
         assert!(message.contains("reverse proxy"));
         assert!(message.contains("occ reset iotp --force"));
     }
+
+    #[test]
+    fn host_reset_phrase_must_match_exactly() {
+        assert!(host_reset_phrase_matches("reset"));
+        assert!(host_reset_phrase_matches("  reset\n"));
+        assert!(!host_reset_phrase_matches(""));
+        assert!(!host_reset_phrase_matches("y"));
+        assert!(!host_reset_phrase_matches("RESET"));
+    }
 }
@@ -1,24 +1,64 @@
 //! Restart command implementation
 //!
-//! Restarts the opencode service (stop + start).
+//! Restarts the opencode service (stop + start), or with `--rolling` first
+//! checks that a replacement boots healthy before stopping the running container.
 
+use crate::commands::rolling_restart::{
+    RollingOutcome, RollingPlan, default_warmup_port, rolling_restart,
+};
+use crate::commands::runtime_shared::env::{container_env_vars, env_equal};
 use crate::commands::runtime_shared::mounts::{collect_bind_mounts, mounts_equal};
-use crate::commands::start::{wait_for_broker_ready, wait_for_service_ready};
+use crate::commands::start::{check_port_available, wait_for_broker_ready, wait_for_service_ready};
+use crate::confirm::confirm;
 use crate::constants::COCKPIT_EXPOSED;
-use crate::output::{CommandSpinner, format_docker_error, format_service_url, show_docker_error};
+use crate::output::{
+    CommandSpinner, format_docker_error_anyhow, format_service_url, show_docker_error,
+};
 use anyhow::{Result, anyhow};
 use clap::Args;
 use console::style;
-use opencode_cloud_core::config::load_config_or_default;
+use opencode_cloud_core::Config;
 use opencode_cloud_core::docker::{
     CONTAINER_NAME, ContainerBindMount, ParsedMount, container_exists, container_is_running,
-    docker_supports_systemd, get_container_bind_mounts, setup_and_start, stop_service,
+    docker_supports_systemd, get_container_bind_mounts, get_container_custom_labels,
+    get_container_env, setup_and_start, stop_service,
 };
+use std::time::Duration;
+
+/// Default seconds to wait for the rolling warm-up container to become healthy
+const DEFAULT_ROLLING_TIMEOUT_SECS: u64 = 120;
 
 /// Arguments for the restart command
 #[derive(Args)]
 pub struct RestartArgs {
     // Future: --port flag to change port on restart
+    /// Remove and recreate the container so port and bind address changes apply (volumes are kept)
+    #[arg(long, conflicts_with = "rolling")]
+    pub recreate: bool,
+
+    /// Check that a replacement boots healthy before stopping the running one
+    /// (downtime is the same as a normal restart)
+    #[arg(long)]
+    pub rolling: bool,
+
+    /// Temporary host port for the warm-up container (default: first free port after the service port)
+    #[arg(long, requires = "rolling")]
+    pub rolling_port: Option<u16>,
+
+    /// Seconds to wait for the warm-up container before falling back to a normal restart
+    #[arg(long, requires = "rolling", default_value_t = DEFAULT_ROLLING_TIMEOUT_SECS)]
+    pub rolling_timeout: u64,
+}
+
+impl Default for RestartArgs {
+    fn default() -> Self {
+        Self {
+            recreate: false,
+            rolling: false,
+            rolling_port: None,
+            rolling_timeout: DEFAULT_ROLLING_TIMEOUT_SECS,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -106,10 +146,13 @@ fn display_configured_mounts(mounts: &[ParsedMount]) {
 ///
 /// This command:
 /// 1. Connects to Docker
-/// 2. Stops the service if running
-/// 3. Starts the service
+/// 2. With --rolling and a running container, tries a rolling restart
+///    (see `rolling_restart`) and returns if it completes
+/// 3. Stops the service if running, removing the container when configured
+///    mounts or environment no longer match it
+/// 4. Starts the service
 pub async fn cmd_restart(
-    _args: &RestartArgs,
+    args: &RestartArgs,
     maybe_host: Option<&str>,
     quiet: bool,
     verbose: u8,
@@ -127,13 +170,13 @@ pub async fn cmd_restart(
     }
 
     // Verify connection
-    client.verify_connection().await.map_err(|e| {
-        let msg = format_docker_error(&e);
-        anyhow!("{msg}")
-    })?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| format_docker_error_anyhow(&e))?;
 
     // Load config for port and bind_address
-    let config = load_config_or_default()?;
+    let config = crate::load_config_for_host(host_name.as_deref())?;
     let port = config.opencode_web_port;
     let bind_addr = &config.bind_address;
     let systemd_enabled = docker_supports_systemd(&client).await?;
@@ -143,7 +186,26 @@ pub async fn cmd_restart(
     } else {
         Some(bind_mounts.clone())
     };
+    let env_vars = container_env_vars(&config);
     let mut recreate_container = false;
+    let recreate_requested = args.recreate && container_exists(&client, CONTAINER_NAME).await?;
+
+    if args.rolling
+        && container_is_running(&client, CONTAINER_NAME).await?
+        && run_rolling_restart(
+            &client,
+            args,
+            &config,
+            host_name.as_deref(),
+            systemd_enabled,
+            bind_mounts_option.clone(),
+            quiet,
+            verbose,
+        )
+        .await?
+    {
+        return Ok(());
+    }
 
     if container_exists(&client, CONTAINER_NAME).await? {
         let current_mounts = get_container_bind_mounts(&client, CONTAINER_NAME).await?;
@@ -152,11 +214,12 @@ pub async fn cmd_restart(
             MountMismatchAction::NoMismatch => {}
             MountMismatchAction::PromptRecreate => {
                 display_mount_mismatch(&current_mounts, &bind_mounts);
-                let confirm = dialoguer::Confirm::new()
-                    .with_prompt("Recreate container with new mount configuration?")
-                    .default(true)
-                    .interact()?;
-                if !confirm {
+                let confirmed = confirm(
+                    "Recreate container with new mount configuration?",
+                    true,
+                    quiet,
+                )?;
+                if !confirmed {
                     return Err(anyhow!(
                         "Container not recreated. Mount changes were not applied.\n\
                          To apply mount changes, run:\n  \
@@ -170,16 +233,35 @@ pub async fn cmd_restart(
         }
     }
 
+    // Environment is fixed at creation, so configured changes need a fresh container
+    let env_changed = !recreate_container
+        && container_exists(&client, CONTAINER_NAME).await?
+        && !env_equal(
+            &get_container_env(&client, CONTAINER_NAME).await?,
+            &env_vars,
+        );
+    // Labels are fixed at creation as well
+    let labels_changed = !recreate_container
+        && !env_changed
+        && container_exists(&client, CONTAINER_NAME).await?
+        && get_container_custom_labels(&client, CONTAINER_NAME).await? != config.container_labels;
+
     // Create single spinner for the full operation
     let msg = crate::format_host_message(host_name.as_deref(), "Restarting service...");
     let spinner = CommandSpinner::new_maybe(&msg, quiet);
 
-    if recreate_container {
-        spinner.update(&crate::format_host_message(
-            host_name.as_deref(),
-            "Recreating container to apply mount changes...",
-        ));
-        if let Err(e) = stop_service(&client, true, None).await {
+    if recreate_requested || recreate_container || env_changed || labels_changed {
+        let reason = if recreate_requested {
+            "Recreating container..."
+        } else if recreate_container {
+            "Recreating container to apply mount changes..."
+        } else if env_changed {
+            "Recreating container to apply environment changes..."
+        } else {
+            "Recreating container to apply label changes..."
+        };
+        spinner.update(&crate::format_host_message(host_name.as_deref(), reason));
+        if let Err(e) = stop_service(&client, true, Some(config.stop_timeout_secs())).await {
             spinner.fail(&crate::format_host_message(
                 host_name.as_deref(),
                 "Failed to recreate container",
@@ -192,7 +274,7 @@ pub async fn cmd_restart(
             host_name.as_deref(),
             "Stopping service...",
         ));
-        if let Err(e) = stop_service(&client, false, None).await {
+        if let Err(e) = stop_service(&client, false, Some(config.stop_timeout_secs())).await {
             spinner.fail(&crate::format_host_message(
                 host_name.as_deref(),
                 "Failed to stop",
@@ -210,12 +292,14 @@ pub async fn cmd_restart(
     match setup_and_start(
         &client,
         Some(port),
-        None,
+        Some(env_vars),
         Some(bind_addr),
         Some(config.cockpit_port),
         Some(config.cockpit_enabled && COCKPIT_EXPOSED),
         Some(systemd_enabled),
         bind_mounts_option,
+        Some(&config.container_labels),
+        config.platform.as_deref(),
     )
     .await
     {
@@ -242,13 +326,7 @@ pub async fn cmd_restart(
             ));
 
             if !quiet {
-                let url = format_service_url(None, bind_addr, port);
-                println!();
-                println!("URL:        {}", style(&url).cyan());
-                println!(
-                    "Container:  {}",
-                    style(&container_id[..12.min(container_id.len())]).dim()
-                );
+                print_restart_summary(bind_addr, port, &container_id);
             }
         }
         Err(e) => {
@@ -264,6 +342,124 @@ pub async fn cmd_restart(
     Ok(())
 }
 
+/// Attempt a rolling restart
+///
+/// Returns `Ok(true)` when the replacement container is running and ready,
+/// `Ok(false)` when the warm-up failed and a normal restart should follow.
+#[allow(clippy::too_many_arguments)]
+async fn run_rolling_restart(
+    client: &opencode_cloud_core::DockerClient,
+    args: &RestartArgs,
+    config: &Config,
+    host_name: Option<&str>,
+    systemd_enabled: bool,
+    bind_mounts: Option<Vec<ParsedMount>>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<bool> {
+    let port = config.opencode_web_port;
+    let bind_addr = config.bind_address.as_str();
+    // Ports on a remote host cannot be probed from here; Docker reports a clash
+    let warmup_port = args
+        .rolling_port
+        .or_else(|| {
+            default_warmup_port(port, |candidate| {
+                host_name.is_some() || check_port_available(bind_addr, candidate)
+            })
+        })
+        .ok_or_else(|| anyhow!("No free warm-up port after {port}. Pass --rolling-port."))?;
+    if warmup_port == port {
+        return Err(anyhow!(
+            "--rolling-port must differ from the service port ({port})"
+        ));
+    }
+
+    let plan = RollingPlan {
+        port,
+        warmup_port,
+        bind_addr,
+        cockpit_port: config.cockpit_port,
+        cockpit_enabled: config.cockpit_enabled && COCKPIT_EXPOSED,
+        systemd_enabled,
+        env_vars: container_env_vars(config),
+        bind_mounts,
+        container_labels: &config.container_labels,
+        platform: config.platform.as_deref(),
+        stop_timeout_secs: config.stop_timeout_secs(),
+        warmup_timeout: Duration::from_secs(args.rolling_timeout),
+    };
+
+    if verbose > 0 {
+        eprintln!(
+            "{} Rolling restart: warm-up on port {}, timeout {}s, stop timeout {}s",
+            style("[info]").cyan(),
+            warmup_port,
+            args.rolling_timeout,
+            plan.stop_timeout_secs
+        );
+    }
+
+    let msg = crate::format_host_message(host_name, "Rolling restart...");
+    let spinner = CommandSpinner::new_maybe(&msg, quiet);
+
+    let container_id = match rolling_restart(client, plan, &spinner).await {
+        Ok(RollingOutcome::Completed(container_id)) => container_id,
+        Ok(RollingOutcome::FellBack(reason)) => {
+            spinner.fail(&crate::format_host_message(
+                host_name,
+                "Rolling warm-up did not become healthy",
+            ));
+            eprintln!(
+                "{} {}. Falling back to a normal restart.",
+                style("Warning:").yellow().bold(),
+                reason
+            );
+            return Ok(false);
+        }
+        Err(e) => {
+            spinner.fail(&crate::format_host_message(
+                host_name,
+                "Rolling restart failed",
+            ));
+            return Err(e);
+        }
+    };
+
+    if let Err(e) = wait_for_service_ready(client, bind_addr, port, &spinner).await {
+        spinner.fail(&crate::format_host_message(
+            host_name,
+            "Service failed to become ready",
+        ));
+        return Err(e);
+    }
+
+    if let Err(e) = wait_for_broker_ready(client, &spinner).await {
+        spinner.fail(&crate::format_host_message(
+            host_name,
+            "Broker failed to become ready",
+        ));
+        return Err(e);
+    }
+
+    spinner.success(&crate::format_host_message(host_name, "Service restarted"));
+
+    if !quiet {
+        print_restart_summary(bind_addr, port, &container_id);
+    }
+
+    Ok(true)
+}
+
+fn print_restart_summary(bind_addr: &str, port: u16, container_id: &str) {
+    let url = format_service_url(None, bind_addr, port);
+    println!();
+    println!("URL:        {}", style(&url).cyan());
+    println!(
+        "Container:  {}",
+        style(&container_id[..12.min(container_id.len())]).dim()
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,295 @@
+//! Rolling restart for `occ restart --rolling`
+//!
+//! Docker cannot move port bindings between running containers, so a true
+//! blue/green swap is not possible with the single-container design. A rolling
+//! restart does not shorten downtime; it checks that the image and
+//! configuration boot healthy before the running container is touched, so a
+//! broken replacement never takes the service down:
+//!
+//! 1. Warm-up: start a candidate container (`<name>-rolling`) from the current
+//!    image on a free temporary port, with Cockpit disabled and no persistent
+//!    storage, so it never writes the live session or state directories.
+//! 2. Wait until `collect_status_view` reports opencode and the broker healthy,
+//!    up to the warm-up timeout. The candidate is removed either way.
+//! 3. If the candidate never went green, return `FellBack` so the caller can
+//!    run a normal stop-then-start restart.
+//! 4. Cutover: create (but do not start) the replacement container as
+//!    `<name>-next` with the real port bindings and storage.
+//! 5. Stop and remove the old container, rename `<name>-next` to `<name>`,
+//!    start it, and restore persisted users. The replacement boots from cold
+//!    here, so downtime matches a normal restart. If the rename fails, the
+//!    replacement is started as `<name>-next` and the error says how to
+//!    finish the swap.
+
+use crate::commands::runtime_shared::backend::HostBackend;
+use crate::commands::runtime_shared::status_model::{OpencodeHealthStatus, StatusViewModel};
+use crate::commands::runtime_shared::{
+    DEFAULT_PROBE_TIMEOUT, broker_is_ready, collect_status_view,
+};
+use crate::output::CommandSpinner;
+use anyhow::Result;
+use anyhow::anyhow;
+use opencode_cloud_core::docker::{
+    DockerClient, ParsedMount, active_resource_names, container_exists, create_container,
+    create_scratch_container, remove_container, rename_container, restore_persisted_users,
+    start_container, stop_container,
+};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// Interval between warm-up health polls
+const WARMUP_POLL_INTERVAL_MS: u64 = 2000;
+
+/// How many ports after the service port to try for the warm-up
+const WARMUP_PORT_SEARCH_RANGE: u16 = 100;
+
+/// Container settings shared by the warm-up candidate and the replacement
+pub(super) struct RollingPlan<'a> {
+    pub port: u16,
+    pub warmup_port: u16,
+    pub bind_addr: &'a str,
+    pub cockpit_port: u16,
+    pub cockpit_enabled: bool,
+    pub systemd_enabled: bool,
+    pub env_vars: Vec<String>,
+    pub bind_mounts: Option<Vec<ParsedMount>>,
+    pub container_labels: &'a BTreeMap<String, String>,
+    pub platform: Option<&'a str>,
+    pub stop_timeout_secs: i64,
+    pub warmup_timeout: Duration,
+}
+
+/// Result of a rolling restart attempt
+pub(super) enum RollingOutcome {
+    /// Replacement container is running under the primary name
+    Completed(String),
+    /// Warm-up never went healthy; nothing was changed on the primary container
+    FellBack(String),
+}
+
+/// Names of the temporary containers derived from the primary container name
+fn rolling_container_names(primary: &str) -> (String, String) {
+    (format!("{primary}-rolling"), format!("{primary}-next"))
+}
+
+/// Whether a status snapshot counts as healthy enough to cut over
+fn is_warm(status: &StatusViewModel) -> bool {
+    matches!(status.opencode_health, Some(OpencodeHealthStatus::Healthy))
+        && broker_is_ready(status.broker_health)
+}
+
+/// Pick the first free port after the service port for the warm-up
+///
+/// `is_free` probes a candidate, e.g. by binding it on the service address.
+pub(super) fn default_warmup_port(port: u16, is_free: impl Fn(u16) -> bool) -> Option<u16> {
+    let first = port.checked_add(1)?;
+    let last = port.saturating_add(WARMUP_PORT_SEARCH_RANGE);
+    (first..=last).find(|&candidate| is_free(candidate))
+}
+
+/// Run the rolling restart against a running primary container
+pub(super) async fn rolling_restart(
+    client: &DockerClient,
+    plan: RollingPlan<'_>,
+    spinner: &CommandSpinner,
+) -> Result<RollingOutcome> {
+    let primary = active_resource_names().container_name;
+    let (warmup_name, next_name) = rolling_container_names(&primary);
+
+    spinner.update(&format!(
+        "Warming up replacement on port {}...",
+        plan.warmup_port
+    ));
+    let warm = warm_up_candidate(client, &plan, &warmup_name).await;
+    remove_if_exists(client, &warmup_name).await?;
+    if let Err(reason) = warm? {
+        return Ok(RollingOutcome::FellBack(reason));
+    }
+
+    spinner.update("Preparing replacement container...");
+    remove_if_exists(client, &next_name).await?;
+    let container_id = create_container(
+        client,
+        Some(&next_name),
+        None,
+        Some(plan.port),
+        Some(plan.env_vars.clone()),
+        Some(plan.bind_addr),
+        Some(plan.cockpit_port),
+        Some(plan.cockpit_enabled),
+        Some(plan.systemd_enabled),
+        plan.bind_mounts.clone(),
+        Some(plan.container_labels),
+        plan.platform,
+    )
+    .await?;
+
+    spinner.update("Swapping containers...");
+    if let Err(e) = stop_container(client, &primary, Some(plan.stop_timeout_secs)).await
+        && !e.to_string().contains("is not running")
+    {
+        // Never remove or rename over a container that may still be running
+        remove_if_exists(client, &next_name).await?;
+        return Err(anyhow!(
+            "Could not stop {primary}: {e}. The running service was left in place."
+        ));
+    }
+    remove_container(client, &primary, false).await?;
+    // The old container is gone from here on, so failures must say what is left
+    if let Err(e) = rename_container(client, &next_name, &primary).await {
+        return Err(recover_unrenamed(client, &primary, &next_name, e.into()).await);
+    }
+    if let Err(e) = start_container(client, &primary).await {
+        return Err(anyhow!(
+            "Could not start the replacement {primary}: {e}. The service is stopped.\n\
+             Retry with: docker start {primary}\n\
+             Or recreate it with: occ start"
+        ));
+    }
+    restore_persisted_users(client, &primary).await?;
+
+    Ok(RollingOutcome::Completed(container_id))
+}
+
+/// Start the warm-up candidate and wait for it to report healthy
+///
+/// The inner `Err` carries the reason the warm-up did not go green; the outer
+/// error is reserved for Docker failures while creating the candidate.
+async fn warm_up_candidate(
+    client: &DockerClient,
+    plan: &RollingPlan<'_>,
+    name: &str,
+) -> Result<std::result::Result<(), String>> {
+    remove_if_exists(client, name).await?;
+    // No volumes or bind mounts: the live container keeps sole use of its data
+    if let Err(e) = create_scratch_container(
+        client,
+        name,
+        None,
+        plan.warmup_port,
+        Some(plan.env_vars.clone()),
+        Some(plan.bind_addr),
+        plan.systemd_enabled,
+        plan.platform,
+    )
+    .await
+    {
+        return Ok(Err(format!("could not create warm-up container: {e}")));
+    }
+    if let Err(e) = start_container(client, name).await {
+        return Ok(Err(format!("could not start warm-up container: {e}")));
+    }
+
+    let backend = HostBackend::for_container(client, name);
+    let start = Instant::now();
+    loop {
+        let status = collect_status_view(
+            &backend,
+            true,
+            plan.bind_addr,
+            plan.warmup_port,
+            DEFAULT_PROBE_TIMEOUT,
+        )
+        .await?;
+        if is_warm(&status) {
+            return Ok(Ok(()));
+        }
+        if start.elapsed() >= plan.warmup_timeout {
+            return Ok(Err(format!(
+                "warm-up container not healthy after {}s",
+                plan.warmup_timeout.as_secs()
+            )));
+        }
+        tokio::time::sleep(Duration::from_millis(WARMUP_POLL_INTERVAL_MS)).await;
+    }
+}
+
+/// Start the replacement under its temporary name after a failed rename
+///
+/// Returns the error to report, naming the leftover container and how to
+/// finish the swap by hand.
+async fn recover_unrenamed(
+    client: &DockerClient,
+    primary: &str,
+    next_name: &str,
+    cause: anyhow::Error,
+) -> anyhow::Error {
+    match start_container(client, next_name).await {
+        Ok(_) => {
+            let _ = restore_persisted_users(client, next_name).await;
+            anyhow!(
+                "Could not rename {next_name} to {primary}: {cause}. \
+                 The service is running as {next_name}.\n\
+                 Finish the swap with: docker rename {next_name} {primary}"
+            )
+        }
+        Err(e) => anyhow!(
+            "Could not rename {next_name} to {primary}: {cause}. \
+             Starting {next_name} also failed ({e}), so the service is stopped.\n\
+             Recover with: docker rm -f {next_name} && occ start"
+        ),
+    }
+}
+
+async fn remove_if_exists(client: &DockerClient, name: &str) -> Result<()> {
+    if container_exists(client, name).await? {
+        remove_container(client, name, true).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::runtime_shared::status_model::{BrokerHealthStatus, RuntimeCapabilities};
+
+    fn status(
+        opencode_health: Option<OpencodeHealthStatus>,
+        broker_health: BrokerHealthStatus,
+    ) -> StatusViewModel {
+        StatusViewModel {
+            opencode_health,
+            broker_health,
+            opencode_version: "unknown".to_string(),
+            opencode_commit: "unknown".to_string(),
+            image_version: "unknown".to_string(),
+            capabilities: RuntimeCapabilities {
+                systemd_available: None,
+                journalctl_available: None,
+                root_required_for_user_management: None,
+            },
+        }
+    }
+
+    #[test]
+    fn rolling_container_names_append_suffixes() {
+        let (warmup, next) = rolling_container_names("opencode-cloud-sandbox");
+        assert_eq!(warmup, "opencode-cloud-sandbox-rolling");
+        assert_eq!(next, "opencode-cloud-sandbox-next");
+    }
+
+    #[test]
+    fn is_warm_requires_opencode_and_broker_healthy() {
+        assert!(is_warm(&status(
+            Some(OpencodeHealthStatus::Healthy),
+            BrokerHealthStatus::Healthy
+        )));
+        assert!(!is_warm(&status(
+            Some(OpencodeHealthStatus::Starting),
+            BrokerHealthStatus::Healthy
+        )));
+        assert!(!is_warm(&status(
+            Some(OpencodeHealthStatus::Healthy),
+            BrokerHealthStatus::Degraded
+        )));
+        assert!(!is_warm(&status(None, BrokerHealthStatus::Healthy)));
+    }
+
+    #[test]
+    fn default_warmup_port_skips_busy_ports() {
+        assert_eq!(default_warmup_port(3000, |_| true), Some(3001));
+        assert_eq!(default_warmup_port(3000, |p| p > 3002), Some(3003));
+        assert_eq!(default_warmup_port(3000, |_| false), None);
+        assert_eq!(default_warmup_port(u16::MAX, |_| true), None);
+    }
+}
@@ -13,6 +13,17 @@ use std::path::Path;
 
 use super::status_model::{OpencodeHttpProbe, RuntimeCapabilities};
 
+/// Hit opencode's `/global/health` endpoint directly, without Docker
+pub async fn probe_opencode_http(addr: &str, port: u16) -> OpencodeHttpProbe {
+    match check_health(addr, port).await {
+        Ok(_) => OpencodeHttpProbe::Healthy,
+        Err(HealthError::ConnectionRefused) => OpencodeHttpProbe::ConnectionRefused,
+        Err(HealthError::Timeout) => OpencodeHttpProbe::Timeout,
+        Err(HealthError::Unhealthy(code)) => OpencodeHttpProbe::Unhealthy(code),
+        Err(_) => OpencodeHttpProbe::Failed,
+    }
+}
+
 pub trait RuntimeBackend {
     async fn probe_opencode_http_health(
         &self,
@@ -29,11 +40,20 @@ pub trait RuntimeBackend {
 
 pub struct HostBackend<'a> {
     client: &'a DockerClient,
+    container_name: &'a str,
 }
 
 impl<'a> HostBackend<'a> {
     pub fn new(client: &'a DockerClient) -> Self {
-        Self { client }
+        Self::for_container(client, CONTAINER_NAME)
+    }
+
+    /// Probe a container other than the primary service container
+    pub fn for_container(client: &'a DockerClient, container_name: &'a str) -> Self {
+        Self {
+            client,
+            container_name,
+        }
     }
 }
 
@@ -43,20 +63,13 @@ impl RuntimeBackend for HostBackend<'_> {
         bind_addr: &str,
         host_port: u16,
     ) -> Result<OpencodeHttpProbe> {
-        let probe = match check_health(normalize_bind_addr(bind_addr), host_port).await {
-            Ok(_) => OpencodeHttpProbe::Healthy,
-            Err(HealthError::ConnectionRefused) => OpencodeHttpProbe::ConnectionRefused,
-            Err(HealthError::Timeout) => OpencodeHttpProbe::Timeout,
-            Err(HealthError::Unhealthy(code)) => OpencodeHttpProbe::Unhealthy(code),
-            Err(_) => OpencodeHttpProbe::Failed,
-        };
-        Ok(probe)
+        Ok(probe_opencode_http(normalize_bind_addr(bind_addr), host_port).await)
     }
 
     async fn probe_broker_process_active(&self) -> Result<bool> {
         let (_output, status) = exec_command_with_status(
             self.client,
-            CONTAINER_NAME,
+            self.container_name,
             vec![
                 "sh",
                 "-lc",
@@ -70,7 +83,7 @@ impl RuntimeBackend for HostBackend<'_> {
     async fn probe_broker_socket_present(&self) -> Result<bool> {
         let (_output, status) = exec_command_with_status(
             self.client,
-            CONTAINER_NAME,
+            self.container_name,
             vec!["sh", "-lc", "test -S /run/opencode/auth.sock"],
         )
         .await?;
@@ -80,7 +93,7 @@ impl RuntimeBackend for HostBackend<'_> {
     async fn read_opencode_version(&self) -> Result<Option<String>> {
         let output = match exec_command(
             self.client,
-            CONTAINER_NAME,
+            self.container_name,
             vec!["/opt/opencode/bin/opencode", "--version"],
         )
         .await
@@ -100,7 +113,7 @@ impl RuntimeBackend for HostBackend<'_> {
     async fn read_opencode_commit(&self) -> Result<Option<String>> {
         let output = match exec_command(
             self.client,
-            CONTAINER_NAME,
+            self.container_name,
             vec!["cat", "/opt/opencode/COMMIT"],
         )
         .await
@@ -114,7 +127,7 @@ impl RuntimeBackend for HostBackend<'_> {
     async fn read_image_version(&self) -> Result<Option<String>> {
         let output = match exec_command(
             self.client,
-            CONTAINER_NAME,
+            self.container_name,
             vec!["cat", "/etc/opencode-cloud-version"],
         )
         .await
@@ -156,14 +169,7 @@ impl RuntimeBackend for ContainerBackend {
         _bind_addr: &str,
         host_port: u16,
     ) -> Result<OpencodeHttpProbe> {
-        let probe = match check_health("127.0.0.1", host_port).await {
-            Ok(_) => OpencodeHttpProbe::Healthy,
-            Err(HealthError::ConnectionRefused) => OpencodeHttpProbe::ConnectionRefused,
-            Err(HealthError::Timeout) => OpencodeHttpProbe::Timeout,
-            Err(HealthError::Unhealthy(code)) => OpencodeHttpProbe::Unhealthy(code),
-            Err(_) => OpencodeHttpProbe::Failed,
-        };
-        Ok(probe)
+        Ok(probe_opencode_http("127.0.0.1", host_port).await)
     }
 
     async fn probe_broker_process_active(&self) -> Result<bool> {
@@ -0,0 +1,102 @@
+//! Shared container environment helpers.
+//!
+//! Docker fixes a container's environment when it is created, so changes to
+//! `container_env` only reach the service once the container is recreated.
+
+use opencode_cloud_core::Config;
+use opencode_cloud_core::docker::ContainerEnv;
+use std::collections::BTreeSet;
+
+/// Environment variables to create the container with.
+///
+/// Configured `container_env` entries, plus `OPENCODE_CLOUD_ENV` passed through
+/// from the invoking shell when set.
+pub fn container_env_vars(config: &Config) -> Vec<String> {
+    let mut env = config.container_env.clone();
+    if let Some(value) = std::env::var("OPENCODE_CLOUD_ENV")
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+    {
+        env.retain(|entry| env_key(entry) != "OPENCODE_CLOUD_ENV");
+        env.push(format!("OPENCODE_CLOUD_ENV={value}"));
+    }
+    env
+}
+
+/// Compare a container's environment with the configured variables (ignoring order).
+///
+/// Only keys that are configured now, or were configured when the container
+/// was created, are compared; image and default variables are ignored.
+pub fn env_equal(current: &ContainerEnv, configured: &[String]) -> bool {
+    let mut managed: BTreeSet<&str> = configured.iter().map(|entry| env_key(entry)).collect();
+    if let Some(keys) = &current.configured_keys {
+        managed.extend(keys.iter().map(String::as_str));
+    }
+
+    let mut have: Vec<&str> = current
+        .env
+        .iter()
+        .map(String::as_str)
+        .filter(|entry| managed.contains(env_key(entry)))
+        .collect();
+    let mut want: Vec<&str> = configured.iter().map(String::as_str).collect();
+    have.sort_unstable();
+    want.sort_unstable();
+    have == want
+}
+
+fn env_key(entry: &str) -> &str {
+    entry.split_once('=').map_or(entry, |(key, _)| key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container_env(env: &[&str], configured_keys: Option<&[&str]>) -> ContainerEnv {
+        ContainerEnv {
+            env: env.iter().map(|e| e.to_string()).collect(),
+            configured_keys: configured_keys
+                .map(|keys| keys.iter().map(|k| k.to_string()).collect()),
+        }
+    }
+
+    fn configured(entries: &[&str]) -> Vec<String> {
+        entries.iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn env_equal_ignores_image_and_default_variables() {
+        let current = container_env(
+            &[
+                "PATH=/usr/bin",
+                "FOO=bar",
+                "XDG_DATA_HOME=/home/opencoder/.local/share",
+            ],
+            Some(&["FOO"]),
+        );
+        assert!(env_equal(&current, &configured(&["FOO=bar"])));
+    }
+
+    #[test]
+    fn env_equal_detects_changed_and_added_values() {
+        let current = container_env(&["PATH=/usr/bin", "FOO=bar"], Some(&["FOO"]));
+        assert!(!env_equal(&current, &configured(&["FOO=baz"])));
+        assert!(!env_equal(&current, &configured(&["FOO=bar", "NEW=1"])));
+    }
+
+    #[test]
+    fn env_equal_detects_removed_variables_via_label() {
+        let current = container_env(&["FOO=bar", "OLD=1"], Some(&["FOO", "OLD"]));
+        assert!(!env_equal(&current, &configured(&["FOO=bar"])));
+    }
+
+    #[test]
+    fn env_equal_without_label_compares_configured_keys_only() {
+        let current = container_env(&["PATH=/usr/bin", "FOO=bar"], None);
+        assert!(env_equal(&current, &configured(&[])));
+        assert!(env_equal(&current, &configured(&["FOO=bar"])));
+        assert!(!env_equal(&current, &configured(&["FOO=baz"])));
+    }
+}
@@ -2,18 +2,40 @@
 
 pub mod backend;
 pub mod drift;
+pub mod env;
 pub mod health;
 pub mod mounts;
 pub mod status_model;
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use backend::RuntimeBackend;
 use health::{map_broker_health_status, map_opencode_health_status};
 use status_model::{BrokerHealthStatus, StatusViewModel};
+use std::future::Future;
+use std::time::Duration;
+
+/// Default bound on each status probe, so a wedged container cannot hang `occ status`
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Await a probe, turning a timeout into an error the status mapping already handles
+async fn within<T>(timeout: Duration, probe: impl Future<Output = Result<T>>) -> Result<T> {
+    tokio::time::timeout(timeout, probe)
+        .await
+        .unwrap_or_else(|_| Err(anyhow!("probe timed out after {}s", timeout.as_secs_f32())))
+}
 
 pub async fn probe_broker_health<B: RuntimeBackend>(backend: &B) -> BrokerHealthStatus {
-    let process_probe = backend.probe_broker_process_active().await;
-    let socket_probe = backend.probe_broker_socket_present().await;
+    probe_broker_health_within(backend, DEFAULT_PROBE_TIMEOUT).await
+}
+
+async fn probe_broker_health_within<B: RuntimeBackend>(
+    backend: &B,
+    probe_timeout: Duration,
+) -> BrokerHealthStatus {
+    let (process_probe, socket_probe) = tokio::join!(
+        within(probe_timeout, backend.probe_broker_process_active()),
+        within(probe_timeout, backend.probe_broker_socket_present())
+    );
 
     match (process_probe, socket_probe) {
         (Ok(process_ok), Ok(socket_ok)) => map_broker_health_status(process_ok, socket_ok),
@@ -26,37 +48,42 @@ pub async fn collect_status_view<B: RuntimeBackend>(
     include_opencode_probe: bool,
     bind_addr: &str,
     host_port: u16,
+    probe_timeout: Duration,
 ) -> Result<StatusViewModel> {
-    let opencode_health = if include_opencode_probe {
-        let probe = match backend
-            .probe_opencode_http_health(bind_addr, host_port)
-            .await
+    // The probes and reads are independent; on a remote host each one is a
+    // round trip, so run them together instead of one after another.
+    let opencode_probe = async {
+        if !include_opencode_probe {
+            return None;
+        }
+        let probe = match within(
+            probe_timeout,
+            backend.probe_opencode_http_health(bind_addr, host_port),
+        )
+        .await
         {
             Ok(probe) => probe,
             Err(_) => status_model::OpencodeHttpProbe::Failed,
         };
         Some(map_opencode_health_status(probe))
-    } else {
-        None
     };
 
-    let broker_health = probe_broker_health(backend).await;
-
-    let opencode_version = backend
-        .read_opencode_version()
-        .await
+    let (opencode_health, broker_health, opencode_version, opencode_commit, image_version) = tokio::join!(
+        opencode_probe,
+        probe_broker_health_within(backend, probe_timeout),
+        within(probe_timeout, backend.read_opencode_version()),
+        within(probe_timeout, backend.read_opencode_commit()),
+        within(probe_timeout, backend.read_image_version())
+    );
+    let opencode_version = opencode_version
         .ok()
         .flatten()
         .unwrap_or_else(|| "unknown".to_string());
-    let opencode_commit = backend
-        .read_opencode_commit()
-        .await
+    let opencode_commit = opencode_commit
         .ok()
         .flatten()
         .unwrap_or_else(|| "unknown".to_string());
-    let image_version = backend
-        .read_image_version()
-        .await
+    let image_version = image_version
         .ok()
         .flatten()
         .unwrap_or_else(|| "unknown".to_string());
@@ -79,6 +106,7 @@ pub fn broker_is_ready(status: BrokerHealthStatus) -> bool {
 mod tests {
     use super::*;
     use crate::commands::runtime_shared::status_model::OpencodeHttpProbe;
+    use std::time::Duration;
 
     #[derive(Clone)]
     struct FakeBackend {
@@ -92,6 +120,7 @@ mod tests {
         opencode_commit: Option<String>,
         image_version: Option<String>,
         capabilities: status_model::RuntimeCapabilities,
+        probe_delay: Duration,
     }
 
     impl RuntimeBackend for FakeBackend {
@@ -100,6 +129,7 @@ mod tests {
             _bind_addr: &str,
             _host_port: u16,
         ) -> Result<OpencodeHttpProbe> {
+            tokio::time::sleep(self.probe_delay).await;
             if self.fail_opencode_probe {
                 return Err(anyhow::anyhow!("opencode probe failed"));
             }
@@ -107,6 +137,7 @@ mod tests {
         }
 
         async fn probe_broker_process_active(&self) -> Result<bool> {
+            tokio::time::sleep(self.probe_delay).await;
             if self.fail_broker_process {
                 return Err(anyhow::anyhow!("broker process probe failed"));
             }
@@ -114,6 +145,7 @@ mod tests {
         }
 
         async fn probe_broker_socket_present(&self) -> Result<bool> {
+            tokio::time::sleep(self.probe_delay).await;
             if self.fail_broker_socket {
                 return Err(anyhow::anyhow!("broker socket probe failed"));
             }
@@ -121,14 +153,17 @@ mod tests {
         }
 
         async fn read_opencode_version(&self) -> Result<Option<String>> {
+            tokio::time::sleep(self.probe_delay).await;
             Ok(self.opencode_version.clone())
         }
 
         async fn read_opencode_commit(&self) -> Result<Option<String>> {
+            tokio::time::sleep(self.probe_delay).await;
             Ok(self.opencode_commit.clone())
         }
 
         async fn read_image_version(&self) -> Result<Option<String>> {
+            tokio::time::sleep(self.probe_delay).await;
             Ok(self.image_version.clone())
         }
 
@@ -154,6 +189,7 @@ mod tests {
                 journalctl_available: None,
                 root_required_for_user_management: None,
             },
+            probe_delay: Duration::ZERO,
         };
 
         let container_backend = FakeBackend {
@@ -165,12 +201,24 @@ mod tests {
             ..host_backend.clone()
         };
 
-        let host_view = collect_status_view(&host_backend, true, "127.0.0.1", 3000)
-            .await
-            .expect("host view");
-        let container_view = collect_status_view(&container_backend, true, "127.0.0.1", 3000)
-            .await
-            .expect("container view");
+        let host_view = collect_status_view(
+            &host_backend,
+            true,
+            "127.0.0.1",
+            3000,
+            DEFAULT_PROBE_TIMEOUT,
+        )
+        .await
+        .expect("host view");
+        let container_view = collect_status_view(
+            &container_backend,
+            true,
+            "127.0.0.1",
+            3000,
+            DEFAULT_PROBE_TIMEOUT,
+        )
+        .await
+        .expect("container view");
 
         assert_eq!(host_view.opencode_health, container_view.opencode_health);
         assert_eq!(host_view.broker_health, container_view.broker_health);
@@ -189,12 +237,72 @@ mod tests {
             opencode_commit: Some("abcdef0".to_string()),
             image_version: Some("v2".to_string()),
             capabilities: status_model::RuntimeCapabilities::default(),
+            probe_delay: Duration::ZERO,
+        };
+
+        let view = collect_status_view(&backend, true, "127.0.0.1", 3000, DEFAULT_PROBE_TIMEOUT)
+            .await
+            .expect("view");
+        assert_eq!(view.broker_health, BrokerHealthStatus::CheckFailed);
+    }
+
+    // Paused time auto-advances through the sleeps, so load can't skew elapsed
+    #[tokio::test(start_paused = true)]
+    async fn status_probes_run_concurrently() {
+        let delay = Duration::from_millis(100);
+        let backend = FakeBackend {
+            opencode_probe: OpencodeHttpProbe::Healthy,
+            broker_process: true,
+            broker_socket: true,
+            fail_opencode_probe: false,
+            fail_broker_process: false,
+            fail_broker_socket: false,
+            opencode_version: Some("v1".to_string()),
+            opencode_commit: None,
+            image_version: Some("v2".to_string()),
+            capabilities: status_model::RuntimeCapabilities::default(),
+            probe_delay: delay,
         };
 
-        let view = collect_status_view(&backend, true, "127.0.0.1", 3000)
+        let started = tokio::time::Instant::now();
+        let view = collect_status_view(&backend, true, "127.0.0.1", 3000, DEFAULT_PROBE_TIMEOUT)
             .await
             .expect("view");
+        let elapsed = started.elapsed();
+
+        // Six probes one after another would take at least 600ms
+        assert!(elapsed < delay * 3, "status probes took {elapsed:?}");
+        assert_eq!(view.broker_health, BrokerHealthStatus::Healthy);
+        assert_eq!(view.opencode_version, "v1");
+        assert_eq!(view.opencode_commit, "unknown");
+    }
+
+    #[tokio::test]
+    async fn hung_probes_time_out_as_failed_checks() {
+        let backend = FakeBackend {
+            opencode_probe: OpencodeHttpProbe::Healthy,
+            broker_process: true,
+            broker_socket: true,
+            fail_opencode_probe: false,
+            fail_broker_process: false,
+            fail_broker_socket: false,
+            opencode_version: Some("v1".to_string()),
+            opencode_commit: Some("abcdef0".to_string()),
+            image_version: Some("v2".to_string()),
+            capabilities: status_model::RuntimeCapabilities::default(),
+            probe_delay: Duration::from_secs(60),
+        };
+
+        let view =
+            collect_status_view(&backend, true, "127.0.0.1", 3000, Duration::from_millis(20))
+                .await
+                .expect("view");
+        assert_eq!(
+            view.opencode_health,
+            Some(map_opencode_health_status(OpencodeHttpProbe::Failed))
+        );
         assert_eq!(view.broker_health, BrokerHealthStatus::CheckFailed);
+        assert_eq!(view.opencode_version, "unknown");
     }
 
     #[test]
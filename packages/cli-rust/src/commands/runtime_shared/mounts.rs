@@ -4,9 +4,11 @@ use anyhow::{Result, anyhow};
 use console::style;
 use opencode_cloud_core::Config;
 use opencode_cloud_core::docker::{
-    ContainerBindMount, ParsedMount, check_container_path_warning, validate_mount_path,
+    ContainerBindMount, MOUNT_PROJECTS, ParsedMount, check_container_path_warning,
+    validate_mount_path,
 };
 use std::collections::HashMap;
+use std::path::Path;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DuplicateMountTargetResolution {
@@ -80,6 +82,28 @@ pub fn collect_bind_mounts(
     Ok(all_mounts)
 }
 
+/// Build the workspace mount for `occ start --project`.
+///
+/// Relative paths resolve against the current directory. The container's
+/// working directory is the workspace, so the project opens there.
+pub fn project_mount(path: &Path) -> Result<ParsedMount> {
+    let absolute = std::path::absolute(path)
+        .map_err(|e| anyhow!("Invalid project path '{}': {e}", path.display()))?;
+    let host_path = validate_mount_path(&absolute)
+        .map_err(|e| anyhow!("Project path validation failed:\n  {e}"))?;
+    Ok(ParsedMount {
+        host_path,
+        container_path: MOUNT_PROJECTS.to_string(),
+        read_only: false,
+    })
+}
+
+/// Replace any workspace mount from config or `--mount` with the project mount.
+pub fn apply_project_mount(mounts: &mut Vec<ParsedMount>, project: ParsedMount) {
+    mounts.retain(|mount| mount.container_path != project.container_path);
+    mounts.push(project);
+}
+
 pub fn normalize_mount_targets(
     mounts: Vec<ParsedMount>,
 ) -> (Vec<ParsedMount>, Vec<DuplicateMountTargetResolution>) {
@@ -322,4 +346,39 @@ mod tests {
             make_parsed_mount("/a", "/workspace", false)
         );
     }
+
+    #[test]
+    fn apply_project_mount_replaces_workspace_mount() {
+        let mut mounts = vec![
+            make_parsed_mount("/home/me/opencode", MOUNT_PROJECTS, false),
+            make_parsed_mount("/b", "/data", false),
+        ];
+
+        apply_project_mount(
+            &mut mounts,
+            make_parsed_mount("/src/app", MOUNT_PROJECTS, false),
+        );
+
+        assert_eq!(
+            mounts,
+            vec![
+                make_parsed_mount("/b", "/data", false),
+                make_parsed_mount("/src/app", MOUNT_PROJECTS, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn project_mount_resolves_relative_paths() {
+        let mount = project_mount(Path::new(".")).unwrap();
+        let cwd = std::fs::canonicalize(std::env::current_dir().unwrap()).unwrap();
+        assert_eq!(mount.host_path, cwd);
+        assert_eq!(mount.container_path, MOUNT_PROJECTS);
+        assert!(!mount.read_only);
+    }
+
+    #[test]
+    fn project_mount_rejects_missing_directory() {
+        assert!(project_mount(Path::new("/nonexistent/occ-project-path")).is_err());
+    }
 }
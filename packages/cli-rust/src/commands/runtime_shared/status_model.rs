@@ -1,9 +1,10 @@
 //! Shared runtime status model and formatting helpers.
 
 use console::style;
+use serde::Serialize;
 
 /// Runtime constraints/capabilities used to normalize behavior across runtimes.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
 pub struct RuntimeCapabilities {
     /// Whether systemd is available in this runtime.
     pub systemd_available: Option<bool>,
@@ -24,7 +25,7 @@ pub enum OpencodeHttpProbe {
 }
 
 /// Normalized opencode health state used by host/container status outputs.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
 pub enum OpencodeHealthStatus {
     Healthy,
     Starting,
@@ -33,7 +34,7 @@ pub enum OpencodeHealthStatus {
 }
 
 /// Normalized broker health state used by host/container status outputs.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
 pub enum BrokerHealthStatus {
     Healthy,
     Degraded,
@@ -42,7 +43,7 @@ pub enum BrokerHealthStatus {
 }
 
 /// Shared status snapshot collected through a runtime backend.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct StatusViewModel {
     pub opencode_health: Option<OpencodeHealthStatus>,
     pub broker_health: BrokerHealthStatus,
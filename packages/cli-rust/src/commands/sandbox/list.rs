@@ -0,0 +1,100 @@
+//! occ sandbox list - List sandbox instances found in Docker
+
+use anyhow::Result;
+use clap::Args;
+use comfy_table::{Cell, Color, Table};
+use console::style;
+use opencode_cloud_core::docker::{DockerClient, env_instance_id};
+
+use super::{discover_instance_ids, inspect_instance};
+
+/// Arguments for sandbox list command
+#[derive(Args)]
+pub struct SandboxListArgs {
+    /// Show only instance ids (for scripting)
+    #[arg(long)]
+    pub names_only: bool,
+}
+
+pub async fn cmd_sandbox_list(
+    client: &DockerClient,
+    args: &SandboxListArgs,
+    host_name: Option<&str>,
+    quiet: bool,
+    _verbose: u8,
+) -> Result<()> {
+    let ids = discover_instance_ids(client).await?;
+
+    if ids.is_empty() {
+        if !quiet && !args.names_only {
+            println!(
+                "{}",
+                crate::format_host_message(host_name, "No sandbox instances found.")
+            );
+            println!();
+            println!(
+                "  {} {}",
+                style("Create one with:").dim(),
+                style("occ --sandbox-instance auto start").yellow()
+            );
+        }
+        return Ok(());
+    }
+
+    if args.names_only || quiet {
+        for id in &ids {
+            println!("{id}");
+        }
+        return Ok(());
+    }
+
+    let active = env_instance_id();
+    let mut table = Table::new();
+    table.set_header(vec![
+        "Instance",
+        "Container",
+        "State",
+        "Image Tag",
+        "Volumes",
+        "Active",
+    ]);
+
+    for id in &ids {
+        let resources = inspect_instance(client, id).await?;
+        let state_cell = match resources.container_state.as_deref() {
+            Some("running") => Cell::new("running").fg(Color::Green),
+            Some(state) => Cell::new(state).fg(Color::Yellow),
+            None => Cell::new("-").fg(Color::DarkGrey),
+        };
+        let image_cell = if resources.image_present {
+            Cell::new(&resources.names.image_tag)
+        } else {
+            Cell::new(format!("{} (missing)", resources.names.image_tag)).fg(Color::DarkGrey)
+        };
+        let active_cell = if active.as_deref() == Some(id.as_str()) {
+            Cell::new("*").fg(Color::Green)
+        } else {
+            Cell::new("")
+        };
+
+        table.add_row(vec![
+            Cell::new(id).fg(Color::Cyan),
+            Cell::new(&resources.names.container_name),
+            state_cell,
+            image_cell,
+            Cell::new(format!(
+                "{}/{}",
+                resources.volumes.len(),
+                resources.names.volume_names().len()
+            )),
+            active_cell,
+        ]);
+    }
+
+    if let Some(name) = host_name {
+        println!("Host: {}", style(name).cyan());
+    }
+    println!("{table}");
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+//! Sandbox instance profile subcommand implementations
+//!
+//! Provides `occ sandbox` subcommands for inspecting and removing the
+//! worktree-isolated resources created with `--sandbox-instance`, and for
+//! choosing the instance commands use by default.
+
+mod list;
+mod remove;
+mod show;
+mod use_instance;
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use opencode_cloud_core::docker::{
+    DockerClient, DockerResourceNames, IMAGE_NAME_GHCR, INSTANCE_LABEL_KEY, container_exists,
+    container_state, image_exists, list_instance_containers, list_instance_volumes,
+    resource_names_for_instance, volume_exists,
+};
+use std::collections::{BTreeSet, HashMap};
+
+pub use list::cmd_sandbox_list;
+pub use remove::cmd_sandbox_remove;
+pub use show::cmd_sandbox_show;
+pub use use_instance::cmd_sandbox_use;
+
+/// Sandbox command arguments
+#[derive(Args)]
+pub struct SandboxArgs {
+    #[command(subcommand)]
+    pub command: SandboxCommands,
+}
+
+/// Sandbox subcommands
+#[derive(Subcommand)]
+pub enum SandboxCommands {
+    /// List sandbox instances found in Docker
+    List(list::SandboxListArgs),
+    /// Show resource names and status for an instance
+    Show(show::SandboxShowArgs),
+    /// Remove an instance's container and volumes
+    Remove(remove::SandboxRemoveArgs),
+    /// Make an instance the default for later commands (`default` for the shared sandbox)
+    Use(use_instance::SandboxUseArgs),
+}
+
+/// Handle sandbox command
+///
+/// Routes to the appropriate handler based on the subcommand.
+pub async fn cmd_sandbox(
+    args: &SandboxArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
+    // Selecting an instance only touches the config, so it works without Docker
+    if let SandboxCommands::Use(use_args) = &args.command {
+        return cmd_sandbox_use(use_args, quiet);
+    }
+
+    let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
+
+    match &args.command {
+        SandboxCommands::List(list_args) => {
+            cmd_sandbox_list(&client, list_args, host_name.as_deref(), quiet, verbose).await
+        }
+        SandboxCommands::Show(show_args) => {
+            cmd_sandbox_show(&client, show_args, host_name.as_deref(), quiet, verbose).await
+        }
+        SandboxCommands::Remove(remove_args) => {
+            cmd_sandbox_remove(&client, remove_args, host_name.as_deref(), quiet, verbose).await
+        }
+        SandboxCommands::Use(_) => unreachable!("handled before connecting to Docker"),
+    }
+}
+
+/// Docker resources belonging to one sandbox instance
+#[derive(Debug)]
+struct InstanceResources {
+    names: DockerResourceNames,
+    /// Container state, or `None` if the container does not exist
+    container_state: Option<String>,
+    /// Volumes that currently exist
+    volumes: Vec<String>,
+    image_present: bool,
+}
+
+impl InstanceResources {
+    fn has_resources(&self) -> bool {
+        self.container_state.is_some() || !self.volumes.is_empty()
+    }
+}
+
+/// Collect instance ids from resource labels
+fn instance_ids_from_labels<'a>(
+    labels: impl IntoIterator<Item = Option<&'a HashMap<String, String>>>,
+) -> BTreeSet<String> {
+    labels
+        .into_iter()
+        .flatten()
+        .filter_map(|labels| labels.get(INSTANCE_LABEL_KEY))
+        .filter(|id| !id.is_empty())
+        .cloned()
+        .collect()
+}
+
+/// Find every instance id labeled on a container or volume
+async fn discover_instance_ids(client: &DockerClient) -> Result<BTreeSet<String>> {
+    let containers = list_instance_containers(client).await?;
+    let volumes = list_instance_volumes(client).await?;
+
+    let mut ids = instance_ids_from_labels(containers.iter().map(|c| c.labels.as_ref()));
+    ids.extend(instance_ids_from_labels(
+        volumes.iter().map(|v| Some(&v.labels)),
+    ));
+    Ok(ids)
+}
+
+/// Look up which of an instance's resources exist
+async fn inspect_instance(client: &DockerClient, instance_id: &str) -> Result<InstanceResources> {
+    let names = resource_names_for_instance(Some(instance_id));
+
+    let container_state = if container_exists(client, &names.container_name).await? {
+        Some(container_state(client, &names.container_name).await?)
+    } else {
+        None
+    };
+
+    let mut volumes = Vec::new();
+    for volume in names.volume_names() {
+        if volume_exists(client, volume).await? {
+            volumes.push(volume.to_string());
+        }
+    }
+
+    let image_present = image_exists(client, IMAGE_NAME_GHCR, &names.image_tag).await?;
+
+    Ok(InstanceResources {
+        names,
+        container_state,
+        volumes,
+        image_present,
+    })
+}
+
+/// Normalize a user-supplied instance id the same way `--sandbox-instance` does
+fn normalize_instance_id(value: &str) -> String {
+    value.trim().to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instance_ids_from_labels_dedupes_and_sorts() {
+        let a = HashMap::from([(INSTANCE_LABEL_KEY.to_string(), "wt-b".to_string())]);
+        let b = HashMap::from([(INSTANCE_LABEL_KEY.to_string(), "wt-a".to_string())]);
+        let other = HashMap::from([("managed-by".to_string(), "opencode-cloud".to_string())]);
+        let ids = instance_ids_from_labels([Some(&a), None, Some(&b), Some(&a), Some(&other)]);
+        assert_eq!(ids.into_iter().collect::<Vec<_>>(), vec!["wt-a", "wt-b"]);
+    }
+
+    #[test]
+    fn normalize_instance_id_matches_flag_handling() {
+        assert_eq!(normalize_instance_id("  WT-Foo "), "wt-foo");
+    }
+}
@@ -0,0 +1,112 @@
+//! occ sandbox remove - Remove an instance's container and volumes
+
+use anyhow::{Result, bail};
+use clap::Args;
+use console::style;
+use opencode_cloud_core::docker::{
+    DockerClient, container_is_running, remove_container, remove_volume, stop_container,
+};
+use std::io::IsTerminal;
+
+use super::{inspect_instance, normalize_instance_id};
+use crate::commands::service::resolve_stop_timeout_secs;
+use crate::confirm::{confirm, skip_confirmation};
+use crate::output::CommandSpinner;
+
+/// Arguments for sandbox remove command
+#[derive(Args)]
+pub struct SandboxRemoveArgs {
+    /// Instance id (as shown by `occ sandbox list`)
+    pub name: String,
+
+    /// Skip confirmation prompt
+    #[arg(short, long)]
+    pub force: bool,
+}
+
+pub async fn cmd_sandbox_remove(
+    client: &DockerClient,
+    args: &SandboxRemoveArgs,
+    host_name: Option<&str>,
+    quiet: bool,
+    _verbose: u8,
+) -> Result<()> {
+    let instance_id = normalize_instance_id(&args.name);
+    let resources = inspect_instance(client, &instance_id).await?;
+
+    if !resources.has_resources() {
+        bail!("Sandbox instance '{instance_id}' has no container or volumes.");
+    }
+
+    if !skip_confirmation(args.force) {
+        let non_interactive = quiet || !std::io::stdin().is_terminal();
+        if non_interactive {
+            bail!(
+                "Refusing to remove sandbox instance data without confirmation. Re-run with --force."
+            );
+        }
+
+        println!(
+            "This removes container {} and {} volume(s). Data in those volumes will be lost.",
+            style(&resources.names.container_name).cyan(),
+            resources.volumes.len()
+        );
+        let confirmed = confirm(
+            format!("Remove sandbox instance '{}'?", style(&instance_id).cyan()),
+            false,
+            non_interactive,
+        )?;
+
+        if !confirmed {
+            println!("Cancelled.");
+            return Ok(());
+        }
+    }
+
+    let msg = crate::format_host_message(
+        host_name,
+        &format!("Removing sandbox instance {instance_id}..."),
+    );
+    let spinner = CommandSpinner::new_maybe(&msg, quiet);
+
+    let container_name = resources.names.container_name.as_str();
+    if resources.container_state.is_some() {
+        if container_is_running(client, container_name).await? {
+            spinner.update("Stopping container...");
+            stop_container(
+                client,
+                container_name,
+                Some(resolve_stop_timeout_secs(None)),
+            )
+            .await?;
+        }
+        spinner.update("Removing container...");
+        remove_container(client, container_name, false).await?;
+    }
+
+    for volume in &resources.volumes {
+        spinner.update(&format!("Removing volume {volume}..."));
+        if let Err(e) = remove_volume(client, volume).await {
+            spinner.fail(&crate::format_host_message(
+                host_name,
+                "Failed to remove volumes",
+            ));
+            return Err(e.into());
+        }
+    }
+
+    spinner.success(&crate::format_host_message(
+        host_name,
+        &format!("Sandbox instance '{instance_id}' removed"),
+    ));
+
+    if !quiet && resources.image_present {
+        println!(
+            "  {} Image tag {} was kept.",
+            style("Note:").dim(),
+            style(&resources.names.image_tag).cyan()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! occ sandbox show - Show resource names and status for an instance
+
+use anyhow::Result;
+use clap::Args;
+use console::style;
+use opencode_cloud_core::docker::{DockerClient, env_instance_id};
+
+use super::{inspect_instance, normalize_instance_id};
+
+/// Arguments for sandbox show command
+#[derive(Args)]
+pub struct SandboxShowArgs {
+    /// Instance id (as shown by `occ sandbox list`)
+    pub name: String,
+}
+
+pub async fn cmd_sandbox_show(
+    client: &DockerClient,
+    args: &SandboxShowArgs,
+    host_name: Option<&str>,
+    _quiet: bool,
+    _verbose: u8,
+) -> Result<()> {
+    let instance_id = normalize_instance_id(&args.name);
+    let resources = inspect_instance(client, &instance_id).await?;
+    let names = &resources.names;
+
+    println!(
+        "{}",
+        crate::format_host_message(host_name, &style(&instance_id).cyan().bold().to_string())
+    );
+    if env_instance_id().as_deref() == Some(instance_id.as_str()) {
+        println!("  {} (active)", style("*").green());
+    }
+    if !resources.has_resources() {
+        println!(
+            "  {}",
+            style("No container or volumes exist for this instance.").dim()
+        );
+    }
+    println!();
+
+    let container_status = resources
+        .container_state
+        .clone()
+        .unwrap_or_else(|| "not created".to_string());
+    println!(
+        "  {:<15} {} ({})",
+        style("Container:").dim(),
+        names.container_name,
+        container_status
+    );
+    println!(
+        "  {:<15} {} ({})",
+        style("Image tag:").dim(),
+        names.image_tag,
+        if resources.image_present {
+            "present"
+        } else {
+            "missing"
+        }
+    );
+    println!("  {:<15} {}", style("Hostname:").dim(), names.hostname);
+
+    println!("  {}", style("Volumes:").dim());
+    for volume in names.volume_names() {
+        let marker = if resources.volumes.iter().any(|v| v == volume) {
+            style("exists").green()
+        } else {
+            style("missing").dim()
+        };
+        println!("    - {volume} ({marker})");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! occ sandbox use - Persist the sandbox instance later commands operate on
+
+use anyhow::Result;
+use clap::Args;
+use console::style;
+use opencode_cloud_core::{load_config_or_default, save_config};
+
+use crate::sandbox_profile::{SHARED_INSTANCE_NAME, persisted_selection};
+
+/// Arguments for sandbox use command
+#[derive(Args)]
+pub struct SandboxUseArgs {
+    /// Instance id, `auto` for the current worktree, or `default` for the shared sandbox
+    pub name: String,
+}
+
+/// Save the selection as the config's `sandbox_instance`
+///
+/// `--sandbox-instance` and `OPENCODE_SANDBOX_INSTANCE` still take precedence.
+pub fn cmd_sandbox_use(args: &SandboxUseArgs, quiet: bool) -> Result<()> {
+    let selection = persisted_selection(&args.name)?;
+
+    let mut config = load_config_or_default()?;
+    config.sandbox_instance = selection.clone();
+    save_config(&config)?;
+
+    if quiet {
+        return Ok(());
+    }
+
+    match selection {
+        Some(instance) => {
+            println!(
+                "{} Commands now use sandbox instance {}",
+                style("Success:").green().bold(),
+                style(&instance).cyan()
+            );
+            println!(
+                "Run {} to return to the shared sandbox.",
+                style(format!("occ sandbox use {SHARED_INSTANCE_NAME}")).green()
+            );
+        }
+        None => println!(
+            "{} Commands now use the shared sandbox",
+            style("Success:").green().bold()
+        ),
+    }
+    Ok(())
+}
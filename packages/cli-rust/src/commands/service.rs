@@ -5,10 +5,22 @@
 use crate::output::{CommandSpinner, show_docker_error};
 use anyhow::Result;
 use console::style;
-use opencode_cloud_core::docker::{DockerClient, stop_service};
+use opencode_cloud_core::docker::{DEFAULT_STOP_TIMEOUT_SECS, DockerClient, stop_service};
+use opencode_cloud_core::load_config_or_default;
 use std::io::IsTerminal;
 use std::time::Instant;
 
+/// Resolve the graceful stop timeout
+///
+/// Precedence: explicit flag > `stop_timeout_seconds` in config > built-in default.
+pub fn resolve_stop_timeout_secs(flag: Option<i64>) -> i64 {
+    flag.unwrap_or_else(|| {
+        load_config_or_default()
+            .map(|config| config.stop_timeout_secs())
+            .unwrap_or(DEFAULT_STOP_TIMEOUT_SECS)
+    })
+}
+
 pub struct StopSpinnerMessages<'a> {
     pub action_message: &'a str,
     pub update_label: &'a str,
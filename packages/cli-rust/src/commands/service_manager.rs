@@ -0,0 +1,158 @@
+//! Service manager subcommands
+//!
+//! `occ service status` reports what systemd/launchd thinks of the registered
+//! service, which can differ from the container state shown by `occ status`.
+
+use super::install::platform_backend_name;
+use anyhow::{Result, anyhow};
+use clap::{Args, Subcommand};
+use console::{StyledObject, style};
+use opencode_cloud_core::config::load_config_or_default;
+use opencode_cloud_core::platform::{
+    ServiceStatus, get_service_manager, is_service_registration_supported,
+};
+
+/// Service manager command arguments
+#[derive(Args)]
+pub struct ServiceArgs {
+    #[command(subcommand)]
+    pub command: ServiceCommands,
+}
+
+/// Service manager subcommands
+#[derive(Subcommand)]
+pub enum ServiceCommands {
+    /// Show whether the OS service is active, enabled, or failed
+    Status,
+}
+
+/// Handle service command
+pub fn cmd_service(args: &ServiceArgs, quiet: bool) -> Result<()> {
+    match args.command {
+        ServiceCommands::Status => cmd_service_status(quiet),
+    }
+}
+
+fn cmd_service_status(quiet: bool) -> Result<()> {
+    if !is_service_registration_supported() {
+        return Err(anyhow!(
+            "Service registration not supported on this platform.\n\
+             Supported platforms: Linux (systemd), macOS (launchd)"
+        ));
+    }
+
+    let config = load_config_or_default()?;
+    let manager = get_service_manager(&config.boot_mode)?;
+
+    if !manager.is_installed()? {
+        if quiet {
+            println!("not-installed");
+        } else {
+            println!("{}", style("Service not installed.").yellow());
+            println!("Install it with: {}", style("occ install").cyan());
+        }
+        return Ok(());
+    }
+
+    let status = manager.status()?;
+    if quiet {
+        println!("{}", status.state);
+        return Ok(());
+    }
+
+    println!(
+        "{:<12} {} ({})",
+        "Backend:",
+        platform_backend_name(),
+        config.boot_mode
+    );
+    println!(
+        "{:<12} {}",
+        "Unit file:",
+        style(manager.service_file_path().display()).dim()
+    );
+    println!("{:<12} {}", "State:", service_state_style(&status));
+    println!("{:<12} {}", "Enabled:", yes_no(status.enabled));
+    if let Some(pid) = status.pid {
+        println!("{:<12} {pid}", "PID:");
+    }
+    if let Some(code) = status.last_exit_status {
+        let code_style = if code == 0 {
+            style(code.to_string()).green()
+        } else {
+            style(code.to_string()).red()
+        };
+        println!("{:<12} {code_style}", "Last exit:");
+    }
+
+    if let Some(hint) = status_hint(&status) {
+        println!();
+        println!("{hint}");
+    }
+    Ok(())
+}
+
+fn service_state_style(status: &ServiceStatus) -> StyledObject<&str> {
+    let state = style(status.state.as_str());
+    if status.failed {
+        state.red().bold()
+    } else if status.active {
+        state.green().bold()
+    } else {
+        state.yellow()
+    }
+}
+
+fn yes_no(value: bool) -> StyledObject<&'static str> {
+    if value {
+        style("yes").green()
+    } else {
+        style("no").yellow()
+    }
+}
+
+/// Next step for an installed service that is not healthy
+fn status_hint(status: &ServiceStatus) -> Option<String> {
+    if status.failed {
+        return Some(format!(
+            "The service failed. Check its logs with {}, then reinstall with {}.",
+            style("occ logs").cyan(),
+            style("occ install --force").cyan()
+        ));
+    }
+    if !status.enabled {
+        return Some(format!(
+            "The service will not start on boot/login. Re-enable it with {}.",
+            style("occ install --force").cyan()
+        ));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_hint_covers_failed_and_disabled_services() {
+        let healthy = ServiceStatus {
+            active: true,
+            enabled: true,
+            ..Default::default()
+        };
+        assert!(status_hint(&healthy).is_none());
+
+        let disabled = ServiceStatus {
+            active: true,
+            ..Default::default()
+        };
+        assert!(status_hint(&disabled).unwrap().contains("boot/login"));
+
+        let failed = ServiceStatus {
+            enabled: true,
+            failed: true,
+            ..Default::default()
+        };
+        assert!(status_hint(&failed).unwrap().contains("failed"));
+    }
+}
@@ -1,6 +1,6 @@
 //! Setup command implementation
 //!
-//! Runs the interactive setup wizard.
+//! Runs the interactive setup wizard, on first launch or with `--reconfigure`.
 
 use anyhow::Result;
 use clap::Args;
@@ -11,9 +11,10 @@ use opencode_cloud_core::{Config, load_config_or_default, save_config};
 
 use crate::commands::iotp::{IOTP_FALLBACK_COMMAND, IotpState, fetch_iotp_snapshot};
 use crate::commands::{cmd_start, cmd_stop};
+use crate::confirm::{confirm, skip_confirmation};
 use crate::constants::COCKPIT_EXPOSED;
 use crate::output::format_service_url;
-use crate::wizard::run_wizard;
+use crate::wizard::{WizardState, run_wizard};
 
 /// Arguments for the setup command
 #[derive(Args)]
@@ -33,6 +34,10 @@ pub struct SetupArgs {
     /// Force local Docker (ignores default_host)
     #[arg(long, conflicts_with = "remote_host")]
     pub local: bool,
+
+    /// Rerun the wizard on the existing configuration (with --yes, reapply the wizard defaults)
+    #[arg(long)]
+    pub reconfigure: bool,
 }
 
 /// Run the setup command
@@ -45,6 +50,10 @@ pub async fn cmd_setup(args: &SetupArgs, quiet: bool) -> Result<()> {
         return run_bootstrap_setup(existing_config, target_host.as_deref(), quiet).await;
     }
 
+    if args.reconfigure {
+        return run_reconfigure_setup(args, target_host.as_deref(), quiet).await;
+    }
+
     // Handle --yes flag for non-interactive mode
     if args.yes {
         let config_exists =
@@ -119,13 +128,14 @@ pub async fn cmd_setup(args: &SetupArgs, quiet: bool) -> Result<()> {
 
     println!();
 
-    // Stop first if restarting (use longer timeout for graceful shutdown)
+    // Stop first if restarting (honors stop_timeout_seconds)
     if action == Action::Restart {
         let stop_args = crate::commands::StopArgs {
-            timeout: 60,
+            timeout: None,
             remove: false,
+            all_instances: false,
         };
-        cmd_stop(&stop_args, target_host.as_deref(), quiet).await?;
+        cmd_stop(&stop_args, target_host.as_deref(), quiet, 0).await?;
         println!();
     }
 
@@ -180,9 +190,83 @@ async fn run_bootstrap_setup(
     .await
 }
 
+/// Rerun the wizard on the saved config, or reapply its defaults with `--yes`
+async fn run_reconfigure_setup(
+    args: &SetupArgs,
+    target_host: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let config_exists =
+        opencode_cloud_core::config::paths::get_config_path().is_some_and(|path| path.exists());
+    if !config_exists {
+        anyhow::bail!(
+            "No configuration to reconfigure yet.\n\n\
+            Run the first-time wizard instead:\n  \
+            occ setup"
+        );
+    }
+    let existing_config = load_config_or_default()?;
+
+    let non_interactive = skip_confirmation(args.yes);
+    let new_config = if non_interactive {
+        build_reconfigured_defaults(&existing_config)
+    } else {
+        run_wizard(Some(&existing_config)).await?
+    };
+    save_config(&new_config)?;
+
+    if !quiet {
+        println!();
+        println!(
+            "{} Configuration updated successfully!",
+            style("Success:").green().bold()
+        );
+        println!();
+    }
+
+    if non_interactive || quiet {
+        return start_or_restart_after_setup(
+            Some(&existing_config),
+            &new_config,
+            target_host,
+            quiet,
+            true,
+        )
+        .await;
+    }
+
+    let (client, _) = crate::resolve_docker_client(target_host).await?;
+    let is_running = container_is_running(&client, CONTAINER_NAME)
+        .await
+        .unwrap_or(false);
+    let prompt = if is_running {
+        "Apply the new configuration to the running service now?"
+    } else {
+        "Start opencode-cloud now?"
+    };
+    if !confirm(prompt, true, false)? {
+        return Ok(());
+    }
+    println!();
+    start_or_restart_after_setup(
+        Some(&existing_config),
+        &new_config,
+        target_host,
+        quiet,
+        false,
+    )
+    .await
+}
+
+/// Existing config with the wizard's default answers applied
+fn build_reconfigured_defaults(existing_config: &Config) -> Config {
+    let mut config = existing_config.clone();
+    WizardState::defaults().apply_to_config(&mut config);
+    config
+}
+
 fn build_bootstrap_config(existing_config: Option<Config>) -> Config {
     let mut config = existing_config.unwrap_or_default();
-    config.bind = "0.0.0.0".to_string();
     config.bind_address = "0.0.0.0".to_string();
     config.cockpit_enabled = false;
     config.allow_unauthenticated_network = true;
@@ -212,10 +296,11 @@ async fn start_or_restart_after_setup(
 
     if is_running && config_changed {
         let stop_args = crate::commands::StopArgs {
-            timeout: 60,
+            timeout: None,
             remove: false,
+            all_instances: false,
         };
-        cmd_stop(&stop_args, target_host, quiet || non_interactive).await?;
+        cmd_stop(&stop_args, target_host, quiet || non_interactive, 0).await?;
     }
 
     let start_args = crate::commands::StartArgs {
@@ -237,7 +322,7 @@ enum Action {
 
 /// Check if config changes require a container restart
 fn requires_restart(old: &Config, new: &Config) -> bool {
-    if old.opencode_web_port != new.opencode_web_port || old.bind != new.bind {
+    if old.opencode_web_port != new.opencode_web_port || old.bind_address != new.bind_address {
         return true;
     }
     if COCKPIT_EXPOSED
@@ -254,7 +339,7 @@ fn show_running_status(config: &Config, host: Option<&str>) {
     println!("{}", style(msg).dim());
     println!();
 
-    let url = format_service_url(None, &config.bind, config.opencode_web_port);
+    let url = format_service_url(None, &config.bind_address, config.opencode_web_port);
 
     println!("URL: {}", style(url).cyan());
 }
@@ -344,4 +429,18 @@ mod tests {
         let message = build_iotp_fallback_message(false, Some("bootstrap helper unavailable"));
         assert!(message.contains("Reason: bootstrap helper unavailable"));
     }
+
+    #[test]
+    fn test_reconfigured_defaults_keep_unrelated_settings() {
+        let existing = Config {
+            opencode_web_port: 4000,
+            bind_address: "0.0.0.0".to_string(),
+            users: vec!["admin".to_string()],
+            ..Config::default()
+        };
+        let config = build_reconfigured_defaults(&existing);
+        assert_eq!(config.opencode_web_port, 3000);
+        assert_eq!(config.bind_address, "localhost");
+        assert_eq!(config.users, existing.users);
+    }
 }
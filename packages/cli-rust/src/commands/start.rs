@@ -5,15 +5,23 @@
 use crate::cli_platform::cli_platform_label;
 use crate::commands::iotp::{IOTP_FALLBACK_COMMAND, IotpSnapshot, IotpState, fetch_iotp_snapshot};
 use crate::commands::runtime_shared::backend::HostBackend;
-use crate::commands::runtime_shared::mounts::{collect_bind_mounts, mounts_equal};
+use crate::commands::runtime_shared::drift::{REBUILD_CACHED_COMMAND, detect_runtime_asset_drift};
+use crate::commands::runtime_shared::env::container_env_vars;
+use crate::commands::runtime_shared::mounts::{
+    apply_project_mount, collect_bind_mounts, mounts_equal, project_mount,
+};
 use crate::commands::runtime_shared::{
     broker_is_ready as broker_ready_from_status, probe_broker_health,
 };
-use crate::commands::service::{StopSpinnerMessages, stop_service_with_spinner};
+use crate::commands::service::{
+    StopSpinnerMessages, resolve_stop_timeout_secs, stop_service_with_spinner,
+};
+use crate::confirm::{confirm, skip_confirmation};
 use crate::constants::COCKPIT_EXPOSED;
+use crate::error::CliError;
 use crate::output::{
-    CommandSpinner, format_cockpit_url, format_docker_error, format_service_url,
-    localhost_display_addr, normalize_bind_addr, resolve_remote_addr, show_docker_error,
+    CommandSpinner, format_cockpit_url, format_docker_error_anyhow, format_service_url,
+    normalize_bind_addr, resolve_remote_addr, show_docker_error,
 };
 use anyhow::{Result, anyhow};
 use clap::Args;
@@ -23,18 +31,22 @@ use opencode_cloud_core::bollard::container::LogOutput;
 use opencode_cloud_core::bollard::query_parameters::LogsOptions;
 use opencode_cloud_core::config::save_config;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DEFAULT_STOP_TIMEOUT_SECS, DOCKERFILE, DockerClient, DockerError,
-    IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT, ImageState, ParsedMount, ProgressReporter,
-    active_resource_names, build_image, container_exists, container_is_running,
+    ArchMismatch, BuildLogOptions, CONTAINER_NAME, DOCKER_DEFAULT_MAX_CONCURRENT_DOWNLOADS,
+    DOCKERFILE, DockerClient, DockerError, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT, ImageState,
+    ParsedMount, ProgressReporter, PullOptions, PullRegistry, active_resource_names, build_image,
+    check_image_architecture, container_exists, container_is_running, container_state,
     docker_supports_systemd, get_cli_version, get_container_bind_mounts, get_container_ports,
-    get_image_version, image_exists, pull_image, save_state, setup_and_start, versions_compatible,
+    get_image_version, image_exists, pull_image, save_state, setup_and_start, validate_platform,
+    versions_compatible,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::io::IsTerminal;
 use std::net::{TcpListener, TcpStream};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::{Duration, Instant};
 
+use super::logs::follow_container_logs;
 use super::update_signal::run_update_command_listener;
 
 /// Arguments for the start command
@@ -48,6 +60,10 @@ pub struct StartArgs {
     #[arg(long)]
     pub open: bool,
 
+    /// Follow container logs after starting; Ctrl+C detaches and offers to stop
+    #[arg(long, conflicts_with_all = ["open", "no_daemon"])]
+    pub attach: bool,
+
     /// Run in foreground (for service managers like systemd/launchd)
     /// Keeps the process running and listens for update commands
     #[arg(long)]
@@ -65,10 +81,27 @@ pub struct StartArgs {
     #[arg(long)]
     pub full_rebuild_sandbox_image: bool,
 
+    /// Skip the Docker layer cache if this start builds the image (does not force a build)
+    #[arg(long, conflicts_with = "pull_sandbox_image")]
+    pub no_cache: bool,
+
     /// Build sandbox image from local packages/opencode checkout (dev mode)
     #[arg(long)]
     pub local_opencode_submodule: bool,
 
+    /// Write the full build output here if an image build fails
+    /// (default: a timestamped file in the data dir's build-logs/)
+    #[arg(long, value_name = "PATH", conflicts_with = "pull_sandbox_image")]
+    pub save_build_log: Option<PathBuf>,
+
+    /// Pull only from this registry (ghcr or dockerhub) instead of GHCR with Docker Hub fallback
+    #[arg(long, value_name = "REGISTRY")]
+    pub registry: Option<PullRegistry>,
+
+    /// Pull and run the image for this platform, e.g. linux/arm64 (overrides the platform setting)
+    #[arg(long, value_name = "PLATFORM", value_parser = parse_platform)]
+    pub platform: Option<String>,
+
     /// Skip version compatibility check between CLI and Docker image
     #[arg(long)]
     pub ignore_version: bool,
@@ -86,11 +119,65 @@ pub struct StartArgs {
     #[arg(long)]
     pub no_mounts: bool,
 
-    /// Automatically accept the running-container image-change prompt
+    /// Mount this directory as the workspace and working directory (default: .)
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = ".")]
+    pub project: Option<PathBuf>,
+
+    /// Remove any existing container and create a fresh one (volumes are kept)
+    #[arg(long)]
+    pub recreate: bool,
+
+    /// Automatically accept the image-change and stale-container prompts
     #[arg(long)]
     pub yes: bool,
 }
 
+fn parse_platform(value: &str) -> Result<String, String> {
+    validate_platform(value)?;
+    Ok(value.to_string())
+}
+
+/// Warn when the sandbox image was built for another architecture than the Docker host
+///
+/// Skipped when a platform was chosen explicitly, since emulation is then intended.
+async fn warn_on_arch_mismatch(client: &DockerClient, platform: Option<&str>, quiet: bool) {
+    if quiet || platform.is_some() {
+        return;
+    }
+    let image = format!("{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}");
+    let Ok(Some(mismatch)) = check_image_architecture(client, &image).await else {
+        return;
+    };
+    for line in arch_mismatch_lines(&mismatch) {
+        eprintln!("{line}");
+    }
+    eprintln!();
+}
+
+fn arch_mismatch_lines(mismatch: &ArchMismatch) -> Vec<String> {
+    let suggested = mismatch.suggested_platform();
+    vec![
+        format!(
+            "{} The sandbox image is built for {} but Docker runs on {}.",
+            style("Warning:").yellow().bold(),
+            mismatch.image_arch,
+            mismatch.daemon_arch
+        ),
+        "It will run under emulation (slow) or fail to start.".to_string(),
+        format!(
+            "Pull the matching image: {}",
+            style(format!(
+                "occ start --pull-sandbox-image --platform {suggested}"
+            ))
+            .green()
+        ),
+        format!(
+            "Or build it locally:     {}",
+            style("occ config set image_source build").green()
+        ),
+    ]
+}
+
 /// Check if container mounts differ from configured mounts
 ///
 /// Returns `Some(true)` to rebuild, `Some(false)` on user decline (error), `None` if no mismatch.
@@ -117,12 +204,13 @@ async fn check_mount_mismatch(
 
     display_mount_mismatch(&current_mounts, configured);
 
-    let confirm = dialoguer::Confirm::new()
-        .with_prompt("Recreate container with new mount configuration?")
-        .default(true)
-        .interact()?;
+    let confirmed = confirm(
+        "Recreate container with new mount configuration?",
+        true,
+        false,
+    )?;
 
-    if !confirm {
+    if !confirmed {
         let container_name = active_container_name();
         return Err(anyhow!(
             "Container not recreated. To apply mount changes, run:\n  \
@@ -214,7 +302,7 @@ async fn ensure_container_stopped_for_image_flag(
             host_name,
             quiet,
             true,
-            DEFAULT_STOP_TIMEOUT_SECS,
+            resolve_stop_timeout_secs(None),
             StopSpinnerMessages {
                 action_message: "Stopping container for rebuild...",
                 update_label: "Stopping container",
@@ -226,12 +314,13 @@ async fn ensure_container_stopped_for_image_flag(
         return Ok(());
     }
 
-    let confirm = dialoguer::Confirm::new()
-        .with_prompt("Container is running. Stop and apply image change?")
-        .default(false)
-        .interact()?;
+    let confirmed = confirm(
+        "Container is running. Stop and apply image change?",
+        false,
+        false,
+    )?;
 
-    if !confirm {
+    if !confirmed {
         return Err(anyhow!("Aborted. Stop container first with: occ stop"));
     }
 
@@ -240,7 +329,7 @@ async fn ensure_container_stopped_for_image_flag(
         host_name,
         quiet,
         true,
-        DEFAULT_STOP_TIMEOUT_SECS,
+        resolve_stop_timeout_secs(None),
         StopSpinnerMessages {
             action_message: "Stopping container for rebuild...",
             update_label: "Stopping container",
@@ -252,6 +341,40 @@ async fn ensure_container_stopped_for_image_flag(
     Ok(())
 }
 
+/// Whether `occ start` should offer to rebuild for runtime asset drift
+///
+/// Only local, interactive runs that are not already replacing the image get
+/// the prompt; everything else sees just the preflight warning.
+fn should_offer_drift_rebuild(
+    quiet: bool,
+    image_change_pending: bool,
+    remote: bool,
+    interactive: bool,
+) -> bool {
+    !quiet && !image_change_pending && !remote && interactive
+}
+
+/// Detect runtime asset drift and ask whether to fix it with a cached rebuild
+async fn prompt_drift_rebuild(client: &DockerClient) -> Result<bool> {
+    let report = detect_runtime_asset_drift(client).await;
+    if !report.drift_detected {
+        return Ok(false);
+    }
+
+    let confirmed = confirm(
+        "Rebuild the sandbox image now (cached) and restart the container?",
+        true,
+        false,
+    )?;
+    if !confirmed {
+        eprintln!(
+            "Continuing with the running container. Rebuild later with: {}",
+            style(REBUILD_CACHED_COMMAND).cyan()
+        );
+    }
+    Ok(confirmed)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum VersionMismatchAction {
     RebuildFromSource,
@@ -391,12 +514,9 @@ async fn check_port_mismatch(
         );
     }
 
-    let confirm = dialoguer::Confirm::new()
-        .with_prompt("Recreate container with new port(s)?")
-        .default(true)
-        .interact()?;
+    let confirmed = confirm("Recreate container with new port(s)?", true, false)?;
 
-    if !confirm {
+    if !confirmed {
         let container_name = active_container_name();
         return Err(anyhow!(
             "Container not recreated. To use port {port}, run:\n  \
@@ -457,8 +577,7 @@ async fn check_init_mismatch(
 ) -> Result<Option<bool>> {
     let container_name = active_container_name();
     let info = client
-        .inner()
-        .inspect_container(&container_name, None)
+        .inspect_container(&container_name)
         .await
         .map_err(|e| anyhow!("Failed to inspect container: {e}"))?;
 
@@ -513,12 +632,9 @@ async fn check_init_mismatch(
         style("Container must be recreated to change init mode.").dim()
     );
 
-    let confirm = dialoguer::Confirm::new()
-        .with_prompt("Recreate container with new init mode?")
-        .default(true)
-        .interact()?;
+    let confirmed = confirm("Recreate container with new init mode?", true, false)?;
 
-    if !confirm {
+    if !confirmed {
         return Err(anyhow!(
             "Container not recreated. To switch init mode, run:\n  \
              occ stop --remove\n  \
@@ -529,35 +645,130 @@ async fn check_init_mismatch(
     Ok(Some(true))
 }
 
+/// Exit codes left behind by a normal stop (clean exit, SIGINT, SIGKILL, SIGTERM)
+const CLEAN_EXIT_CODES: &[i64] = &[0, 130, 137, 143];
+
+/// Describe why an existing container looks broken, if it does
+///
+/// Containers in these states are usually restarted as-is by `occ start` and
+/// fail the same way again; recreating them is the common fix.
+fn stale_container_reason(state: &str, exit_code: Option<i64>, oom_killed: bool) -> Option<String> {
+    match state {
+        "dead" => Some("container is dead".to_string()),
+        "exited" if oom_killed => {
+            Some("container was killed for running out of memory".to_string())
+        }
+        "exited" => exit_code
+            .filter(|code| !CLEAN_EXIT_CODES.contains(code))
+            .map(|code| format!("container exited with code {code}")),
+        _ => None,
+    }
+}
+
+/// Check for a dead or crashed container and offer to recreate it
+///
+/// Returns `true` when the container should be removed before starting.
+/// Quiet and non-interactive runs only get a hint, unless `--yes` is set.
+async fn check_stale_container(
+    client: &DockerClient,
+    quiet: bool,
+    yes: bool,
+    interactive: bool,
+) -> Result<bool> {
+    let state = container_state(client, CONTAINER_NAME).await?;
+    let (exit_code, oom_killed) = if state == "exited" {
+        let info = client
+            .inspect_container(&active_container_name())
+            .await
+            .map_err(|e| anyhow!("Failed to inspect container: {e}"))?;
+        let container_state = info.state.unwrap_or_default();
+        (
+            container_state.exit_code,
+            container_state.oom_killed.unwrap_or(false),
+        )
+    } else {
+        (None, false)
+    };
+
+    let Some(reason) = stale_container_reason(&state, exit_code, oom_killed) else {
+        return Ok(false);
+    };
+
+    if skip_confirmation(yes) {
+        if !quiet {
+            eprintln!(
+                "{} Existing {reason}; recreating it.",
+                style("Note:").yellow()
+            );
+        }
+        return Ok(true);
+    }
+
+    if quiet || !interactive {
+        if !quiet {
+            eprintln!(
+                "{} Existing {reason}. If start fails, retry with: {}",
+                style("Warning:").yellow().bold(),
+                style("occ start --recreate").cyan()
+            );
+        }
+        return Ok(false);
+    }
+
+    eprintln!();
+    eprintln!(
+        "{} Existing {reason}.",
+        style("Stale container detected:").yellow().bold()
+    );
+    eprintln!(
+        "{}",
+        style("Recreating removes the container; your data volumes will be preserved.").dim()
+    );
+    confirm("Recreate the container before starting?", true, false)
+}
+
+/// Suggest `--recreate` after a start that reused an existing container failed
+fn print_recreate_hint(reused_container: bool, quiet: bool) {
+    if reused_container && !quiet {
+        eprintln!();
+        eprintln!(
+            "The existing container may be in a broken state. Retry with: {}",
+            style("occ start --recreate").cyan()
+        );
+    }
+}
+
+/// How to build the image from source if this start needs to
+struct SourceBuild<'a> {
+    /// Build without the Docker layer cache
+    no_cache: bool,
+    local_opencode_submodule: bool,
+    config_build_args: &'a BTreeMap<String, String>,
+    log_options: BuildLogOptions,
+}
+
 /// Acquire Docker image (build or pull) based on configuration
 async fn acquire_image(
     client: &DockerClient,
     use_prebuilt: bool,
-    full_rebuild: bool,
+    pull_options: &PullOptions,
+    build: &SourceBuild<'_>,
     quiet: bool,
     verbose: u8,
-    local_opencode_submodule: bool,
 ) -> Result<()> {
     if !use_prebuilt {
-        build_docker_image(
-            client,
-            full_rebuild,
-            quiet,
-            verbose,
-            local_opencode_submodule,
-        )
-        .await?;
+        build_docker_image(client, build, quiet, verbose).await?;
         save_state(&ImageState::built(get_cli_version())).ok();
         return Ok(());
     }
 
     // Try pulling prebuilt image
-    match pull_docker_image(client, verbose).await {
+    match pull_docker_image(client, pull_options, verbose).await {
         Ok(registry) => {
             save_state(&ImageState::prebuilt(get_cli_version(), &registry)).ok();
             Ok(())
         }
-        Err(e) => handle_pull_failure(client, e, quiet, verbose, local_opencode_submodule).await,
+        Err(e) => handle_pull_failure(client, e, build, quiet, verbose).await,
     }
 }
 
@@ -565,9 +776,9 @@ async fn acquire_image(
 async fn handle_pull_failure(
     client: &DockerClient,
     error: anyhow::Error,
+    build: &SourceBuild<'_>,
     quiet: bool,
     verbose: u8,
-    local_opencode_submodule: bool,
 ) -> Result<()> {
     if quiet {
         return Err(error);
@@ -580,10 +791,11 @@ async fn handle_pull_failure(
     );
     eprintln!();
 
-    let build_instead = dialoguer::Confirm::new()
-        .with_prompt("Build from source instead? (This takes 30-60 minutes)")
-        .default(true)
-        .interact()?;
+    let build_instead = confirm(
+        "Build from source instead? (This takes 30-60 minutes)",
+        true,
+        false,
+    )?;
 
     if !build_instead {
         return Err(anyhow!(
@@ -591,7 +803,7 @@ async fn handle_pull_failure(
         ));
     }
 
-    build_docker_image(client, false, quiet, verbose, local_opencode_submodule).await?;
+    build_docker_image(client, build, quiet, verbose).await?;
     save_state(&ImageState::built(get_cli_version())).ok();
     Ok(())
 }
@@ -667,15 +879,15 @@ pub async fn cmd_start(
 
     if let Err(e) = client.verify_connection().await {
         preflight_spinner.fail("Docker connection failed");
-        let msg = format_docker_error(&e);
-        return Err(anyhow!("{msg}"));
+        return Err(format_docker_error_anyhow(&e));
     }
 
     // Load config for port and bind_address
     preflight_spinner.update("Loading configuration...");
-    let config = opencode_cloud_core::config::load_config_or_default()?;
+    let config = crate::load_config_for_host(host_name.as_deref())?;
     let port = args.port.unwrap_or(config.opencode_web_port);
     let bind_addr = &config.bind_address;
+    let platform = args.platform.as_deref().or(config.platform.as_deref());
     // Validate config before starting
     match opencode_cloud_core::config::validate_config(&config) {
         Ok(warnings) => {
@@ -685,16 +897,20 @@ pub async fn cmd_start(
         }
         Err(error) => {
             opencode_cloud_core::config::display_validation_error(&error);
-            return Err(anyhow::anyhow!(
-                "Configuration invalid. Fix the error above and try again."
-            ));
+            return Err(CliError::ConfigInvalid(
+                "Configuration invalid. Fix the error above and try again.".to_string(),
+            )
+            .into());
         }
     }
 
     let systemd_enabled = docker_supports_systemd(&client).await?;
 
     // Collect and validate bind mounts
-    let bind_mounts = collect_bind_mounts(&config, &args.mounts, args.no_mounts, quiet)?;
+    let mut bind_mounts = collect_bind_mounts(&config, &args.mounts, args.no_mounts, quiet)?;
+    if let Some(project) = &args.project {
+        apply_project_mount(&mut bind_mounts, project_mount(project)?);
+    }
     let bind_mounts_option = if bind_mounts.is_empty() {
         None
     } else {
@@ -737,7 +953,7 @@ pub async fn cmd_start(
     .await?;
 
     let mut rebuild_image = args.cached_rebuild_sandbox_image || args.full_rebuild_sandbox_image;
-    let mut recreate_container = rebuild_image;
+    let mut recreate_container = rebuild_image || args.recreate;
     let mut force_pull = false;
 
     // Determine image source: flag > config default
@@ -763,9 +979,33 @@ pub async fn cmd_start(
         VersionMismatchAction::Continue => {}
     }
 
+    // Offer a cached rebuild when the running container's runtime assets have
+    // drifted from this CLI. The drift warning itself comes from the preflight
+    // in main, so quiet and non-interactive runs keep warning-only behavior.
+    let interactive = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+    if should_offer_drift_rebuild(
+        quiet,
+        has_image_flag || rebuild_image || force_pull,
+        host_name.is_some(),
+        interactive,
+    ) && prompt_drift_rebuild(&client).await?
+    {
+        rebuild_image = true;
+        recreate_container = true;
+        use_prebuilt = false;
+    }
+
     // Determine whether this is first container start
     let is_first_start = !container_exists(&client, CONTAINER_NAME).await?;
 
+    // Offer to replace a dead or crashed container instead of restarting it as-is
+    if !is_first_start
+        && !recreate_container
+        && check_stale_container(&client, quiet, args.yes, interactive).await?
+    {
+        recreate_container = true;
+    }
+
     // Check for port mismatch on existing container
     if !is_first_start
         && !recreate_container
@@ -798,7 +1038,7 @@ pub async fn cmd_start(
         handle_rebuild(&client, host_name.as_deref(), quiet, verbose).await?;
     } else if container_is_running(&client, CONTAINER_NAME).await? {
         // Already running (idempotent behavior) - only when not rebuilding
-        return show_already_running(
+        show_already_running(
             &client,
             port,
             bind_addr,
@@ -806,7 +1046,11 @@ pub async fn cmd_start(
             quiet,
             host_name.as_deref(),
         )
-        .await;
+        .await?;
+        if args.attach {
+            attach_to_logs(&client, host_name.as_deref(), quiet).await?;
+        }
+        return Ok(());
     }
 
     // Security check: warn if network exposed without authentication
@@ -819,17 +1063,23 @@ pub async fn cmd_start(
         display_network_exposure_warning(bind_addr);
     }
 
-    // Pre-check port availability
-    if !check_port_available(bind_addr, port) {
-        return Err(port_in_use_error(bind_addr, port));
+    // Pre-check port availability. A local bind test says nothing about a
+    // remote host, so there we rely on create_container's "port is already
+    // allocated" mapping instead.
+    if !client.is_remote() {
+        let cockpit_port =
+            (config.cockpit_enabled && COCKPIT_EXPOSED).then_some(config.cockpit_port);
+        preflight_port_conflicts(bind_addr, port, cockpit_port)?;
     }
 
     // First-run image source prompt (if no image and no flag specified)
     if !image_already_exists && !has_image_flag && !quiet {
         let (new_use_prebuilt, updated_config) = prompt_image_source_choice(&config)?;
-        // Save config with new image_source
+        // Save the new image_source into the global config, without this host's overrides
         if updated_config.image_source != config.image_source {
-            save_config(&updated_config)?;
+            let mut global = opencode_cloud_core::config::load_config_or_default()?;
+            global.image_source = updated_config.image_source;
+            save_config(&global)?;
         }
         // Use the choice for this run
         use_prebuilt = new_use_prebuilt;
@@ -839,23 +1089,48 @@ pub async fn cmd_start(
     let needs_image =
         rebuild_image || force_pull || args.pull_sandbox_image || !image_already_exists;
 
+    if args.no_cache && !quiet && !(needs_image && (rebuild_image || !use_prebuilt)) {
+        eprintln!(
+            "{} --no-cache has no effect: this start does not build the image. \
+             Use --full-rebuild-sandbox-image to force a rebuild.",
+            style("Note:").dim()
+        );
+    }
+
     if needs_image {
+        let build = SourceBuild {
+            no_cache: args.full_rebuild_sandbox_image || args.no_cache,
+            local_opencode_submodule: args.local_opencode_submodule,
+            config_build_args: &config.build_args,
+            log_options: BuildLogOptions {
+                save_path: args.save_build_log.clone(),
+                log_tail: config.build_log_tail,
+                error_tail: config.build_error_tail,
+            },
+        };
+        let pull_options = PullOptions {
+            registry: args.registry,
+            retries: config.pull_retries,
+            retry_delay_secs: config.pull_retry_delay,
+            platform: platform.map(str::to_string),
+        };
         acquire_image(
             &client,
             use_prebuilt && !rebuild_image,
-            args.full_rebuild_sandbox_image,
+            &pull_options,
+            &build,
             quiet,
             verbose,
-            args.local_opencode_submodule,
         )
         .await?;
     }
 
-    let env_vars = std::env::var("OPENCODE_CLOUD_ENV")
-        .ok()
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
-        .map(|value| vec![format!("OPENCODE_CLOUD_ENV={value}")]);
+    let env_vars = Some(container_env_vars(&config));
+    let reused_container = !is_first_start && !recreate_container;
+
+    if !reused_container {
+        warn_on_arch_mismatch(&client, platform, quiet).await;
+    }
 
     // Start container
     let msg = crate::format_host_message(host_name.as_deref(), "Starting container...");
@@ -869,6 +1144,8 @@ pub async fn cmd_start(
         config.cockpit_enabled && COCKPIT_EXPOSED,
         systemd_enabled,
         bind_mounts_option,
+        &config.container_labels,
+        platform,
     )
     .await
     {
@@ -880,6 +1157,7 @@ pub async fn cmd_start(
             ));
             show_docker_error(&e);
             show_logs_if_container_exists(&client).await;
+            print_recreate_hint(reused_container, quiet);
             return Err(e.into());
         }
     };
@@ -893,6 +1171,7 @@ pub async fn cmd_start(
         eprintln!();
         eprintln!("{}", style("Recent container logs:").yellow());
         show_recent_logs(&client, 20).await;
+        print_recreate_hint(reused_container, quiet);
         return Err(e);
     }
 
@@ -922,6 +1201,10 @@ pub async fn cmd_start(
     maybe_print_iotp_info(&client, host_name.as_deref(), &config).await;
     open_browser_if_requested(args.open, port, bind_addr);
 
+    if args.attach {
+        attach_to_logs(&client, host_name.as_deref(), quiet).await?;
+    }
+
     if args.no_daemon {
         run_update_command_listener(&client, &config, maybe_host, quiet, verbose).await?;
     }
@@ -929,6 +1212,47 @@ pub async fn cmd_start(
     Ok(())
 }
 
+/// Follow container logs until Ctrl+C, then offer to stop the service
+///
+/// Quiet or piped runs leave the service running unless
+/// `OPENCODE_CLOUD_ASSUME_YES` is set.
+async fn attach_to_logs(client: &DockerClient, host_name: Option<&str>, quiet: bool) -> Result<()> {
+    if !quiet {
+        eprintln!();
+        eprintln!("{}", style("Following logs (Ctrl+C to detach)...").dim());
+        eprintln!();
+    }
+
+    let line_prefix = host_name.map(|name| format!("[{}] ", style(name).cyan()));
+    {
+        let _interrupt = crate::interrupt::HandlesInterrupt::new();
+        tokio::select! {
+            result = follow_container_logs(client, line_prefix.as_deref(), quiet) => return result,
+            _ = tokio::signal::ctrl_c() => {}
+        }
+    }
+
+    eprintln!();
+    let non_interactive = quiet || !std::io::stdin().is_terminal();
+    if confirm("Stop the service?", false, non_interactive)? {
+        stop_service_with_spinner(
+            client,
+            host_name,
+            quiet,
+            false,
+            resolve_stop_timeout_secs(None),
+            StopSpinnerMessages {
+                action_message: "Stopping service...",
+                update_label: "Stopping service",
+                success_base_message: "Service stopped",
+                failure_message: "Failed to stop",
+            },
+        )
+        .await?;
+    }
+    Ok(())
+}
+
 /// Handle rebuild flags: remove existing container so a new one is created from the new image
 async fn handle_rebuild(
     client: &DockerClient,
@@ -960,7 +1284,7 @@ async fn handle_rebuild(
         host_name,
         quiet,
         true,
-        DEFAULT_STOP_TIMEOUT_SECS,
+        resolve_stop_timeout_secs(None),
         StopSpinnerMessages {
             action_message: "Stopping container for rebuild...",
             update_label: "Stopping container",
@@ -989,11 +1313,7 @@ async fn show_already_running(
     println!("{}", style(msg).dim());
 
     let container_name = active_container_name();
-    let container_id = match client
-        .inner()
-        .inspect_container(&container_name, None)
-        .await
-    {
+    let container_id = match client.inspect_container(&container_name).await {
         Ok(info) => info.id.unwrap_or_else(|| "unknown".to_string()),
         Err(error) => {
             eprintln!(
@@ -1008,26 +1328,55 @@ async fn show_already_running(
     Ok(())
 }
 
+/// Fail before image or container work if a host port the container needs is taken
+fn preflight_port_conflicts(bind_addr: &str, port: u16, cockpit_port: Option<u16>) -> Result<()> {
+    if !check_port_available(bind_addr, port) {
+        return Err(port_in_use_error(bind_addr, port));
+    }
+    if let Some(cockpit_port) = cockpit_port
+        && !check_port_available(bind_addr, cockpit_port)
+    {
+        return Err(anyhow!(port_conflict_message(
+            "Cockpit port",
+            bind_addr,
+            cockpit_port,
+            "occ config set cockpit_port <port>",
+        )));
+    }
+    Ok(())
+}
+
 /// Create error message for port already in use
 fn port_in_use_error(bind_addr: &str, port: u16) -> anyhow::Error {
-    let mut msg = format!("Port {port} is already in use");
-    if let Some(p) = find_next_available_port(bind_addr, port) {
-        msg.push_str(&format!(". Try: occ start --port {p}"));
-    }
-    anyhow!(msg)
+    let alternative = match find_next_available_port(bind_addr, port) {
+        Some(p) => format!("occ start --port {p}"),
+        None => "occ start --port <port>".to_string(),
+    };
+    anyhow!(port_conflict_message("Port", bind_addr, port, &alternative))
+}
+
+fn port_conflict_message(label: &str, bind_addr: &str, port: u16, alternative: &str) -> String {
+    format!(
+        "{label} {port} is already in use on {bind_addr}.\n\n\
+         Options:\n  \
+         1. Stop the process using port {port}\n  \
+         2. Use a different port: {alternative}"
+    )
 }
 
 /// Build the Docker image with progress reporting
 ///
-/// If `no_cache` is true, builds from scratch ignoring Docker layer cache.
-/// Otherwise uses cached layers for faster builds.
+/// If `build.no_cache` is true, builds from scratch ignoring Docker layer cache.
+/// Otherwise uses cached layers for faster builds. `build_args` from config
+/// override the args derived here.
 async fn build_docker_image(
     client: &DockerClient,
-    no_cache: bool,
+    build: &SourceBuild<'_>,
     quiet: bool,
     verbose: u8,
-    local_opencode_submodule: bool,
 ) -> Result<()> {
+    let no_cache = build.no_cache;
+    let local_opencode_submodule = build.local_opencode_submodule;
     if verbose > 0 {
         let action = if no_cache {
             "Full rebuilding Docker image"
@@ -1047,7 +1396,9 @@ async fn build_docker_image(
         );
     }
 
-    let build_args = build_opencode_build_args(local_opencode_submodule)?;
+    let mut build_args = build_opencode_build_args(local_opencode_submodule)?;
+    build_args.extend(build.config_build_args.clone());
+    print_build_args(&build_args, verbose);
     if local_opencode_submodule && !quiet {
         eprintln!(
             "{}",
@@ -1082,11 +1433,23 @@ async fn build_docker_image(
         &mut progress,
         no_cache,
         Some(build_args),
+        &build.log_options,
     )
     .await?;
     Ok(())
 }
 
+/// Echo the effective build args in verbose mode
+pub(super) fn print_build_args(build_args: &HashMap<String, String>, verbose: u8) {
+    if verbose == 0 {
+        return;
+    }
+    let sorted: BTreeMap<_, _> = build_args.iter().collect();
+    for (key, value) in sorted {
+        eprintln!("{} Build arg {key}={value}", style("[info]").cyan());
+    }
+}
+
 fn build_opencode_build_args(local_opencode_submodule: bool) -> Result<HashMap<String, String>> {
     let mut build_args = HashMap::new();
     build_args.insert(
@@ -1191,7 +1554,11 @@ fn resolve_local_opencode_ref() -> Result<String> {
 
 /// Pull the Docker image with progress reporting
 /// Returns the registry name on success (for provenance tracking)
-async fn pull_docker_image(client: &DockerClient, verbose: u8) -> Result<String> {
+async fn pull_docker_image(
+    client: &DockerClient,
+    pull_options: &PullOptions,
+    verbose: u8,
+) -> Result<String> {
     if verbose > 0 {
         eprintln!(
             "{} Pulling prebuilt Docker image from registry...",
@@ -1200,7 +1567,9 @@ async fn pull_docker_image(client: &DockerClient, verbose: u8) -> Result<String>
     }
 
     let mut progress = ProgressReporter::with_context("Pulling prebuilt image");
-    let full_image = pull_image(client, Some(IMAGE_TAG_DEFAULT), &mut progress).await?;
+    let full_image =
+        pull_image(client, Some(IMAGE_TAG_DEFAULT), &mut progress, pull_options).await?;
+    print_pull_concurrency_hint(&progress, verbose);
 
     // Extract registry from full image name
     let registry = if full_image.starts_with("ghcr.io") {
@@ -1214,6 +1583,26 @@ async fn pull_docker_image(client: &DockerClient, verbose: u8) -> Result<String>
     Ok(registry.to_string())
 }
 
+/// Suggest raising the daemon's parallel download limit after a many-layer pull
+///
+/// The limit is a dockerd setting, so it can only be pointed out, not changed here.
+pub(super) fn print_pull_concurrency_hint(progress: &ProgressReporter, verbose: u8) {
+    if verbose == 0 || progress.layer_count() <= DOCKER_DEFAULT_MAX_CONCURRENT_DOWNLOADS {
+        return;
+    }
+    eprintln!(
+        "{} Pulled {} layers; Docker downloads {} at a time by default.",
+        style("[info]").cyan(),
+        progress.layer_count(),
+        DOCKER_DEFAULT_MAX_CONCURRENT_DOWNLOADS
+    );
+    eprintln!(
+        "       For faster pulls on a fast connection, set {} in the Docker daemon config",
+        style("\"max-concurrent-downloads\": 6").cyan()
+    );
+    eprintln!("       (e.g. /etc/docker/daemon.json) and restart Docker.");
+}
+
 /// Prompt user to choose between prebuilt and build from source
 fn prompt_image_source_choice(
     config: &opencode_cloud_core::Config,
@@ -1284,6 +1673,8 @@ async fn start_container(
     cockpit_enabled: bool,
     systemd_enabled: bool,
     bind_mounts: Option<Vec<ParsedMount>>,
+    container_labels: &BTreeMap<String, String>,
+    platform: Option<&str>,
 ) -> Result<String, DockerError> {
     setup_and_start(
         client,
@@ -1294,6 +1685,8 @@ async fn start_container(
         Some(cockpit_enabled),
         Some(systemd_enabled),
         bind_mounts,
+        Some(container_labels),
+        platform,
     )
     .await
 }
@@ -1448,8 +1841,7 @@ fn open_browser_if_requested(should_open: bool, port: u16, bind_addr: &str) {
     }
 
     // Use localhost for loopback/wildcard browser URLs to preserve passkey support.
-    let browser_addr = localhost_display_addr(bind_addr);
-    let url = format!("http://{browser_addr}:{port}");
+    let url = format_service_url(None, bind_addr, port);
     if let Err(e) = webbrowser::open(&url) {
         eprintln!(
             "{} Failed to open browser: {}",
@@ -1460,7 +1852,7 @@ fn open_browser_if_requested(should_open: bool, port: u16, bind_addr: &str) {
 }
 
 /// Check if a port is available for binding
-fn check_port_available(bind_addr: &str, port: u16) -> bool {
+pub fn check_port_available(bind_addr: &str, port: u16) -> bool {
     let bind_target = format_bind_addr(bind_addr, port);
     TcpListener::bind(&bind_target).is_ok()
 }
@@ -1689,6 +2081,35 @@ mod tests {
         assert!(!check_port_available("127.0.0.1", 1));
     }
 
+    #[test]
+    fn port_conflict_message_lists_options() {
+        let msg = port_conflict_message("Port", "127.0.0.1", 3000, "occ start --port 3001");
+        assert!(msg.starts_with("Port 3000 is already in use on 127.0.0.1."));
+        assert!(msg.contains("Stop the process using port 3000"));
+        assert!(msg.contains("occ start --port 3001"));
+    }
+
+    #[test]
+    fn preflight_port_conflicts_detects_taken_ports() {
+        if !can_bind_localhost() {
+            eprintln!("Skipping test: cannot bind to localhost in this environment.");
+            return;
+        }
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let taken = listener.local_addr().unwrap().port();
+
+        let err = preflight_port_conflicts("127.0.0.1", taken, None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(&format!("Port {taken} is already in use"))
+        );
+
+        let free = find_next_available_port("127.0.0.1", 49152).unwrap();
+        let err = preflight_port_conflicts("127.0.0.1", free, Some(taken)).unwrap_err();
+        assert!(err.to_string().starts_with("Cockpit port"));
+        assert!(preflight_port_conflicts("127.0.0.1", free, None).is_ok());
+    }
+
     #[test]
     fn find_next_port_finds_available_port() {
         if !can_bind_localhost() {
@@ -1737,6 +2158,29 @@ mod tests {
         assert_eq!(reason, "IOTP state is inactive (users configured)");
     }
 
+    #[test]
+    fn stale_container_reason_flags_dead_and_crashed_containers() {
+        assert!(stale_container_reason("dead", None, false).is_some());
+        assert_eq!(
+            stale_container_reason("exited", Some(1), false).as_deref(),
+            Some("container exited with code 1")
+        );
+        assert!(
+            stale_container_reason("exited", Some(137), true)
+                .unwrap()
+                .contains("out of memory")
+        );
+    }
+
+    #[test]
+    fn stale_container_reason_ignores_normal_stops() {
+        for code in [0, 137, 143] {
+            assert_eq!(stale_container_reason("exited", Some(code), false), None);
+        }
+        assert_eq!(stale_container_reason("running", None, false), None);
+        assert_eq!(stale_container_reason("created", None, false), None);
+    }
+
     #[test]
     fn iotp_reset_hint_lines_includes_force_hint_when_exposed() {
         let lines = iotp_reset_hint_lines(true);
@@ -1750,6 +2194,19 @@ mod tests {
         assert_eq!(lines, vec!["Reset IOTP: occ reset iotp".to_string()]);
     }
 
+    #[test]
+    fn arch_mismatch_lines_suggest_matching_platform() {
+        let mismatch = ArchMismatch {
+            image_arch: "amd64".to_string(),
+            daemon_arch: "arm64".to_string(),
+        };
+        let lines = arch_mismatch_lines(&mismatch).join("\n");
+        assert!(lines.contains("built for amd64 but Docker runs on arm64"));
+        assert!(lines.contains("--platform linux/arm64"));
+        assert!(parse_platform("linux/arm64").is_ok());
+        assert!(parse_platform("arm64").is_err());
+    }
+
     #[test]
     fn local_opencode_submodule_requires_rebuild_flag() {
         let args = StartArgs {
@@ -1781,6 +2238,15 @@ mod tests {
         validate_local_opencode_submodule_args(&args).expect("full rebuild should be accepted");
     }
 
+    #[test]
+    fn drift_rebuild_offered_only_for_local_interactive_starts() {
+        assert!(should_offer_drift_rebuild(false, false, false, true));
+        assert!(!should_offer_drift_rebuild(true, false, false, true));
+        assert!(!should_offer_drift_rebuild(false, true, false, true));
+        assert!(!should_offer_drift_rebuild(false, false, true, true));
+        assert!(!should_offer_drift_rebuild(false, false, false, false));
+    }
+
     #[test]
     fn extracts_pinned_opencode_commit_from_embedded_dockerfile() {
         let commit = extract_pinned_opencode_commit_from_dockerfile()
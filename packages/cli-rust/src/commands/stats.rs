@@ -0,0 +1,195 @@
+//! Stats command implementation
+//!
+//! Streams CPU, memory, and network usage of the sandbox container as a
+//! live-updating table, a single sample, or line-delimited JSON.
+
+use crate::commands::disk_usage::format_bytes_u64;
+use crate::output::format_docker_error_anyhow;
+use anyhow::{Result, anyhow};
+use clap::Args;
+use comfy_table::Table;
+use console::{Term, style};
+use futures_util::StreamExt;
+use opencode_cloud_core::docker::{
+    ContainerStatsSample, DockerClient, active_resource_names, container_is_running,
+    container_stats,
+};
+
+/// Arguments for the stats command
+#[derive(Args)]
+pub struct StatsArgs {
+    /// Print a single sample and exit
+    #[arg(long)]
+    pub no_stream: bool,
+
+    /// Output one JSON object per sample (line-delimited)
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Show live resource usage of the sandbox container
+///
+/// Docker sends a sample about once per second. The table is redrawn in
+/// place on a terminal until Ctrl+C, or until the container stops.
+pub async fn cmd_stats(args: &StatsArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+    let container_name = active_resource_names().container_name;
+    let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
+
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| format_docker_error_anyhow(&e))?;
+
+    if !container_is_running(&client, &container_name).await? {
+        return Err(anyhow!(
+            "The service is not running. Start it first: {}",
+            style("occ start").cyan()
+        ));
+    }
+
+    let stream = !args.no_stream;
+    if stream && !args.json && !quiet {
+        let target = host_name
+            .as_deref()
+            .map(|name| format!(" on {}", style(name).cyan()))
+            .unwrap_or_default();
+        eprintln!(
+            "{}",
+            style(format!("Streaming stats{target} (Ctrl+C to exit)...")).dim()
+        );
+        eprintln!();
+    }
+
+    let _interrupt = crate::interrupt::HandlesInterrupt::new();
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+    let mut samples = std::pin::pin!(container_stats(&client, &container_name, stream));
+    let mut renderer = StatsRenderer::new(args.json);
+
+    loop {
+        let next = tokio::select! {
+            _ = &mut shutdown => return Ok(()),
+            next = samples.next() => next,
+        };
+        match next {
+            Some(Ok(sample)) => renderer.render(&sample)?,
+            Some(Err(e)) => {
+                if stopped(&client, &container_name).await {
+                    break;
+                }
+                return Err(e.into());
+            }
+            None => break,
+        }
+    }
+
+    if stream && !quiet && stopped(&client, &container_name).await {
+        eprintln!();
+        eprintln!("{}", style("Container stopped").dim());
+    }
+    Ok(())
+}
+
+async fn stopped(client: &DockerClient, container_name: &str) -> bool {
+    !container_is_running(client, container_name)
+        .await
+        .unwrap_or(false)
+}
+
+/// Prints samples, redrawing the previous table on an interactive terminal
+struct StatsRenderer {
+    json: bool,
+    term: Term,
+    redraw: bool,
+    drawn_lines: usize,
+}
+
+impl StatsRenderer {
+    fn new(json: bool) -> Self {
+        let term = Term::stdout();
+        Self {
+            json,
+            redraw: !json && term.is_term(),
+            term,
+            drawn_lines: 0,
+        }
+    }
+
+    fn render(&mut self, sample: &ContainerStatsSample) -> Result<()> {
+        if self.json {
+            println!("{}", serde_json::to_string(sample)?);
+            return Ok(());
+        }
+
+        let table = stats_table(sample).to_string();
+        if self.redraw && self.drawn_lines > 0 {
+            self.term.clear_last_lines(self.drawn_lines)?;
+        }
+        self.term.write_line(&table)?;
+        self.drawn_lines = table.lines().count();
+        Ok(())
+    }
+}
+
+fn stats_table(sample: &ContainerStatsSample) -> Table {
+    let mut table = Table::new();
+    table.set_header(vec![
+        "CPU %",
+        "Mem usage / limit",
+        "Mem %",
+        "Net I/O",
+        "PIDs",
+    ]);
+    table.add_row(stats_row(sample));
+    table
+}
+
+fn stats_row(sample: &ContainerStatsSample) -> Vec<String> {
+    vec![
+        format!("{:.2}%", sample.cpu_percent),
+        format!(
+            "{} / {}",
+            format_bytes_u64(sample.memory_usage_bytes),
+            format_bytes_u64(sample.memory_limit_bytes)
+        ),
+        format!("{:.2}%", sample.memory_percent),
+        format!(
+            "{} / {}",
+            format_bytes_u64(sample.net_rx_bytes),
+            format_bytes_u64(sample.net_tx_bytes)
+        ),
+        sample
+            .pids
+            .map(|pids| pids.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_row_formats_sample() {
+        let sample = ContainerStatsSample {
+            cpu_percent: 12.5,
+            memory_usage_bytes: 512 * 1024 * 1024,
+            memory_limit_bytes: 2 * 1024 * 1024 * 1024,
+            memory_percent: 25.0,
+            net_rx_bytes: 2048,
+            net_tx_bytes: 100,
+            pids: None,
+            ..Default::default()
+        };
+        assert_eq!(
+            stats_row(&sample),
+            [
+                "12.50%",
+                "512.00 MB / 2.00 GB",
+                "25.00%",
+                "2.00 KB / 100 B",
+                "-"
+            ]
+        );
+    }
+}
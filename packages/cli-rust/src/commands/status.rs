@@ -5,44 +5,99 @@
 
 use crate::cli_platform::cli_platform_label;
 use crate::commands::disk_usage::{
-    format_disk_usage_report, format_host_disk_report, get_disk_usage_report, get_host_disk_report,
+    format_bytes_i64, format_disk_usage_report, format_host_disk_report, format_volume_breakdown,
+    get_disk_usage_with_volumes, get_host_disk_report,
 };
 use crate::commands::iotp::{IOTP_FALLBACK_COMMAND, IotpSnapshot, IotpState, fetch_iotp_snapshot};
-use crate::commands::runtime_shared::backend::HostBackend;
-use crate::commands::runtime_shared::collect_status_view;
+use crate::commands::runtime_shared::backend::{HostBackend, probe_opencode_http};
 use crate::commands::runtime_shared::drift::{
     RuntimeAssetDrift, detect_runtime_asset_drift, stale_container_warning_lines,
 };
 use crate::commands::runtime_shared::status_model::{
-    BrokerHealthStatus, OpencodeHealthStatus, format_broker_health_label,
-    format_opencode_health_label,
+    BrokerHealthStatus, OpencodeHealthStatus, OpencodeHttpProbe, StatusViewModel,
+    format_broker_health_label, format_opencode_health_label,
 };
+use crate::commands::runtime_shared::{DEFAULT_PROBE_TIMEOUT, collect_status_view};
+use crate::commands::status_diff::cmd_status_diff;
+use crate::commands::update::{fetch_latest_opencode_commit, short_commit};
 use crate::constants::COCKPIT_EXPOSED;
+use crate::error::CliError;
+use crate::exit_code::{ExitCode, exit_with};
 use crate::output::{
-    format_cockpit_url, format_docker_error_anyhow, format_service_url, resolve_remote_addr,
-    state_style,
+    format_cockpit_url, format_docker_error_anyhow, format_service_url, normalize_bind_addr,
+    resolve_remote_addr, state_style,
 };
 use anyhow::{Result, anyhow};
 use clap::Args;
+use comfy_table::{Cell, Color, Table};
 use console::style;
 use opencode_cloud_core::Config;
-use opencode_cloud_core::bollard::service::MountTypeEnum;
+use opencode_cloud_core::bollard::service::{ContainerSummary, MountTypeEnum, PortSummary};
 use opencode_cloud_core::config;
 use opencode_cloud_core::docker::{
-    MOUNT_CACHE, MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, MOUNT_SSH, MOUNT_STATE,
-    OPENCODE_WEB_PORT, ParsedMount, active_resource_names, get_cli_version, get_image_version,
-    load_state,
+    DaemonInfo, DockerClient, ExtendedHealthResponse, HealthError, HealthHistory,
+    INSTANCE_LABEL_KEY, MOUNT_CACHE, MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, MOUNT_SSH,
+    MOUNT_STATE, OPENCODE_WEB_PORT, ParsedMount, active_resource_names, check_health_extended,
+    custom_labels, daemon_info, docker_supports_systemd, get_cli_version, get_image_version,
+    list_instance_containers, load_health_history, load_state, record_health_status,
 };
 use opencode_cloud_core::platform::{get_service_manager, is_service_registration_supported};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::time::Duration;
 
 /// Arguments for the status command
 #[derive(Args)]
-pub struct StatusArgs {}
+pub struct StatusArgs {
+    /// List every sandbox instance container instead of the active service
+    #[arg(long)]
+    pub instances: bool,
+
+    /// Show recent health transitions of the local service
+    #[arg(long, conflicts_with = "instances")]
+    pub history: bool,
+
+    /// Only probe opencode over HTTP; print nothing and exit 0 healthy, 1 unhealthy, 2 unreachable
+    #[arg(long, conflicts_with_all = ["instances", "history"])]
+    pub probe: bool,
+
+    /// Seconds to wait for each health probe before reporting it as failed
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = DEFAULT_PROBE_TIMEOUT.as_secs(),
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub timeout: u64,
+
+    /// Compare with another host or sandbox instance and print the fields that differ
+    /// (`host:<name>` or `instance:<id>` to disambiguate)
+    #[arg(long, value_name = "OTHER", conflicts_with_all = ["instances", "history", "probe"])]
+    pub diff: Option<String>,
+}
+
+impl Default for StatusArgs {
+    fn default() -> Self {
+        Self {
+            instances: false,
+            history: false,
+            probe: false,
+            diff: None,
+            timeout: DEFAULT_PROBE_TIMEOUT.as_secs(),
+        }
+    }
+}
+
+impl StatusArgs {
+    pub(crate) fn probe_timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+}
 
 const STATUS_LABEL_WIDTH: usize = 15;
 
+/// Longest `occ status` waits for the upstream opencode commit lookup
+const OPENCODE_UPDATE_LOOKUP_TIMEOUT: Duration = Duration::from_secs(3);
+
 /// Show the status of the opencode service
 ///
 /// In normal mode, displays a key-value formatted status including:
@@ -59,12 +114,56 @@ const STATUS_LABEL_WIDTH: usize = 15;
 /// - Exits 0 if running
 /// - Exits 1 if stopped
 /// - No output
+///
+/// With `--instances`, lists all sandbox instance containers instead
+/// (quiet mode exits 0 if any instance is running).
+///
+/// With `--history`, prints the recorded health transitions instead.
+///
+/// With `--diff`, compares the service with another host or sandbox
+/// instance and prints only the differing fields.
+///
+/// With `--probe`, only checks opencode over HTTP and returns
+/// [`probe_result`], skipping Docker entirely.
 pub async fn cmd_status(
-    _args: &StatusArgs,
+    args: &StatusArgs,
     maybe_host: Option<&str>,
     quiet: bool,
     verbose: u8,
 ) -> Result<()> {
+    if args.probe {
+        let config = crate::load_config_for_host(maybe_host)?;
+        let addr = match maybe_host {
+            Some(name) => {
+                resolve_remote_addr(Some(name)).ok_or_else(|| anyhow!("Unknown host '{name}'"))?
+            }
+            None => normalize_bind_addr(&config.bind_address).to_string(),
+        };
+        let probe = tokio::time::timeout(
+            args.probe_timeout(),
+            probe_opencode_http(&addr, config.opencode_web_port),
+        )
+        .await
+        .unwrap_or(OpencodeHttpProbe::Timeout);
+        return probe_result(probe);
+    }
+
+    if args.history {
+        if maybe_host.is_some() {
+            return Err(anyhow!(
+                "Health history is only recorded for the local service. Run 'occ status --history' on the remote host."
+            ));
+        }
+        if !quiet {
+            print_health_history(&load_health_history());
+        }
+        return Ok(());
+    }
+
+    if let Some(other) = args.diff.as_deref() {
+        return cmd_status_diff(maybe_host, other, args.probe_timeout(), quiet).await;
+    }
+
     let resources = active_resource_names();
 
     // Resolve Docker client (local or remote)
@@ -85,11 +184,12 @@ pub async fn cmd_status(
         println!();
     }
 
+    if args.instances {
+        return print_instances(&client, quiet).await;
+    }
+
     // Check if container exists
-    let inspect_result = client
-        .inner()
-        .inspect_container(&resources.container_name, None)
-        .await;
+    let inspect_result = client.inspect_container(&resources.container_name).await;
 
     let info = match inspect_result {
         Ok(info) => info,
@@ -98,7 +198,7 @@ pub async fn cmd_status(
             ..
         }) => {
             if quiet {
-                std::process::exit(1);
+                exit_with(ExitCode::ContainerNotFound);
             }
             println!("{}", style("No service found.").yellow());
             println!();
@@ -166,7 +266,7 @@ pub async fn cmd_status(
         .unwrap_or_else(|| "unknown".to_string());
 
     // Load config early for reuse in multiple sections
-    let config = config::load_config_or_default().ok();
+    let config = crate::load_config_for_host(host_name.as_deref()).ok();
     let bind_addr = config
         .as_ref()
         .map(|cfg| cfg.bind_address.as_str())
@@ -203,20 +303,49 @@ pub async fn cmd_status(
     // Label config path - clarify it's local config when using remote host
     print_config_path(host_name.as_deref(), &config_path);
 
+    if !running && host_name.is_none() {
+        record_health_transition(STOPPED_HISTORY_STATUS);
+    }
+
     if running {
+        // Look up the latest upstream commit while the local checks run
+        let latest_commit_lookup = config
+            .as_ref()
+            .is_some_and(|cfg| cfg.check_opencode_updates)
+            .then(|| tokio::spawn(fetch_latest_opencode_commit(false)));
+
         print_section_header("OpenCode");
-        let broker_health = print_opencode_section(
+        let status_view = print_opencode_section(
             &client,
             host_name.as_deref(),
             maybe_remote_addr.as_deref(),
             bind_addr,
             host_port,
             started_at.as_deref(),
+            args.probe_timeout(),
         )
         .await?;
 
+        if let Some(lookup) = latest_commit_lookup {
+            print_opencode_update_hint(&status_view.opencode_commit, lookup, verbose).await;
+        }
+
+        if host_name.is_none()
+            && let Some(health_status) = status_view.opencode_health
+        {
+            record_health_transition(&health_history_status(health_status));
+        }
+
+        if verbose > 0 && host_name.is_none() {
+            print_section_header("Health details");
+            let extended = check_health_extended(&client, bind_addr, host_port).await;
+            for line in render_health_details_lines(&extended) {
+                println!("{line}");
+            }
+        }
+
         print_section_header("OpenCode Broker");
-        print_opencode_broker_section(broker_health);
+        print_opencode_broker_section(status_view.broker_health);
     }
 
     print_section_header("Sandbox");
@@ -269,10 +398,35 @@ pub async fn cmd_status(
             } else {
                 "prebuilt".to_string()
             }
-        } else {
+        } else if state.source == "build" {
             "built from source".to_string()
+        } else {
+            state.source.clone()
         };
         println!("{}", format_kv("Image src:", style(&source_info).dim()));
+        if let Some(applied_at) = state.applied_at.as_deref() {
+            let display = parse_timestamp_display(applied_at).unwrap_or_else(|| applied_at.into());
+            println!(
+                "{}",
+                format_kv(
+                    "Image updated:",
+                    format!("{display} {}", style(format!("({})", state.version)).dim())
+                )
+            );
+        }
+    }
+
+    if verbose > 0 {
+        let labels = info
+            .config
+            .as_ref()
+            .and_then(|c| c.labels.as_ref())
+            .map(custom_labels)
+            .unwrap_or_default();
+        let configured = config.as_ref().map(|cfg| &cfg.container_labels);
+        for line in render_container_labels_lines(&labels, configured) {
+            println!("{line}");
+        }
     }
 
     let runtime_asset_drift = if running && host_name.is_none() {
@@ -282,15 +436,31 @@ pub async fn cmd_status(
     };
     print_runtime_asset_drift_warning(&runtime_asset_drift, verbose);
 
+    if verbose > 0 {
+        print_section_header("Daemon");
+        match daemon_info(&client).await {
+            Ok(info) => {
+                for line in render_daemon_info_lines(&info) {
+                    println!("{line}");
+                }
+            }
+            Err(e) => println!(
+                "{}",
+                format_kv("Unavailable:", style(e.to_string()).yellow())
+            ),
+        }
+    }
+
     print_disk_usage_section(&client, host_name.as_deref()).await;
 
-    if running {
-        print_cockpit(
-            maybe_remote_addr.as_deref(),
-            host_name.as_deref(),
-            config.as_ref(),
-        );
-    }
+    print_cockpit(
+        &client,
+        running,
+        maybe_remote_addr.as_deref(),
+        host_name.as_deref(),
+        config.as_ref(),
+    )
+    .await;
 
     if host_name.is_some() {
         print_remote_health(health.as_deref());
@@ -321,11 +491,17 @@ async fn print_disk_usage_section(
     maybe_host_name: Option<&str>,
 ) {
     print_section_header("Disk");
-    match get_disk_usage_report(client).await {
-        Ok(report) => {
+    let resources = active_resource_names();
+    let volume_names = resources.volume_names();
+    match get_disk_usage_with_volumes(client, &volume_names).await {
+        Ok((report, volumes)) => {
             for line in format_disk_usage_report("current", report, None) {
                 println!("{line}");
             }
+            println!();
+            for line in format_volume_breakdown(&volumes) {
+                println!("{line}");
+            }
         }
         Err(err) => {
             println!("{} {err}", style("Warning:").yellow().bold());
@@ -359,7 +535,7 @@ async fn print_disk_usage_section(
 /// Parse uptime from ISO8601 started_at timestamp
 ///
 /// Returns (duration since start, human-readable start time) or None if parsing fails
-fn parse_uptime(started_at: &str) -> Option<(Duration, String)> {
+pub(crate) fn parse_uptime(started_at: &str) -> Option<(Duration, String)> {
     // Docker timestamps are in format: "2024-01-15T10:30:00.123456789Z"
     // We need to handle this format and calculate uptime
 
@@ -457,10 +633,17 @@ async fn print_opencode_section(
     bind_addr: &str,
     host_port: u16,
     started_at: Option<&str>,
-) -> Result<BrokerHealthStatus> {
+    probe_timeout: Duration,
+) -> Result<StatusViewModel> {
     let backend = HostBackend::new(client);
-    let status_view =
-        collect_status_view(&backend, maybe_host_name.is_none(), bind_addr, host_port).await?;
+    let status_view = collect_status_view(
+        &backend,
+        maybe_host_name.is_none(),
+        bind_addr,
+        host_port,
+        probe_timeout,
+    )
+    .await?;
 
     print_urls(maybe_remote_addr, bind_addr, host_port);
 
@@ -472,7 +655,180 @@ async fn print_opencode_section(
     print_uptime(started_at);
     print_port(host_port);
 
-    Ok(status_view.broker_health)
+    Ok(status_view)
+}
+
+/// Outcome of `occ status --probe`: `Ok` when healthy, otherwise a silent
+/// exit 1 (unhealthy) or 2 (unreachable)
+pub(crate) fn probe_result(probe: OpencodeHttpProbe) -> Result<()> {
+    let code = match probe {
+        OpencodeHttpProbe::Healthy => return Ok(()),
+        OpencodeHttpProbe::Unhealthy(_) => ExitCode::Failure,
+        OpencodeHttpProbe::ConnectionRefused
+        | OpencodeHttpProbe::Timeout
+        | OpencodeHttpProbe::Failed => ExitCode::ProbeUnreachable,
+    };
+    Err(CliError::Silent(code).into())
+}
+
+/// `Labels:` lines, noting when the config no longer matches the container
+fn render_container_labels_lines(
+    labels: &BTreeMap<String, String>,
+    configured: Option<&BTreeMap<String, String>>,
+) -> Vec<String> {
+    let mut entries = labels.iter().map(|(key, value)| format!("{key}={value}"));
+    let mut lines = vec![format_kv(
+        "Labels:",
+        entries.next().unwrap_or_else(|| "none".to_string()),
+    )];
+    lines.extend(entries.map(format_continuation));
+    if configured.is_some_and(|configured| configured != labels) {
+        lines.push(format_continuation(
+            style("(config differs; restart with 'occ restart' to apply)").dim(),
+        ));
+    }
+    lines
+}
+
+/// Status lines explaining whether the Docker host can run Cockpit
+fn cockpit_systemd_lines(systemd_supported: Option<bool>) -> Vec<String> {
+    match systemd_supported {
+        Some(true) => vec![format_kv("Systemd:", style("supported").green())],
+        Some(false) => vec![
+            format_kv("Systemd:", style("not supported").yellow()),
+            format_continuation(
+                style("Cockpit needs systemd in the container, which Docker Desktop and rootless Docker cannot run.")
+                    .dim(),
+            ),
+        ],
+        None => vec![format_kv("Systemd:", style("unknown").dim())],
+    }
+}
+
+/// Lines for the verbose "Health details" section
+///
+/// Images that predate the extended fields answer `/global/health` with a
+/// different shape (or not at all), so failures become a note, not an error.
+fn render_health_details_lines(
+    result: &Result<ExtendedHealthResponse, HealthError>,
+) -> Vec<String> {
+    let health = match result {
+        Ok(health) => health,
+        Err(HealthError::Unhealthy(404)) => {
+            return vec![
+                style("Extended health is not available from this image.")
+                    .dim()
+                    .to_string(),
+            ];
+        }
+        Err(HealthError::RequestError(e)) if e.is_decode() => {
+            return vec![
+                style("This image reports basic health only; extended fields are unavailable.")
+                    .dim()
+                    .to_string(),
+            ];
+        }
+        Err(e) => {
+            return vec![format_kv("Unavailable:", style(e.to_string()).yellow())];
+        }
+    };
+
+    let healthy = if health.healthy {
+        style("yes").green().to_string()
+    } else {
+        style("no").red().to_string()
+    };
+    let mut lines = vec![
+        format_kv("Healthy:", healthy),
+        format_kv("Version:", &health.version),
+        format_kv("Container:", state_style(&health.container_state)),
+        format_kv(
+            "Uptime:",
+            format_duration(Duration::from_secs(health.uptime_seconds)),
+        ),
+    ];
+    if let Some(memory) = health.memory_usage_mb {
+        lines.push(format_kv("Memory:", format!("{memory} MB")));
+    }
+    lines
+}
+
+/// Lines for the verbose "Daemon" section; fields the daemon doesn't report are left out
+pub(crate) fn render_daemon_info_lines(info: &DaemonInfo) -> Vec<String> {
+    let cgroup = match (&info.cgroup_version, &info.cgroup_driver) {
+        (Some(version), Some(driver)) => Some(format!("v{version} ({driver})")),
+        (Some(version), None) => Some(format!("v{version}")),
+        (None, Some(driver)) => Some(driver.clone()),
+        (None, None) => None,
+    };
+    let os = match (&info.operating_system, &info.architecture) {
+        (Some(os), Some(arch)) => Some(format!("{os} ({arch})")),
+        (os, arch) => os.clone().or_else(|| arch.clone()),
+    };
+    [
+        ("Server:", info.server_version.clone()),
+        ("OS:", os),
+        ("Kernel:", info.kernel_version.clone()),
+        ("Storage:", info.storage_driver.clone()),
+        ("Cgroup:", cgroup),
+        ("CPUs:", info.cpus.map(|cpus| cpus.to_string())),
+        ("Memory:", info.total_memory.map(format_bytes_i64)),
+    ]
+    .into_iter()
+    .filter_map(|(label, value)| value.map(|value| format_kv(label, value)))
+    .collect()
+}
+
+/// History label used when the container is not running
+const STOPPED_HISTORY_STATUS: &str = "stopped";
+
+/// Plain status label, stored in the health history and shown by `status --diff`
+pub(super) fn health_history_status(status: OpencodeHealthStatus) -> String {
+    match status {
+        OpencodeHealthStatus::Healthy => "healthy".to_string(),
+        OpencodeHealthStatus::Starting => "starting".to_string(),
+        OpencodeHealthStatus::Unhealthy(code) => format!("unhealthy (HTTP {code})"),
+        OpencodeHealthStatus::CheckFailed => "check failed".to_string(),
+    }
+}
+
+/// Persist a health transition; status output never fails because of it
+fn record_health_transition(status: &str) {
+    if let Err(e) = record_health_status(status) {
+        tracing::debug!("Failed to record health history: {e}");
+    }
+}
+
+fn print_health_history(history: &HealthHistory) {
+    if history.transitions.is_empty() {
+        println!("{}", style("No health history recorded yet.").yellow());
+        println!();
+        println!(
+            "History is recorded each time '{}' runs.",
+            style("occ status").cyan()
+        );
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Time", "Status"]);
+    for transition in history.transitions.iter().rev() {
+        let time = parse_timestamp_display(&transition.at).unwrap_or_else(|| transition.at.clone());
+        table.add_row(vec![
+            Cell::new(time),
+            Cell::new(&transition.status).fg(history_status_color(&transition.status)),
+        ]);
+    }
+    println!("{table}");
+}
+
+fn history_status_color(status: &str) -> Color {
+    match status {
+        "healthy" => Color::Green,
+        STOPPED_HISTORY_STATUS => Color::Red,
+        s if s.starts_with("unhealthy") => Color::Red,
+        _ => Color::Yellow,
+    }
 }
 
 fn print_opencode_broker_section(status: BrokerHealthStatus) {
@@ -526,7 +882,9 @@ fn print_port(host_port: u16) {
     );
 }
 
-fn print_cockpit(
+async fn print_cockpit(
+    client: &DockerClient,
+    running: bool,
     maybe_remote_addr: Option<&str>,
     maybe_host_name: Option<&str>,
     config: Option<&Config>,
@@ -541,6 +899,15 @@ fn print_cockpit(
         return;
     }
 
+    // Cockpit runs under systemd in the container; without it, it never starts
+    let systemd_supported = docker_supports_systemd(client).await.ok();
+    for line in cockpit_systemd_lines(systemd_supported) {
+        println!("{line}");
+    }
+    if !running {
+        return;
+    }
+
     let cockpit_url = format_cockpit_url(maybe_remote_addr, &cfg.bind_address, cfg.cockpit_port);
     println!(
         "{}",
@@ -649,6 +1016,48 @@ fn print_opencode_version_commit(version: &str, commit: &str) {
     }
 }
 
+/// Print a dim hint when the upstream opencode commit differs from the running one
+///
+/// The lookup is best-effort: a slow or failing GitHub request never delays
+/// status by more than [`OPENCODE_UPDATE_LOOKUP_TIMEOUT`] or fails the command.
+async fn print_opencode_update_hint(
+    current_commit: &str,
+    lookup: tokio::task::JoinHandle<Result<String>>,
+    verbose: u8,
+) {
+    let latest = match tokio::time::timeout(OPENCODE_UPDATE_LOOKUP_TIMEOUT, lookup).await {
+        Ok(Ok(Ok(latest))) => latest,
+        Ok(Ok(Err(err))) => {
+            if verbose > 0 {
+                eprintln!(
+                    "{} opencode update check failed: {err}",
+                    style("[warn]").yellow()
+                );
+            }
+            return;
+        }
+        Ok(Err(_)) | Err(_) => return,
+    };
+
+    if let Some(line) = opencode_update_available_line(current_commit, &latest) {
+        println!("{}", format_continuation(style(line).dim()));
+    }
+}
+
+fn opencode_update_available_line(current_commit: &str, latest_commit: &str) -> Option<String> {
+    if current_commit == "unknown" || latest_commit.is_empty() {
+        return None;
+    }
+    let current = short_commit(current_commit);
+    let latest = short_commit(latest_commit);
+    if current.eq_ignore_ascii_case(&latest) {
+        return None;
+    }
+    Some(format!(
+        "opencode update available ({current} → {latest}); run occ update opencode"
+    ))
+}
+
 /// Display the Mounts section of status output
 fn display_mounts_section(
     mounts: &[opencode_cloud_core::bollard::service::Mount],
@@ -992,10 +1401,231 @@ fn format_iotp_reset_hint_line(line: &str) -> String {
     style(line).dim().to_string()
 }
 
+/// Print a table of every container labeled with a sandbox instance id
+async fn print_instances(client: &DockerClient, quiet: bool) -> Result<()> {
+    let mut containers = list_instance_containers(client)
+        .await
+        .map_err(|e| format_docker_error_anyhow(&e))?;
+    containers.sort_by_key(|c| instance_id_of(c).unwrap_or_default().to_string());
+
+    if quiet {
+        let any_running = containers.iter().any(|c| summary_state(c) == "running");
+        if any_running {
+            return Ok(());
+        }
+        return Err(CliError::Silent(ExitCode::Failure).into());
+    }
+
+    if containers.is_empty() {
+        println!("{}", style("No sandbox instances found.").yellow());
+        println!();
+        println!(
+            "Run '{}' to start one.",
+            style("occ --sandbox-instance auto start").cyan()
+        );
+        return Ok(());
+    }
+
+    let active = active_resource_names().instance_id;
+    let mut table = Table::new();
+    table.set_header(vec!["Instance", "State", "Port", "Image Version", "Active"]);
+
+    for container in &containers {
+        let instance_id = instance_id_of(container).unwrap_or("unknown");
+        let state = summary_state(container);
+        let state_cell = match state.as_str() {
+            "running" => Cell::new(&state).fg(Color::Green),
+            "exited" | "dead" => Cell::new(&state).fg(Color::Red),
+            _ => Cell::new(&state).fg(Color::Yellow),
+        };
+        let port = container
+            .ports
+            .as_deref()
+            .and_then(web_host_port)
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let image_version = match container.image.as_deref() {
+            Some(image) => get_image_version(client, image)
+                .await
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| "unknown".to_string()),
+            None => "unknown".to_string(),
+        };
+        let active_cell = if active.as_deref() == Some(instance_id) {
+            Cell::new("*").fg(Color::Green)
+        } else {
+            Cell::new("")
+        };
+
+        table.add_row(vec![
+            Cell::new(instance_id).fg(Color::Cyan),
+            state_cell,
+            Cell::new(port),
+            Cell::new(image_version),
+            active_cell,
+        ]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+fn instance_id_of(container: &ContainerSummary) -> Option<&str> {
+    container
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(INSTANCE_LABEL_KEY))
+        .map(String::as_str)
+}
+
+fn summary_state(container: &ContainerSummary) -> String {
+    container
+        .state
+        .map(|s| s.to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Host port published for the opencode web UI, if any
+fn web_host_port(ports: &[PortSummary]) -> Option<u16> {
+    ports
+        .iter()
+        .filter(|p| p.private_port == OPENCODE_WEB_PORT)
+        .find_map(|p| p.public_port)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn probe_exit_codes_separate_unhealthy_from_unreachable() {
+        let code = |probe| crate::exit_code::exit_code_for(&probe_result(probe).unwrap_err());
+        assert!(probe_result(OpencodeHttpProbe::Healthy).is_ok());
+        assert_eq!(code(OpencodeHttpProbe::Unhealthy(503)).code(), 1);
+        assert_eq!(code(OpencodeHttpProbe::ConnectionRefused).code(), 2);
+        assert_eq!(code(OpencodeHttpProbe::Timeout).code(), 2);
+    }
+
+    #[test]
+    fn cockpit_systemd_lines_explain_unsupported_hosts() {
+        assert_eq!(cockpit_systemd_lines(Some(true)).len(), 1);
+        let unsupported = cockpit_systemd_lines(Some(false));
+        assert_eq!(unsupported.len(), 2);
+        assert!(unsupported[1].contains("Docker Desktop"));
+        assert!(cockpit_systemd_lines(None)[0].contains("unknown"));
+    }
+
+    #[test]
+    fn web_host_port_picks_published_web_port() {
+        let ports = vec![
+            PortSummary {
+                private_port: 9090,
+                public_port: Some(9090),
+                ..Default::default()
+            },
+            PortSummary {
+                private_port: OPENCODE_WEB_PORT,
+                public_port: None,
+                ..Default::default()
+            },
+            PortSummary {
+                private_port: OPENCODE_WEB_PORT,
+                public_port: Some(3100),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(web_host_port(&ports), Some(3100));
+        assert_eq!(web_host_port(&[]), None);
+    }
+
+    #[test]
+    fn health_history_status_uses_plain_labels() {
+        assert_eq!(
+            health_history_status(OpencodeHealthStatus::Healthy),
+            "healthy"
+        );
+        assert_eq!(
+            health_history_status(OpencodeHealthStatus::Unhealthy(503)),
+            "unhealthy (HTTP 503)"
+        );
+        assert_eq!(
+            history_status_color(&health_history_status(OpencodeHealthStatus::Unhealthy(503))),
+            Color::Red
+        );
+        assert_eq!(
+            history_status_color(&health_history_status(OpencodeHealthStatus::Starting)),
+            Color::Yellow
+        );
+    }
+
+    #[test]
+    fn render_health_details_lines_lists_extended_fields() {
+        let health = ExtendedHealthResponse {
+            healthy: true,
+            version: "1.2.3".to_string(),
+            container_state: "running".to_string(),
+            uptime_seconds: 90,
+            memory_usage_mb: Some(256),
+        };
+        let lines: Vec<String> = render_health_details_lines(&Ok(health))
+            .iter()
+            .map(|line| console::strip_ansi_codes(line).to_string())
+            .collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1].contains("1.2.3"));
+        assert!(lines[3].contains("1m 30s"));
+        assert!(lines[4].contains("256 MB"));
+    }
+
+    #[test]
+    fn render_container_labels_lines_flags_config_drift() {
+        let labels = BTreeMap::from([
+            ("com.example.env".to_string(), "prod".to_string()),
+            ("com.example.team".to_string(), "infra".to_string()),
+        ]);
+        let lines: Vec<String> = render_container_labels_lines(&labels, Some(&labels))
+            .iter()
+            .map(|line| console::strip_ansi_codes(line).to_string())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("Labels:") && lines[0].contains("com.example.env=prod"));
+        assert!(lines[1].contains("com.example.team=infra"));
+
+        let drifted = render_container_labels_lines(&BTreeMap::new(), Some(&labels));
+        assert!(console::strip_ansi_codes(&drifted[0]).contains("none"));
+        assert!(console::strip_ansi_codes(&drifted[1]).contains("config differs"));
+    }
+
+    #[test]
+    fn render_daemon_info_lines_skips_unreported_fields() {
+        let info = DaemonInfo {
+            server_version: Some("27.3.1".to_string()),
+            operating_system: Some("Ubuntu 24.04".to_string()),
+            architecture: Some("x86_64".to_string()),
+            storage_driver: Some("overlay2".to_string()),
+            cgroup_version: Some("2".to_string()),
+            cgroup_driver: Some("systemd".to_string()),
+            ..Default::default()
+        };
+        let lines: Vec<String> = render_daemon_info_lines(&info)
+            .iter()
+            .map(|line| console::strip_ansi_codes(line).to_string())
+            .collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].contains("Ubuntu 24.04 (x86_64)"));
+        assert!(lines[3].contains("v2 (systemd)"));
+        assert!(!lines.iter().any(|line| line.starts_with("Kernel")));
+    }
+
+    #[test]
+    fn render_health_details_lines_notes_missing_endpoint() {
+        let lines = render_health_details_lines(&Err(HealthError::Unhealthy(404)));
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("not available"));
+    }
+
     #[test]
     fn format_duration_seconds() {
         assert_eq!(format_duration(Duration::from_secs(30)), "30s");
@@ -1115,6 +1745,16 @@ mod tests {
         assert_eq!(lines, vec!["Reset IOTP: occ reset iotp".to_string()]);
     }
 
+    #[test]
+    fn opencode_update_line_only_when_commits_differ() {
+        assert_eq!(
+            opencode_update_available_line("abc1234", "def5678").as_deref(),
+            Some("opencode update available (abc1234 → def5678); run occ update opencode")
+        );
+        assert!(opencode_update_available_line("abc1234", "abc1234def").is_none());
+        assert!(opencode_update_available_line("unknown", "def5678").is_none());
+    }
+
     #[test]
     fn render_runtime_asset_drift_lines_empty_when_no_drift() {
         let report = RuntimeAssetDrift::default();
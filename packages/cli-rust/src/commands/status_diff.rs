@@ -0,0 +1,414 @@
+//! `occ status --diff` - compare the service on two hosts or sandbox instances
+//!
+//! Collects the same status snapshot for the current target and the other
+//! one, then prints only the fields that differ: image, versions, health,
+//! mounts, and the security-relevant port binding and users.
+
+use crate::commands::runtime_shared::backend::HostBackend;
+use crate::commands::runtime_shared::collect_status_view;
+use crate::commands::runtime_shared::status_model::{BrokerHealthStatus, StatusViewModel};
+use crate::commands::status::health_history_status;
+use crate::error::CliError;
+use crate::exit_code::ExitCode;
+use crate::output::{format_docker_error_anyhow, normalize_bind_addr, resolve_remote_addr};
+use crate::sandbox_profile::SHARED_INSTANCE_NAME;
+use anyhow::{Result, anyhow};
+use comfy_table::{Cell, Table};
+use console::style;
+use opencode_cloud_core::bollard::errors::Error as BollardError;
+use opencode_cloud_core::bollard::service::ContainerInspectResponse;
+use opencode_cloud_core::docker::{
+    env_instance_id, get_image_version, list_users, resource_names_for_instance,
+    validate_sandbox_instance,
+};
+use opencode_cloud_core::load_hosts;
+use std::time::Duration;
+
+/// Placeholder for fields that do not apply, e.g. health of a stopped service
+const NOT_APPLICABLE: &str = "-";
+
+/// Compared fields, in display order
+const FIELDS: [&str; 11] = [
+    "State",
+    "Image",
+    "Image version",
+    "opencode version",
+    "opencode commit",
+    "opencode health",
+    "Broker health",
+    "Bind address",
+    "Host port",
+    "Auth users",
+    "Mounts",
+];
+
+/// Host and sandbox instance a status snapshot is taken from
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DiffTarget {
+    /// Remote host name, `None` for local Docker
+    host: Option<String>,
+    /// Sandbox instance id, `None` for the shared sandbox
+    instance: Option<String>,
+}
+
+impl DiffTarget {
+    fn label(&self) -> String {
+        let host = self.host.as_deref().unwrap_or("local");
+        match self.instance.as_deref() {
+            Some(instance) => format!("{host}/{instance}"),
+            None => host.to_string(),
+        }
+    }
+}
+
+/// Resolve `--diff <OTHER>` relative to the current target
+///
+/// `host:<name>` and `instance:<id>` pick explicitly. A bare value is
+/// `local`, then a host from hosts.json, then a sandbox instance on the
+/// current host (`default` being the shared sandbox).
+fn parse_diff_target(
+    value: &str,
+    current: &DiffTarget,
+    is_known_host: impl Fn(&str) -> bool,
+) -> Result<DiffTarget> {
+    let value = value.trim();
+    let with_host = |host: &str| DiffTarget {
+        host: (host != "local").then(|| host.to_string()),
+        instance: current.instance.clone(),
+    };
+    let with_instance = |instance: &str| -> Result<DiffTarget> {
+        let instance = instance.to_ascii_lowercase();
+        let instance = match instance.as_str() {
+            SHARED_INSTANCE_NAME => None,
+            id if id != "auto" && validate_sandbox_instance(id).is_ok() => Some(instance.clone()),
+            _ => {
+                return Err(anyhow!(
+                    "Invalid sandbox instance '{instance}'. Expected [a-z0-9][a-z0-9-]{{0,31}}"
+                ));
+            }
+        };
+        Ok(DiffTarget {
+            host: current.host.clone(),
+            instance,
+        })
+    };
+
+    if let Some(host) = value.strip_prefix("host:") {
+        if host != "local" && !is_known_host(host) {
+            return Err(crate::error::CliError::HostNotFound(host.to_string()).into());
+        }
+        return Ok(with_host(host));
+    }
+    if let Some(instance) = value.strip_prefix("instance:") {
+        return with_instance(instance);
+    }
+    if value == "local" || is_known_host(value) {
+        return Ok(with_host(value));
+    }
+    with_instance(value).map_err(|_| {
+        anyhow!(
+            "'{value}' is neither a host in hosts.json nor a sandbox instance. \
+             Use host:<name> or instance:<id>."
+        )
+    })
+}
+
+/// Compare the current target's status with `other` and print differing fields
+///
+/// In quiet mode nothing is printed; the process exits 0 when the compared
+/// fields match and 1 when they differ.
+pub(crate) async fn cmd_status_diff(
+    maybe_host: Option<&str>,
+    other: &str,
+    probe_timeout: Duration,
+    quiet: bool,
+) -> Result<()> {
+    let current = DiffTarget {
+        host: maybe_host.map(str::to_string),
+        instance: env_instance_id(),
+    };
+    let hosts = load_hosts().unwrap_or_default();
+    let other = parse_diff_target(other, &current, |name| hosts.get_host(name).is_some())?;
+    if other == current {
+        return Err(anyhow!(
+            "--diff target '{}' is the current target; pick another host or instance",
+            other.label()
+        ));
+    }
+
+    let (left, right) = tokio::join!(
+        collect_snapshot(&current, probe_timeout),
+        collect_snapshot(&other, probe_timeout)
+    );
+    let (left, right) = (left?, right?);
+    let rows = differing_fields(&left, &right);
+
+    if quiet {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        return Err(CliError::Silent(ExitCode::Failure).into());
+    }
+
+    println!(
+        "{} {} vs {}",
+        style("Status diff:").bold(),
+        style(current.label()).cyan(),
+        style(other.label()).cyan()
+    );
+    println!();
+    if rows.is_empty() {
+        println!(
+            "{}",
+            style(format!("No differences in {} compared fields.", left.len())).green()
+        );
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Field"),
+        Cell::new(current.label()),
+        Cell::new(other.label()),
+    ]);
+    for (field, left_value, right_value) in &rows {
+        table.add_row(vec![*field, left_value.as_str(), right_value.as_str()]);
+    }
+    println!("{table}");
+    println!();
+    println!(
+        "{}",
+        style(format!(
+            "{} of {} compared fields differ.",
+            rows.len(),
+            left.len()
+        ))
+        .dim()
+    );
+    Ok(())
+}
+
+type Snapshot = Vec<(&'static str, String)>;
+
+/// Fields whose values differ, as (field, left, right)
+fn differing_fields<'a>(
+    left: &'a Snapshot,
+    right: &'a Snapshot,
+) -> Vec<(&'static str, &'a String, &'a String)> {
+    left.iter()
+        .zip(right)
+        .filter(|((_, a), (_, b))| a != b)
+        .map(|((field, a), (_, b))| (*field, a, b))
+        .collect()
+}
+
+async fn collect_snapshot(target: &DiffTarget, probe_timeout: Duration) -> Result<Snapshot> {
+    let (client, host_name) = crate::resolve_docker_client(target.host.as_deref()).await?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| format_docker_error_anyhow(&e))?;
+
+    let container_name = resource_names_for_instance(target.instance.as_deref()).container_name;
+    let info = match client.inspect_container(&container_name).await {
+        Ok(info) => info,
+        Err(BollardError::DockerResponseServerError {
+            status_code: 404, ..
+        }) => return Ok(missing_snapshot()),
+        Err(e) => {
+            return Err(anyhow!(
+                "Failed to inspect {container_name} on {}: {e}",
+                target.label()
+            ));
+        }
+    };
+
+    let running = info.state.as_ref().and_then(|s| s.running).unwrap_or(false);
+    let image = info
+        .config
+        .as_ref()
+        .and_then(|c| c.image.clone())
+        .unwrap_or_else(|| "unknown".to_string());
+    let (bind_ip, host_port) = web_port_binding(&info);
+
+    let view = if running {
+        let probe_addr = resolve_remote_addr(host_name.as_deref())
+            .unwrap_or_else(|| normalize_bind_addr(&bind_ip).to_string());
+        let backend = HostBackend::for_container(&client, &container_name);
+        let port = host_port.parse().unwrap_or(0);
+        Some(collect_status_view(&backend, true, &probe_addr, port, probe_timeout).await?)
+    } else {
+        None
+    };
+    let image_version = match &view {
+        Some(view) => view.image_version.clone(),
+        None => get_image_version(&client, &image)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| "unknown".to_string()),
+    };
+    let users = if running {
+        match list_users(&client, &container_name).await {
+            Ok(users) => {
+                let mut names: Vec<_> = users.into_iter().map(|u| u.username).collect();
+                names.sort();
+                if names.is_empty() {
+                    "none".to_string()
+                } else {
+                    names.join(", ")
+                }
+            }
+            Err(_) => "unknown".to_string(),
+        }
+    } else {
+        NOT_APPLICABLE.to_string()
+    };
+
+    let from_view = |field: fn(&StatusViewModel) -> String| {
+        view.as_ref()
+            .map(field)
+            .unwrap_or_else(|| NOT_APPLICABLE.to_string())
+    };
+
+    let values = [
+        container_state(&info),
+        image,
+        image_version,
+        from_view(|v| v.opencode_version.clone()),
+        from_view(|v| v.opencode_commit.clone()),
+        from_view(|v| {
+            v.opencode_health
+                .map(health_history_status)
+                .unwrap_or_else(|| NOT_APPLICABLE.to_string())
+        }),
+        from_view(|v| broker_health_text(v.broker_health)),
+        bind_ip,
+        host_port,
+        users,
+        mounts_text(&info),
+    ];
+    Ok(FIELDS.into_iter().zip(values).collect())
+}
+
+fn missing_snapshot() -> Snapshot {
+    FIELDS
+        .into_iter()
+        .map(|field| {
+            let value = if field == "State" {
+                "not created"
+            } else {
+                NOT_APPLICABLE
+            };
+            (field, value.to_string())
+        })
+        .collect()
+}
+
+fn container_state(info: &ContainerInspectResponse) -> String {
+    info.state
+        .as_ref()
+        .and_then(|s| s.status.as_ref())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Host IP and port published for the opencode web port
+pub(crate) fn web_port_binding(info: &ContainerInspectResponse) -> (String, String) {
+    let binding = info
+        .host_config
+        .as_ref()
+        .and_then(|hc| hc.port_bindings.as_ref())
+        .and_then(|ports| ports.get("3000/tcp"))
+        .and_then(|bindings| bindings.as_ref())
+        .and_then(|bindings| bindings.first());
+    let ip = binding
+        .and_then(|b| b.host_ip.clone())
+        .filter(|ip| !ip.is_empty())
+        .unwrap_or_else(|| "0.0.0.0".to_string());
+    let port = binding
+        .and_then(|b| b.host_port.clone())
+        .unwrap_or_else(|| NOT_APPLICABLE.to_string());
+    (ip, port)
+}
+
+/// Sorted `source:target[:ro]` list of the container's mounts
+fn mounts_text(info: &ContainerInspectResponse) -> String {
+    let mut mounts: Vec<String> = info
+        .host_config
+        .as_ref()
+        .and_then(|hc| hc.mounts.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|mount| {
+            let source = mount.source.as_deref().unwrap_or("?");
+            let target = mount.target.as_deref().unwrap_or("?");
+            let ro = if mount.read_only.unwrap_or(false) {
+                ":ro"
+            } else {
+                ""
+            };
+            format!("{source}:{target}{ro}")
+        })
+        .collect();
+    mounts.sort();
+    if mounts.is_empty() {
+        "none".to_string()
+    } else {
+        mounts.join("\n")
+    }
+}
+
+fn broker_health_text(status: BrokerHealthStatus) -> String {
+    match status {
+        BrokerHealthStatus::Healthy => "healthy",
+        BrokerHealthStatus::Degraded => "degraded",
+        BrokerHealthStatus::Unhealthy => "unhealthy",
+        BrokerHealthStatus::CheckFailed => "check failed",
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current() -> DiffTarget {
+        DiffTarget {
+            host: None,
+            instance: Some("wt-a".to_string()),
+        }
+    }
+
+    #[test]
+    fn parse_diff_target_prefers_hosts_then_instances() {
+        let known = |name: &str| name == "prod";
+
+        let host = parse_diff_target("prod", &current(), known).unwrap();
+        assert_eq!(host.host.as_deref(), Some("prod"));
+        assert_eq!(host.instance.as_deref(), Some("wt-a"));
+
+        let instance = parse_diff_target("wt-b", &current(), known).unwrap();
+        assert_eq!(instance.host, None);
+        assert_eq!(instance.instance.as_deref(), Some("wt-b"));
+
+        let shared = parse_diff_target("instance:default", &current(), known).unwrap();
+        assert_eq!(shared.instance, None);
+
+        assert!(parse_diff_target("host:staging", &current(), known).is_err());
+        assert!(parse_diff_target("Bad_Name", &current(), known).is_err());
+        assert_eq!(shared.label(), "local");
+        assert_eq!(host.label(), "prod/wt-a");
+    }
+
+    #[test]
+    fn differing_fields_keeps_only_changed_values() {
+        let left: Snapshot = vec![("State", "running".into()), ("Image", "a".into())];
+        let right: Snapshot = vec![("State", "running".into()), ("Image", "b".into())];
+        let rows = differing_fields(&left, &right);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].0, "Image");
+        assert!(differing_fields(&left, &left).is_empty());
+        assert_eq!(missing_snapshot().len(), FIELDS.len());
+    }
+}
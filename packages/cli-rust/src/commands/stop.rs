@@ -2,26 +2,36 @@
 //!
 //! Stops the opencode service with a graceful timeout.
 //! Docker sends SIGTERM first, then SIGKILL if timeout expires.
+//! With `--all-instances`, stops every sandbox instance container instead.
 
-use crate::commands::service::{StopSpinnerMessages, stop_service_with_spinner};
-use crate::output::format_docker_error;
-use anyhow::{Result, anyhow};
+use crate::commands::service::{
+    StopSpinnerMessages, resolve_stop_timeout_secs, stop_service_with_spinner,
+};
+use crate::error::CliError;
+use crate::output::{CommandSpinner, format_docker_error_anyhow};
+use anyhow::Result;
 use clap::Args;
 use console::style;
+use opencode_cloud_core::bollard::models::ContainerSummary;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DEFAULT_STOP_TIMEOUT_SECS, container_exists, container_is_running,
+    CONTAINER_NAME, DockerClient, INSTANCE_LABEL_KEY, container_exists, container_is_running,
+    list_instance_containers, remove_container, resource_names_for_instance, stop_container,
 };
 
 /// Arguments for the stop command
 #[derive(Args, Default)]
 pub struct StopArgs {
-    /// Graceful shutdown timeout in seconds (default: 30)
-    #[arg(long, short, default_value_t = DEFAULT_STOP_TIMEOUT_SECS)]
-    pub timeout: i64,
+    /// Graceful shutdown timeout in seconds (default: stop_timeout_seconds from config, 30)
+    #[arg(long, short, value_parser = clap::value_parser!(i64).range(1..))]
+    pub timeout: Option<i64>,
 
     /// Remove the container after stopping
     #[arg(long)]
     pub remove: bool,
+
+    /// Stop every sandbox instance container instead of the active one
+    #[arg(long)]
+    pub all_instances: bool,
 }
 
 /// Stop the opencode service
@@ -29,16 +39,41 @@ pub struct StopArgs {
 /// This command:
 /// 1. Connects to Docker
 /// 2. Checks if service is running (idempotent - exits 0 if already stopped)
-/// 3. Stops the container with graceful timeout (default 30s)
-pub async fn cmd_stop(args: &StopArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+/// 3. Stops the container with graceful timeout (--timeout, config, or 30s)
+pub async fn cmd_stop(
+    args: &StopArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
     // Resolve Docker client (local or remote)
     let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
 
     // Verify connection
-    client.verify_connection().await.map_err(|e| {
-        let msg = format_docker_error(&e);
-        anyhow!("{msg}")
-    })?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| format_docker_error_anyhow(&e))?;
+
+    let timeout_secs = resolve_stop_timeout_secs(args.timeout);
+    if verbose > 0 {
+        eprintln!(
+            "{} Graceful stop timeout: {}s",
+            style("[info]").cyan(),
+            timeout_secs
+        );
+    }
+
+    if args.all_instances {
+        return stop_all_instances(
+            &client,
+            args.remove,
+            timeout_secs,
+            host_name.as_deref(),
+            quiet,
+        )
+        .await;
+    }
 
     let is_running = container_is_running(&client, CONTAINER_NAME).await?;
     let exists = container_exists(&client, CONTAINER_NAME).await?;
@@ -51,7 +86,7 @@ pub async fn cmd_stop(args: &StopArgs, maybe_host: Option<&str>, quiet: bool) ->
         host_name.as_deref(),
         quiet,
         args.remove,
-        args.timeout,
+        timeout_secs,
         StopSpinnerMessages {
             action_message: "Stopping service...",
             update_label: "Stopping service",
@@ -64,6 +99,94 @@ pub async fn cmd_stop(args: &StopArgs, maybe_host: Option<&str>, quiet: bool) ->
     Ok(())
 }
 
+/// Stop (and optionally remove) each sandbox instance container, reporting per instance
+async fn stop_all_instances(
+    client: &DockerClient,
+    remove: bool,
+    timeout_secs: i64,
+    host_name: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let containers = list_instance_containers(client)
+        .await
+        .map_err(|e| format_docker_error_anyhow(&e))?;
+    let instances = managed_instances(&containers);
+
+    if instances.is_empty() {
+        print_dimmed_status(quiet, host_name, "No sandbox instances found");
+        return Ok(());
+    }
+
+    let mut failed = Vec::new();
+    for (instance_id, container_name) in &instances {
+        let msg = crate::format_host_message(
+            host_name,
+            &format!("Stopping sandbox instance {instance_id}..."),
+        );
+        let spinner = CommandSpinner::new_maybe(&msg, quiet);
+        match stop_instance(client, container_name, remove, timeout_secs).await {
+            Ok(outcome) => spinner.success(&format!("{instance_id}: {outcome}")),
+            Err(e) => {
+                spinner.fail(&format!("{instance_id}: {e}"));
+                failed.push(instance_id.clone());
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(CliError::Failed(format!(
+            "Failed to stop {} of {} sandbox instance(s): {}",
+            failed.len(),
+            instances.len(),
+            failed.join(", ")
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Stop (and optionally remove) one instance container, describing what happened
+async fn stop_instance(
+    client: &DockerClient,
+    container_name: &str,
+    remove: bool,
+    timeout_secs: i64,
+) -> Result<&'static str> {
+    let was_running = container_is_running(client, container_name).await?;
+    if was_running {
+        stop_container(client, container_name, Some(timeout_secs)).await?;
+    }
+    if remove {
+        remove_container(client, container_name, false).await?;
+    }
+    Ok(match (was_running, remove) {
+        (true, true) => "stopped and removed",
+        (true, false) => "stopped",
+        (false, true) => "removed",
+        (false, false) => "already stopped",
+    })
+}
+
+/// `(instance id, container name)` for labeled containers whose name matches
+/// the name occ derives for that instance, so unrelated containers are never touched
+fn managed_instances(containers: &[ContainerSummary]) -> Vec<(String, String)> {
+    let mut instances: Vec<_> = containers
+        .iter()
+        .filter_map(|container| {
+            let instance_id = container.labels.as_ref()?.get(INSTANCE_LABEL_KEY)?;
+            let expected = resource_names_for_instance(Some(instance_id)).container_name;
+            container
+                .names
+                .as_ref()?
+                .iter()
+                .any(|name| name.trim_start_matches('/') == expected)
+                .then(|| (instance_id.clone(), expected))
+        })
+        .collect();
+    instances.sort();
+    instances
+}
+
 fn handle_not_running(
     exists: bool,
     remove: bool,
@@ -103,3 +226,41 @@ fn stop_success_message(remove: bool) -> &'static str {
         "Service stopped"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn summary(name: &str, instance_id: Option<&str>) -> ContainerSummary {
+        ContainerSummary {
+            names: Some(vec![format!("/{name}")]),
+            labels: instance_id
+                .map(|id| HashMap::from([(INSTANCE_LABEL_KEY.to_string(), id.to_string())])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn managed_instances_skip_unrelated_containers() {
+        let containers = [
+            summary("opencode-cloud-sandbox-wt-b", Some("wt-b")),
+            summary("opencode-cloud-sandbox-wt-a", Some("wt-a")),
+            summary("postgres", Some("wt-c")),
+            summary("redis", None),
+        ];
+        assert_eq!(
+            managed_instances(&containers),
+            vec![
+                (
+                    "wt-a".to_string(),
+                    "opencode-cloud-sandbox-wt-a".to_string()
+                ),
+                (
+                    "wt-b".to_string(),
+                    "opencode-cloud-sandbox-wt-b".to_string()
+                ),
+            ]
+        );
+    }
+}
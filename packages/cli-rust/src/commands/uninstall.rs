@@ -3,11 +3,12 @@
 //! Removes the opencode-cloud service registration from the platform's
 //! service manager (systemd on Linux, launchd on macOS).
 
+use crate::confirm::{confirm, skip_confirmation};
+use crate::error::CliError;
 use crate::output::CommandSpinner;
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, bail};
 use clap::Args;
 use console::style;
-use dialoguer::Confirm;
 use opencode_cloud_core::config::load_config_or_default;
 use opencode_cloud_core::config::paths::{get_config_dir, get_data_dir};
 use opencode_cloud_core::docker::{
@@ -15,6 +16,7 @@ use opencode_cloud_core::docker::{
     stop_container,
 };
 use opencode_cloud_core::platform::{get_service_manager, is_service_registration_supported};
+use serde::Serialize;
 
 /// Arguments for the uninstall command
 #[derive(Args)]
@@ -26,6 +28,34 @@ pub struct UninstallArgs {
     /// Skip confirmation prompts
     #[arg(long)]
     force: bool,
+
+    /// Skip the confirmation prompt (does not allow --volumes; use --force for that)
+    #[arg(short, long)]
+    yes: bool,
+
+    /// Print a JSON summary of what was removed instead of progress output
+    #[arg(long)]
+    report: bool,
+}
+
+/// JSON summary printed by `occ uninstall --report`
+#[derive(Debug, Default, Serialize)]
+struct UninstallReport {
+    /// Whether a service registration was found
+    installed: bool,
+    /// Whether the registration was removed by this run
+    removed: bool,
+    /// Path of the unit file or plist
+    #[serde(skip_serializing_if = "Option::is_none")]
+    service_file: Option<String>,
+    /// Whether the service was enabled before removal (unknown if the manager could not say)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    was_enabled: Option<bool>,
+    /// Whether the service was running before removal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    was_active: Option<bool>,
+    volumes_removed: bool,
+    errors: Vec<String>,
 }
 
 /// Remove the service registration from the platform's service manager
@@ -36,6 +66,8 @@ pub struct UninstallArgs {
 /// 3. Optionally removes Docker volumes (with --volumes --force)
 ///
 /// The command is idempotent - exits 0 if service is not installed.
+/// With --report, a JSON summary is printed instead and any failure is
+/// recorded in it before exiting non-zero.
 pub async fn cmd_uninstall(args: &UninstallArgs, quiet: bool, _verbose: u8) -> Result<()> {
     // 1. Validate --volumes requires --force
     if args.volumes && !args.force {
@@ -53,11 +85,36 @@ pub async fn cmd_uninstall(args: &UninstallArgs, quiet: bool, _verbose: u8) -> R
         ));
     }
 
+    let mut report = UninstallReport::default();
+    let result = uninstall_service(args, quiet || args.report, &mut report).await;
+
+    if args.report {
+        if let Err(e) = &result {
+            report.errors.push(e.to_string());
+        }
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        if !report.errors.is_empty() {
+            return Err(CliError::Failed(
+                "Uninstall finished with errors (see report)".to_string(),
+            )
+            .into());
+        }
+        return Ok(());
+    }
+    result
+}
+
+async fn uninstall_service(
+    args: &UninstallArgs,
+    quiet: bool,
+    report: &mut UninstallReport,
+) -> Result<()> {
     // 3. Load config to determine boot mode
     let config = load_config_or_default()?;
 
     // 4. Get service manager with correct boot_mode
     let manager = get_service_manager(&config.boot_mode)?;
+    report.service_file = Some(manager.service_file_path().display().to_string());
 
     // 5. Check if installed
     if !manager.is_installed()? {
@@ -66,21 +123,36 @@ pub async fn cmd_uninstall(args: &UninstallArgs, quiet: bool, _verbose: u8) -> R
         }
         return Ok(()); // Exit 0 - idempotent
     }
+    report.installed = true;
+
+    // 6. Confirm uninstallation (unless --force, --yes, or OPENCODE_CLOUD_ASSUME_YES)
+    // Errors here land in report.errors, so a cancelled --report run exits non-zero
+    if !skip_confirmation(args.force || args.yes) {
+        if quiet {
+            bail!(
+                "Uninstall needs confirmation, which --quiet and --report cannot prompt for.\n\
+                 Run: occ uninstall --yes"
+            );
+        }
+        let confirmed = confirm(
+            "This will remove the service registration. Continue?",
+            false,
+            quiet,
+        )?;
+
+        if !confirmed {
+            return Err(CliError::Failed("Uninstall cancelled.".to_string()).into());
+        }
+    }
 
-    // 6. Confirm uninstallation (unless --force)
-    if !args.force {
-        let confirm = Confirm::new()
-            .with_prompt("This will remove the service registration. Continue?")
-            .default(false)
-            .interact()
-            .unwrap_or(false);
-
-        if !confirm {
-            if !quiet {
-                println!("Cancelled.");
-            }
-            return Ok(());
+    match manager.status() {
+        Ok(status) => {
+            report.was_enabled = Some(status.enabled);
+            report.was_active = Some(status.active);
         }
+        Err(e) => report
+            .errors
+            .push(format!("Failed to query service state: {e}")),
     }
 
     // 7. Stop container if running (using existing stop logic)
@@ -91,21 +163,26 @@ pub async fn cmd_uninstall(args: &UninstallArgs, quiet: bool, _verbose: u8) -> R
 
     // 8. Uninstall service registration
     let spinner = CommandSpinner::new_maybe("Removing service registration...", quiet);
-    let service_file = manager.service_file_path();
-    manager.uninstall()?;
+    let removed = manager.uninstall()?;
+    report.removed = true;
+    report.service_file = Some(removed.service_file_path.display().to_string());
     spinner.success("Service registration removed");
 
     // 9. Optionally remove volumes
     if args.volumes {
         let spinner = CommandSpinner::new_maybe("Removing Docker volumes...", quiet);
         remove_volumes().await?;
+        report.volumes_removed = true;
         spinner.success("Docker volumes removed");
     }
 
     // 10. Print what was removed
     if !quiet {
         println!();
-        println!("Removed: {}", style(service_file.display()).dim());
+        println!(
+            "Removed: {}",
+            style(removed.service_file_path.display()).dim()
+        );
         if args.volumes {
             println!("Removed: Docker volumes (all data deleted)");
         }
@@ -9,23 +9,31 @@ use crate::commands::disk_usage::{
     format_bytes_i64, format_disk_usage_report, format_host_disk_report, get_disk_usage_report,
     get_host_disk_report,
 };
+use crate::commands::runtime_shared::env::container_env_vars;
+use crate::commands::start::{print_build_args, print_pull_concurrency_hint};
 use crate::commands::{RestartArgs, cmd_restart};
+use crate::confirm::{confirm, skip_confirmation};
 use crate::constants::COCKPIT_EXPOSED;
+use crate::error::CliError;
 use crate::output::{CommandSpinner, format_service_url};
 use anyhow::{Result, anyhow};
 use clap::{Args, Subcommand};
+use comfy_table::{Cell, Color, Table};
 use console::style;
-use dialoguer::{Confirm, MultiSelect};
-use opencode_cloud_core::config::load_config_or_default;
+use dialoguer::MultiSelect;
 use opencode_cloud_core::docker::update::tag_current_as_previous;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DockerClient, DockerError, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT, ImageState,
-    ProgressReporter, active_resource_names, build_image, container_exists, container_is_running,
-    docker_supports_systemd, exec_command, exec_command_with_status, get_cli_version,
-    get_image_version, get_registry_latest_version, has_previous_image, image_exists, pull_image,
-    rollback_image, save_state, setup_and_start, stop_service,
+    BuildLogOptions, CONTAINER_NAME, DockerClient, DockerError, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT,
+    ImageState, OpencodeState, ProgressReporter, PullOptions, PullRegistry, active_resource_names,
+    build_image, cached_version, container_exists, container_is_running, docker_supports_systemd,
+    exec_command, exec_command_with_status, get_cli_version, get_image_version,
+    get_registry_latest_version_cached, image_exists, list_previous_images, load_opencode_state,
+    pull_image, resolve_rollback_tag, rollback_to_image, save_opencode_state, save_state,
+    setup_and_start, stop_service, store_version,
 };
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::process::Command;
 use tokio::time::{Duration, sleep};
 
@@ -40,9 +48,37 @@ pub struct UpdateArgs {
     #[arg(long)]
     pub rollback: bool,
 
+    /// List the images kept for rollback instead of restoring one
+    #[arg(long, requires = "rollback", conflicts_with = "to")]
+    pub list: bool,
+
+    /// Restore this image (a tag from --list such as previous-2, or its generation number)
+    #[arg(long, requires = "rollback", value_name = "TAG")]
+    pub to: Option<String>,
+
     /// Skip confirmation prompt
     #[arg(short, long)]
     pub yes: bool,
+
+    /// Ignore cached registry and GitHub version lookups
+    #[arg(long, global = true)]
+    pub refresh: bool,
+
+    /// Build the container image without the Docker layer cache (image_source=build only)
+    #[arg(long, global = true)]
+    pub no_cache: bool,
+
+    /// Show what would be updated without changing anything
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Write the full build output here if the image build fails (image_source=build only)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub save_build_log: Option<PathBuf>,
+
+    /// Pull only from this registry (ghcr or dockerhub) instead of GHCR with Docker Hub fallback
+    #[arg(long, global = true, value_name = "REGISTRY")]
+    pub registry: Option<PullRegistry>,
 }
 
 #[derive(Subcommand)]
@@ -61,6 +97,10 @@ pub struct UpdateCliArgs {
     /// Skip confirmation prompt
     #[arg(short, long)]
     pub yes: bool,
+
+    /// Only report the current and latest versions; change nothing
+    #[arg(long, conflicts_with = "yes")]
+    pub check: bool,
 }
 
 /// Arguments for updating opencode inside the container
@@ -74,6 +114,10 @@ pub struct UpdateOpencodeArgs {
     #[arg(long, conflicts_with = "branch")]
     pub commit: Option<String>,
 
+    /// Rebuild the commit that was running before the last opencode update (local service only)
+    #[arg(long, conflicts_with_all = ["branch", "commit"])]
+    pub rollback: bool,
+
     /// Skip confirmation prompt
     #[arg(short, long)]
     pub yes: bool,
@@ -161,10 +205,11 @@ pub async fn cmd_update(
 ) -> Result<()> {
     match args.command.as_ref() {
         Some(UpdateCommand::Cli(cli_args)) => {
-            return cmd_update_cli(cli_args, maybe_host, quiet, verbose).await;
+            return cmd_update_cli(cli_args, args.dry_run, maybe_host, quiet, verbose).await;
         }
         Some(UpdateCommand::Opencode(opencode_args)) => {
-            return cmd_update_opencode(opencode_args, maybe_host, quiet, verbose).await;
+            return cmd_update_opencode(opencode_args, args.dry_run, maybe_host, quiet, verbose)
+                .await;
         }
         Some(UpdateCommand::Container) => {}
         None => {
@@ -189,33 +234,28 @@ pub async fn cmd_update(
     client
         .verify_connection()
         .await
-        .map_err(|e| anyhow!("Docker connection error: {e}"))?;
+        .map_err(|e| CliError::docker(&e, format!("Docker connection error: {e}")))?;
 
     // Load config
-    let config = load_config_or_default()?;
+    let config = crate::load_config_for_host(host_name.as_deref())?;
 
-    if args.rollback {
+    if args.rollback && args.list {
+        print_rollback_images(&client, &config, quiet).await
+    } else if args.rollback {
         // Rollback flow
         handle_rollback(
             &client,
             &config,
+            args.to.as_deref(),
             args.yes,
+            args.dry_run,
             quiet,
             verbose,
-            host_name.as_deref(),
         )
         .await
     } else {
         // Update flow
-        handle_update(
-            &client,
-            &config,
-            args.yes,
-            quiet,
-            verbose,
-            host_name.as_deref(),
-        )
-        .await
+        handle_update(&client, &config, args, quiet, verbose, host_name.as_deref()).await
     }
 }
 
@@ -228,9 +268,9 @@ async fn cmd_update_selector(
     let spinner = CommandSpinner::new_maybe("Checking for updates...", quiet);
     let cli_label = cli_platform_label();
     spinner.update(&format!("Checking {cli_label} version..."));
-    let cli_candidate = build_cli_candidate();
+    let cli_candidate = build_cli_candidate().await;
 
-    let (config, config_note) = load_update_config();
+    let (config, config_note) = load_update_config(maybe_host);
     let (docker_client, docker_note) =
         resolve_update_docker(config.is_some(), maybe_host, verbose).await;
 
@@ -240,17 +280,19 @@ async fn cmd_update_selector(
         docker_client.as_ref(),
         config_note.as_deref(),
         docker_note.as_deref(),
+        args.refresh,
     )
     .await;
 
     spinner.update("Checking opencode commit...");
-    let (latest_opencode_commit, opencode_note) = match fetch_latest_opencode_commit().await {
-        Ok(commit) => (Some(commit), None),
-        Err(err) => (
-            None,
-            Some(format!("Failed to fetch latest opencode commit: {err}")),
-        ),
-    };
+    let (latest_opencode_commit, opencode_note) =
+        match fetch_latest_opencode_commit(args.refresh).await {
+            Ok(commit) => (Some(commit), None),
+            Err(err) => (
+                None,
+                Some(format!("Failed to fetch latest opencode commit: {err}")),
+            ),
+        };
     let opencode_candidate = build_opencode_candidate(
         config.is_some(),
         docker_client.as_ref(),
@@ -271,7 +313,7 @@ async fn cmd_update_selector(
         return Ok(());
     }
 
-    if !confirm_update_selection(args)? {
+    if !args.dry_run && !confirm_update_selection(args, quiet)? {
         if !quiet {
             eprintln!("Update cancelled.");
         }
@@ -291,7 +333,7 @@ async fn cmd_update_selector(
     .await
 }
 
-fn build_cli_candidate() -> UpdateCandidate {
+async fn build_cli_candidate() -> UpdateCandidate {
     let current_cli = get_cli_version().to_string();
     let mut available = false;
     let mut selectable = true;
@@ -306,7 +348,7 @@ fn build_cli_candidate() -> UpdateCandidate {
                 .to_string(),
         );
     } else if let Some(install_method) = detect_install_method() {
-        let target_version = get_target_cli_version(&install_method);
+        let target_version = get_target_cli_version(&install_method).await;
         match target_version.as_ref() {
             Some(target) if target.target == current_cli => {
                 available = false;
@@ -345,8 +387,10 @@ fn build_cli_candidate() -> UpdateCandidate {
     }
 }
 
-fn load_update_config() -> (Option<opencode_cloud_core::config::Config>, Option<String>) {
-    match load_config_or_default() {
+fn load_update_config(
+    maybe_host: Option<&str>,
+) -> (Option<opencode_cloud_core::config::Config>, Option<String>) {
+    match crate::load_config_for_host(maybe_host) {
         Ok(config) => (Some(config), None),
         Err(err) => (None, Some(format!("Failed to load config: {err}"))),
     }
@@ -385,6 +429,7 @@ async fn build_container_candidate(
     client: Option<&DockerClient>,
     config_note: Option<&str>,
     docker_note: Option<&str>,
+    refresh: bool,
 ) -> UpdateCandidate {
     let Some(config) = config else {
         return UpdateCandidate {
@@ -451,7 +496,7 @@ async fn build_container_candidate(
     let maybe_registry_version = if use_build {
         None
     } else {
-        match get_registry_latest_version(client).await {
+        match get_registry_latest_version_cached(client, refresh).await {
             Ok(version) => version,
             Err(err) => {
                 note = Some(format!("Failed to fetch registry version: {err}"));
@@ -617,7 +662,8 @@ fn select_update_targets(
         return Ok(Vec::new());
     }
 
-    let selected_targets: Vec<UpdateTarget> = if args.yes {
+    // Every candidate is selected by default, which is what non-interactive runs get
+    let selected_targets: Vec<UpdateTarget> = if skip_confirmation(args.yes) || quiet {
         selectable_candidates
             .iter()
             .map(|candidate| candidate.target)
@@ -646,16 +692,12 @@ fn select_update_targets(
     Ok(selected_targets)
 }
 
-fn confirm_update_selection(args: &UpdateArgs) -> Result<bool> {
+fn confirm_update_selection(args: &UpdateArgs, quiet: bool) -> Result<bool> {
     if args.yes {
         return Ok(true);
     }
 
-    let confirmed = Confirm::new()
-        .with_prompt("Proceed with selected updates?")
-        .default(true)
-        .interact()?;
-    Ok(confirmed)
+    confirm("Proceed with selected updates?", true, quiet)
 }
 
 struct UpdateSelection {
@@ -692,8 +734,11 @@ async fn run_selected_updates(
     verbose: u8,
 ) -> Result<()> {
     if selection.cli {
-        let cli_args = UpdateCliArgs { yes: args.yes };
-        cmd_update_cli(&cli_args, maybe_host, quiet, verbose).await?;
+        let cli_args = UpdateCliArgs {
+            yes: args.yes,
+            check: false,
+        };
+        cmd_update_cli(&cli_args, args.dry_run, maybe_host, quiet, verbose).await?;
     }
 
     if selection.container {
@@ -703,16 +748,17 @@ async fn run_selected_updates(
         let Some(client) = docker_client else {
             return Err(anyhow!("Docker is unavailable; cannot update container."));
         };
-        handle_update(client, config, args.yes, quiet, verbose, None).await?;
+        handle_update(client, config, args, quiet, verbose, None).await?;
     }
 
     if selection.opencode {
         let opencode_args = UpdateOpencodeArgs {
             branch: None,
             commit: None,
+            rollback: false,
             yes: args.yes,
         };
-        cmd_update_opencode(&opencode_args, maybe_host, quiet, verbose).await?;
+        cmd_update_opencode(&opencode_args, args.dry_run, maybe_host, quiet, verbose).await?;
     }
 
     Ok(())
@@ -721,10 +767,71 @@ async fn run_selected_updates(
 #[cfg(test)]
 mod tests {
     use super::{
-        container_not_running_update_error, ensure_in_place_opencode_update_supported,
-        non_systemd_in_place_update_error, parse_cargo_info_versions,
+        CHANGELOG_MAX_COMMITS, CliTargetVersion, GithubCommitDetail, GithubCompareCommit,
+        GithubCompareResponse, cli_check_lines, container_not_running_update_error,
+        container_update_plan, disk_estimate_line, ensure_in_place_opencode_update_supported,
+        format_changelog_lines, non_systemd_in_place_update_error, parse_cargo_info_versions,
     };
 
+    fn compare_with(count: usize, total_commits: usize) -> GithubCompareResponse {
+        GithubCompareResponse {
+            total_commits,
+            commits: (0..count)
+                .map(|i| GithubCompareCommit {
+                    sha: format!("{i:040}"),
+                    commit: GithubCommitDetail {
+                        message: format!("commit {i}\n\nbody"),
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn changelog_lists_newest_first_with_subjects_only() {
+        let lines = format_changelog_lines(&compare_with(2, 2));
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("commit 1"));
+        assert!(lines[1].ends_with("commit 0"));
+    }
+
+    #[test]
+    fn changelog_is_capped_with_remaining_count() {
+        let lines = format_changelog_lines(&compare_with(30, 45));
+        assert_eq!(lines.len(), CHANGELOG_MAX_COMMITS + 1);
+        assert_eq!(lines.last().unwrap(), "... and 25 more");
+    }
+
+    #[test]
+    fn container_update_plan_reflects_image_source() {
+        let pull = container_update_plan(false, false, Some("1.2.3"), true);
+        assert!(pull.iter().any(|step| step.contains("(version 1.2.3)")));
+        assert!(pull.iter().any(|step| step.contains("brief downtime")));
+
+        let build = container_update_plan(true, true, None, true);
+        assert!(
+            build
+                .iter()
+                .any(|step| step.contains("without the layer cache"))
+        );
+        assert!(
+            build
+                .iter()
+                .any(|step| step.contains("lasts for the build"))
+        );
+
+        let fresh = container_update_plan(false, false, None, false);
+        assert!(fresh.iter().any(|step| step.contains("no running service")));
+    }
+
+    #[test]
+    fn disk_estimate_needs_usage_and_image_size() {
+        assert!(disk_estimate_line(None, Some(1)).is_none());
+        assert!(disk_estimate_line(Some(1), None).is_none());
+        let line = disk_estimate_line(Some(1024), Some(1024)).unwrap();
+        assert!(line.contains("up to"));
+    }
+
     #[test]
     fn parse_cargo_info_versions_latest() {
         let output = "name: opencode-cloud\nversion: 10.4.0 (latest 11.0.0)\n";
@@ -733,6 +840,31 @@ mod tests {
         assert_eq!(parsed.compatible.as_deref(), Some("10.4.0"));
     }
 
+    #[test]
+    fn cli_check_lines_report_update_and_toolchain_note() {
+        let strip = |lines: Vec<String>| -> Vec<String> {
+            lines
+                .iter()
+                .map(|line| console::strip_ansi_codes(line).to_string())
+                .collect()
+        };
+        let target = CliTargetVersion {
+            target: "11.0.0".to_string(),
+            compatible: Some("10.4.0".to_string()),
+        };
+        let lines = strip(cli_check_lines("cargo", "10.3.0", Some(&target)));
+        assert!(lines.contains(&"Current:    v10.3.0".to_string()));
+        assert!(lines.contains(&"Latest:     v11.0.0".to_string()));
+        assert!(lines.iter().any(|line| line.contains("Update available")));
+        assert!(lines.iter().any(|line| line.contains("v10.4.0")));
+
+        let lines = strip(cli_check_lines("npm", "11.0.0", Some(&target)));
+        assert!(lines.last().unwrap().contains("Up to date"));
+
+        let lines = strip(cli_check_lines("npm", "11.0.0", None));
+        assert!(lines.iter().any(|line| line.contains("unknown")));
+    }
+
     #[test]
     fn parse_cargo_info_versions_plain() {
         let output = "name: opencode-cloud\nversion: 11.0.0\n";
@@ -792,6 +924,7 @@ mod tests {
 
 async fn cmd_update_cli(
     args: &UpdateCliArgs,
+    dry_run: bool,
     maybe_host: Option<&str>,
     quiet: bool,
     verbose: u8,
@@ -828,7 +961,17 @@ async fn cmd_update_cli(
     };
 
     let current_version = get_cli_version();
-    let maybe_target_version = get_target_cli_version(&install_method);
+    let maybe_target_version = get_target_cli_version(&install_method).await;
+    if args.check {
+        for line in cli_check_lines(
+            install_method.label(),
+            current_version,
+            maybe_target_version.as_ref(),
+        ) {
+            println!("{line}");
+        }
+        return Ok(());
+    }
     if let Some(target_version) = maybe_target_version.as_ref()
         && target_version.target == current_version
     {
@@ -873,11 +1016,35 @@ async fn cmd_update_cli(
         eprintln!();
     }
 
+    if dry_run {
+        let target = maybe_target_version
+            .as_ref()
+            .map(|info| info.target.as_str());
+        let action = match install_method.update_command(target) {
+            Some((program, update_args)) => {
+                format!("Would run: {program} {}", update_args.join(" "))
+            }
+            None => format!(
+                "Would download the {} release binary, check it against the published checksum, and replace {}",
+                target.map_or_else(|| "latest".to_string(), |v| format!("v{v}")),
+                std::env::current_exe()
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_else(|_| "the current executable".to_string())
+            ),
+        };
+        print_dry_run_plan(&[
+            action,
+            "Would restart the service (brief downtime)".to_string(),
+        ]);
+        return Ok(());
+    }
+
     if !args.yes {
-        let confirmed = Confirm::new()
-            .with_prompt(format!("Continue with opencode-cloud {cli_label} update?"))
-            .default(true)
-            .interact()?;
+        let confirmed = confirm(
+            format!("Continue with opencode-cloud {cli_label} update?"),
+            true,
+            quiet,
+        )?;
 
         if !confirmed {
             if !quiet {
@@ -894,10 +1061,11 @@ async fn cmd_update_cli(
         .map(|info| info.target.as_str());
     install_method
         .run_update(target_version)
+        .await
         .map_err(|e| anyhow!("{e}"))?;
     spinner.success(&format!("opencode-cloud {cli_label} updated"));
 
-    let restart_args = RestartArgs {};
+    let restart_args = RestartArgs::default();
     cmd_restart(&restart_args, maybe_host, quiet, verbose).await?;
 
     if !quiet {
@@ -912,10 +1080,58 @@ async fn cmd_update_cli(
     Ok(())
 }
 
-fn get_target_cli_version(install_method: &CliInstallMethod) -> Option<CliTargetVersion> {
+/// Report for `occ update cli --check`
+fn cli_check_lines(
+    install_label: &str,
+    current_version: &str,
+    maybe_target: Option<&CliTargetVersion>,
+) -> Vec<String> {
+    let mut lines = vec![
+        format!("Install:    {install_label}"),
+        format!("Current:    v{current_version}"),
+    ];
+    let Some(target) = maybe_target else {
+        lines.push(format!("Latest:     {}", style("unknown").dim()));
+        lines.push(format!(
+            "{} Could not look up the latest version with {install_label}.",
+            style("Note:").yellow()
+        ));
+        return lines;
+    };
+
+    lines.push(format!("Latest:     v{}", target.target));
+    if target.target == current_version {
+        lines.push(format!("{} Up to date.", style("✓").green()));
+        return lines;
+    }
+
+    lines.push(format!(
+        "{} Update available. Run: {}",
+        style("→").cyan(),
+        style("occ update cli").cyan()
+    ));
+    if let Some(compatible) = target.compatible.as_deref() {
+        lines.push(format!(
+            "{} v{} requires a newer Rust toolchain; v{compatible} is the newest your toolchain can build. Run {} first.",
+            style("Note:").yellow(),
+            target.target,
+            style("rustup update").dim()
+        ));
+    }
+    lines
+}
+
+async fn get_target_cli_version(install_method: &CliInstallMethod) -> Option<CliTargetVersion> {
     let (program, args) = match install_method {
         CliInstallMethod::Cargo => ("cargo", vec!["info", "opencode-cloud"]),
         CliInstallMethod::Npm => ("npm", vec!["view", "opencode-cloud", "version"]),
+        CliInstallMethod::Binary => {
+            let version = crate::release_binary::latest_release_version().await.ok()?;
+            return Some(CliTargetVersion {
+                target: version,
+                compatible: None,
+            });
+        }
     };
 
     let output = Command::new(program).args(args).output().ok()?;
@@ -930,6 +1146,7 @@ fn get_target_cli_version(install_method: &CliInstallMethod) -> Option<CliTarget
             target: version,
             compatible: None,
         }),
+        CliInstallMethod::Binary => None,
     }
 }
 
@@ -984,6 +1201,7 @@ fn parse_npm_view_version(output: &str) -> Option<String> {
 
 pub(crate) async fn cmd_update_opencode(
     args: &UpdateOpencodeArgs,
+    dry_run: bool,
     maybe_host: Option<&str>,
     quiet: bool,
     verbose: u8,
@@ -1002,17 +1220,47 @@ pub(crate) async fn cmd_update_opencode(
     client
         .verify_connection()
         .await
-        .map_err(|e| anyhow!("Docker connection error: {e}"))?;
+        .map_err(|e| CliError::docker(&e, format!("Docker connection error: {e}")))?;
 
-    let config = load_config_or_default()?;
+    let config = crate::load_config_for_host(host_name.as_deref())?;
 
     if !container_exists(&client, CONTAINER_NAME).await? {
-        return Err(anyhow!(
-            "Container does not exist. Start it first with:\n  occ start"
-        ));
+        return Err(CliError::ContainerMissing(
+            "Container does not exist. Start it first with:\n  occ start".to_string(),
+        )
+        .into());
     }
 
+    // The recorded commit lives in the local data dir and isn't keyed by host
+    if args.rollback && host_name.is_some() {
+        return Err(CliError::Unsupported(
+            "opencode rollback is only recorded for the local service.\n\
+             Run 'occ update opencode --rollback' on the remote host, or pass --commit <sha>."
+                .to_string(),
+        )
+        .into());
+    }
+
+    let maybe_commit = if args.rollback {
+        Some(previous_opencode_commit()?)
+    } else {
+        args.commit.clone()
+    };
+    let target_ref = maybe_commit
+        .clone()
+        .or_else(|| args.branch.clone())
+        .unwrap_or_else(|| "dev".to_string());
+
     if !container_is_running(&client, CONTAINER_NAME).await? {
+        if dry_run {
+            print_dry_run_plan(&[
+                "Would start the stopped container".to_string(),
+                format!("Would update opencode from {target_ref} and rebuild it in place"),
+                "Would restart the service (downtime lasts for the rebuild)".to_string(),
+            ]);
+            return Ok(());
+        }
+
         if !quiet {
             eprintln!();
             eprintln!(
@@ -1022,10 +1270,7 @@ pub(crate) async fn cmd_update_opencode(
         }
 
         if !args.yes {
-            let confirmed = Confirm::new()
-                .with_prompt("Start container now?")
-                .default(true)
-                .interact()?;
+            let confirmed = confirm("Start container now?", true, quiet)?;
 
             if !confirmed {
                 return Err(anyhow!(
@@ -1038,23 +1283,20 @@ pub(crate) async fn cmd_update_opencode(
         setup_and_start(
             &client,
             Some(config.opencode_web_port),
-            None,
+            Some(container_env_vars(&config)),
             Some(&config.bind_address),
             Some(config.cockpit_port),
             Some(config.cockpit_enabled && COCKPIT_EXPOSED),
             Some(systemd_enabled),
             None,
+            Some(&config.container_labels),
+            config.platform.as_deref(),
         )
         .await
         .map_err(|e| anyhow!("Failed to start container: {e}"))?;
     }
 
-    let target_ref = args
-        .commit
-        .clone()
-        .or_else(|| args.branch.clone())
-        .unwrap_or_else(|| "dev".to_string());
-    let checkout_cmd = if args.commit.is_some() {
+    let checkout_cmd = if maybe_commit.is_some() {
         "git checkout \"$OPENCODE_REF\"".to_string()
     } else {
         "git checkout -B \"$OPENCODE_REF\" \"origin/$OPENCODE_REF\"".to_string()
@@ -1062,7 +1304,7 @@ pub(crate) async fn cmd_update_opencode(
 
     let current_version = get_current_opencode_version(&client).await;
     let current_commit = get_current_opencode_commit(&client).await;
-    let next_commit = if let Some(commit) = args.commit.as_deref() {
+    let next_commit = if let Some(commit) = maybe_commit.as_deref() {
         Some(short_commit(commit))
     } else {
         resolve_remote_commit(&client, &target_ref).await
@@ -1089,18 +1331,33 @@ pub(crate) async fn cmd_update_opencode(
         eprintln!(
             "Current:    version={}, hash={}",
             style(current_version.unwrap_or_else(|| "unknown".to_string())).dim(),
-            style(current_commit.unwrap_or_else(|| "unknown".to_string())).dim()
+            style(current_commit.as_deref().unwrap_or("unknown")).dim()
         );
         let next_hash = next_commit.as_deref().unwrap_or("unknown");
         eprintln!("Next hash:  {}", style(next_hash).dim());
         eprintln!();
+        if let (Some(from), Some(to)) = (current_commit.as_deref(), next_commit.as_deref()) {
+            print_opencode_changelog(from, to).await;
+        }
+    }
+
+    if dry_run {
+        let next_hash = next_commit.as_deref().unwrap_or("unknown");
+        print_dry_run_plan(&[
+            format!("Would stop opencode and check out {target_ref} (hash {next_hash})"),
+            "Would rebuild opencode inside the container".to_string(),
+            "Would restart the service (downtime lasts for the rebuild)".to_string(),
+        ]);
+        return Ok(());
     }
 
     if !args.yes {
-        let confirmed = Confirm::new()
-            .with_prompt("Continue with opencode update?")
-            .default(true)
-            .interact()?;
+        let prompt = if args.rollback {
+            "Continue with opencode rollback?"
+        } else {
+            "Continue with opencode update?"
+        };
+        let confirmed = confirm(prompt, true, quiet)?;
 
         if !confirmed {
             if !quiet {
@@ -1118,6 +1375,19 @@ pub(crate) async fn cmd_update_opencode(
 
     stop_opencode_for_update(&client, quiet).await?;
 
+    // Record the running commit so `occ update opencode --rollback` can return to it;
+    // remote updates skip this so they can't overwrite the local service's record
+    if host_name.is_none()
+        && let Some(previous) = current_commit.as_deref()
+        && let Err(err) = save_opencode_state(&OpencodeState::new(previous, next_commit.as_deref()))
+        && !quiet
+    {
+        eprintln!(
+            "{} Failed to record the current opencode commit for rollback: {err}",
+            style("Warning:").yellow().bold()
+        );
+    }
+
     let update_script = build_opencode_update_script(&target_ref, &checkout_cmd);
 
     let (update_output, update_status) =
@@ -1149,7 +1419,7 @@ pub(crate) async fn cmd_update_opencode(
 
     spinner.success("Opencode updated, restarting service...");
 
-    let restart_args = RestartArgs {};
+    let restart_args = RestartArgs::default();
     cmd_restart(&restart_args, maybe_host, quiet, verbose).await?;
 
     if !quiet {
@@ -1164,6 +1434,18 @@ pub(crate) async fn cmd_update_opencode(
     Ok(())
 }
 
+/// Commit recorded before the last opencode update, for `--rollback`
+fn previous_opencode_commit() -> Result<String> {
+    load_opencode_state()
+        .map(|state| state.previous_commit)
+        .ok_or_else(|| {
+            anyhow!(
+                "No previous opencode commit recorded.\n\
+                 Rollback becomes available after an `occ update opencode`."
+            )
+        })
+}
+
 async fn get_current_opencode_version(client: &DockerClient) -> Option<String> {
     let output = exec_command(
         client,
@@ -1445,12 +1727,14 @@ async fn ensure_container_running_for_update(
     setup_and_start(
         client,
         Some(config.opencode_web_port),
-        None,
+        Some(container_env_vars(config)),
         Some(&config.bind_address),
         Some(config.cockpit_port),
         Some(config.cockpit_enabled && COCKPIT_EXPOSED),
         Some(systemd_enabled),
         None,
+        Some(&config.container_labels),
+        config.platform.as_deref(),
     )
     .await
     .map_err(|e| anyhow!("Failed to start container: {e}"))?;
@@ -1563,14 +1847,57 @@ struct GithubCommitResponse {
     sha: String,
 }
 
-async fn fetch_latest_opencode_commit() -> Result<String> {
-    let client = reqwest::Client::builder()
+#[derive(Deserialize)]
+struct GithubCompareResponse {
+    total_commits: usize,
+    commits: Vec<GithubCompareCommit>,
+}
+
+#[derive(Deserialize)]
+struct GithubCompareCommit {
+    sha: String,
+    commit: GithubCommitDetail,
+}
+
+#[derive(Deserialize)]
+struct GithubCommitDetail {
+    message: String,
+}
+
+/// Most commits listed in the changelog shown before an opencode update
+const CHANGELOG_MAX_COMMITS: usize = 20;
+
+/// How long to wait for the GitHub compare API before skipping the changelog
+const CHANGELOG_LOOKUP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Branch whose head is offered by the update selector
+const OPENCODE_UPDATE_BRANCH: &str = "dev";
+
+/// Latest opencode commit on the update branch, cached for a few minutes
+pub(super) async fn fetch_latest_opencode_commit(refresh: bool) -> Result<String> {
+    let key = format!("opencode-commit:pRizz/opencode@{OPENCODE_UPDATE_BRANCH}");
+    if !refresh && let Some(Some(commit)) = cached_version(&key) {
+        return Ok(commit);
+    }
+    let commit = fetch_latest_opencode_commit_uncached().await?;
+    store_version(&key, Some(commit.clone()));
+    Ok(commit)
+}
+
+fn github_client() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
         .user_agent("opencode-cloud")
         .build()
-        .map_err(|e| anyhow!("Failed to build HTTP client: {e}"))?;
+        .map_err(|e| anyhow!("Failed to build HTTP client: {e}"))
+}
+
+async fn fetch_latest_opencode_commit_uncached() -> Result<String> {
+    let client = github_client()?;
 
     let response = client
-        .get("https://api.github.com/repos/pRizz/opencode/commits/dev")
+        .get(format!(
+            "https://api.github.com/repos/pRizz/opencode/commits/{OPENCODE_UPDATE_BRANCH}"
+        ))
         .send()
         .await
         .map_err(|e| anyhow!("Failed to request latest commit: {e}"))?;
@@ -1587,6 +1914,70 @@ async fn fetch_latest_opencode_commit() -> Result<String> {
     Ok(short_commit(&commit.sha))
 }
 
+/// Commits between two opencode revisions, from the GitHub compare API
+async fn fetch_opencode_changelog(from: &str, to: &str) -> Result<GithubCompareResponse> {
+    let client = github_client()?;
+    let response = client
+        .get(format!(
+            "https://api.github.com/repos/pRizz/opencode/compare/{from}...{to}"
+        ))
+        .send()
+        .await
+        .map_err(|e| anyhow!("Failed to request commit range: {e}"))?;
+
+    if !response.status().is_success() {
+        return Err(anyhow!("GitHub API returned status {}", response.status()));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| anyhow!("Failed to parse compare response: {e}"))
+}
+
+/// One line per commit, newest first, capped at `CHANGELOG_MAX_COMMITS`
+fn format_changelog_lines(compare: &GithubCompareResponse) -> Vec<String> {
+    let mut lines: Vec<String> = compare
+        .commits
+        .iter()
+        .rev()
+        .take(CHANGELOG_MAX_COMMITS)
+        .map(|entry| {
+            let subject = entry.commit.message.lines().next().unwrap_or("").trim();
+            format!("{} {subject}", short_commit(&entry.sha))
+        })
+        .collect();
+    let shown = lines.len();
+    if compare.total_commits > shown {
+        lines.push(format!("... and {} more", compare.total_commits - shown));
+    }
+    lines
+}
+
+/// Print what changed between the current and next opencode commits
+///
+/// Best effort: lookup failures only produce a dim note.
+async fn print_opencode_changelog(from: &str, to: &str) {
+    let result = tokio::time::timeout(CHANGELOG_LOOKUP_TIMEOUT, fetch_opencode_changelog(from, to))
+        .await
+        .map_err(|_| anyhow!("timed out"))
+        .and_then(|result| result);
+    match result {
+        Ok(compare) if compare.commits.is_empty() => {}
+        Ok(compare) => {
+            eprintln!("Changes:");
+            for line in format_changelog_lines(&compare) {
+                eprintln!("  {}", style(line).dim());
+            }
+            eprintln!();
+        }
+        Err(err) => {
+            eprintln!("{}", style(format!("Changelog unavailable ({err}).")).dim());
+            eprintln!();
+        }
+    }
+}
+
 async fn purge_unused_docker_resources(client: &DockerClient, quiet: bool) -> Result<Option<i64>> {
     let spinner = CommandSpinner::new_maybe("Pruning unused Docker resources...", quiet);
     let mut reclaimed = 0i64;
@@ -1626,11 +2017,71 @@ async fn purge_unused_docker_resources(client: &DockerClient, quiet: bool) -> Re
     Ok(has_reclaimed.then_some(reclaimed))
 }
 
+/// Print the steps a `--dry-run` update would take
+fn print_dry_run_plan(steps: &[String]) {
+    println!("{}", style("Dry run: no changes made").bold());
+    for step in steps {
+        println!("  - {step}");
+    }
+}
+
+/// Steps `occ update container` would take for the configured image source
+///
+/// Prebuilt images are pulled before the service stops, so downtime covers only
+/// the container recreate; source builds run after the stop.
+fn container_update_plan(
+    use_build: bool,
+    no_cache: bool,
+    maybe_registry_version: Option<&str>,
+    container_present: bool,
+) -> Vec<String> {
+    let mut plan = vec!["Would tag the current image as previous (for --rollback)".to_string()];
+    if use_build {
+        let cache = if no_cache {
+            "without the layer cache"
+        } else {
+            "with the layer cache"
+        };
+        plan.push(format!("Would build the image from source {cache}"));
+    } else {
+        let version = maybe_registry_version
+            .map(|version| format!("version {version}"))
+            .unwrap_or_else(|| "latest".to_string());
+        plan.push(format!(
+            "Would pull {IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT} ({version})"
+        ));
+    }
+    let downtime = match (container_present, use_build) {
+        (false, _) => "Would create the container (no running service to stop)",
+        (true, false) => "Would stop the service and recreate the container (brief downtime)",
+        (true, true) => {
+            "Would stop the service and recreate the container (downtime lasts for the build)"
+        }
+    };
+    plan.push(downtime.to_string());
+    plan.push("Would purge unused images and containers".to_string());
+    plan
+}
+
+/// Upper-bound Docker disk usage after an update, assuming no shared layers
+fn disk_estimate_line(
+    maybe_total_before: Option<i64>,
+    maybe_image_size: Option<i64>,
+) -> Option<String> {
+    let before = maybe_total_before?;
+    let image_size = maybe_image_size?;
+    Some(format!(
+        "Docker disk: {} now, up to {} after the new image lands (before purge)",
+        format_bytes_i64(before),
+        format_bytes_i64(before + image_size)
+    ))
+}
+
 /// Handle the normal update flow
 async fn handle_update(
     client: &DockerClient,
     config: &opencode_cloud_core::config::Config,
-    skip_confirm: bool,
+    args: &UpdateArgs,
     quiet: bool,
     verbose: u8,
     _host_name: Option<&str>,
@@ -1638,6 +2089,18 @@ async fn handle_update(
     let port = config.opencode_web_port;
     let bind_addr = &config.bind_address;
     let use_build = config.image_source == "build";
+    let pull_options = PullOptions {
+        registry: args.registry,
+        retries: config.pull_retries,
+        retry_delay_secs: config.pull_retry_delay,
+        platform: config.platform.clone(),
+    };
+    if args.no_cache && !use_build && !quiet {
+        eprintln!(
+            "{} --no-cache only applies when building from source (image_source=build); pulling instead.",
+            style("Note:").dim()
+        );
+    }
     let resources = active_resource_names();
     let image_name = format!("{IMAGE_NAME_GHCR}:{}", resources.image_tag);
     let maybe_current_image_version = get_image_version(client, &image_name).await.ok().flatten();
@@ -1658,7 +2121,7 @@ async fn handle_update(
         None
     } else {
         let spinner = CommandSpinner::new_maybe("Checking registry version...", quiet);
-        match get_registry_latest_version(client).await {
+        match get_registry_latest_version_cached(client, args.refresh).await {
             Ok(version) => {
                 spinner.success("Registry version checked");
                 version
@@ -1728,7 +2191,12 @@ async fn handle_update(
         let current = maybe_current_image_version.as_deref().unwrap_or("unknown");
         eprintln!("Current:    {}", style(current).dim());
         if use_build {
-            eprintln!("Target:     {}", style("build from source").dim());
+            let target = if args.no_cache {
+                "build from source (no cache)"
+            } else {
+                "build from source"
+            };
+            eprintln!("Target:     {}", style(target).dim());
         } else if let Some(version) = maybe_registry_version.as_deref() {
             eprintln!(
                 "Target:     {}",
@@ -1752,8 +2220,32 @@ async fn handle_update(
         }
     }
 
+    if args.dry_run {
+        let container_present = container_exists(client, CONTAINER_NAME).await?;
+        let maybe_image_size = client
+            .inner()
+            .inspect_image(&image_name)
+            .await
+            .ok()
+            .and_then(|image| image.size);
+        let mut plan = container_update_plan(
+            use_build,
+            args.no_cache,
+            maybe_registry_version.as_deref(),
+            container_present,
+        );
+        if let Some(line) = disk_estimate_line(
+            maybe_usage_before.and_then(|report| report.total),
+            maybe_image_size,
+        ) {
+            plan.push(line);
+        }
+        print_dry_run_plan(&plan);
+        return Ok(());
+    }
+
     // Confirm with user unless --yes
-    if !skip_confirm {
+    if !args.yes {
         if !quiet {
             eprintln!(
                 "{} Unused images and containers will be purged to save space.",
@@ -1761,10 +2253,7 @@ async fn handle_update(
             );
             eprintln!();
         }
-        let confirmed = Confirm::new()
-            .with_prompt("Continue with update?")
-            .default(true)
-            .interact()?;
+        let confirmed = confirm("Continue with update?", true, quiet)?;
 
         if !confirmed {
             if !quiet {
@@ -1783,7 +2272,7 @@ async fn handle_update(
                 style("[preflight]").cyan()
             );
         }
-        tag_current_as_previous(client)
+        tag_current_as_previous(client, config.rollback_depth)
             .await
             .map_err(|e| anyhow!("Failed to backup current image: {e}"))?;
 
@@ -1793,9 +2282,15 @@ async fn handle_update(
             ProgressReporter::with_context("Checking image")
         };
 
-        let full_image = pull_image(client, Some(IMAGE_TAG_DEFAULT), &mut progress)
-            .await
-            .map_err(|e| anyhow!("Failed to pull image: {e}"))?;
+        let full_image = pull_image(
+            client,
+            Some(IMAGE_TAG_DEFAULT),
+            &mut progress,
+            &pull_options,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to pull image: {e}"))?;
+        print_pull_concurrency_hint(&progress, verbose);
         prebuilt_pulled = true;
         maybe_target_version = get_image_version(client, &full_image).await.ok().flatten();
 
@@ -1857,7 +2352,7 @@ async fn handle_update(
         }
 
         // First, tag current as previous for rollback (same as update_image does)
-        tag_current_as_previous(client)
+        tag_current_as_previous(client, config.rollback_depth)
             .await
             .map_err(|e| anyhow!("Failed to backup current image: {e}"))?;
 
@@ -1868,9 +2363,22 @@ async fn handle_update(
             ProgressReporter::with_context("Building image")
         };
 
-        build_image(client, Some(IMAGE_TAG_DEFAULT), &mut progress, false, None)
-            .await
-            .map_err(|e| anyhow!("Failed to build image: {e}"))?;
+        let build_args: HashMap<String, String> = config.build_args.clone().into_iter().collect();
+        print_build_args(&build_args, verbose);
+        build_image(
+            client,
+            Some(IMAGE_TAG_DEFAULT),
+            &mut progress,
+            args.no_cache,
+            Some(build_args),
+            &BuildLogOptions {
+                save_path: args.save_build_log.clone(),
+                log_tail: config.build_log_tail,
+                error_tail: config.build_error_tail,
+            },
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to build image: {e}"))?;
 
         // Save provenance
         save_state(&ImageState::built(get_cli_version())).ok();
@@ -1893,7 +2401,7 @@ async fn handle_update(
             image_name.clone()
         } else {
             // First, tag current as previous for rollback
-            tag_current_as_previous(client)
+            tag_current_as_previous(client, config.rollback_depth)
                 .await
                 .map_err(|e| anyhow!("Failed to backup current image: {e}"))?;
 
@@ -1904,9 +2412,16 @@ async fn handle_update(
                 ProgressReporter::with_context("Updating image")
             };
 
-            pull_image(client, Some(IMAGE_TAG_DEFAULT), &mut progress)
-                .await
-                .map_err(|e| anyhow!("Failed to pull image: {e}"))?
+            let full_image = pull_image(
+                client,
+                Some(IMAGE_TAG_DEFAULT),
+                &mut progress,
+                &pull_options,
+            )
+            .await
+            .map_err(|e| anyhow!("Failed to pull image: {e}"))?;
+            print_pull_concurrency_hint(&progress, verbose);
+            full_image
         };
 
         // Determine registry and save provenance
@@ -1930,12 +2445,14 @@ async fn handle_update(
     if let Err(e) = setup_and_start(
         client,
         Some(port),
-        None,
+        Some(container_env_vars(config)),
         Some(bind_addr),
         Some(config.cockpit_port),
         Some(config.cockpit_enabled && COCKPIT_EXPOSED),
         Some(systemd_enabled),
         None, // bind_mounts: update recreates without bind mounts (user can restart with mounts)
+        Some(&config.container_labels),
+        config.platform.as_deref(),
     )
     .await
     {
@@ -2046,22 +2563,77 @@ async fn handle_update(
     Ok(())
 }
 
+/// Print the images kept for rollback, newest first
+async fn print_rollback_images(
+    client: &DockerClient,
+    config: &opencode_cloud_core::config::Config,
+    quiet: bool,
+) -> Result<()> {
+    let images = list_previous_images(client, config.rollback_depth).await?;
+    if quiet {
+        for image in &images {
+            println!("{}", image.tag);
+        }
+        return Ok(());
+    }
+    if images.is_empty() {
+        println!("No previous images available for rollback.");
+        println!("Images are kept here each time `occ update` replaces the current one.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Generation", "Tag", "Version", "Created"]);
+    for image in &images {
+        table.add_row(vec![
+            Cell::new(image.generation),
+            Cell::new(&image.tag).fg(Color::Cyan),
+            Cell::new(image.version.as_deref().unwrap_or("unknown")),
+            Cell::new(image.created.as_deref().unwrap_or("unknown")),
+        ]);
+    }
+    println!("{table}");
+    println!();
+    println!(
+        "Restore one with {}",
+        style("occ update --rollback --to <TAG>").green()
+    );
+    Ok(())
+}
+
 /// Handle the rollback flow
+///
+/// `target` selects a kept generation; without it the most recent one is restored.
 async fn handle_rollback(
     client: &DockerClient,
     config: &opencode_cloud_core::config::Config,
+    target: Option<&str>,
     skip_confirm: bool,
+    dry_run: bool,
     quiet: bool,
     verbose: u8,
-    _host_name: Option<&str>,
 ) -> Result<()> {
     let port = config.opencode_web_port;
     let bind_addr = &config.bind_address;
 
-    // Check if previous image exists
-    if !has_previous_image(client).await? {
+    let resources = active_resource_names();
+    let rollback_tag = match target {
+        Some(input) => {
+            resolve_rollback_tag(input, &resources.previous_image_tag, config.rollback_depth)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Unknown rollback image: {input}\n\
+                 Run 'occ update --rollback --list' to see the images kept for rollback."
+                    )
+                })?
+        }
+        None => resources.previous_image_tag.clone(),
+    };
+
+    // Check if the selected image exists
+    if !image_exists(client, IMAGE_NAME_GHCR, &rollback_tag).await? {
         return Err(anyhow!(
-            "No previous image available for rollback.\n\
+            "No image tagged {rollback_tag} available for rollback.\n\
              You must update at least once before using --rollback."
         ));
     }
@@ -2076,12 +2648,23 @@ async fn handle_rollback(
         eprintln!();
     }
 
+    if dry_run {
+        let previous_image = format!("{IMAGE_NAME_GHCR}:{rollback_tag}");
+        let previous_version = get_image_version(client, &previous_image)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| "unknown".to_string());
+        print_dry_run_plan(&[
+            format!("Would restore {previous_image} (version {previous_version})"),
+            "Would stop the service and recreate the container (brief downtime)".to_string(),
+        ]);
+        return Ok(());
+    }
+
     // Confirm with user unless --yes
     if !skip_confirm {
-        let confirmed = Confirm::new()
-            .with_prompt("Continue with rollback?")
-            .default(true)
-            .interact()?;
+        let confirmed = confirm("Continue with rollback?", true, quiet)?;
 
         if !confirmed {
             if !quiet {
@@ -2113,12 +2696,13 @@ async fn handle_rollback(
     if verbose > 0 {
         eprintln!("{} Rolling back image...", style("[2/4]").cyan());
     }
-    let spinner = CommandSpinner::new_maybe("Rolling back to previous image...", quiet);
-    if let Err(e) = rollback_image(client).await {
+    let spinner =
+        CommandSpinner::new_maybe(&format!("Rolling back to {rollback_tag} image..."), quiet);
+    if let Err(e) = rollback_to_image(client, &rollback_tag).await {
         spinner.fail("Failed to rollback image");
         return Err(anyhow!("Failed to rollback: {e}"));
     }
-    spinner.success("Rolled back to previous image");
+    spinner.success(&format!("Rolled back to {rollback_tag} image"));
 
     // Step 3: Recreate container
     if verbose > 0 {
@@ -2129,12 +2713,14 @@ async fn handle_rollback(
     if let Err(e) = setup_and_start(
         client,
         Some(port),
-        None,
+        Some(container_env_vars(config)),
         Some(bind_addr),
         Some(config.cockpit_port),
         Some(config.cockpit_enabled && COCKPIT_EXPOSED),
         Some(systemd_enabled),
         None, // bind_mounts: rollback recreates without bind mounts (user can restart with mounts)
+        Some(&config.container_labels),
+        config.platform.as_deref(),
     )
     .await
     {
@@ -85,6 +85,7 @@ pub async fn run_update_command_listener(
 
     let mut interval = time::interval(COMMAND_POLL_INTERVAL);
     let mut last_processed: Option<Instant> = None;
+    let _interrupt = crate::interrupt::HandlesInterrupt::new();
     let shutdown = tokio::signal::ctrl_c();
     tokio::pin!(shutdown);
 
@@ -249,9 +250,10 @@ async fn handle_command(
     let args = UpdateOpencodeArgs {
         branch: request.branch.clone(),
         commit: request.commit.clone(),
+        rollback: false,
         yes: true,
     };
-    cmd_update_opencode(&args, maybe_host, quiet, verbose).await?;
+    cmd_update_opencode(&args, false, maybe_host, quiet, verbose).await?;
     Ok("Update completed".to_string())
 }
 
@@ -0,0 +1,214 @@
+//! User lock-all/unlock-all subcommands
+//!
+//! Disables or re-enables every managed user at once, e.g. for a
+//! maintenance window. Built-in accounts are never touched.
+
+use anyhow::{Result, bail};
+use clap::Args;
+use console::style;
+use opencode_cloud_core::docker::{
+    CONTAINER_NAME, DockerClient, UserInfo, is_builtin_system_user, list_users, lock_user,
+    persist_user, unlock_user,
+};
+
+/// Arguments for the user lock-all command
+#[derive(Args)]
+pub struct UserLockAllArgs {
+    /// Keep this user enabled (repeatable), e.g. an admin account
+    #[arg(long, value_name = "USER")]
+    pub except: Vec<String>,
+}
+
+/// Arguments for the user unlock-all command
+#[derive(Args)]
+pub struct UserUnlockAllArgs {
+    /// Leave this user as it is (repeatable)
+    #[arg(long, value_name = "USER")]
+    pub except: Vec<String>,
+}
+
+/// How the users in the container split up for a bulk lock/unlock
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct BulkPlan {
+    /// Users whose lock state will change
+    pub targets: Vec<String>,
+    /// Users already in the requested state
+    pub unchanged: Vec<String>,
+    /// Users excluded with `--except`
+    pub excepted: Vec<String>,
+}
+
+/// Decide which users a bulk lock (`lock = true`) or unlock would change
+///
+/// Fails when `--except` names a user that does not exist, since a typo
+/// there could otherwise lock out the account meant to stay usable.
+pub(crate) fn plan_bulk(users: &[UserInfo], lock: bool, except: &[String]) -> Result<BulkPlan> {
+    let unknown: Vec<&str> = except
+        .iter()
+        .filter(|name| !users.iter().any(|u| &u.username == *name))
+        .map(String::as_str)
+        .collect();
+    if !unknown.is_empty() {
+        bail!(
+            "Unknown user(s) in --except: {}\n\nList users with: occ user list",
+            unknown.join(", ")
+        );
+    }
+
+    let mut plan = BulkPlan::default();
+    for user in users {
+        if is_builtin_system_user(&user.username) {
+            continue;
+        }
+        let bucket = if except.contains(&user.username) {
+            &mut plan.excepted
+        } else if user.locked == lock {
+            &mut plan.unchanged
+        } else {
+            &mut plan.targets
+        };
+        bucket.push(user.username.clone());
+    }
+    Ok(plan)
+}
+
+/// Print which users changed and fail if any of them could not be updated
+pub(crate) fn report_bulk(
+    plan: &BulkPlan,
+    failed: &[String],
+    lock: bool,
+    quiet: bool,
+) -> Result<()> {
+    let (verb, state) = if lock {
+        ("Disabled", "disabled")
+    } else {
+        ("Enabled", "enabled")
+    };
+
+    if !quiet {
+        let changed: Vec<&str> = plan
+            .targets
+            .iter()
+            .filter(|name| !failed.contains(name))
+            .map(String::as_str)
+            .collect();
+        if changed.is_empty() {
+            println!("No users changed.");
+        } else {
+            println!(
+                "{} {verb} {} user(s): {}",
+                style("Success:").green().bold(),
+                changed.len(),
+                changed.join(", ")
+            );
+        }
+        if !plan.unchanged.is_empty() {
+            println!(
+                "{}",
+                style(format!("Already {state}: {}", plan.unchanged.join(", "))).dim()
+            );
+        }
+        if !plan.excepted.is_empty() {
+            println!(
+                "{}",
+                style(format!("Skipped (--except): {}", plan.excepted.join(", "))).dim()
+            );
+        }
+    }
+
+    if !failed.is_empty() {
+        bail!(
+            "Failed to update {} user(s): {}",
+            failed.len(),
+            failed.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Disable every managed user account
+pub async fn cmd_user_lock_all(
+    client: &DockerClient,
+    args: &UserLockAllArgs,
+    quiet: bool,
+    _verbose: u8,
+) -> Result<()> {
+    run_bulk(client, true, &args.except, quiet).await
+}
+
+/// Re-enable every managed user account
+pub async fn cmd_user_unlock_all(
+    client: &DockerClient,
+    args: &UserUnlockAllArgs,
+    quiet: bool,
+    _verbose: u8,
+) -> Result<()> {
+    run_bulk(client, false, &args.except, quiet).await
+}
+
+async fn run_bulk(client: &DockerClient, lock: bool, except: &[String], quiet: bool) -> Result<()> {
+    let users = list_users(client, CONTAINER_NAME).await?;
+    let plan = plan_bulk(&users, lock, except)?;
+
+    let mut failed = Vec::new();
+    for username in &plan.targets {
+        let result = if lock {
+            lock_user(client, CONTAINER_NAME, username).await
+        } else {
+            unlock_user(client, CONTAINER_NAME, username).await
+        };
+        // Persist updated lock state for rebuild/update restores
+        let result = match result {
+            Ok(()) => persist_user(client, CONTAINER_NAME, username).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            failed.push(username.clone());
+            if !quiet {
+                eprintln!("{} {username}: {e}", style("Error:").red().bold());
+            }
+        }
+    }
+
+    report_bulk(&plan, &failed, lock, quiet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(username: &str, locked: bool) -> UserInfo {
+        UserInfo {
+            username: username.to_string(),
+            uid: 1001,
+            home: format!("/home/{username}"),
+            shell: "/bin/bash".to_string(),
+            locked,
+        }
+    }
+
+    #[test]
+    fn plan_bulk_splits_targets_unchanged_and_excepted() {
+        let users = vec![
+            user("alice", false),
+            user("bob", true),
+            user("admin", false),
+            user("opencoder", false),
+        ];
+        let plan = plan_bulk(&users, true, &["admin".to_string()]).unwrap();
+        assert_eq!(plan.targets, vec!["alice"]);
+        assert_eq!(plan.unchanged, vec!["bob"]);
+        assert_eq!(plan.excepted, vec!["admin"]);
+
+        let plan = plan_bulk(&users, false, &[]).unwrap();
+        assert_eq!(plan.targets, vec!["bob"]);
+        assert_eq!(plan.unchanged, vec!["alice", "admin"]);
+    }
+
+    #[test]
+    fn plan_bulk_rejects_unknown_except_user() {
+        let users = vec![user("alice", false)];
+        let err = plan_bulk(&users, true, &["admn".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("admn"));
+    }
+}
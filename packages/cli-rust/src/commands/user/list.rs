@@ -1,28 +1,74 @@
 //! User list subcommand
 //!
-//! Lists users in the container with their status.
+//! Lists users in the container with their status and last login.
 
 use anyhow::Result;
 use clap::Args;
 use comfy_table::{Cell, Color, Table};
-use opencode_cloud_core::docker::{CONTAINER_NAME, DockerClient, list_users};
+use opencode_cloud_core::docker::{CONTAINER_NAME, DockerClient, UserInfo, last_login, list_users};
+use serde::Serialize;
 
 /// Arguments for the user list command
 #[derive(Args)]
-pub struct UserListArgs {}
+pub struct UserListArgs {
+    /// Output users as a JSON array
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// One row of the user list, as printed by `--json`
+#[derive(Debug, Serialize)]
+pub(crate) struct UserListEntry {
+    pub username: String,
+    pub uid: u32,
+    pub home: String,
+    pub shell: String,
+    pub locked: bool,
+    /// Most recent login from `lastlog`, if any
+    pub last_login: Option<String>,
+}
+
+impl UserListEntry {
+    pub(crate) fn new(user: UserInfo, last_login: Option<String>) -> Self {
+        Self {
+            username: user.username,
+            uid: user.uid,
+            home: user.home,
+            shell: user.shell,
+            locked: user.locked,
+            last_login,
+        }
+    }
+}
 
 /// List users in the container
 pub async fn cmd_user_list(
     client: &DockerClient,
-    _args: &UserListArgs,
+    args: &UserListArgs,
     quiet: bool,
     _verbose: u8,
 ) -> Result<()> {
     // Get users from container
     let users = list_users(client, CONTAINER_NAME).await?;
 
+    let mut entries = Vec::with_capacity(users.len());
+    for user in users {
+        let login = last_login(client, CONTAINER_NAME, &user.username).await;
+        entries.push(UserListEntry::new(user, login));
+    }
+
+    print_user_list(&entries, args.json, quiet)
+}
+
+/// Print users as JSON, bare usernames (quiet), or a table
+pub(crate) fn print_user_list(entries: &[UserListEntry], json: bool, quiet: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(entries)?);
+        return Ok(());
+    }
+
     // Handle empty list
-    if users.is_empty() {
+    if entries.is_empty() {
         if !quiet {
             println!("No users configured.");
         }
@@ -31,29 +77,41 @@ pub async fn cmd_user_list(
 
     // Quiet mode: just usernames, one per line
     if quiet {
-        for user in &users {
-            println!("{}", user.username);
+        for entry in entries {
+            println!("{}", entry.username);
         }
         return Ok(());
     }
 
     // Table output
     let mut table = Table::new();
-    table.set_header(vec!["Username", "Status", "UID", "Home", "Shell"]);
+    table.set_header(vec![
+        "Username",
+        "Status",
+        "Last login",
+        "UID",
+        "Home",
+        "Shell",
+    ]);
 
-    for user in &users {
-        let status_cell = if user.locked {
+    for entry in entries {
+        let status_cell = if entry.locked {
             Cell::new("disabled").fg(Color::Yellow)
         } else {
             Cell::new("enabled").fg(Color::Green)
         };
+        let login_cell = match &entry.last_login {
+            Some(login) => Cell::new(login),
+            None => Cell::new("never").fg(Color::DarkGrey),
+        };
 
         table.add_row(vec![
-            Cell::new(&user.username),
+            Cell::new(&entry.username),
             status_cell,
-            Cell::new(user.uid.to_string()),
-            Cell::new(&user.home),
-            Cell::new(&user.shell),
+            login_cell,
+            Cell::new(entry.uid.to_string()),
+            Cell::new(&entry.home),
+            Cell::new(&entry.shell),
         ]);
     }
 
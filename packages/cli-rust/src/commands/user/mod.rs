@@ -3,8 +3,9 @@
 //! Provides `occ user` subcommands for managing container users.
 
 mod add;
+pub(crate) mod bulk;
 mod enable;
-mod list;
+pub(crate) mod list;
 mod passwd;
 mod remove;
 
@@ -14,6 +15,7 @@ use opencode_cloud_core::docker::{CONTAINER_NAME, container_is_running};
 
 pub use add::UserAddArgs;
 pub use add::cmd_user_add;
+pub use bulk::{cmd_user_lock_all, cmd_user_unlock_all};
 pub use enable::{UserDisableArgs, UserEnableArgs};
 pub use enable::{cmd_user_disable, cmd_user_enable};
 pub use list::UserListArgs;
@@ -45,6 +47,10 @@ pub enum UserCommands {
     Enable(enable::UserEnableArgs),
     /// Disable a user account
     Disable(enable::UserDisableArgs),
+    /// Disable all user accounts (e.g. for maintenance)
+    LockAll(bulk::UserLockAllArgs),
+    /// Re-enable all user accounts
+    UnlockAll(bulk::UserUnlockAllArgs),
 }
 
 /// Handle user command
@@ -84,5 +90,11 @@ pub async fn cmd_user(
         UserCommands::Disable(disable_args) => {
             cmd_user_disable(&client, disable_args, quiet, verbose).await
         }
+        UserCommands::LockAll(lock_args) => {
+            cmd_user_lock_all(&client, lock_args, quiet, verbose).await
+        }
+        UserCommands::UnlockAll(unlock_args) => {
+            cmd_user_unlock_all(&client, unlock_args, quiet, verbose).await
+        }
     }
 }
@@ -8,7 +8,8 @@ use clap::Args;
 use console::style;
 use dialoguer::Password;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DockerClient, persist_user, set_user_password, user_exists,
+    CONTAINER_NAME, DockerClient, is_builtin_system_user, persist_user, set_user_password,
+    user_exists,
 };
 
 /// Arguments for the user passwd command
@@ -42,6 +43,11 @@ pub async fn cmd_user_passwd(
 
     let username = &args.username;
 
+    // Built-in accounts are not managed users and cannot be persisted
+    if is_builtin_system_user(username) {
+        bail!("User '{username}' is built-in; its password cannot be changed with occ");
+    }
+
     // Check if user exists
     if !user_exists(client, CONTAINER_NAME, username).await? {
         bail!("User '{username}' does not exist in the container");
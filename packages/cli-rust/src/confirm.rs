@@ -0,0 +1,62 @@
+//! Confirmation prompts that never block automation.
+//!
+//! `OPENCODE_CLOUD_ASSUME_YES=1` answers every prompt with yes, the same as
+//! passing a command's `--yes`/`--force`. Under `--quiet` prompts take their
+//! default instead of waiting for input.
+
+use anyhow::Result;
+use dialoguer::Confirm;
+
+/// Environment variable that answers confirmation prompts with yes.
+pub const ASSUME_YES_ENV: &str = "OPENCODE_CLOUD_ASSUME_YES";
+
+/// Whether `OPENCODE_CLOUD_ASSUME_YES` is set to a truthy value.
+pub fn assume_yes() -> bool {
+    std::env::var(ASSUME_YES_ENV)
+        .map(|value| is_truthy(&value))
+        .unwrap_or(false)
+}
+
+/// Combine a command's `--yes`/`--force` flag with `OPENCODE_CLOUD_ASSUME_YES`.
+pub fn skip_confirmation(flag: bool) -> bool {
+    flag || assume_yes()
+}
+
+/// Ask a yes/no question unless the run is non-interactive.
+///
+/// Returns `true` when `OPENCODE_CLOUD_ASSUME_YES` is set, and `default`
+/// when `non_interactive` (usually `--quiet`) is set.
+pub fn confirm(prompt: impl Into<String>, default: bool, non_interactive: bool) -> Result<bool> {
+    if assume_yes() {
+        return Ok(true);
+    }
+    if non_interactive {
+        return Ok(default);
+    }
+    Ok(Confirm::new()
+        .with_prompt(prompt)
+        .default(default)
+        .interact()?)
+}
+
+fn is_truthy(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truthy_values_enable_assume_yes() {
+        for value in ["1", "true", "YES", " on "] {
+            assert!(is_truthy(value), "{value}");
+        }
+        for value in ["", "0", "false", "no", "off", "maybe"] {
+            assert!(!is_truthy(value), "{value}");
+        }
+    }
+}
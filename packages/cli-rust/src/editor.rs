@@ -0,0 +1,153 @@
+//! Open config files in the user's editor and only keep valid results.
+//!
+//! The editor comes from `$VISUAL`, then `$EDITOR`, falling back to
+//! `notepad` on Windows and `vi` elsewhere. Edits happen on a sibling copy
+//! (`<file>.edit`), which replaces the real file only after it validates.
+
+use anyhow::{Context, Result, bail};
+use console::style;
+use dialoguer::Confirm;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Result of an editor session
+pub enum EditOutcome<T> {
+    /// The edited file validated and replaced the original
+    Saved(T),
+    /// The file was saved without changes
+    Unchanged,
+    /// Validation failed and the user chose not to reopen the editor
+    Discarded,
+}
+
+/// Editor command line from `$VISUAL`/`$EDITOR`, split on whitespace
+pub fn editor_command() -> Vec<String> {
+    resolve_editor(
+        std::env::var("VISUAL").ok().as_deref(),
+        std::env::var("EDITOR").ok().as_deref(),
+    )
+}
+
+fn resolve_editor(visual: Option<&str>, editor: Option<&str>) -> Vec<String> {
+    let fallback = if cfg!(windows) { "notepad" } else { "vi" };
+    [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(|value| {
+            value
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .find(|parts| !parts.is_empty())
+        .unwrap_or_else(|| vec![fallback.to_string()])
+}
+
+/// Run the editor on `path` and wait for it to exit
+pub fn open_in_editor(path: &Path) -> Result<()> {
+    let command = editor_command();
+    let (program, args) = command
+        .split_first()
+        .expect("editor command is never empty");
+    let status = Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+        .with_context(|| {
+            format!("Failed to launch editor '{program}'. Set $EDITOR or $VISUAL to your editor.")
+        })?;
+    if !status.success() {
+        bail!("Editor '{program}' exited with {status}");
+    }
+    Ok(())
+}
+
+/// Edit `path` through a copy, checking it with `validate` before it replaces the original
+///
+/// When validation fails the error is shown and the user can reopen the
+/// editor with their changes intact; declining leaves the original untouched.
+/// The original is kept as `<file>.bak` when the edit is saved.
+pub fn edit_validated<T>(
+    path: &Path,
+    validate: impl Fn(&Path) -> Result<T>,
+) -> Result<EditOutcome<T>> {
+    let original =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let draft = draft_path(path);
+    fs::write(&draft, &original)
+        .with_context(|| format!("Failed to create {}", draft.display()))?;
+
+    let outcome = edit_draft(path, &draft, &original, validate);
+    if draft.exists() {
+        let _ = fs::remove_file(&draft);
+    }
+    outcome
+}
+
+fn edit_draft<T>(
+    path: &Path,
+    draft: &Path,
+    original: &str,
+    validate: impl Fn(&Path) -> Result<T>,
+) -> Result<EditOutcome<T>> {
+    loop {
+        open_in_editor(draft)?;
+
+        let edited = fs::read_to_string(draft)
+            .with_context(|| format!("Failed to read {}", draft.display()))?;
+        if edited == original {
+            return Ok(EditOutcome::Unchanged);
+        }
+
+        match validate(draft) {
+            Ok(value) => {
+                fs::copy(path, path.with_extension("json.bak"))
+                    .with_context(|| format!("Failed to back up {}", path.display()))?;
+                fs::rename(draft, path)
+                    .with_context(|| format!("Failed to save {}", path.display()))?;
+                return Ok(EditOutcome::Saved(value));
+            }
+            Err(e) => {
+                eprintln!("{} {e:#}", style("Invalid:").red().bold());
+                let reopen = Confirm::new()
+                    .with_prompt("Reopen the editor to fix it?")
+                    .default(true)
+                    .interact()?;
+                if !reopen {
+                    return Ok(EditOutcome::Discarded);
+                }
+            }
+        }
+    }
+}
+
+fn draft_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".edit");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_editor_prefers_visual_then_editor() {
+        assert_eq!(
+            resolve_editor(Some("code --wait"), Some("nano")),
+            vec!["code", "--wait"]
+        );
+        assert_eq!(resolve_editor(Some("  "), Some("nano")), vec!["nano"]);
+        let fallback = if cfg!(windows) { "notepad" } else { "vi" };
+        assert_eq!(resolve_editor(None, None), vec![fallback]);
+    }
+
+    #[test]
+    fn draft_path_is_a_sibling() {
+        assert_eq!(
+            draft_path(Path::new("/tmp/occ/config.json")),
+            PathBuf::from("/tmp/occ/config.json.edit")
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Categorized CLI errors
+//!
+//! Command handlers return these, usually wrapped in `anyhow::Error`, so
+//! `run_with_exit_code()` can pick the documented exit code and, for `--json`
+//! runs, print a machine-readable error envelope. Messages match the text
+//! the commands printed before the categories existed.
+
+use crate::exit_code::ExitCode;
+use opencode_cloud_core::SingletonError;
+use opencode_cloud_core::docker::DockerError;
+use serde_json::json;
+use thiserror::Error;
+
+/// Failure categories surfaced by CLI commands
+#[derive(Debug, Error)]
+pub enum CliError {
+    /// Docker daemon not running, socket missing, permission denied, or timed out
+    #[error("{0}")]
+    DockerUnreachable(String),
+
+    /// The service container does not exist
+    #[error("{0}")]
+    ContainerMissing(String),
+
+    /// The config file failed validation
+    #[error("{0}")]
+    ConfigInvalid(String),
+
+    /// `--remote-host` or a host argument names a host not in hosts.json
+    #[error("Host '{0}' not found. Run 'occ host list' to see available hosts.")]
+    HostNotFound(String),
+
+    /// The command cannot run in this runtime or against this target
+    #[error("{0}")]
+    Unsupported(String),
+
+    /// Any other failure the command wants to report with its own message
+    #[error("{0}")]
+    Failed(String),
+
+    /// Exit with this code without printing anything, for `--probe` and `--quiet` checks
+    #[error("exited with status {}", .0.code())]
+    Silent(ExitCode),
+}
+
+impl CliError {
+    /// Categorize a Docker error, keeping `message` as the displayed text
+    pub fn docker(err: &DockerError, message: impl Into<String>) -> Self {
+        let message = message.into();
+        match ExitCode::for_docker_error(err) {
+            ExitCode::DockerUnavailable => Self::DockerUnreachable(message),
+            ExitCode::ContainerNotFound => Self::ContainerMissing(message),
+            _ => Self::Failed(message),
+        }
+    }
+
+    /// Exit code documented for this category
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            Self::DockerUnreachable(_) => ExitCode::DockerUnavailable,
+            Self::ContainerMissing(_) => ExitCode::ContainerNotFound,
+            Self::ConfigInvalid(_) => ExitCode::ConfigInvalid,
+            Self::HostNotFound(_) | Self::Unsupported(_) | Self::Failed(_) => ExitCode::Failure,
+            Self::Silent(code) => *code,
+        }
+    }
+
+    /// Stable identifier for the `--json` error envelope
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::DockerUnreachable(_) => "docker_unreachable",
+            Self::ContainerMissing(_) => "container_missing",
+            Self::ConfigInvalid(_) => "config_invalid",
+            Self::HostNotFound(_) => "host_not_found",
+            Self::Unsupported(_) => "unsupported",
+            Self::Failed(_) => "failed",
+            Self::Silent(_) => "silent",
+        }
+    }
+}
+
+/// Envelope printed to stdout when a `--json` command fails
+///
+/// ```json
+/// {"error": {"kind": "docker_unreachable", "message": "...", "exit_code": 3}}
+/// ```
+pub fn error_envelope(err: &anyhow::Error) -> serde_json::Value {
+    let code = crate::exit_code::exit_code_for(err);
+    let kind = err
+        .chain()
+        .find_map(|cause| {
+            if let Some(cli) = cause.downcast_ref::<CliError>() {
+                return Some(cli.kind());
+            }
+            if let Some(SingletonError::AlreadyRunning(_)) = cause.downcast_ref::<SingletonError>()
+            {
+                return Some("already_running");
+            }
+            cause
+                .downcast_ref::<DockerError>()
+                .map(|docker| CliError::docker(docker, "").kind())
+        })
+        .unwrap_or("failed");
+
+    json!({
+        "error": {
+            "kind": kind,
+            "message": console::strip_ansi_codes(&format!("{err:#}")),
+            "exit_code": code.code(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn docker_errors_pick_their_category() {
+        let err = CliError::docker(&DockerError::NotRunning, "Docker is not responding");
+        assert!(matches!(err, CliError::DockerUnreachable(_)));
+        assert_eq!(err.exit_code(), ExitCode::DockerUnavailable);
+        assert_eq!(err.to_string(), "Docker is not responding");
+
+        let missing = DockerError::Container("Container 'x' does not exist".to_string());
+        assert!(matches!(
+            CliError::docker(&missing, "gone"),
+            CliError::ContainerMissing(_)
+        ));
+        assert!(matches!(
+            CliError::docker(&DockerError::Container("boom".to_string()), "boom"),
+            CliError::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn host_not_found_keeps_existing_message() {
+        let err = CliError::HostNotFound("prod".to_string());
+        assert_eq!(
+            err.to_string(),
+            "Host 'prod' not found. Run 'occ host list' to see available hosts."
+        );
+        assert_eq!(err.exit_code(), ExitCode::Failure);
+    }
+
+    #[test]
+    fn envelope_reports_kind_code_and_message() {
+        let err: anyhow::Result<()> = Err(CliError::ConfigInvalid("bad config".to_string()).into());
+        let err = err.context("loading").unwrap_err();
+        let envelope = error_envelope(&err);
+        assert_eq!(envelope["error"]["kind"], "config_invalid");
+        assert_eq!(envelope["error"]["exit_code"], 5);
+        assert_eq!(envelope["error"]["message"], "loading: bad config");
+
+        let envelope = error_envelope(&anyhow::anyhow!("something else"));
+        assert_eq!(envelope["error"]["kind"], "failed");
+        assert_eq!(envelope["error"]["exit_code"], 1);
+    }
+}
@@ -0,0 +1,134 @@
+//! Process exit codes
+//!
+//! Scripts can branch on these instead of parsing error text:
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | 0 | Success |
+//! | 1 | General failure; `occ status --quiet` when the service is stopped |
+//! | 2 | Invalid usage (unknown flag, missing argument); `occ status --probe` when unreachable |
+//! | 3 | Docker daemon unreachable (not running, socket missing, permission denied, timeout) |
+//! | 4 | Service container not found |
+//! | 5 | Configuration file invalid |
+//! | 6 | Another opencode-cloud instance is already running |
+//! | 130 | Interrupted with Ctrl+C |
+//!
+//! Code 2 comes from clap, which exits before `run()` returns.
+//! `occ status --probe` has its own scheme for monitors: 0 healthy, 1 unhealthy,
+//! 2 unreachable.
+
+use crate::error::CliError;
+use opencode_cloud_core::SingletonError;
+use opencode_cloud_core::docker::DockerError;
+
+/// Well-known failure categories mapped to distinct exit codes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitCode {
+    Failure = 1,
+    /// Only for `occ status --probe`; clap also exits 2 on usage errors
+    ProbeUnreachable = 2,
+    DockerUnavailable = 3,
+    ContainerNotFound = 4,
+    ConfigInvalid = 5,
+    AlreadyRunning = 6,
+    Interrupted = 130,
+}
+
+impl ExitCode {
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+
+    /// Category for a Docker error
+    pub fn for_docker_error(err: &DockerError) -> Self {
+        match err {
+            DockerError::Connection(_)
+            | DockerError::NotRunning
+            | DockerError::DesktopPaused
+            | DockerError::SocketNotFound
+            | DockerError::PermissionDenied
+            | DockerError::Timeout => Self::DockerUnavailable,
+            DockerError::Container(msg) if msg.ends_with("does not exist") => {
+                Self::ContainerNotFound
+            }
+            _ => Self::Failure,
+        }
+    }
+}
+
+impl From<ExitCode> for std::process::ExitCode {
+    fn from(code: ExitCode) -> Self {
+        std::process::ExitCode::from(code as u8)
+    }
+}
+
+/// Exit the process immediately with `code`
+pub fn exit_with(code: ExitCode) -> ! {
+    std::process::exit(code.code())
+}
+
+/// Pick the exit code for an error returned from `run()`
+///
+/// Walks the error chain so context added with `.context(...)` does not hide
+/// the original category.
+pub fn exit_code_for(err: &anyhow::Error) -> ExitCode {
+    for cause in err.chain() {
+        if let Some(cli) = cause.downcast_ref::<CliError>() {
+            return cli.exit_code();
+        }
+        if let Some(docker) = cause.downcast_ref::<DockerError>() {
+            return ExitCode::for_docker_error(docker);
+        }
+        if let Some(SingletonError::AlreadyRunning(_)) = cause.downcast_ref::<SingletonError>() {
+            return ExitCode::AlreadyRunning;
+        }
+    }
+    ExitCode::Failure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn docker_connection_errors_map_to_unavailable() {
+        let err = anyhow::Error::new(DockerError::NotRunning);
+        assert_eq!(exit_code_for(&err), ExitCode::DockerUnavailable);
+
+        let err = anyhow::Error::new(DockerError::PermissionDenied).context("starting service");
+        assert_eq!(exit_code_for(&err), ExitCode::DockerUnavailable);
+    }
+
+    #[test]
+    fn missing_container_maps_to_not_found() {
+        let err = anyhow::Error::new(DockerError::Container(
+            "Container 'opencode-cloud-sandbox' does not exist".to_string(),
+        ));
+        assert_eq!(exit_code_for(&err), ExitCode::ContainerNotFound);
+
+        let err = anyhow::Error::new(DockerError::Container("boom".to_string()));
+        assert_eq!(exit_code_for(&err), ExitCode::Failure);
+    }
+
+    #[test]
+    fn cli_error_keeps_message_and_code() {
+        let err = anyhow::Error::new(CliError::ConfigInvalid("bad config".to_string()));
+        assert_eq!(err.to_string(), "bad config");
+        assert_eq!(exit_code_for(&err), ExitCode::ConfigInvalid);
+
+        let wrapped: anyhow::Result<()> = Err(err);
+        let wrapped = wrapped.context("loading").unwrap_err();
+        assert_eq!(exit_code_for(&wrapped), ExitCode::ConfigInvalid);
+    }
+
+    #[test]
+    fn singleton_and_plain_errors() {
+        let err = anyhow::Error::new(SingletonError::AlreadyRunning(42));
+        assert_eq!(exit_code_for(&err), ExitCode::AlreadyRunning);
+        assert_eq!(
+            exit_code_for(&anyhow::anyhow!("something else")),
+            ExitCode::Failure
+        );
+    }
+}
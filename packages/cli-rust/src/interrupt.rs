@@ -0,0 +1,78 @@
+//! Ctrl+C handling
+//!
+//! Without a handler, an interrupt during a pull, build, or update kills the
+//! process mid-draw and can leave spinners half-rendered and the cursor hidden.
+//! [`install`] listens for Ctrl+C for the whole run, stops any live progress
+//! bars, restores the cursor, and exits with the conventional 130.
+//!
+//! Commands that treat Ctrl+C as "stop following" (log attach, stats, metrics)
+//! hold a [`HandlesInterrupt`] guard so they can wind down on their own.
+
+use crate::exit_code::{ExitCode, exit_with};
+use console::{Term, style};
+use opencode_cloud_core::docker::abandon_live_bars;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of live guards whose command handles Ctrl+C itself
+static SELF_HANDLED: AtomicUsize = AtomicUsize::new(0);
+
+/// Marks a scope whose command reacts to Ctrl+C itself
+///
+/// While any guard is alive the global handler ignores interrupts.
+pub(crate) struct HandlesInterrupt(());
+
+impl HandlesInterrupt {
+    pub(crate) fn new() -> Self {
+        SELF_HANDLED.fetch_add(1, Ordering::SeqCst);
+        Self(())
+    }
+}
+
+impl Drop for HandlesInterrupt {
+    fn drop(&mut self) {
+        SELF_HANDLED.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn handled_elsewhere() -> bool {
+    SELF_HANDLED.load(Ordering::SeqCst) > 0
+}
+
+/// Listen for Ctrl+C on `rt` for the rest of the process
+///
+/// Only call once the process environment is final; see `start_runtime`.
+pub(crate) fn install(rt: &tokio::runtime::Runtime) {
+    rt.spawn(async {
+        while tokio::signal::ctrl_c().await.is_ok() {
+            if !handled_elsewhere() {
+                exit_interrupted();
+            }
+        }
+    });
+}
+
+/// Leave the terminal usable and exit with code 130
+fn exit_interrupted() -> ! {
+    abandon_live_bars("(interrupted)");
+    let _ = Term::stderr().show_cursor();
+    let _ = Term::stdout().show_cursor();
+    eprintln!();
+    eprintln!("{}", style("Interrupted").yellow().bold());
+    exit_with(ExitCode::Interrupted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guards_nest_and_release() {
+        let outer = HandlesInterrupt::new();
+        let inner = HandlesInterrupt::new();
+        assert!(handled_elsewhere());
+        drop(inner);
+        assert!(handled_elsewhere());
+        drop(outer);
+        assert!(!handled_elsewhere());
+    }
+}
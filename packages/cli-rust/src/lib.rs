@@ -4,22 +4,27 @@
 
 mod cli_platform;
 mod commands;
+mod confirm;
 mod constants;
+mod editor;
+mod error;
+mod exit_code;
+mod interrupt;
 mod output;
 mod passwords;
+mod release_binary;
 mod sandbox_profile;
 pub mod wizard;
 
 use crate::commands::runtime_shared::drift::{
     RuntimeAssetDrift, detect_runtime_asset_drift, stale_container_warning_lines,
 };
+use crate::error::CliError;
 use anyhow::{Result, anyhow};
 use clap::{Parser, Subcommand, ValueEnum};
 use console::style;
-use dialoguer::Confirm;
 use opencode_cloud_core::{
-    DockerClient, InstanceLock, SingletonError, config, get_version, load_config_or_default,
-    load_hosts, save_config,
+    DockerClient, InstanceLock, SingletonError, config, get_version, load_hosts, save_config,
 };
 use std::path::Path;
 
@@ -57,9 +62,18 @@ struct Cli {
     #[arg(long, global = true, value_enum)]
     runtime: Option<RuntimeChoice>,
 
-    /// Optional sandbox instance profile for worktree-isolated resources
+    /// Sandbox instance profile for worktree-isolated resources ("default" for the
+    /// shared sandbox); overrides the selection saved with `occ sandbox use`
     #[arg(long, global = true, value_name = "NAME|auto")]
     sandbox_instance: Option<String>,
+
+    /// Use an alternate config file instead of the default config.json
+    #[arg(long = "config", global = true, value_name = "PATH")]
+    config_path: Option<std::path::PathBuf>,
+
+    /// Format of diagnostic log output (also OPENCODE_LOG_FORMAT)
+    #[arg(long, global = true, value_enum)]
+    log_format: Option<LogFormat>,
 }
 
 #[derive(Subcommand)]
@@ -74,10 +88,18 @@ enum Commands {
     Status(commands::StatusArgs),
     /// View service logs
     Logs(commands::LogsArgs),
+    /// Show live CPU, memory, and network usage
+    Stats(commands::StatsArgs),
+    /// Print service metrics in Prometheus text format
+    Metrics(commands::MetricsArgs),
+    /// Open the web UI of the running service in the browser
+    Open(commands::OpenArgs),
     /// Register service to start on boot/login
     Install(commands::InstallArgs),
     /// Remove service registration
     Uninstall(commands::UninstallArgs),
+    /// Inspect the OS service registration (systemd/launchd)
+    Service(commands::ServiceArgs),
     /// Manage configuration
     Config(commands::ConfigArgs),
     /// Run interactive setup wizard
@@ -88,6 +110,8 @@ enum Commands {
     Mount(commands::MountArgs),
     /// Reset containers, mounts, and host data
     Reset(commands::ResetArgs),
+    /// Free space held by a managed volume (e.g. the opencode cache)
+    Prune(commands::PruneArgs),
     /// Update to the latest version or rollback (interactive when no subcommand is provided)
     Update(commands::UpdateArgs),
     /// Open Cockpit web console
@@ -95,6 +119,10 @@ enum Commands {
     Cockpit(commands::CockpitArgs),
     /// Manage remote hosts
     Host(commands::HostArgs),
+    /// Inspect and remove sandbox instance profiles
+    Sandbox(commands::SandboxArgs),
+    /// Check Docker, config, ports, and disk space for common problems
+    Doctor(commands::DoctorArgs),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
@@ -104,6 +132,16 @@ enum RuntimeChoice {
     Container,
 }
 
+/// How the CLI's own tracing output is rendered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum LogFormat {
+    /// Human-readable lines (default)
+    #[default]
+    Human,
+    /// One JSON object per event on stderr, for tools that orchestrate occ
+    Json,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum RuntimeMode {
     Host,
@@ -114,6 +152,7 @@ enum RuntimeMode {
 enum CommandKind {
     None,
     Status,
+    Doctor,
     Other,
 }
 
@@ -162,11 +201,9 @@ pub async fn resolve_docker_client(
     match target_host {
         Some(name) => {
             // Remote host requested
-            let host_config = hosts.get_host(&name).ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Host '{name}' not found. Run 'occ host list' to see available hosts."
-                )
-            })?;
+            let host_config = hosts
+                .get_host(&name)
+                .ok_or_else(|| CliError::HostNotFound(name.clone()))?;
 
             let client = DockerClient::connect_remote(host_config, &name).await?;
             Ok((client, Some(name)))
@@ -179,6 +216,21 @@ pub async fn resolve_docker_client(
     }
 }
 
+/// Load the global config with the target host's overrides layered on top
+///
+/// Hosts in hosts.json may carry `web_port` and `bind_address`; they apply only
+/// when that host is the target. Command-line flags still win over both.
+/// Never save the returned config, or the overrides would leak into the global file.
+pub(crate) fn load_config_for_host(host_name: Option<&str>) -> anyhow::Result<config::Config> {
+    let mut config = config::load_config_or_default()?;
+    if let Some(name) = host_name
+        && let Some(host) = load_hosts().unwrap_or_default().get_host(name)
+    {
+        host.apply_overrides(&mut config);
+    }
+    Ok(config)
+}
+
 /// Format a message with optional host prefix
 ///
 /// For remote hosts: "[prod-1] Starting container..."
@@ -202,6 +254,22 @@ fn detect_container_runtime() -> bool {
     container_runtime_from_markers(is_container, is_opencode_image)
 }
 
+fn log_format_from_env() -> Option<LogFormat> {
+    let value = std::env::var("OPENCODE_LOG_FORMAT").ok()?;
+    LogFormat::from_str(&value, true).ok()
+}
+
+/// Install the tracing subscriber; `RUST_LOG` filters events in both formats
+fn init_tracing(format: LogFormat) {
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env());
+    match format {
+        LogFormat::Human => builder.init(),
+        // Keep stdout free for command output such as --json results
+        LogFormat::Json => builder.json().with_writer(std::io::stderr).init(),
+    }
+}
+
 fn runtime_choice_from_env() -> Option<RuntimeChoice> {
     let value = std::env::var("OPENCODE_RUNTIME").ok()?;
     match value.to_lowercase().as_str() {
@@ -212,6 +280,30 @@ fn runtime_choice_from_env() -> Option<RuntimeChoice> {
     }
 }
 
+/// What selected the Docker target, for `occ config show --effective`
+fn target_host_source(remote_host: Option<&str>, local: bool, is_remote: bool) -> &'static str {
+    if local {
+        "--local"
+    } else if remote_host.is_some() {
+        "--remote-host"
+    } else if is_remote {
+        "default_host in hosts.json"
+    } else {
+        "local Docker (no default_host)"
+    }
+}
+
+/// What selected the runtime mode, for `occ config show --effective`
+fn runtime_choice_source(flag: Option<RuntimeChoice>) -> &'static str {
+    if flag.is_some() {
+        "--runtime"
+    } else if runtime_choice_from_env().is_some() {
+        "OPENCODE_RUNTIME"
+    } else {
+        "auto-detected"
+    }
+}
+
 fn resolve_runtime(choice: RuntimeChoice) -> (RuntimeMode, bool) {
     let auto_container = detect_container_runtime();
     resolve_runtime_with_autodetect(choice, auto_container)
@@ -236,21 +328,81 @@ fn resolve_runtime_with_autodetect(
 }
 
 fn container_mode_unsupported_error() -> anyhow::Error {
-    anyhow!(
+    CliError::Unsupported(
         "Command not supported in container runtime.\n\
 Supported commands:\n  occ status\n  occ logs\n  occ user\n  occ update opencode\n\
 To force host runtime:\n  occ --runtime host <command>\n  OPENCODE_RUNTIME=host occ <command>"
+            .to_string(),
     )
+    .into()
 }
 
 fn command_kind(command: Option<&Commands>) -> CommandKind {
     match command {
         None => CommandKind::None,
         Some(Commands::Status(_)) => CommandKind::Status,
+        Some(Commands::Doctor(_)) => CommandKind::Doctor,
         Some(_) => CommandKind::Other,
     }
 }
 
+/// Forced color setting, or `None` to auto-detect from the terminal
+///
+/// Precedence: `--no-color` > `CLICOLOR_FORCE` (set and not `0`) > `NO_COLOR` (any value).
+fn color_override(
+    no_color_flag: bool,
+    clicolor_force: Option<&std::ffi::OsStr>,
+    no_color_env: bool,
+) -> Option<bool> {
+    if no_color_flag {
+        return Some(false);
+    }
+    if clicolor_force.is_some_and(|value| !value.is_empty() && value != "0") {
+        return Some(true);
+    }
+    if no_color_env {
+        return Some(false);
+    }
+    None
+}
+
+/// Effective UID of this process, from `id -u`
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn effective_uid() -> Option<u32> {
+    let output = std::process::Command::new("id").arg("-u").output().ok()?;
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn effective_uid() -> Option<u32> {
+    None
+}
+
+/// Whether to warn about running as root
+///
+/// `OPENCODE_ALLOW_ROOT` (set and not `0`) acknowledges the risk and silences it.
+fn root_warning_applies(uid: Option<u32>, allow_root: Option<&std::ffi::OsStr>) -> bool {
+    uid == Some(0) && !allow_root.is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+fn warn_if_running_as_root() {
+    if !root_warning_applies(
+        effective_uid(),
+        std::env::var_os("OPENCODE_ALLOW_ROOT").as_deref(),
+    ) {
+        return;
+    }
+    eprintln!(
+        "{} Running occ as root. Config and data written now (~/.config/opencode-cloud, ~/.local/share/opencode-cloud) and new bind mount directories will be owned by root, which can break later runs as your normal user.",
+        style("Warning:").yellow().bold()
+    );
+    eprintln!(
+        "  Run occ as your user, or set {} to silence this warning.",
+        style("OPENCODE_ALLOW_ROOT=1").green()
+    );
+    eprintln!();
+}
+
 fn should_run_runtime_asset_preflight(
     kind: CommandKind,
     target_host: Option<&str>,
@@ -262,9 +414,7 @@ fn should_run_runtime_asset_preflight(
     matches!(kind, CommandKind::Other)
 }
 
-fn run_container_mode(cli: &Cli) -> Result<()> {
-    let rt = tokio::runtime::Runtime::new()?;
-
+fn run_container_mode(cli: &Cli, rt: &tokio::runtime::Runtime) -> Result<()> {
     match cli.command {
         Some(Commands::Status(ref args)) => rt.block_on(commands::container::cmd_status_container(
             args,
@@ -286,7 +436,7 @@ fn run_container_mode(cli: &Cli) -> Result<()> {
         )),
         Some(_) => Err(container_mode_unsupported_error()),
         None => {
-            let status_args = commands::StatusArgs {};
+            let status_args = commands::StatusArgs::default();
             rt.block_on(commands::container::cmd_status_container(
                 &status_args,
                 cli.quiet,
@@ -296,48 +446,120 @@ fn run_container_mode(cli: &Cli) -> Result<()> {
     }
 }
 
+/// Run the CLI and map failures to the documented exit codes
+///
+/// Errors are printed the same way `main() -> Result<()>` would print them.
+/// `--json` runs also get the error envelope on stdout.
+pub fn run_with_exit_code() -> std::process::ExitCode {
+    let cli = Cli::parse();
+    let json = json_output(cli.command.as_ref());
+    match run_cli(cli) {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(err) => {
+            if !matches!(err.downcast_ref::<CliError>(), Some(CliError::Silent(_))) {
+                eprintln!("Error: {err:?}");
+                if json {
+                    println!("{}", error::error_envelope(&err));
+                }
+            }
+            exit_code::exit_code_for(&err).into()
+        }
+    }
+}
+
+/// Build the tokio runtime and start the Ctrl+C handler on it
+///
+/// Callers must finish every `std::env::set_var` (the sandbox profile) first:
+/// both the worker threads and the handler task may read the environment.
+fn start_runtime() -> Result<tokio::runtime::Runtime> {
+    let rt = tokio::runtime::Runtime::new()?;
+    interrupt::install(&rt);
+    Ok(rt)
+}
+
+/// Message for a config file that failed to load, with where to look next
+fn config_load_error_message(err: &anyhow::Error, config_path: &Path) -> String {
+    format!(
+        "Configuration error: {err:#}\n\n  \
+         Config file: {}\n\n  \
+         Tip: Check the config file for syntax errors or unknown fields.\n  \
+         Tip: See schemas/config.example.jsonc for valid configuration.",
+        config_path.display()
+    )
+}
+
+/// Whether the parsed command was asked for JSON output
+fn json_output(command: Option<&Commands>) -> bool {
+    match command {
+        Some(Commands::Stats(args)) => args.json,
+        Some(Commands::Config(args)) => args.json_output(),
+        Some(Commands::User(args)) => {
+            matches!(&args.command, commands::UserCommands::List(list) if list.json)
+        }
+        Some(Commands::Host(args)) => match &args.command {
+            commands::HostCommands::Show(show) => show.json,
+            commands::HostCommands::List(list) => list.json_output(),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 pub fn run() -> Result<()> {
+    run_cli(Cli::parse())
+}
+
+fn run_cli(cli: Cli) -> Result<()> {
     // Initialize tracing
-    tracing_subscriber::fmt::init();
+    init_tracing(
+        cli.log_format
+            .or_else(log_format_from_env)
+            .unwrap_or_default(),
+    );
 
-    let cli = Cli::parse();
+    // Loads, saves, and the first-run wizard all go through this path
+    config::paths::set_config_path_override(cli.config_path.as_deref());
 
     // Configure color output
-    if cli.no_color {
-        console::set_colors_enabled(false);
+    if let Some(enabled) = color_override(
+        cli.no_color,
+        std::env::var_os("CLICOLOR_FORCE").as_deref(),
+        std::env::var_os("NO_COLOR").is_some(),
+    ) {
+        console::set_colors_enabled(enabled);
+        console::set_colors_enabled_stderr(enabled);
     }
 
-    let sandbox_profile =
-        sandbox_profile::resolve_sandbox_profile(cli.sandbox_instance.as_deref())?;
-    sandbox_profile::apply_active_profile_env(&sandbox_profile);
-    if cli.verbose > 0
-        && let Some(instance) = sandbox_profile.instance_id.as_deref()
-    {
+    // Health probes for external monitors must stay silent
+    let probe_only = matches!(cli.command, Some(Commands::Status(ref args)) if args.probe);
+
+    if !probe_only {
         eprintln!(
-            "{} Using sandbox instance profile: {}",
-            style("[info]").cyan(),
-            style(instance).cyan()
+            "{} This tool is still a work in progress and is rapidly evolving. Expect bugs, frequent updates, and breaking changes. Follow updates on GitHub (https://github.com/pRizz/opencode-cloud) and X (Twitter) (https://x.com/pryszkie). Stability will be announced at some point. Use with caution.",
+            style("Warning:").yellow().bold()
         );
+        eprintln!();
     }
 
-    eprintln!(
-        "{} This tool is still a work in progress and is rapidly evolving. Expect bugs, frequent updates, and breaking changes. Follow updates on GitHub (https://github.com/pRizz/opencode-cloud) and X (Twitter) (https://x.com/pryszkie). Stability will be announced at some point. Use with caution.",
-        style("Warning:").yellow().bold()
-    );
-    eprintln!();
-
     let runtime_choice = cli
         .runtime
         .or_else(runtime_choice_from_env)
         .unwrap_or(RuntimeChoice::Auto);
     let (runtime_mode, auto_container) = resolve_runtime(runtime_choice);
 
+    // Root is expected inside the sandbox container, not on the host
+    if !probe_only && runtime_mode == RuntimeMode::Host {
+        warn_if_running_as_root();
+    }
+
     if runtime_mode == RuntimeMode::Container {
         if cli.remote_host.is_some() || cli.local {
-            return Err(anyhow!(
+            return Err(CliError::Unsupported(
                 "Remote and local Docker flags are not supported in container runtime.\n\
 Use host mode instead:\n  occ --runtime host <command>"
-            ));
+                    .to_string(),
+            )
+            .into());
         }
 
         if auto_container && runtime_choice == RuntimeChoice::Auto && !cli.quiet {
@@ -350,7 +572,8 @@ Use host mode instead:\n  occ --runtime host <command>"
             eprintln!();
         }
 
-        return run_container_mode(&cli);
+        let rt = start_runtime()?;
+        return run_container_mode(&cli, &rt);
     }
 
     let config_path = config::paths::get_config_path()
@@ -362,13 +585,13 @@ Use host mode instead:\n  occ --runtime host <command>"
         Some(Commands::Setup(ref args)) if args.bootstrap || args.yes
     );
 
-    if !config_exists && !skip_wizard {
+    if !config_exists && !skip_wizard && !probe_only {
         eprintln!(
             "{} First-time setup required. Running wizard...",
             style("Note:").cyan()
         );
         eprintln!();
-        let rt = tokio::runtime::Runtime::new()?;
+        let rt = start_runtime()?;
         let new_config = rt.block_on(wizard::run_wizard(None))?;
         save_config(&new_config)?;
         eprintln!();
@@ -380,8 +603,8 @@ Use host mode instead:\n  occ --runtime host <command>"
     }
 
     // Load config
-    let config = match load_config_or_default() {
-        Ok(config) => {
+    let config = match config::load_config_with_migrations() {
+        Ok((config, migrations)) => {
             // If config was just created, inform the user
             if cli.verbose > 0 {
                 eprintln!(
@@ -389,28 +612,59 @@ Use host mode instead:\n  occ --runtime host <command>"
                     style("[info]").cyan(),
                     config_path.display()
                 );
+                for step in &migrations {
+                    eprintln!("{} Migrated config {step}", style("[info]").cyan());
+                }
             }
             config
         }
         Err(e) => {
-            // Display rich error for invalid config
-            eprintln!("{} Configuration error", style("Error:").red().bold());
-            eprintln!();
-            eprintln!("  {e}");
-            eprintln!();
-            eprintln!("  Config file: {}", style(config_path.display()).yellow());
-            eprintln!();
-            eprintln!(
-                "  {} Check the config file for syntax errors or unknown fields.",
-                style("Tip:").cyan()
+            // Returned rather than exiting so --json runs still get the envelope
+            return Err(
+                CliError::ConfigInvalid(config_load_error_message(&e, &config_path)).into(),
             );
+        }
+    };
+
+    // Every Docker resource name below derives from the configured prefix
+    if let Err(e) =
+        opencode_cloud_core::docker::set_container_prefix(config.container_prefix.as_deref())
+    {
+        // `occ config` must still run so the bad value can be fixed
+        if matches!(cli.command, Some(Commands::Config(_))) {
             eprintln!(
-                "  {} See schemas/config.example.jsonc for valid configuration.",
-                style("Tip:").cyan()
+                "{} Invalid container_prefix: {e}",
+                style("Warning:").yellow().bold()
             );
-            std::process::exit(1);
+            eprintln!();
+        } else {
+            return Err(CliError::ConfigInvalid(format!(
+                "Invalid container_prefix in {}: {e}\n\
+             Fix it with: occ config set container_prefix <name> (or '' for the default names)",
+                config_path.display()
+            ))
+            .into());
         }
-    };
+    }
+
+    let sandbox_profile = sandbox_profile::resolve_sandbox_profile(
+        cli.sandbox_instance.as_deref(),
+        config.sandbox_instance.as_deref(),
+    )?;
+    sandbox_profile::apply_active_profile_env(&sandbox_profile);
+
+    // Built only now: the profile env above must be set before any worker thread exists.
+    // One runtime serves the preflight and the command itself.
+    let rt = start_runtime()?;
+    if cli.verbose > 0
+        && let Some(instance) = sandbox_profile.instance_id.as_deref()
+    {
+        eprintln!(
+            "{} Using sandbox instance profile: {}",
+            style("[info]").cyan(),
+            style(instance).cyan()
+        );
+    }
 
     // Show verbose info if requested
     if cli.verbose > 0 {
@@ -429,136 +683,144 @@ Use host mode instead:\n  occ --runtime host <command>"
     let target_host = resolve_target_host(cli.remote_host.as_deref(), cli.local);
     let dispatch_kind = command_kind(cli.command.as_ref());
 
-    if should_run_runtime_asset_preflight(dispatch_kind, target_host.as_deref(), cli.quiet) {
-        match tokio::runtime::Runtime::new() {
-            Ok(rt) => {
-                if let Err(err) = rt.block_on(maybe_print_runtime_asset_preflight(
-                    target_host.as_deref(),
-                    cli.verbose,
-                )) && cli.verbose > 0
-                {
-                    eprintln!(
-                        "{} Runtime drift preflight failed: {err}",
-                        style("[warn]").yellow()
-                    );
-                }
-            }
-            Err(err) => {
-                if cli.verbose > 0 {
-                    eprintln!(
-                        "{} Failed to initialize runtime drift preflight: {err}",
-                        style("[warn]").yellow()
-                    );
-                }
-            }
-        }
+    if should_run_runtime_asset_preflight(dispatch_kind, target_host.as_deref(), cli.quiet)
+        && let Err(err) = rt.block_on(maybe_print_runtime_asset_preflight(
+            target_host.as_deref(),
+            cli.verbose,
+        ))
+        && cli.verbose > 0
+    {
+        eprintln!(
+            "{} Runtime drift preflight failed: {err}",
+            style("[warn]").yellow()
+        );
     }
 
     match cli.command {
-        Some(Commands::Start(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_start(
-                &args,
-                target_host.as_deref(),
-                cli.quiet,
-                cli.verbose,
-            ))
-        }
-        Some(Commands::Stop(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_stop(&args, target_host.as_deref(), cli.quiet))
-        }
-        Some(Commands::Restart(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_restart(
-                &args,
-                target_host.as_deref(),
-                cli.quiet,
-                cli.verbose,
-            ))
-        }
-        Some(Commands::Status(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_status(
-                &args,
-                target_host.as_deref(),
-                cli.quiet,
-                cli.verbose,
-            ))
-        }
+        Some(Commands::Start(args)) => rt.block_on(commands::cmd_start(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        Some(Commands::Stop(args)) => rt.block_on(commands::cmd_stop(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        Some(Commands::Restart(args)) => rt.block_on(commands::cmd_restart(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        Some(Commands::Status(args)) => rt.block_on(commands::cmd_status(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
         Some(Commands::Logs(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_logs(&args, target_host.as_deref(), cli.quiet))
         }
         Some(Commands::Install(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_install(&args, cli.quiet, cli.verbose))
         }
         Some(Commands::Uninstall(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_uninstall(&args, cli.quiet, cli.verbose))
         }
-        Some(Commands::Config(cmd)) => commands::cmd_config(cmd, &config, cli.quiet),
-        Some(Commands::Setup(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_setup(&args, cli.quiet))
-        }
-        Some(Commands::User(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_user(
-                &args,
-                target_host.as_deref(),
-                cli.quiet,
-                cli.verbose,
-            ))
-        }
-        Some(Commands::Mount(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_mount(
-                &args,
-                target_host.as_deref(),
-                cli.quiet,
-                cli.verbose,
-            ))
-        }
-        Some(Commands::Reset(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_reset(
-                &args,
-                target_host.as_deref(),
-                cli.quiet,
-                cli.verbose,
-            ))
-        }
-        Some(Commands::Update(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_update(
-                &args,
+        Some(Commands::Service(args)) => commands::cmd_service(&args, cli.quiet),
+        Some(Commands::Config(cmd)) => {
+            let context = commands::ResolvedContext {
+                target_host: target_host.clone(),
+                target_source: target_host_source(
+                    cli.remote_host.as_deref(),
+                    cli.local,
+                    target_host.is_some(),
+                ),
+                runtime_mode: "host",
+                runtime_source: runtime_choice_source(cli.runtime),
+            };
+            commands::cmd_config(
+                cmd,
+                &config,
                 target_host.as_deref(),
+                &context,
+                &rt,
                 cli.quiet,
                 cli.verbose,
-            ))
+            )
         }
-        Some(Commands::Cockpit(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_cockpit(
-                &args,
-                target_host.as_deref(),
-                cli.quiet,
-            ))
+        Some(Commands::Setup(args)) => rt.block_on(commands::cmd_setup(&args, cli.quiet)),
+        Some(Commands::User(args)) => rt.block_on(commands::cmd_user(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        Some(Commands::Mount(args)) => rt.block_on(commands::cmd_mount(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        Some(Commands::Reset(args)) => rt.block_on(commands::cmd_reset(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        Some(Commands::Prune(args)) => rt.block_on(commands::cmd_prune(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+        )),
+        Some(Commands::Update(args)) => rt.block_on(commands::cmd_update(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        Some(Commands::Stats(args)) => rt.block_on(commands::cmd_stats(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+        )),
+        Some(Commands::Metrics(args)) => rt.block_on(commands::cmd_metrics(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+        )),
+        Some(Commands::Open(args)) => {
+            rt.block_on(commands::cmd_open(&args, target_host.as_deref(), cli.quiet))
         }
+        Some(Commands::Cockpit(args)) => rt.block_on(commands::cmd_cockpit(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
         Some(Commands::Host(args)) => {
-            let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_host(&args, cli.quiet, cli.verbose))
         }
-        None => {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(handle_no_command(
-                target_host.as_deref(),
-                cli.quiet,
-                cli.verbose,
-            ))
-        }
+        Some(Commands::Sandbox(args)) => rt.block_on(commands::cmd_sandbox(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        Some(Commands::Doctor(args)) => rt.block_on(commands::cmd_doctor(
+            &args,
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
+        None => rt.block_on(handle_no_command(
+            target_host.as_deref(),
+            cli.quiet,
+            cli.verbose,
+        )),
     }
 }
 
@@ -571,7 +833,7 @@ async fn handle_no_command(target_host: Option<&str>, quiet: bool, verbose: u8)
     client
         .verify_connection()
         .await
-        .map_err(|e| anyhow!("Docker connection error: {e}"))?;
+        .map_err(|e| CliError::docker(&e, format!("Docker connection error: {e}")))?;
 
     let running = opencode_cloud_core::docker::container_is_running(
         &client,
@@ -581,34 +843,38 @@ async fn handle_no_command(target_host: Option<&str>, quiet: bool, verbose: u8)
     .map_err(|e| anyhow!("Docker error: {e}"))?;
 
     if running {
-        let status_args = commands::StatusArgs {};
+        let status_args = commands::StatusArgs::default();
         return commands::cmd_status(&status_args, host_name.as_deref(), quiet, verbose).await;
     }
 
     eprintln!("{} Service is not running.", style("Note:").yellow());
 
-    let confirmed = Confirm::new()
-        .with_prompt("Start the service now?")
-        .default(true)
-        .interact()?;
+    let confirmed = confirm::confirm("Start the service now?", true, quiet)?;
 
     if confirmed {
         let start_args = commands::StartArgs {
             port: None,
             open: false,
+            attach: false,
             no_daemon: false,
             pull_sandbox_image: false,
             cached_rebuild_sandbox_image: false,
             full_rebuild_sandbox_image: false,
+            no_cache: false,
             local_opencode_submodule: false,
+            save_build_log: None,
+            registry: None,
+            platform: None,
             ignore_version: false,
             no_update_check: false,
             mounts: Vec::new(),
             no_mounts: false,
+            project: None,
+            recreate: false,
             yes: false,
         };
         commands::cmd_start(&start_args, host_name.as_deref(), quiet, verbose).await?;
-        let status_args = commands::StatusArgs {};
+        let status_args = commands::StatusArgs::default();
         return commands::cmd_status(&status_args, host_name.as_deref(), quiet, verbose).await;
     }
 
@@ -768,16 +1034,119 @@ mod tests {
     }
 
     #[test]
-    fn command_kind_maps_none_status_and_other() {
+    fn command_kind_maps_none_status_doctor_and_other() {
         assert_eq!(command_kind(None), CommandKind::None);
 
-        let status = Commands::Status(commands::StatusArgs {});
+        let status = Commands::Status(commands::StatusArgs::default());
         assert_eq!(command_kind(Some(&status)), CommandKind::Status);
 
+        let doctor = Commands::Doctor(commands::DoctorArgs::default());
+        assert_eq!(command_kind(Some(&doctor)), CommandKind::Doctor);
+
         let start = Commands::Start(commands::StartArgs::default());
         assert_eq!(command_kind(Some(&start)), CommandKind::Other);
     }
 
+    /// Startup cost of one shared runtime vs a fresh runtime per command
+    ///
+    /// Run with `cargo test -p opencode-cloud shared_runtime -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "timing measurement"]
+    fn shared_runtime_startup_overhead() {
+        const COMMANDS: u32 = 20;
+        let command = || async { tokio::task::yield_now().await };
+
+        let started = std::time::Instant::now();
+        for _ in 0..COMMANDS {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(command());
+        }
+        let fresh = started.elapsed();
+
+        let started = std::time::Instant::now();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        for _ in 0..COMMANDS {
+            rt.block_on(command());
+        }
+        let shared = started.elapsed();
+
+        println!("{COMMANDS} commands: fresh runtimes {fresh:?}, shared runtime {shared:?}");
+        assert!(shared < fresh);
+    }
+
+    #[test]
+    fn target_host_source_reports_what_picked_the_target() {
+        assert_eq!(target_host_source(None, true, false), "--local");
+        assert_eq!(
+            target_host_source(Some("prod"), false, true),
+            "--remote-host"
+        );
+        assert_eq!(
+            target_host_source(None, false, true),
+            "default_host in hosts.json"
+        );
+        assert_eq!(
+            target_host_source(None, false, false),
+            "local Docker (no default_host)"
+        );
+    }
+
+    #[test]
+    fn json_output_follows_parsed_flags_not_raw_argv() {
+        let parse = |args: &[&str]| {
+            let cli =
+                Cli::try_parse_from(std::iter::once("occ").chain(args.iter().copied())).unwrap();
+            json_output(cli.command.as_ref())
+        };
+        assert!(parse(&["stats", "--json"]));
+        assert!(parse(&["config", "show", "--json"]));
+        assert!(parse(&["config", "--json"]));
+        assert!(parse(&["user", "list", "--json"]));
+        assert!(parse(&["host", "list", "--format", "json"]));
+        assert!(!parse(&["host", "list", "--format", "yaml"]));
+        assert!(!parse(&[
+            "host",
+            "list",
+            "--format",
+            "json",
+            "--names-only"
+        ]));
+        assert!(!parse(&["config", "set", "image_source", "--json"]));
+        assert!(!parse(&["start"]));
+    }
+
+    #[test]
+    fn log_format_parses_case_insensitively() {
+        assert_eq!(LogFormat::from_str("JSON", true), Ok(LogFormat::Json));
+        assert_eq!(LogFormat::from_str("human", true), Ok(LogFormat::Human));
+        assert!(LogFormat::from_str("xml", true).is_err());
+    }
+
+    #[test]
+    fn root_warning_respects_allow_root() {
+        use std::ffi::OsStr;
+        assert!(root_warning_applies(Some(0), None));
+        assert!(root_warning_applies(Some(0), Some(OsStr::new("0"))));
+        assert!(!root_warning_applies(Some(0), Some(OsStr::new("1"))));
+        assert!(!root_warning_applies(Some(1000), None));
+        assert!(!root_warning_applies(None, None));
+    }
+
+    #[test]
+    fn color_override_precedence() {
+        use std::ffi::OsStr;
+        let force = Some(OsStr::new("1"));
+        assert_eq!(color_override(true, force, false), Some(false));
+        assert_eq!(color_override(false, force, true), Some(true));
+        assert_eq!(
+            color_override(false, Some(OsStr::new("0")), true),
+            Some(false)
+        );
+        assert_eq!(color_override(false, None, true), Some(false));
+        assert_eq!(color_override(false, Some(OsStr::new("0")), false), None);
+        assert_eq!(color_override(false, None, false), None);
+    }
+
     #[test]
     fn should_run_runtime_asset_preflight_gating() {
         assert!(should_run_runtime_asset_preflight(
@@ -795,6 +1164,11 @@ mod tests {
             None,
             false
         ));
+        assert!(!should_run_runtime_asset_preflight(
+            CommandKind::Doctor,
+            None,
+            false
+        ));
         assert!(!should_run_runtime_asset_preflight(
             CommandKind::Other,
             Some("prod-host"),
@@ -21,24 +21,46 @@ pub fn state_style(state: &str) -> StyledObject<String> {
     style.apply_to(state.to_string())
 }
 
+/// Log severity recognized in a log line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+/// Find the log level of a line
+///
+/// Looks for the first standalone level token (case-insensitive), so
+/// `[WARN]`, `level=error`, and `INFO:` all match, while words that merely
+/// contain one (`terror`, `information`) do not. `WARNING` counts as warn and
+/// `TRACE` as debug.
+pub fn detect_log_level(line: &str) -> Option<LogLevel> {
+    line.split(|c: char| !c.is_ascii_alphabetic())
+        .find_map(|token| match token.to_ascii_lowercase().as_str() {
+            "error" => Some(LogLevel::Error),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "info" => Some(LogLevel::Info),
+            "debug" | "trace" => Some(LogLevel::Debug),
+            _ => None,
+        })
+}
+
 /// Style a log line based on detected log level
 ///
-/// - Contains "ERROR" or "error" -> red
-/// - Contains "WARN" or "warn" -> yellow
-/// - Contains "INFO" or "info" -> cyan
-/// - Contains "DEBUG" or "debug" -> dim
-/// - else -> unstyled
+/// - ERROR -> red
+/// - WARN -> yellow
+/// - INFO -> cyan
+/// - DEBUG -> dim
+/// - no level token -> unstyled
 pub fn log_level_style(line: &str) -> StyledObject<&str> {
-    let style = if line.contains("ERROR") || line.contains("error") {
-        Style::new().red()
-    } else if line.contains("WARN") || line.contains("warn") {
-        Style::new().yellow()
-    } else if line.contains("INFO") || line.contains("info") {
-        Style::new().cyan()
-    } else if line.contains("DEBUG") || line.contains("debug") {
-        Style::new().dim()
-    } else {
-        Style::new()
+    let style = match detect_log_level(line) {
+        Some(LogLevel::Error) => Style::new().red(),
+        Some(LogLevel::Warn) => Style::new().yellow(),
+        Some(LogLevel::Info) => Style::new().cyan(),
+        Some(LogLevel::Debug) => Style::new().dim(),
+        None => Style::new(),
     };
     style.apply_to(line)
 }
@@ -116,4 +138,43 @@ mod tests {
         let styled = log_level_style("plain log line");
         assert!(styled.to_string().contains("plain log line"));
     }
+
+    #[test]
+    fn detect_log_level_matches_tokens_case_insensitively() {
+        assert_eq!(
+            detect_log_level("2024-01-01 ERROR: x"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(detect_log_level("level=error msg=x"), Some(LogLevel::Error));
+        assert_eq!(detect_log_level("[Warn] disk low"), Some(LogLevel::Warn));
+        assert_eq!(
+            detect_log_level("WARNING: deprecated"),
+            Some(LogLevel::Warn)
+        );
+        assert_eq!(
+            detect_log_level(" INFO opencode_broker::server"),
+            Some(LogLevel::Info)
+        );
+        assert_eq!(detect_log_level("TRACE poll"), Some(LogLevel::Debug));
+    }
+
+    #[test]
+    fn detect_log_level_uses_first_token() {
+        assert_eq!(
+            detect_log_level("INFO retrying after error"),
+            Some(LogLevel::Info)
+        );
+    }
+
+    #[test]
+    fn detect_log_level_ignores_words_containing_levels() {
+        assert_eq!(detect_log_level("terror information debugger"), None);
+        assert_eq!(detect_log_level("plain log line"), None);
+    }
+
+    #[test]
+    fn log_level_style_keeps_line_text() {
+        let styled = log_level_style("ERROR: boom").to_string();
+        assert_eq!(console::strip_ansi_codes(&styled), "ERROR: boom");
+    }
 }
@@ -3,7 +3,7 @@
 //! This module provides consistent, actionable error messages for Docker-related
 //! errors across all CLI commands.
 
-use anyhow::anyhow;
+use crate::error::CliError;
 use console::style;
 use opencode_cloud_core::docker::DockerError;
 
@@ -13,6 +13,19 @@ use opencode_cloud_core::docker::DockerError;
 /// This is the most complete version with documentation links.
 pub fn format_docker_error(e: &DockerError) -> String {
     match e {
+        DockerError::DesktopPaused => {
+            format!(
+                "{}\n\n  {}\n  {}\n\n  {}: {}",
+                style("Docker Desktop appears paused").red().bold(),
+                "Resume it from the whale menu (or the Dashboard) and retry.",
+                style("  Check: docker info").cyan(),
+                style("Docs").dim(),
+                style("https://github.com/pRizz/opencode-cloud#troubleshooting").dim()
+            )
+        }
+        DockerError::NotRunning | DockerError::SocketNotFound if cfg!(target_os = "macos") => {
+            format_docker_desktop_stopped()
+        }
         DockerError::NotRunning => {
             format!(
                 "{}\n\n  {}\n  {}\n  {}\n\n  {}: {}",
@@ -73,11 +86,26 @@ pub fn format_docker_error(e: &DockerError) -> String {
     }
 }
 
+/// On macOS a missing or unresponsive socket almost always means Docker
+/// Desktop is stopped (or still starting), not a daemon to restart by hand.
+fn format_docker_desktop_stopped() -> String {
+    format!(
+        "{}\n\n  {}\n  {}\n  {}\n\n  {}: {}",
+        style("Docker Desktop appears stopped").red().bold(),
+        "Start Docker Desktop, wait for it to finish starting, and retry:",
+        style("  open -a Docker").cyan(),
+        "If it is already running, it may be paused: resume it from the whale menu.",
+        style("Docs").dim(),
+        style("https://github.com/pRizz/opencode-cloud#troubleshooting").dim()
+    )
+}
+
 /// Format Docker errors as anyhow::Error
 ///
 /// Convenience wrapper for commands that want to return the error directly.
+/// The error keeps the Docker failure's exit code category.
 pub fn format_docker_error_anyhow(e: &DockerError) -> anyhow::Error {
-    anyhow!("{}", format_docker_error(e))
+    CliError::docker(e, format_docker_error(e)).into()
 }
 
 /// Show Docker error in a rich format to stderr
@@ -94,6 +122,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(not(target_os = "macos"))]
     fn format_docker_error_not_running() {
         let error = DockerError::NotRunning;
         let msg = format_docker_error(&error);
@@ -102,6 +131,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(target_os = "macos"))]
     fn format_docker_error_socket_not_found() {
         let error = DockerError::SocketNotFound;
         let msg = format_docker_error(&error);
@@ -109,6 +139,23 @@ mod tests {
         assert!(msg.contains("/var/run/docker.sock"));
     }
 
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn format_docker_error_not_running_points_at_docker_desktop() {
+        for error in [DockerError::NotRunning, DockerError::SocketNotFound] {
+            let msg = format_docker_error(&error);
+            assert!(msg.contains("Docker Desktop appears stopped"));
+            assert!(msg.contains("open -a Docker"));
+        }
+    }
+
+    #[test]
+    fn format_docker_error_desktop_paused() {
+        let msg = format_docker_error(&DockerError::DesktopPaused);
+        assert!(msg.contains("Docker Desktop appears paused"));
+        assert!(msg.contains("whale menu"));
+    }
+
     #[test]
     fn format_docker_error_permission_denied() {
         let error = DockerError::PermissionDenied;
@@ -138,6 +185,9 @@ mod tests {
         let error = DockerError::NotRunning;
         let anyhow_err = format_docker_error_anyhow(&error);
         let err_msg = anyhow_err.to_string();
-        assert!(err_msg.contains("Docker is not responding"));
+        assert!(
+            err_msg.contains("Docker is not responding")
+                || err_msg.contains("Docker Desktop appears stopped")
+        );
     }
 }
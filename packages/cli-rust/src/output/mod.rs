@@ -12,9 +12,6 @@ pub mod spinner;
 pub mod urls;
 
 pub use colors::{log_level_style, state_style};
-pub use errors::{format_docker_error, format_docker_error_anyhow, show_docker_error};
+pub use errors::{format_docker_error_anyhow, show_docker_error};
 pub use spinner::CommandSpinner;
-pub use urls::{
-    format_cockpit_url, format_service_url, localhost_display_addr, normalize_bind_addr,
-    resolve_remote_addr,
-};
+pub use urls::{format_cockpit_url, format_service_url, normalize_bind_addr, resolve_remote_addr};
@@ -4,6 +4,7 @@
 //! animated spinner and elapsed time indicator.
 
 use indicatif::{ProgressBar, ProgressStyle};
+use opencode_cloud_core::docker::track_live_bar;
 use std::time::Duration;
 
 /// A spinner for command operations with elapsed time display
@@ -36,6 +37,7 @@ impl CommandSpinner {
         );
         bar.set_message(message.to_string());
         bar.enable_steady_tick(Duration::from_millis(100));
+        track_live_bar(&bar);
         Self { bar: Some(bar) }
     }
 
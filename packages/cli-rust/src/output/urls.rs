@@ -10,6 +10,7 @@
 #![allow(dead_code)]
 
 use opencode_cloud_core::load_hosts;
+use std::borrow::Cow;
 
 /// Resolve the remote address for a host by looking up its configuration.
 ///
@@ -46,10 +47,11 @@ pub fn resolve_remote_addr(host_name: Option<&str>) -> Option<String> {
 ///
 /// A display-friendly address string
 pub fn normalize_bind_addr(bind_addr: &str) -> &str {
-    if bind_addr == "0.0.0.0" || bind_addr == "::" {
+    let addr = strip_ipv6_brackets(bind_addr);
+    if addr == "0.0.0.0" || addr == "::" {
         "127.0.0.1"
     } else {
-        bind_addr
+        addr
     }
 }
 
@@ -58,12 +60,32 @@ pub fn normalize_bind_addr(bind_addr: &str) -> &str {
 /// For loopback and wildcard bind addresses, this returns `localhost` so browser
 /// navigation uses passkey-eligible localhost origins.
 pub fn localhost_display_addr(bind_addr: &str) -> &str {
-    if bind_addr.eq_ignore_ascii_case("localhost")
-        || matches!(bind_addr, "127.0.0.1" | "::1" | "0.0.0.0" | "::")
+    let addr = strip_ipv6_brackets(bind_addr);
+    if addr.eq_ignore_ascii_case("localhost")
+        || matches!(addr, "127.0.0.1" | "::1" | "0.0.0.0" | "::")
     {
         "localhost"
     } else {
-        bind_addr
+        addr
+    }
+}
+
+/// Remove surrounding brackets from an IPv6 literal like `[::1]`.
+fn strip_ipv6_brackets(addr: &str) -> &str {
+    addr.strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(addr)
+}
+
+/// Format a host for the authority part of a URL.
+///
+/// IPv6 literals must be bracketed (`http://[::1]:3000`); hostnames and
+/// IPv4 addresses are returned unchanged.
+pub fn url_host(addr: &str) -> Cow<'_, str> {
+    if addr.contains(':') && !addr.starts_with('[') {
+        Cow::Owned(format!("[{addr}]"))
+    } else {
+        Cow::Borrowed(addr)
     }
 }
 
@@ -86,12 +108,8 @@ pub fn format_cockpit_url(
     bind_addr: &str,
     cockpit_port: u16,
 ) -> String {
-    if let Some(remote_addr) = maybe_remote_addr {
-        format!("http://{remote_addr}:{cockpit_port}")
-    } else {
-        let cockpit_addr = localhost_display_addr(bind_addr);
-        format!("http://{cockpit_addr}:{cockpit_port}")
-    }
+    let host = maybe_remote_addr.unwrap_or_else(|| localhost_display_addr(bind_addr));
+    format!("http://{}:{cockpit_port}", url_host(host))
 }
 
 /// Format a service URL for display.
@@ -109,12 +127,8 @@ pub fn format_cockpit_url(
 ///
 /// A formatted service URL string
 pub fn format_service_url(maybe_remote_addr: Option<&str>, bind_addr: &str, port: u16) -> String {
-    if let Some(remote_addr) = maybe_remote_addr {
-        format!("http://{remote_addr}:{port}")
-    } else {
-        let service_addr = localhost_display_addr(bind_addr);
-        format!("http://{service_addr}:{port}")
-    }
+    let host = maybe_remote_addr.unwrap_or_else(|| localhost_display_addr(bind_addr));
+    format!("http://{}:{port}", url_host(host))
 }
 
 #[cfg(test)]
@@ -184,6 +198,53 @@ mod tests {
         assert_eq!(localhost_display_addr("192.168.1.100"), "192.168.1.100");
     }
 
+    #[test]
+    fn format_service_url_brackets_ipv6_bind_addr() {
+        assert_eq!(
+            format_service_url(None, "fd00::10", 3000),
+            "http://[fd00::10]:3000"
+        );
+        assert_eq!(
+            format_service_url(None, "[fd00::10]", 3000),
+            "http://[fd00::10]:3000"
+        );
+    }
+
+    #[test]
+    fn format_service_url_uses_localhost_for_ipv6_loopback_and_wildcard() {
+        assert_eq!(
+            format_service_url(None, "::1", 3000),
+            "http://localhost:3000"
+        );
+        assert_eq!(
+            format_service_url(None, "[::1]", 3000),
+            "http://localhost:3000"
+        );
+        assert_eq!(
+            format_service_url(None, "::", 3000),
+            "http://localhost:3000"
+        );
+    }
+
+    #[test]
+    fn format_cockpit_url_brackets_ipv6_remote_addr() {
+        let url = format_cockpit_url(Some("2001:db8::5"), "127.0.0.1", 9090);
+        assert_eq!(url, "http://[2001:db8::5]:9090");
+    }
+
+    #[test]
+    fn url_host_leaves_hostnames_and_ipv4_unchanged() {
+        assert_eq!(url_host("myserver.local"), "myserver.local");
+        assert_eq!(url_host("192.168.1.100"), "192.168.1.100");
+        assert_eq!(url_host("[::1]"), "[::1]");
+    }
+
+    #[test]
+    fn normalize_bind_addr_strips_ipv6_brackets() {
+        assert_eq!(normalize_bind_addr("[::]"), "127.0.0.1");
+        assert_eq!(normalize_bind_addr("[fd00::10]"), "fd00::10");
+    }
+
     #[test]
     fn resolve_remote_addr_returns_none_for_none_host() {
         let result = resolve_remote_addr(None);
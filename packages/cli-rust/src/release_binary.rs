@@ -0,0 +1,276 @@
+//! Self-update for CLIs installed from a GitHub release binary
+//!
+//! The release workflow attaches an `occ-<target triple>` asset and an
+//! `occ-<target triple>.sha256` checksum to each GitHub release. The download
+//! is checked against that checksum, written next to the running executable,
+//! and renamed over it, so a failed update never leaves a half-written binary
+//! behind. The checksum comes from the same release as the binary, so it only
+//! catches corrupt or truncated downloads; it does not prove who published it.
+//!
+//! Releases published before the assets existed have none; those updates fail
+//! with the cargo/npm guidance instead.
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const RELEASES_API: &str = "https://api.github.com/repos/pRizz/opencode-cloud/releases";
+const RELEASE_TAG_PREFIX: &str = "release/v";
+
+#[derive(Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    assets: Vec<GithubAsset>,
+}
+
+#[derive(Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// Target triple of the release asset for this build, if one is published
+pub(crate) fn release_target() -> Option<&'static str> {
+    let musl = cfg!(target_env = "musl");
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("macos", "aarch64") => Some("aarch64-apple-darwin"),
+        ("macos", "x86_64") => Some("x86_64-apple-darwin"),
+        ("linux", "x86_64") if musl => Some("x86_64-unknown-linux-musl"),
+        ("linux", "x86_64") => Some("x86_64-unknown-linux-gnu"),
+        ("linux", "aarch64") if musl => Some("aarch64-unknown-linux-musl"),
+        ("linux", "aarch64") => Some("aarch64-unknown-linux-gnu"),
+        _ => None,
+    }
+}
+
+/// Release asset name for a target triple
+pub(crate) fn asset_name(target: &str) -> String {
+    format!("occ-{target}")
+}
+
+/// Latest released CLI version that has a binary for this platform
+///
+/// The version has no `release/v` tag prefix. Fails when the latest release
+/// carries no asset for this platform, since it cannot be installed.
+pub(crate) async fn latest_release_version() -> Result<String> {
+    let target = release_target()
+        .ok_or_else(|| anyhow!("No release binary is published for this platform"))?;
+    let release = fetch_release(None).await?;
+    if !release
+        .assets
+        .iter()
+        .any(|asset| asset.name == asset_name(target))
+    {
+        bail!(missing_asset_message(
+            &release.tag_name,
+            &asset_name(target)
+        ));
+    }
+    version_from_tag(&release.tag_name)
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("Unexpected release tag '{}'", release.tag_name))
+}
+
+/// Download, checksum, and install the release binary for `version` (latest when `None`)
+pub(crate) async fn install_release(version: Option<&str>) -> Result<()> {
+    let target = release_target()
+        .ok_or_else(|| anyhow!("No release binary is published for this platform"))?;
+    let name = asset_name(target);
+    let release = fetch_release(version).await?;
+
+    let find_asset = |asset_name: &str| {
+        release
+            .assets
+            .iter()
+            .find(|asset| asset.name == asset_name)
+            .ok_or_else(|| anyhow!(missing_asset_message(&release.tag_name, asset_name)))
+    };
+    let binary_asset = find_asset(&name)?;
+    let checksum_asset = find_asset(&format!("{name}.sha256"))?;
+
+    let client = http_client()?;
+    let checksum_text = download(&client, &checksum_asset.browser_download_url).await?;
+    let expected =
+        parse_checksum(&String::from_utf8_lossy(&checksum_text), &name).ok_or_else(|| {
+            anyhow!(
+                "Could not read a SHA-256 checksum from {}",
+                checksum_asset.name
+            )
+        })?;
+    let binary = download(&client, &binary_asset.browser_download_url).await?;
+    let actual = sha256_hex(&binary);
+    if actual != expected {
+        bail!(
+            "Checksum mismatch for {name}: expected {expected}, got {actual}. Nothing was changed."
+        );
+    }
+
+    let exe = std::env::current_exe()
+        .and_then(fs::canonicalize)
+        .context("Could not locate the running executable")?;
+    replace_executable(&exe, &binary)
+}
+
+/// Error for a release without this platform's binary, with the manual alternatives
+fn missing_asset_message(tag: &str, asset_name: &str) -> String {
+    [
+        format!("Release {tag} has no {asset_name} binary to update from."),
+        "Update with the tool you installed opencode-cloud with instead:".to_string(),
+        "  - cargo install opencode-cloud".to_string(),
+        "  - npm install -g opencode-cloud".to_string(),
+        "If you used another package manager, re-run its update command.".to_string(),
+    ]
+    .join("\n")
+}
+
+async fn fetch_release(version: Option<&str>) -> Result<GithubRelease> {
+    let url = match version {
+        Some(version) => format!("{RELEASES_API}/tags/{RELEASE_TAG_PREFIX}{version}"),
+        None => format!("{RELEASES_API}/latest"),
+    };
+    let response = http_client()?
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| anyhow!("Failed to request release info: {e}"))?;
+    if !response.status().is_success() {
+        bail!("GitHub API returned status {}", response.status());
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| anyhow!("Failed to parse release info: {e}"))
+}
+
+async fn download(client: &reqwest::Client, url: &str) -> Result<Vec<u8>> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| anyhow!("Failed to download {url}: {e}"))?;
+    if !response.status().is_success() {
+        bail!("Download of {url} failed with status {}", response.status());
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| anyhow!("Failed to download {url}: {e}"))?;
+    Ok(bytes.to_vec())
+}
+
+fn http_client() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .user_agent("opencode-cloud")
+        .build()
+        .map_err(|e| anyhow!("Failed to build HTTP client: {e}"))
+}
+
+fn version_from_tag(tag: &str) -> Option<&str> {
+    let version = tag.strip_prefix(RELEASE_TAG_PREFIX)?;
+    (!version.is_empty()).then_some(version)
+}
+
+/// Hash from a `.sha256` file: either a bare hash or `sha256sum` lines
+fn parse_checksum(contents: &str, asset_name: &str) -> Option<String> {
+    let lines: Vec<&str> = contents.lines().filter(|l| !l.trim().is_empty()).collect();
+    let line = match lines.as_slice() {
+        [only] => *only,
+        _ => lines.iter().copied().find(|line| {
+            line.split_whitespace()
+                .nth(1)
+                .is_some_and(|file| file.trim_start_matches('*') == asset_name)
+        })?,
+    };
+    let hash = line.split_whitespace().next()?.to_ascii_lowercase();
+    (hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())).then_some(hash)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Write `bytes` beside `exe` with its permissions, then rename over it
+fn replace_executable(exe: &Path, bytes: &[u8]) -> Result<()> {
+    let staged = staged_path(exe);
+    let permissions = fs::metadata(exe)
+        .with_context(|| format!("Failed to read {}", exe.display()))?
+        .permissions();
+
+    let result = fs::write(&staged, bytes)
+        .and_then(|()| fs::set_permissions(&staged, permissions))
+        .and_then(|()| fs::rename(&staged, exe));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&staged);
+        if e.kind() == std::io::ErrorKind::PermissionDenied {
+            bail!(
+                "No permission to replace {}. Re-run with sudo or reinstall the binary manually.",
+                exe.display()
+            );
+        }
+        return Err(e).with_context(|| format!("Failed to replace {}", exe.display()));
+    }
+    Ok(())
+}
+
+fn staged_path(exe: &Path) -> PathBuf {
+    let name = exe.file_name().unwrap_or_default().to_string_lossy();
+    exe.with_file_name(format!(".{name}.update-{}", std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_checksum_accepts_bare_hash_and_sha256sum_lines() {
+        let hash = "a".repeat(64);
+        assert_eq!(
+            parse_checksum(&format!("{hash}\n"), "occ-x"),
+            Some(hash.clone())
+        );
+        let sums = format!("{}  occ-other\n{hash} *occ-x\n", "b".repeat(64));
+        assert_eq!(parse_checksum(&sums, "occ-x"), Some(hash));
+        assert_eq!(parse_checksum("not-a-hash", "occ-x"), None);
+    }
+
+    #[test]
+    fn sha256_hex_matches_known_digest() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn replace_executable_keeps_permissions() {
+        let dir = tempfile::tempdir().unwrap();
+        let exe = dir.path().join("occ");
+        fs::write(&exe, b"old").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&exe, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        replace_executable(&exe, b"new").unwrap();
+        assert_eq!(fs::read(&exe).unwrap(), b"new");
+        assert!(!staged_path(&exe).exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&exe).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+    }
+
+    #[test]
+    fn version_from_tag_strips_release_prefix() {
+        assert_eq!(version_from_tag("release/v1.2.3"), Some("1.2.3"));
+        assert_eq!(version_from_tag("v1.2.3"), None);
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{Result, anyhow};
-use opencode_cloud_core::docker::SANDBOX_INSTANCE_ENV;
+use opencode_cloud_core::docker::{SANDBOX_INSTANCE_ENV, validate_sandbox_instance};
 use std::path::PathBuf;
 use std::process::Command;
 
@@ -25,10 +25,19 @@ impl SandboxProfile {
     }
 }
 
-pub fn resolve_sandbox_profile(arg_value: Option<&str>) -> Result<SandboxProfile> {
+/// Instance name that selects the shared (non-isolated) sandbox
+pub const SHARED_INSTANCE_NAME: &str = "default";
+
+/// Resolve the active profile: flag, then `OPENCODE_SANDBOX_INSTANCE`, then the
+/// config's `sandbox_instance`, then the shared sandbox.
+pub fn resolve_sandbox_profile(
+    arg_value: Option<&str>,
+    persisted: Option<&str>,
+) -> Result<SandboxProfile> {
     let raw = arg_value
         .map(str::to_string)
-        .or_else(|| std::env::var(SANDBOX_INSTANCE_ENV).ok());
+        .or_else(|| std::env::var(SANDBOX_INSTANCE_ENV).ok())
+        .or_else(|| persisted.map(str::to_string));
     let Some(raw) = raw else {
         return Ok(SandboxProfile::shared());
     };
@@ -40,6 +49,10 @@ pub fn resolve_sandbox_profile(arg_value: Option<&str>) -> Result<SandboxProfile
         ));
     }
 
+    if normalized == SHARED_INSTANCE_NAME {
+        return Ok(SandboxProfile::shared());
+    }
+
     if normalized == "auto" {
         return Ok(SandboxProfile::isolated(derive_auto_instance_id()?));
     }
@@ -50,8 +63,8 @@ pub fn resolve_sandbox_profile(arg_value: Option<&str>) -> Result<SandboxProfile
 
 pub fn apply_active_profile_env(profile: &SandboxProfile) {
     if let Some(instance_id) = profile.instance_id.as_deref() {
-        // SAFETY: This binary is single-process CLI execution and we set a process-local env var
-        // before spawning worker runtimes/threads, so this is safe under Rust 2024 env rules.
+        // SAFETY: run_cli calls this before it builds the tokio runtime, while the process is
+        // still single-threaded, so no other thread can read the environment concurrently.
         unsafe { std::env::set_var(SANDBOX_INSTANCE_ENV, instance_id) };
     } else {
         // SAFETY: Same rationale as set_var above.
@@ -59,6 +72,16 @@ pub fn apply_active_profile_env(profile: &SandboxProfile) {
     }
 }
 
+/// Value to persist as `sandbox_instance`; `None` selects the shared sandbox
+pub fn persisted_selection(value: &str) -> Result<Option<String>> {
+    let normalized = value.trim().to_ascii_lowercase();
+    if normalized.is_empty() || normalized == SHARED_INSTANCE_NAME {
+        return Ok(None);
+    }
+    validate_sandbox_instance(&normalized).map_err(|msg| anyhow!(msg))?;
+    Ok(Some(normalized))
+}
+
 fn normalize_manual_instance_id(value: &str) -> Result<String> {
     if !is_valid_instance_id(value) {
         return Err(anyhow!(
@@ -169,7 +192,27 @@ mod tests {
 
     #[test]
     fn invalid_manual_name_is_rejected() {
-        let err = resolve_sandbox_profile(Some("bad_name")).expect_err("expected validation error");
+        let err =
+            resolve_sandbox_profile(Some("bad_name"), None).expect_err("expected validation error");
         assert!(err.to_string().contains("Expected [a-z0-9][a-z0-9-]{0,31}"));
     }
+
+    #[test]
+    fn flag_overrides_persisted_selection() {
+        let profile = resolve_sandbox_profile(Some("default"), Some("mytree")).unwrap();
+        assert_eq!(profile, SandboxProfile::shared());
+        let profile = resolve_sandbox_profile(Some("other"), Some("mytree")).unwrap();
+        assert_eq!(profile.instance_id.as_deref(), Some("other"));
+    }
+
+    #[test]
+    fn persisted_selection_clears_for_default() {
+        assert_eq!(persisted_selection(" Default ").unwrap(), None);
+        assert_eq!(persisted_selection("").unwrap(), None);
+        assert_eq!(
+            persisted_selection("MyTree").unwrap().as_deref(),
+            Some("mytree")
+        );
+        assert!(persisted_selection("bad_name").is_err());
+    }
 }
@@ -26,14 +26,14 @@ impl Serialize for RedactedConfig<'_> {
         let config = self.config;
         assert_all_fields_covered(config);
 
-        let mut state = serializer.serialize_struct("Config", 19)?;
+        let mut state = serializer.serialize_struct("Config", 30)?;
         state.serialize_field("version", &config.version)?;
         state.serialize_field("opencode_web_port", &config.opencode_web_port)?;
-        state.serialize_field("bind", &config.bind)?;
         state.serialize_field("auto_restart", &config.auto_restart)?;
         state.serialize_field("boot_mode", &config.boot_mode)?;
         state.serialize_field("restart_retries", &config.restart_retries)?;
         state.serialize_field("restart_delay", &config.restart_delay)?;
+        state.serialize_field("stop_timeout_seconds", &config.stop_timeout_seconds)?;
         state.serialize_field("auth_username", &config.auth_username)?;
 
         let redacted_password = config
@@ -58,8 +58,19 @@ impl Serialize for RedactedConfig<'_> {
         )?;
         state.serialize_field("users", &config.users)?;
         state.serialize_field("image_source", &config.image_source)?;
+        state.serialize_field("platform", &config.platform)?;
+        state.serialize_field("build_args", &config.build_args)?;
         state.serialize_field("update_check", &config.update_check)?;
+        state.serialize_field("build_log_tail", &config.build_log_tail)?;
+        state.serialize_field("build_error_tail", &config.build_error_tail)?;
+        state.serialize_field("pull_retries", &config.pull_retries)?;
+        state.serialize_field("rollback_depth", &config.rollback_depth)?;
+        state.serialize_field("pull_retry_delay", &config.pull_retry_delay)?;
+        state.serialize_field("check_opencode_updates", &config.check_opencode_updates)?;
         state.serialize_field("mounts", &config.mounts)?;
+        state.serialize_field("container_prefix", &config.container_prefix)?;
+        state.serialize_field("sandbox_instance", &config.sandbox_instance)?;
+        state.serialize_field("container_labels", &config.container_labels)?;
         state.end()
     }
 }
@@ -88,11 +99,11 @@ fn assert_all_fields_covered(config: &Config) {
     let Config {
         version: _,
         opencode_web_port: _,
-        bind: _,
         auto_restart: _,
         boot_mode: _,
         restart_retries: _,
         restart_delay: _,
+        stop_timeout_seconds: _,
         auth_username: _,
         auth_password: _,
         container_env: _,
@@ -105,7 +116,18 @@ fn assert_all_fields_covered(config: &Config) {
         cockpit_port: _,
         cockpit_enabled: _,
         image_source: _,
+        platform: _,
+        build_args: _,
         update_check: _,
+        build_log_tail: _,
+        build_error_tail: _,
+        pull_retries: _,
+        rollback_depth: _,
+        pull_retry_delay: _,
+        check_opencode_updates: _,
         mounts: _,
+        container_prefix: _,
+        sandbox_instance: _,
+        container_labels: _,
     } = config;
 }
@@ -5,6 +5,7 @@
 mod config_view;
 mod network;
 mod prechecks;
+mod remote_host;
 mod summary;
 
 pub use prechecks::{verify_docker_available, verify_tty};
@@ -16,6 +17,7 @@ use opencode_cloud_core::{Config, config::default_mounts};
 
 use config_view::render_config_snapshot;
 use network::{prompt_hostname, prompt_port};
+use remote_host::{RemoteHostSetup, prompt_remote_host, save_remote_host};
 use summary::display_summary;
 
 /// Wizard state holding collected configuration values
@@ -29,13 +31,26 @@ pub struct WizardState {
     pub image_source: String,
     /// Default bind mounts for persistence
     pub mounts: Vec<String>,
+    /// Optional remote Docker host to register in hosts.json
+    pub remote_host: Option<RemoteHostSetup>,
 }
 
 impl WizardState {
+    /// Values the wizard suggests when every prompt takes its default
+    pub fn defaults() -> Self {
+        Self {
+            port: 3000,
+            bind: "localhost".to_string(),
+            image_source: "prebuilt".to_string(),
+            mounts: default_mounts(),
+            remote_host: None,
+        }
+    }
+
     /// Apply wizard state to a Config struct
     pub fn apply_to_config(&self, config: &mut Config) {
         config.opencode_web_port = self.port;
-        config.bind = self.bind.clone();
+        config.bind_address = self.bind.clone();
         config.image_source = self.image_source.clone();
         config.mounts = self.mounts.clone();
     }
@@ -182,7 +197,9 @@ fn prompt_mounts(step: usize, total: usize, mounts: &[String]) -> Result<Vec<Str
 /// Run the interactive setup wizard
 ///
 /// Guides the user through configuration, collecting values and returning
-/// a complete Config. Does NOT save - the caller is responsible for saving.
+/// a complete Config. Does NOT save the config - the caller is responsible for saving.
+/// If the user registers a remote host, it is written to hosts.json once the
+/// summary is confirmed.
 ///
 /// # Arguments
 /// * `existing_config` - Optional existing config to show current values
@@ -220,7 +237,7 @@ pub async fn run_wizard(existing_config: Option<&Config>) -> Result<Config> {
                 println!("  Password: ********");
             }
             println!("  Port:     {}", config.opencode_web_port);
-            println!("  Binding:  {}", config.bind);
+            println!("  Binding:  {}", config.bind_address);
             println!("  Image:    {}", config.image_source);
             if config.mounts.is_empty() {
                 println!("  Mounts:   {}", style("None").dim());
@@ -260,32 +277,35 @@ pub async fn run_wizard(existing_config: Option<&Config>) -> Result<Config> {
     println!();
 
     // 4. Collect values
-    let total_steps = if quick { 3 } else { 5 };
+    let total_steps = if quick { 4 } else { 6 };
 
     display_auth_bootstrap_info(1, total_steps)?;
     let image_source = prompt_image_source(2, total_steps)?;
 
+    let defaults = WizardState::defaults();
     let (port, bind) = if quick {
-        (3000, "localhost".to_string())
+        (defaults.port, defaults.bind)
     } else {
-        let port = prompt_port(3, total_steps, 3000)?;
-        let bind = prompt_hostname(4, total_steps, "localhost")?;
+        let port = prompt_port(3, total_steps, defaults.port)?;
+        let bind = prompt_hostname(4, total_steps, &defaults.bind)?;
         (port, bind)
     };
 
-    let default_mounts = default_mounts();
     let mounts = if quick {
-        display_mounts_info(3, total_steps, &default_mounts)?;
-        default_mounts
+        display_mounts_info(3, total_steps, &defaults.mounts)?;
+        defaults.mounts
     } else {
-        prompt_mounts(5, total_steps, &default_mounts)?
+        prompt_mounts(5, total_steps, &defaults.mounts)?
     };
 
+    let remote_host = prompt_remote_host(total_steps, total_steps).await?;
+
     let state = WizardState {
         port,
         bind,
         image_source,
         mounts,
+        remote_host,
     };
 
     // 5. Summary
@@ -304,7 +324,12 @@ pub async fn run_wizard(existing_config: Option<&Config>) -> Result<Config> {
         return Err(anyhow!("Setup cancelled"));
     }
 
-    // 7. Build and return config
+    // 7. Register remote host (validated during the prompt)
+    if let Some(remote_host) = &state.remote_host {
+        save_remote_host(remote_host)?;
+    }
+
+    // 8. Build and return config
     let mut config = existing_config.cloned().unwrap_or_default();
     state.apply_to_config(&mut config);
 
@@ -322,13 +347,14 @@ mod tests {
             bind: "0.0.0.0".to_string(),
             image_source: "prebuilt".to_string(),
             mounts: default_mounts(),
+            remote_host: None,
         };
 
         let mut config = Config::default();
         state.apply_to_config(&mut config);
 
         assert_eq!(config.opencode_web_port, 8080);
-        assert_eq!(config.bind, "0.0.0.0");
+        assert_eq!(config.bind_address, "0.0.0.0");
         assert_eq!(config.image_source, "prebuilt");
     }
 
@@ -339,6 +365,7 @@ mod tests {
             bind: "localhost".to_string(),
             image_source: "build".to_string(),
             mounts: default_mounts(),
+            remote_host: None,
         };
 
         let mut config = Config {
@@ -56,8 +56,10 @@ pub fn verify_tty() -> Result<()> {
             For non-interactive setup:\n  \
             occ setup --bootstrap\n\n\
             Then start the service and complete IOTP + passkey onboarding in the web login page.\n\n\
-            Or edit the config file directly:\n  \
-            ~/.config/opencode-cloud/config.json"
+            Or edit the config file directly:\n  {}",
+            opencode_cloud_core::config::paths::get_config_path()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "~/.config/opencode-cloud/config.json".to_string())
         );
     }
     Ok(())
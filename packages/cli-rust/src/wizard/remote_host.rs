@@ -0,0 +1,280 @@
+//! Remote host prompts
+//!
+//! Optionally registers a remote Docker host in hosts.json during setup.
+
+use anyhow::{Result, anyhow};
+use console::{Term, style};
+use dialoguer::{Confirm, Input};
+use opencode_cloud_core::{DockerClient, HostConfig, load_hosts, query_ssh_config, save_hosts};
+
+use crate::commands::build_host_config;
+use crate::output::CommandSpinner;
+
+/// Remote host collected by the wizard
+#[derive(Debug, Clone)]
+pub struct RemoteHostSetup {
+    /// Name used to refer to the host (e.g., "prod-1")
+    pub name: String,
+    /// Verified host configuration
+    pub config: HostConfig,
+    /// Whether to make this host the default target
+    pub set_default: bool,
+}
+
+/// Handle Ctrl+C by restoring cursor and returning error
+fn handle_interrupt() -> anyhow::Error {
+    let _ = Term::stdout().show_cursor();
+    anyhow!("Setup cancelled")
+}
+
+/// Validate a host name entered in the wizard
+fn validate_host_name(input: &str) -> Result<(), String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err("Host name cannot be empty".to_string());
+    }
+    if trimmed.chars().any(char::is_whitespace) {
+        return Err("Host name cannot contain whitespace".to_string());
+    }
+    Ok(())
+}
+
+/// Parse an optional SSH port, treating empty input as "use default"
+fn parse_optional_port(input: &str) -> Result<Option<u16>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    match trimmed.parse::<u16>() {
+        Ok(0) | Err(_) => Err("Invalid port number. Must be between 1 and 65535.".to_string()),
+        Ok(port) => Ok(Some(port)),
+    }
+}
+
+/// Convert optional text input into an Option, treating empty input as None
+fn non_empty(input: String) -> Option<String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Prompt for an optional remote Docker host
+///
+/// Collects SSH details, verifies Docker is reachable through an SSH tunnel,
+/// and returns the host to register. Returns `None` if the user skips the step.
+pub async fn prompt_remote_host(step: usize, total: usize) -> Result<Option<RemoteHostSetup>> {
+    println!(
+        "{}",
+        style(format!("Step {step}/{total}: Remote Host (optional)"))
+            .cyan()
+            .bold()
+    );
+    println!();
+    println!("opencode-cloud can manage a Docker host on another machine over SSH.");
+    println!(
+        "{}",
+        style("You can add hosts later with: occ host add <name> <hostname>").dim()
+    );
+    println!();
+
+    let add_remote = Confirm::new()
+        .with_prompt("Register a remote Docker host now?")
+        .default(false)
+        .interact()
+        .map_err(|_| handle_interrupt())?;
+    println!();
+
+    if !add_remote {
+        return Ok(None);
+    }
+
+    loop {
+        let Some(setup) = collect_remote_host()? else {
+            return Ok(None);
+        };
+
+        match verify_remote_host(&setup.name, &setup.config).await {
+            Ok(()) => return Ok(Some(setup)),
+            Err(e) => {
+                println!();
+                println!("  {e}");
+                println!();
+                let retry = Confirm::new()
+                    .with_prompt("Re-enter remote host details?")
+                    .default(true)
+                    .interact()
+                    .map_err(|_| handle_interrupt())?;
+                println!();
+                if !retry {
+                    println!(
+                        "{}",
+                        style("Skipping remote host. Add it later with: occ host add").dim()
+                    );
+                    println!();
+                    return Ok(None);
+                }
+            }
+        }
+    }
+}
+
+/// Collect host name and SSH settings
+///
+/// Returns `None` if the user declines to overwrite an existing host.
+fn collect_remote_host() -> Result<Option<RemoteHostSetup>> {
+    let name: String = Input::new()
+        .with_prompt("Host name (e.g., prod-1)")
+        .validate_with(|input: &String| validate_host_name(input))
+        .interact_text()
+        .map_err(|_| handle_interrupt())?;
+    let name = name.trim().to_string();
+
+    let hosts = load_hosts()?;
+    if hosts.has_host(&name) {
+        let overwrite = Confirm::new()
+            .with_prompt(format!("Host '{name}' already exists. Overwrite it?"))
+            .default(false)
+            .interact()
+            .map_err(|_| handle_interrupt())?;
+        if !overwrite {
+            return Ok(None);
+        }
+    }
+
+    let hostname: String = Input::new()
+        .with_prompt("SSH hostname or IP address")
+        .validate_with(|input: &String| {
+            if input.trim().is_empty() {
+                Err("Hostname cannot be empty")
+            } else {
+                Ok(())
+            }
+        })
+        .interact_text()
+        .map_err(|_| handle_interrupt())?;
+    let hostname = hostname.trim().to_string();
+
+    // Show SSH config values so blank answers have a visible fallback
+    let ssh_config_match = query_ssh_config(&hostname).unwrap_or_default();
+    if ssh_config_match.has_settings() {
+        println!(
+            "{} Found in ~/.ssh/config: {}",
+            style("SSH Config:").cyan(),
+            ssh_config_match.display_settings()
+        );
+    }
+
+    let user = non_empty(
+        Input::new()
+            .with_prompt("SSH username (blank for SSH config/current user)")
+            .allow_empty(true)
+            .interact_text()
+            .map_err(|_| handle_interrupt())?,
+    );
+
+    let port_input: String = Input::new()
+        .with_prompt("SSH port (blank for default)")
+        .allow_empty(true)
+        .validate_with(|input: &String| parse_optional_port(input).map(|_| ()))
+        .interact_text()
+        .map_err(|_| handle_interrupt())?;
+    let port = parse_optional_port(&port_input).expect("validated above");
+
+    let identity_file = non_empty(
+        Input::new()
+            .with_prompt("SSH identity file (blank for SSH agent/config)")
+            .allow_empty(true)
+            .interact_text()
+            .map_err(|_| handle_interrupt())?,
+    );
+
+    let config = build_host_config(
+        &hostname,
+        user.as_deref(),
+        port,
+        identity_file.as_deref(),
+        None,
+        &ssh_config_match,
+    );
+
+    let set_default = Confirm::new()
+        .with_prompt(format!("Use '{name}' as the default host?"))
+        .default(true)
+        .interact()
+        .map_err(|_| handle_interrupt())?;
+
+    Ok(Some(RemoteHostSetup {
+        name,
+        config,
+        set_default,
+    }))
+}
+
+/// Verify Docker is reachable on the remote host through an SSH tunnel
+async fn verify_remote_host(name: &str, config: &HostConfig) -> Result<()> {
+    println!(
+        "{} {}",
+        style("SSH Command:").cyan(),
+        style(config.format_ssh_command()).dim()
+    );
+
+    let spinner = CommandSpinner::new(&format!(
+        "Connecting to Docker on {}@{}...",
+        config.user, config.hostname
+    ));
+
+    let result = match DockerClient::connect_remote(config, name).await {
+        Ok(client) => client.verify_connection().await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => {
+            spinner.success("Connected to remote Docker");
+            println!();
+            Ok(())
+        }
+        Err(e) => {
+            spinner.fail("Connection failed");
+            Err(anyhow!("{e}"))
+        }
+    }
+}
+
+/// Save the remote host to hosts.json, optionally making it the default
+pub fn save_remote_host(setup: &RemoteHostSetup) -> Result<()> {
+    let mut hosts = load_hosts()?;
+    hosts.add_host(&setup.name, setup.config.clone());
+    if setup.set_default {
+        hosts.set_default(Some(setup.name.clone()));
+    }
+    save_hosts(&hosts)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_host_name() {
+        assert!(validate_host_name("prod-1").is_ok());
+        assert!(validate_host_name("  staging ").is_ok());
+        assert!(validate_host_name("").is_err());
+        assert!(validate_host_name("   ").is_err());
+        assert!(validate_host_name("prod 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_optional_port() {
+        assert_eq!(parse_optional_port(""), Ok(None));
+        assert_eq!(parse_optional_port("  "), Ok(None));
+        assert_eq!(parse_optional_port("2222"), Ok(Some(2222)));
+        assert!(parse_optional_port("0").is_err());
+        assert!(parse_optional_port("70000").is_err());
+        assert!(parse_optional_port("ssh").is_err());
+    }
+}
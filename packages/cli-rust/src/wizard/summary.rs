@@ -42,6 +42,17 @@ pub fn display_summary(state: &WizardState) {
     };
     table.add_row(vec![Cell::new("Mounts:"), Cell::new(mounts_summary)]);
 
+    if let Some(remote) = &state.remote_host {
+        let default_note = if remote.set_default { " (default)" } else { "" };
+        table.add_row(vec![
+            Cell::new("Remote host:"),
+            Cell::new(format!(
+                "{} -> {}@{}{}",
+                remote.name, remote.config.user, remote.config.hostname, default_note
+            )),
+        ]);
+    }
+
     println!("{table}");
 
     println!();
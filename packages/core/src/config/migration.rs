@@ -0,0 +1,150 @@
+//! Config file migrations
+//!
+//! Upgrades older config layouts to the current schema before they are
+//! deserialized into [`Config`](super::Config), which rejects unknown fields.
+//! Each step moves the file's `version` forward by one.
+
+use anyhow::{Result, bail};
+use serde_json::{Map, Value};
+
+/// Config file version written by this release
+pub const CONFIG_VERSION: u32 = 3;
+
+/// A single upgrade step from `from` to `from + 1`
+struct Migration {
+    from: u32,
+    description: &'static str,
+    apply: fn(&mut Map<String, Value>),
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 1,
+        description: "renamed port to opencode_web_port, removed opencode_commit",
+        apply: migrate_v1_to_v2,
+    },
+    Migration {
+        from: 2,
+        description: "merged bind into bind_address",
+        apply: migrate_v2_to_v3,
+    },
+];
+
+fn migrate_v1_to_v2(obj: &mut Map<String, Value>) {
+    if let Some(port) = obj.remove("port") {
+        obj.entry("opencode_web_port").or_insert(port);
+    }
+    obj.remove("opencode_commit");
+}
+
+/// `bind` was written by the wizard but never reached the container, which
+/// always used `bind_address`. Keep `bind_address` when both are present so
+/// the effective binding does not change; adopt `bind` only when it is alone.
+fn migrate_v2_to_v3(obj: &mut Map<String, Value>) {
+    if let Some(bind) = obj.remove("bind") {
+        obj.entry("bind_address").or_insert(bind);
+    }
+}
+
+/// Upgrade a parsed config file to [`CONFIG_VERSION`] in place
+///
+/// Returns a description of each applied step, empty when the file is
+/// already current. Files without a `version` are treated as version 1.
+/// Files from a newer release are rejected rather than silently stripped.
+pub fn migrate(config_json: &mut Value) -> Result<Vec<String>> {
+    let Some(obj) = config_json.as_object_mut() else {
+        bail!("Config file must contain a JSON object");
+    };
+
+    let mut version = match obj.get("version") {
+        None => 1,
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| anyhow::anyhow!("Config version must be a positive integer"))?,
+    };
+
+    if version > CONFIG_VERSION {
+        bail!(
+            "Config file version {version} is newer than this release supports ({CONFIG_VERSION}). \
+             Update occ to use this config."
+        );
+    }
+
+    let mut applied = Vec::new();
+    while version < CONFIG_VERSION {
+        let migration = MIGRATIONS
+            .iter()
+            .find(|m| m.from == version)
+            .ok_or_else(|| anyhow::anyhow!("No config migration from version {version}"))?;
+        (migration.apply)(obj);
+        version += 1;
+        applied.push(format!(
+            "v{} -> v{version}: {}",
+            migration.from, migration.description
+        ));
+    }
+
+    obj.insert("version".to_string(), Value::from(version));
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn migrations_cover_every_version() {
+        for version in 1..CONFIG_VERSION {
+            assert!(MIGRATIONS.iter().any(|m| m.from == version));
+        }
+    }
+
+    #[test]
+    fn migrate_upgrades_v1_renamed_and_removed_keys() {
+        let mut value = json!({"version": 1, "port": 8080, "opencode_commit": "abc123"});
+        let applied = migrate(&mut value).unwrap();
+        assert_eq!(applied.len(), 2);
+        assert_eq!(
+            value,
+            json!({"version": CONFIG_VERSION, "opencode_web_port": 8080})
+        );
+    }
+
+    #[test]
+    fn migrate_treats_missing_version_as_v1() {
+        let mut value = json!({"opencode_commit": "abc123"});
+        assert_eq!(migrate(&mut value).unwrap().len(), 2);
+        assert_eq!(value["version"], json!(CONFIG_VERSION));
+    }
+
+    #[test]
+    fn migrate_v2_keeps_effective_bind_address() {
+        let mut value = json!({"version": 2, "bind": "0.0.0.0", "bind_address": "127.0.0.1"});
+        migrate(&mut value).unwrap();
+        assert_eq!(
+            value,
+            json!({"version": CONFIG_VERSION, "bind_address": "127.0.0.1"})
+        );
+
+        let mut value = json!({"version": 2, "bind": "0.0.0.0"});
+        migrate(&mut value).unwrap();
+        assert_eq!(
+            value,
+            json!({"version": CONFIG_VERSION, "bind_address": "0.0.0.0"})
+        );
+    }
+
+    #[test]
+    fn migrate_is_noop_for_current_version() {
+        let mut value = json!({"version": CONFIG_VERSION});
+        assert!(migrate(&mut value).unwrap().is_empty());
+    }
+
+    #[test]
+    fn migrate_rejects_newer_version() {
+        let mut value = json!({"version": CONFIG_VERSION + 1});
+        assert!(migrate(&mut value).is_err());
+    }
+}
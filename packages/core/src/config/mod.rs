@@ -3,20 +3,28 @@
 //! Handles loading, saving, and validating the JSONC configuration file.
 //! Creates default config if missing, validates against schema.
 
+pub mod migration;
 pub mod paths;
 pub mod schema;
 pub mod validation;
 
 use std::fs::{self, File};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use jsonc_parser::parse_to_serde_value;
 
 use crate::docker::mount::ParsedMount;
-pub use paths::{get_config_dir, get_config_path, get_data_dir, get_hosts_path, get_pid_path};
-pub use schema::{Config, default_mounts, validate_bind_address};
+pub use migration::CONFIG_VERSION;
+pub use paths::{
+    config_path_override, get_config_dir, get_config_path, get_data_dir, get_hosts_path,
+    get_pid_path, set_config_path_override,
+};
+pub use schema::{
+    Config, default_mounts, validate_bind_address, validate_build_arg_key,
+    validate_container_label_key,
+};
 pub use validation::{
     ValidationError, ValidationWarning, display_validation_error, display_validation_warning,
     validate_config,
@@ -66,6 +74,14 @@ pub fn ensure_data_dir() -> Result<PathBuf> {
 /// Supports JSONC (JSON with comments).
 /// Rejects unknown fields for strict validation.
 pub fn load_config_or_default() -> Result<Config> {
+    load_config_with_migrations().map(|(config, _)| config)
+}
+
+/// Load configuration, also returning any schema migrations that were applied
+///
+/// Older config files are upgraded before deserialization and written back;
+/// `save_config` keeps the original as `config.json.bak`.
+pub fn load_config_with_migrations() -> Result<(Config, Vec<String>)> {
     let config_path =
         get_config_path().ok_or_else(|| anyhow::anyhow!("Could not determine config file path"))?;
 
@@ -78,35 +94,14 @@ pub fn load_config_or_default() -> Result<Config> {
         let config = Config::default();
         ensure_default_mount_dirs(&config)?;
         save_config(&config)?;
-        return Ok(config);
+        return Ok((config, Vec::new()));
     }
 
-    // Read the file
-    let mut file = File::open(&config_path)
-        .with_context(|| format!("Failed to open config file: {}", config_path.display()))?;
-
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
-
-    // Parse JSONC (JSON with comments)
-    let mut parsed_value = parse_to_serde_value(&contents, &Default::default())
-        .map_err(|e| anyhow::anyhow!("Invalid JSONC in config file: {e}"))?
-        .ok_or_else(|| anyhow::anyhow!("Config file is empty"))?;
-
-    // Drop deprecated keys that were removed from the schema.
-    if let Some(obj) = parsed_value.as_object_mut() {
-        obj.remove("opencode_commit");
+    let (mut config, migrations) = read_config_file(&config_path)?;
+    for step in &migrations {
+        tracing::info!("Migrated config {step}");
     }
 
-    // Deserialize into Config struct (deny_unknown_fields will reject unknown keys)
-    let mut config: Config = serde_json::from_value(parsed_value).with_context(|| {
-        format!(
-            "Invalid configuration in {}. Check for unknown fields or invalid values.",
-            config_path.display()
-        )
-    })?;
-
     let mut removed_shadowing_mounts = false;
     config.mounts.retain(|mount_str| {
         let parsed = match ParsedMount::parse(mount_str) {
@@ -130,20 +125,69 @@ pub fn load_config_or_default() -> Result<Config> {
         tracing::info!("Removed bind mounts that shadow /opt/opencode");
     }
 
+    if !migrations.is_empty() {
+        save_config(&config)?;
+    }
+
     ensure_default_mount_dirs(&config)?;
-    Ok(config)
+    Ok((config, migrations))
+}
+
+/// Parse a config file without writing anything back
+///
+/// Runs the same JSONC parsing, migrations, and strict deserialization as
+/// [`load_config_or_default`], so an edited copy can be checked before it
+/// replaces the real config.
+pub fn parse_config_file(path: &Path) -> Result<Config> {
+    read_config_file(path).map(|(config, _)| config)
+}
+
+fn read_config_file(path: &Path) -> Result<(Config, Vec<String>)> {
+    // Read the file
+    let mut file = File::open(path)
+        .with_context(|| format!("Failed to open config file: {}", path.display()))?;
+
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)
+        .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+
+    // Parse JSONC (JSON with comments)
+    let mut parsed_value = parse_to_serde_value(&contents, &Default::default())
+        .map_err(|e| anyhow::anyhow!("Invalid JSONC in config file: {e}"))?
+        .ok_or_else(|| anyhow::anyhow!("Config file is empty"))?;
+
+    // Upgrade older layouts before strict deserialization
+    let migrations = migration::migrate(&mut parsed_value)?;
+
+    // Deserialize into Config struct (deny_unknown_fields will reject unknown keys)
+    let config: Config = serde_json::from_value(parsed_value).with_context(|| {
+        format!(
+            "Invalid configuration in {}. Check for unknown fields or invalid values.",
+            path.display()
+        )
+    })?;
+    Ok((config, migrations))
 }
 
 /// Save configuration to the config file
 ///
 /// Creates a backup of the existing config (config.json.bak) before overwriting.
-/// Ensures the config directory exists.
+/// Ensures the config directory (or the `--config` file's directory) exists.
 pub fn save_config(config: &Config) -> Result<()> {
-    ensure_config_dir()?;
-
     let config_path =
         get_config_path().ok_or_else(|| anyhow::anyhow!("Could not determine config file path"))?;
 
+    match config_path_override().as_deref().and_then(Path::parent) {
+        Some(parent) if !parent.as_os_str().is_empty() => {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create config directory: {}", parent.display())
+            })?;
+        }
+        _ => {
+            ensure_config_dir()?;
+        }
+    }
+
     // Create backup if file exists
     if config_path.exists() {
         let backup_path = config_path.with_extension("json.bak");
@@ -225,6 +269,22 @@ mod tests {
         assert!(pid_path.ends_with("opencode-cloud.pid"));
     }
 
+    #[test]
+    fn parse_config_file_accepts_jsonc_and_rejects_unknown_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+
+        fs::write(
+            &path,
+            "{\n  // edited by hand\n  \"opencode_web_port\": 4000\n}",
+        )
+        .unwrap();
+        assert_eq!(parse_config_file(&path).unwrap().opencode_web_port, 4000);
+
+        fs::write(&path, r#"{"not_a_field": true}"#).unwrap();
+        assert!(parse_config_file(&path).is_err());
+    }
+
     // Note: Integration tests for load_config/save_config that modify the real
     // filesystem are run via CLI commands rather than unit tests to avoid
     // test isolation issues with environment variable manipulation in Rust 2024.
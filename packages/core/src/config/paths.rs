@@ -4,7 +4,11 @@
 //! - Linux/macOS: ~/.config/opencode-cloud/ and ~/.local/share/opencode-cloud/
 //! - Windows: %APPDATA%\opencode-cloud\ and %LOCALAPPDATA%\opencode-cloud\
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Config file chosen with `--config`, installed once at startup.
+static CONFIG_PATH_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
 
 /// Get the configuration directory path
 ///
@@ -60,11 +64,34 @@ pub fn get_data_dir() -> Option<PathBuf> {
 
 /// Get the full path to the config file
 ///
-/// Returns: `{config_dir}/config.json`
+/// Returns the path installed by [`set_config_path_override`] if any,
+/// otherwise `{config_dir}/config.json`
 pub fn get_config_path() -> Option<PathBuf> {
+    if let Some(path) = config_path_override() {
+        return Some(path);
+    }
     get_config_dir().map(|d| d.join("config.json"))
 }
 
+/// Use `path` as the config file for the rest of the process
+///
+/// Relative paths are resolved against the current directory. `None` restores
+/// the default location. `hosts.json` and the data directory are unaffected.
+pub fn set_config_path_override(path: Option<&Path>) {
+    let path = path.map(|p| std::path::absolute(p).unwrap_or_else(|_| p.to_path_buf()));
+    if let Ok(mut slot) = CONFIG_PATH_OVERRIDE.write() {
+        *slot = path;
+    }
+}
+
+/// The config file installed by [`set_config_path_override`], if any
+pub fn config_path_override() -> Option<PathBuf> {
+    CONFIG_PATH_OVERRIDE
+        .read()
+        .ok()
+        .and_then(|slot| slot.clone())
+}
+
 /// Get the full path to the PID lock file
 ///
 /// Returns: `{data_dir}/opencode-cloud.pid`
@@ -2,11 +2,17 @@
 //!
 //! Defines the structure and defaults for the config.json file.
 
+use super::migration::CONFIG_VERSION;
 use crate::docker::volume::{
     MOUNT_CACHE, MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, MOUNT_SSH, MOUNT_STATE,
 };
+use crate::docker::{
+    DEFAULT_BUILD_LOG_BUFFER_SIZE, DEFAULT_ERROR_LOG_BUFFER_SIZE, DEFAULT_PULL_RETRIES,
+    DEFAULT_PULL_RETRY_DELAY_SECS, DEFAULT_ROLLBACK_DEPTH, DEFAULT_STOP_TIMEOUT_SECS,
+};
 use directories::BaseDirs;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::net::{IpAddr, Ipv4Addr};
 /// Main configuration structure for opencode-cloud
 ///
@@ -21,12 +27,6 @@ pub struct Config {
     #[serde(default = "default_opencode_web_port")]
     pub opencode_web_port: u16,
 
-    /// Bind address (default: "localhost")
-    /// Use "localhost" for local-only access (secure default)
-    /// Use "0.0.0.0" for network access (requires explicit opt-in)
-    #[serde(default = "default_bind")]
-    pub bind: String,
-
     /// Auto-restart service on crash (default: true)
     #[serde(default = "default_auto_restart")]
     pub auto_restart: bool,
@@ -45,6 +45,11 @@ pub struct Config {
     #[serde(default = "default_restart_delay")]
     pub restart_delay: u32,
 
+    /// Graceful shutdown timeout in seconds before the container is force killed (default: 30)
+    /// Used by `occ stop`, `occ reset`, and other commands that stop the container
+    #[serde(default = "default_stop_timeout_seconds")]
+    pub stop_timeout_seconds: u32,
+
     /// Username for opencode basic auth (DEPRECATED - use PAM users via `occ user add` instead)
     ///
     /// This field is kept for backward compatibility but is ignored.
@@ -66,7 +71,9 @@ pub struct Config {
     pub container_env: Vec<String>,
 
     /// Bind address for opencode web UI (default: "127.0.0.1")
-    /// Use "0.0.0.0" or "::" for network exposure (requires explicit opt-in)
+    /// Use "0.0.0.0" or "::" for network exposure (requires explicit opt-in).
+    /// The single source of truth for the binding; older files' `bind` is
+    /// merged into it by the v2 -> v3 migration.
     #[serde(default = "default_bind_address")]
     pub bind_address: String,
 
@@ -112,24 +119,77 @@ pub struct Config {
     #[serde(default = "default_image_source")]
     pub image_source: String,
 
+    /// Image platform to pull and run, e.g. "linux/arm64" (default: unset, the daemon's own).
+    /// Set this when the image must run under emulation on purpose.
+    #[serde(default)]
+    pub platform: Option<String>,
+
+    /// Extra Dockerfile build args used when building the image locally
+    /// Entries override the args occ sets itself, e.g. {"OPENCODE_COMMIT": "<sha>"}
+    #[serde(default)]
+    pub build_args: BTreeMap<String, String>,
+
     /// When to check for updates: 'always' (every start), 'once' (once per version), 'never'
     #[serde(default = "default_update_check")]
     pub update_check: String,
 
+    /// Recent build log lines shown when a source build fails (default: 20, range 5-500)
+    /// `OPENCODE_DOCKER_BUILD_LOG_TAIL` overrides this when set
+    #[serde(default = "default_build_log_tail")]
+    pub build_log_tail: usize,
+
+    /// Error lines shown separately when a source build fails (default: 10, range 5-500)
+    /// `OPENCODE_DOCKER_BUILD_ERROR_TAIL` overrides this when set
+    #[serde(default = "default_build_error_tail")]
+    pub build_error_tail: usize,
+
+    /// Attempts per registry when pulling the prebuilt image (default: 3, range 1-10)
+    /// `OPENCODE_DOCKER_PULL_RETRIES` overrides this when set
+    #[serde(default = "default_pull_retries")]
+    pub pull_retries: u32,
+
+    /// Seconds before the first pull retry, doubled after each attempt (default: 1, range 0-60)
+    /// `OPENCODE_DOCKER_PULL_RETRY_DELAY` overrides this when set
+    #[serde(default = "default_pull_retry_delay")]
+    pub pull_retry_delay: u64,
+
+    /// Previous images kept for `occ update --rollback` (default: 3, range 1-10)
+    #[serde(default = "default_rollback_depth")]
+    pub rollback_depth: usize,
+
+    /// Compare the running opencode commit with the latest upstream during `occ status`
+    /// (default: false). The upstream lookup is cached briefly to spare the GitHub API.
+    #[serde(default)]
+    pub check_opencode_updates: bool,
+
     /// Bind mounts to apply when starting the container
     /// Format: ["/host/path:/container/path", "/host:/mnt:ro"]
     #[serde(default = "default_mounts")]
     pub mounts: Vec<String>,
+
+    /// Replaces the `opencode-cloud`/`opencode` stem of the container and volume
+    /// names (default: unset), e.g. "work" gives `work-sandbox` and `work-data`.
+    /// Lets unrelated stacks share a Docker host without sharing a container or data.
+    #[serde(default)]
+    pub container_prefix: Option<String>,
+
+    /// Sandbox instance commands use when neither `--sandbox-instance` nor
+    /// `OPENCODE_SANDBOX_INSTANCE` is given (default: unset, the shared sandbox).
+    /// Accepts an instance id or "auto". Set with `occ sandbox use <name>`.
+    #[serde(default)]
+    pub sandbox_instance: Option<String>,
+
+    /// Extra labels added to the container, e.g. {"com.example.team": "infra"}
+    /// Keys under `opencode-cloud.`, `org.opencode-cloud.`, and `org.opencontainers.`
+    /// and `managed-by` are reserved for the labels occ sets itself.
+    #[serde(default)]
+    pub container_labels: BTreeMap<String, String>,
 }
 
 fn default_opencode_web_port() -> u16 {
     3000
 }
 
-fn default_bind() -> String {
-    "localhost".to_string()
-}
-
 fn default_auto_restart() -> bool {
     true
 }
@@ -146,6 +206,10 @@ fn default_restart_delay() -> u32 {
     5
 }
 
+fn default_stop_timeout_seconds() -> u32 {
+    DEFAULT_STOP_TIMEOUT_SECS as u32
+}
+
 fn default_bind_address() -> String {
     "127.0.0.1".to_string()
 }
@@ -174,6 +238,26 @@ fn default_update_check() -> String {
     "always".to_string()
 }
 
+fn default_build_log_tail() -> usize {
+    DEFAULT_BUILD_LOG_BUFFER_SIZE
+}
+
+fn default_build_error_tail() -> usize {
+    DEFAULT_ERROR_LOG_BUFFER_SIZE
+}
+
+fn default_pull_retries() -> u32 {
+    DEFAULT_PULL_RETRIES
+}
+
+fn default_pull_retry_delay() -> u64 {
+    DEFAULT_PULL_RETRY_DELAY_SECS
+}
+
+fn default_rollback_depth() -> usize {
+    DEFAULT_ROLLBACK_DEPTH
+}
+
 pub fn default_mounts() -> Vec<String> {
     let Some(base_dirs) = BaseDirs::new() else {
         return Vec::new();
@@ -232,16 +316,72 @@ pub fn validate_bind_address(addr: &str) -> Result<IpAddr, String> {
     })
 }
 
+/// Validate a Dockerfile build arg name
+///
+/// Names must be non-empty and contain only letters, digits, and underscores.
+pub fn validate_build_arg_key(key: &str) -> Result<(), String> {
+    if key.is_empty() {
+        return Err("Build arg names must not be empty".to_string());
+    }
+    if !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!(
+            "Invalid build arg name: '{key}'. Use letters, digits, and underscores only"
+        ));
+    }
+    Ok(())
+}
+
+/// Label keys occ sets on containers and images itself
+const RESERVED_LABEL_PREFIXES: [&str; 3] = [
+    "opencode-cloud.",
+    "org.opencode-cloud.",
+    "org.opencontainers.",
+];
+
+/// Validate a `container_labels` key
+///
+/// Keys start and end with a letter or digit and otherwise use letters,
+/// digits, `.`, `-`, `_`, and `/`. Keys occ manages itself are rejected.
+pub fn validate_container_label_key(key: &str) -> Result<(), String> {
+    let (Some(first), Some(last)) = (key.chars().next(), key.chars().last()) else {
+        return Err("Container label keys must not be empty".to_string());
+    };
+    if !first.is_ascii_alphanumeric() || !last.is_ascii_alphanumeric() {
+        return Err(format!(
+            "Invalid container label key: '{key}'. It must start and end with a letter or digit"
+        ));
+    }
+    if let Some(bad) = key
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '.' | '-' | '_' | '/'))
+    {
+        return Err(format!(
+            "Invalid container label key: '{key}' contains '{bad}'; use letters, digits, '.', '-', '_', or '/'"
+        ));
+    }
+    let lower = key.to_ascii_lowercase();
+    if lower == "managed-by"
+        || RESERVED_LABEL_PREFIXES
+            .iter()
+            .any(|prefix| lower.starts_with(prefix))
+    {
+        return Err(format!(
+            "Container label key '{key}' is reserved for labels opencode-cloud sets itself"
+        ));
+    }
+    Ok(())
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: CONFIG_VERSION,
             opencode_web_port: default_opencode_web_port(),
-            bind: default_bind(),
             auto_restart: default_auto_restart(),
             boot_mode: default_boot_mode(),
             restart_retries: default_restart_retries(),
             restart_delay: default_restart_delay(),
+            stop_timeout_seconds: default_stop_timeout_seconds(),
             auth_username: None,
             auth_password: None,
             container_env: Vec::new(),
@@ -254,8 +394,19 @@ impl Default for Config {
             cockpit_port: default_cockpit_port(),
             cockpit_enabled: default_cockpit_enabled(),
             image_source: default_image_source(),
+            platform: None,
+            build_args: BTreeMap::new(),
             update_check: default_update_check(),
+            build_log_tail: default_build_log_tail(),
+            build_error_tail: default_build_error_tail(),
+            pull_retries: default_pull_retries(),
+            pull_retry_delay: default_pull_retry_delay(),
+            rollback_depth: default_rollback_depth(),
+            check_opencode_updates: false,
             mounts: default_mounts(),
+            container_prefix: None,
+            sandbox_instance: None,
+            container_labels: BTreeMap::new(),
         }
     }
 }
@@ -266,6 +417,11 @@ impl Config {
         Self::default()
     }
 
+    /// Graceful stop timeout as passed to Docker's stop API
+    pub fn stop_timeout_secs(&self) -> i64 {
+        i64::from(self.stop_timeout_seconds)
+    }
+
     /// Check if required auth credentials are configured
     ///
     /// Returns true if:
@@ -291,12 +447,13 @@ impl Config {
 
     /// Check if the bind address exposes the service to the network
     ///
-    /// Returns true if bind_address is "0.0.0.0" (IPv4 all interfaces) or
-    /// "::" (IPv6 all interfaces).
+    /// Returns true for wildcard addresses ("0.0.0.0", "::") and for any
+    /// specific IPv6 address other than loopback ("::1"), since those are
+    /// typically globally routable.
     pub fn is_network_exposed(&self) -> bool {
         match validate_bind_address(&self.bind_address) {
             Ok(IpAddr::V4(ip)) => ip.is_unspecified(),
-            Ok(IpAddr::V6(ip)) => ip.is_unspecified(),
+            Ok(IpAddr::V6(ip)) => !ip.is_loopback(),
             Err(_) => false, // Invalid addresses are not considered exposed
         }
     }
@@ -322,13 +479,14 @@ mod tests {
     #[test]
     fn test_default_config() {
         let config = Config::default();
-        assert_eq!(config.version, 1);
+        assert_eq!(config.version, CONFIG_VERSION);
         assert_eq!(config.opencode_web_port, 3000);
-        assert_eq!(config.bind, "localhost");
         assert!(config.auto_restart);
         assert_eq!(config.boot_mode, "user");
         assert_eq!(config.restart_retries, 3);
         assert_eq!(config.restart_delay, 5);
+        assert_eq!(config.stop_timeout_seconds, 30);
+        assert_eq!(config.stop_timeout_secs(), DEFAULT_STOP_TIMEOUT_SECS);
         assert!(config.auth_username.is_none());
         assert!(config.auth_password.is_none());
         assert!(config.container_env.is_empty());
@@ -356,11 +514,11 @@ mod tests {
         let config: Config = serde_json::from_str(json).unwrap();
         assert_eq!(config.version, 1);
         assert_eq!(config.opencode_web_port, 3000);
-        assert_eq!(config.bind, "localhost");
         assert!(config.auto_restart);
         assert_eq!(config.boot_mode, "user");
         assert_eq!(config.restart_retries, 3);
         assert_eq!(config.restart_delay, 5);
+        assert_eq!(config.stop_timeout_seconds, 30);
         assert!(config.auth_username.is_none());
         assert!(config.auth_password.is_none());
         assert!(config.container_env.is_empty());
@@ -378,11 +536,11 @@ mod tests {
         let config = Config {
             version: 1,
             opencode_web_port: 9000,
-            bind: "0.0.0.0".to_string(),
             auto_restart: false,
             boot_mode: "system".to_string(),
             restart_retries: 5,
             restart_delay: 10,
+            stop_timeout_seconds: 45,
             auth_username: None,
             auth_password: None,
             container_env: Vec::new(),
@@ -395,8 +553,22 @@ mod tests {
             cockpit_port: 9090,
             cockpit_enabled: true,
             image_source: default_image_source(),
+            platform: Some("linux/arm64".to_string()),
+            build_args: BTreeMap::from([("OPENCODE_SOURCE".to_string(), "remote".to_string())]),
             update_check: default_update_check(),
+            build_log_tail: 50,
+            build_error_tail: 20,
+            pull_retries: 5,
+            pull_retry_delay: 10,
+            rollback_depth: 5,
+            check_opencode_updates: false,
             mounts: Vec::new(),
+            container_prefix: Some("work".to_string()),
+            sandbox_instance: Some("mytree".to_string()),
+            container_labels: BTreeMap::from([(
+                "com.example.team".to_string(),
+                "infra".to_string(),
+            )]),
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: Config = serde_json::from_str(&json).unwrap();
@@ -404,6 +576,7 @@ mod tests {
         assert_eq!(parsed.boot_mode, "system");
         assert_eq!(parsed.restart_retries, 5);
         assert_eq!(parsed.restart_delay, 10);
+        assert_eq!(parsed.stop_timeout_seconds, 45);
         assert_eq!(parsed.bind_address, "0.0.0.0");
         assert!(parsed.trust_proxy);
         assert_eq!(parsed.rate_limit_attempts, 10);
@@ -577,6 +750,21 @@ mod tests {
         assert!(config.is_network_exposed());
     }
 
+    #[test]
+    fn test_is_network_exposed_specific_ipv6() {
+        let config = Config {
+            bind_address: "2001:db8::10".to_string(),
+            ..Config::default()
+        };
+        assert!(config.is_network_exposed());
+
+        let bracketed = Config {
+            bind_address: "[fd00::10]".to_string(),
+            ..Config::default()
+        };
+        assert!(bracketed.is_network_exposed());
+    }
+
     #[test]
     fn test_is_network_exposed_localhost_false() {
         let config = Config::default();
@@ -701,12 +889,14 @@ mod tests {
         let config = Config {
             image_source: "build".to_string(),
             update_check: "never".to_string(),
+            check_opencode_updates: true,
             ..Config::default()
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: Config = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.image_source, "build");
         assert_eq!(parsed.update_check, "never");
+        assert!(parsed.check_opencode_updates);
     }
 
     #[test]
@@ -716,6 +906,13 @@ mod tests {
         let config: Config = serde_json::from_str(json).unwrap();
         assert_eq!(config.image_source, "prebuilt");
         assert_eq!(config.update_check, "always");
+        assert_eq!(config.build_log_tail, 20);
+        assert_eq!(config.build_error_tail, 10);
+        assert_eq!(config.pull_retries, 3);
+        assert_eq!(config.pull_retry_delay, 1);
+        assert_eq!(config.rollback_depth, 3);
+        assert!(config.platform.is_none());
+        assert!(!config.check_opencode_updates);
     }
 
     // Tests for mounts field
@@ -2,7 +2,13 @@
 //!
 //! Validates the configuration and provides exact commands to fix issues.
 
-use super::schema::{Config, validate_bind_address};
+use super::schema::{
+    Config, validate_bind_address, validate_build_arg_key, validate_container_label_key,
+};
+use crate::docker::{
+    BUILD_LOG_TAIL_RANGE, PULL_RETRIES_RANGE, PULL_RETRY_DELAY_RANGE, ROLLBACK_DEPTH_RANGE,
+    validate_container_prefix, validate_platform, validate_sandbox_instance,
+};
 use console::style;
 
 /// A configuration validation error with an actionable fix command
@@ -64,6 +70,15 @@ pub fn validate_config(config: &Config) -> Result<Vec<ValidationWarning>, Valida
         });
     }
 
+    // Stop timeout validation
+    if config.stop_timeout_seconds == 0 {
+        return Err(ValidationError {
+            field: "stop_timeout_seconds".to_string(),
+            message: "stop_timeout_seconds must be > 0".to_string(),
+            fix_command: "occ config set stop_timeout_seconds 30".to_string(),
+        });
+    }
+
     // Rate limit validation
     if config.rate_limit_attempts == 0 {
         return Err(ValidationError {
@@ -81,6 +96,116 @@ pub fn validate_config(config: &Config) -> Result<Vec<ValidationWarning>, Valida
         });
     }
 
+    // Build arg validation
+    for key in config.build_args.keys() {
+        if let Err(msg) = validate_build_arg_key(key) {
+            return Err(ValidationError {
+                field: "build_args".to_string(),
+                message: msg,
+                fix_command: format!("occ config set build_args '-{key}'"),
+            });
+        }
+    }
+
+    // Container label validation
+    for key in config.container_labels.keys() {
+        if let Err(msg) = validate_container_label_key(key) {
+            return Err(ValidationError {
+                field: "container_labels".to_string(),
+                message: msg,
+                fix_command: format!("occ config set container_labels '-{key}'"),
+            });
+        }
+    }
+
+    // Build log tail validation
+    for (field, value, default) in [
+        ("build_log_tail", config.build_log_tail, 20),
+        ("build_error_tail", config.build_error_tail, 10),
+    ] {
+        if !BUILD_LOG_TAIL_RANGE.contains(&value) {
+            return Err(ValidationError {
+                field: field.to_string(),
+                message: format!(
+                    "{field} must be between {} and {}",
+                    BUILD_LOG_TAIL_RANGE.start(),
+                    BUILD_LOG_TAIL_RANGE.end()
+                ),
+                fix_command: format!("occ config set {field} {default}"),
+            });
+        }
+    }
+
+    // Pull retry validation
+    if !PULL_RETRIES_RANGE.contains(&config.pull_retries) {
+        return Err(ValidationError {
+            field: "pull_retries".to_string(),
+            message: format!(
+                "pull_retries must be between {} and {}",
+                PULL_RETRIES_RANGE.start(),
+                PULL_RETRIES_RANGE.end()
+            ),
+            fix_command: "occ config set pull_retries 3".to_string(),
+        });
+    }
+    if !PULL_RETRY_DELAY_RANGE.contains(&config.pull_retry_delay) {
+        return Err(ValidationError {
+            field: "pull_retry_delay".to_string(),
+            message: format!(
+                "pull_retry_delay must be between {} and {} seconds",
+                PULL_RETRY_DELAY_RANGE.start(),
+                PULL_RETRY_DELAY_RANGE.end()
+            ),
+            fix_command: "occ config set pull_retry_delay 1".to_string(),
+        });
+    }
+
+    // Rollback ring depth validation
+    if !ROLLBACK_DEPTH_RANGE.contains(&config.rollback_depth) {
+        return Err(ValidationError {
+            field: "rollback_depth".to_string(),
+            message: format!(
+                "rollback_depth must be between {} and {}",
+                ROLLBACK_DEPTH_RANGE.start(),
+                ROLLBACK_DEPTH_RANGE.end()
+            ),
+            fix_command: "occ config set rollback_depth 3".to_string(),
+        });
+    }
+
+    // Image platform validation
+    if let Some(platform) = &config.platform
+        && let Err(msg) = validate_platform(platform)
+    {
+        return Err(ValidationError {
+            field: "platform".to_string(),
+            message: msg,
+            fix_command: "occ config set platform ''".to_string(),
+        });
+    }
+
+    // Container prefix validation
+    if let Some(prefix) = &config.container_prefix
+        && let Err(msg) = validate_container_prefix(prefix)
+    {
+        return Err(ValidationError {
+            field: "container_prefix".to_string(),
+            message: msg,
+            fix_command: "occ config set container_prefix ''".to_string(),
+        });
+    }
+
+    // Persisted sandbox instance selection
+    if let Some(instance) = &config.sandbox_instance
+        && let Err(msg) = validate_sandbox_instance(instance)
+    {
+        return Err(ValidationError {
+            field: "sandbox_instance".to_string(),
+            message: msg,
+            fix_command: "occ sandbox use default".to_string(),
+        });
+    }
+
     // Warnings (non-fatal)
 
     // Network exposure without auth
@@ -200,6 +325,18 @@ mod tests {
         assert_eq!(err.field, "boot_mode");
     }
 
+    #[test]
+    fn test_stop_timeout_zero() {
+        let config = Config {
+            stop_timeout_seconds: 0,
+            ..Config::default()
+        };
+        let result = validate_config(&config);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.field, "stop_timeout_seconds");
+    }
+
     #[test]
     fn test_rate_limit_attempts_zero() {
         let config = Config {
@@ -224,6 +361,97 @@ mod tests {
         assert_eq!(err.field, "rate_limit_window_seconds");
     }
 
+    #[test]
+    fn test_invalid_build_arg_key() {
+        let config = Config {
+            build_args: [("BAD KEY".to_string(), "x".to_string())].into(),
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "build_args");
+        assert_eq!(err.fix_command, "occ config set build_args '-BAD KEY'");
+    }
+
+    #[test]
+    fn test_build_log_tail_out_of_range() {
+        let config = Config {
+            build_error_tail: 1000,
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "build_error_tail");
+        assert_eq!(err.fix_command, "occ config set build_error_tail 10");
+    }
+
+    #[test]
+    fn test_invalid_container_label_key() {
+        let config = Config {
+            container_labels: [("opencode-cloud.instance".to_string(), "x".to_string())].into(),
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "container_labels");
+        assert_eq!(
+            err.fix_command,
+            "occ config set container_labels '-opencode-cloud.instance'"
+        );
+
+        let config = Config {
+            container_labels: [("com.example/team".to_string(), "infra".to_string())].into(),
+            ..Config::default()
+        };
+        assert!(validate_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_pull_retries_out_of_range() {
+        let config = Config {
+            pull_retries: 0,
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "pull_retries");
+        assert_eq!(err.fix_command, "occ config set pull_retries 3");
+    }
+
+    #[test]
+    fn test_rollback_depth_out_of_range() {
+        let config = Config {
+            rollback_depth: 0,
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "rollback_depth");
+        assert_eq!(err.fix_command, "occ config set rollback_depth 3");
+    }
+
+    #[test]
+    fn test_invalid_platform() {
+        let config = Config {
+            platform: Some("arm64".to_string()),
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "platform");
+        assert_eq!(err.fix_command, "occ config set platform ''");
+    }
+
+    #[test]
+    fn test_invalid_container_prefix() {
+        let config = Config {
+            container_prefix: Some("my stack".to_string()),
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "container_prefix");
+
+        let config = Config {
+            container_prefix: Some("work".to_string()),
+            ..Config::default()
+        };
+        assert!(validate_config(&config).is_ok());
+    }
+
     #[test]
     fn test_network_exposed_without_auth_warning() {
         let config = Config {
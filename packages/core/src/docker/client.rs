@@ -4,10 +4,14 @@
 //! errors gracefully and provides clear error messages.
 
 use bollard::Docker;
+use bollard::errors::Error as BollardError;
+use bollard::models::{ContainerInspectResponse, ImageSummary, SystemInfo};
+use bollard::query_parameters::{InspectContainerOptions, ListImagesOptions};
 use std::path::PathBuf;
 use std::time::Duration;
 
 use super::error::DockerError;
+use super::retry::retry_transient;
 use crate::host::{HostConfig, SshTunnel};
 
 /// Default Unix socket path used when `DOCKER_HOST` does not specify a socket.
@@ -36,6 +40,13 @@ pub enum DockerEndpoint {
     Unix(PathBuf),
     /// HTTP base URL (remote Docker via SSH tunnel).
     Http(String),
+    /// HTTPS base URL plus client certificates (remote Docker over TLS).
+    Tls {
+        url: String,
+        ca: PathBuf,
+        cert: PathBuf,
+        key: PathBuf,
+    },
 }
 
 impl DockerClient {
@@ -83,6 +94,10 @@ impl DockerClient {
     /// * `host` - Remote host configuration
     /// * `host_name` - Name of the host (for display purposes)
     pub async fn connect_remote(host: &HostConfig, host_name: &str) -> Result<Self, DockerError> {
+        if host.uses_tls() {
+            return Self::connect_tls(host, host_name, 120).await;
+        }
+
         // Create SSH tunnel
         let tunnel = SshTunnel::new(host, host_name)
             .map_err(|e| DockerError::Connection(format!("SSH tunnel failed: {e}")))?;
@@ -148,6 +163,10 @@ impl DockerClient {
         host_name: &str,
         timeout_secs: u64,
     ) -> Result<Self, DockerError> {
+        if host.uses_tls() {
+            return Self::connect_tls(host, host_name, timeout_secs).await;
+        }
+
         let tunnel = SshTunnel::new(host, host_name)
             .map_err(|e| DockerError::Connection(format!("SSH tunnel failed: {e}")))?;
         let endpoint = Self::endpoint_from_tunnel(&tunnel);
@@ -174,9 +193,51 @@ impl DockerClient {
         })
     }
 
+    /// Create client connecting directly to a TLS-secured Docker daemon
+    ///
+    /// Used instead of the SSH tunnel when the host config carries
+    /// `tls_ca`/`tls_cert`/`tls_key` paths.
+    async fn connect_tls(
+        host: &HostConfig,
+        host_name: &str,
+        timeout_secs: u64,
+    ) -> Result<Self, DockerError> {
+        let Some(DockerEndpoint::Tls { url, ca, cert, key }) = Self::endpoint_from_tls(host) else {
+            return Err(DockerError::Connection(format!(
+                "Host '{host_name}' has incomplete TLS settings"
+            )));
+        };
+        tracing::debug!("Connecting to remote Docker via {} (TLS)", url);
+
+        let docker = Docker::connect_with_ssl(
+            &url,
+            &key,
+            &cert,
+            &ca,
+            timeout_secs,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .map_err(|e| DockerError::Connection(format!("TLS setup failed: {e}")))?;
+
+        docker.ping().await.map_err(|e| {
+            DockerError::Connection(format!(
+                "Failed to connect to Docker on {host_name} over TLS: {e}"
+            ))
+        })?;
+        tracing::info!("Connected to Docker on {} via TLS", host_name);
+
+        Ok(Self {
+            inner: docker,
+            _tunnel: None,
+            host_name: Some(host_name.to_string()),
+            endpoint: DockerEndpoint::Tls { url, ca, cert, key },
+        })
+    }
+
     /// Verify connection to Docker daemon
     ///
-    /// Returns Ok(()) if connected, descriptive error otherwise.
+    /// Returns Ok(()) if connected, descriptive error otherwise. A paused
+    /// Docker Desktop VM is reported as [`DockerError::DesktopPaused`].
     pub async fn verify_connection(&self) -> Result<(), DockerError> {
         self.inner.ping().await.map_err(DockerError::from)?;
         Ok(())
@@ -202,7 +263,7 @@ impl DockerClient {
 
     /// Check if this is a remote connection
     pub fn is_remote(&self) -> bool {
-        self._tunnel.is_some()
+        self.host_name.is_some()
     }
 
     /// Return the endpoint details used for raw Docker API calls.
@@ -213,6 +274,31 @@ impl DockerClient {
         &self.endpoint
     }
 
+    /// Inspect a container, retrying transient connection errors
+    pub async fn inspect_container(
+        &self,
+        container_name: &str,
+    ) -> Result<ContainerInspectResponse, BollardError> {
+        retry_transient("inspect", || {
+            self.inner
+                .inspect_container(container_name, None::<InspectContainerOptions>)
+        })
+        .await
+    }
+
+    /// List images, retrying transient connection errors
+    pub async fn list_images(
+        &self,
+        options: Option<ListImagesOptions>,
+    ) -> Result<Vec<ImageSummary>, BollardError> {
+        retry_transient("image list", || self.inner.list_images(options.clone())).await
+    }
+
+    /// Fetch daemon info, retrying transient connection errors
+    pub async fn info(&self) -> Result<SystemInfo, BollardError> {
+        retry_transient("info", || self.inner.info()).await
+    }
+
     /// Access inner Bollard client for advanced operations
     pub fn inner(&self) -> &Docker {
         &self.inner
@@ -239,6 +325,17 @@ impl DockerClient {
     fn endpoint_from_tunnel(tunnel: &SshTunnel) -> DockerEndpoint {
         DockerEndpoint::Http(format!("http://127.0.0.1:{}", tunnel.local_port()))
     }
+
+    /// Build the HTTPS endpoint for a host reached over Docker TLS.
+    fn endpoint_from_tls(host: &HostConfig) -> Option<DockerEndpoint> {
+        let (ca, cert, key) = host.tls_paths()?;
+        Some(DockerEndpoint::Tls {
+            url: host.docker_tls_url()?,
+            ca,
+            cert,
+            key,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -268,4 +365,19 @@ mod tests {
             assert!(!client.is_remote());
         }
     }
+
+    #[test]
+    fn endpoint_from_tls_requires_all_paths() {
+        let ssh = HostConfig::new("example.com");
+        assert!(DockerClient::endpoint_from_tls(&ssh).is_none());
+
+        let tls = HostConfig::new("example.com").with_tls("/c/ca.pem", "/c/cert.pem", "/c/key.pem");
+        match DockerClient::endpoint_from_tls(&tls) {
+            Some(DockerEndpoint::Tls { url, key, .. }) => {
+                assert_eq!(url, "https://example.com:2376");
+                assert_eq!(key, PathBuf::from("/c/key.pem"));
+            }
+            other => panic!("expected TLS endpoint, got {other:?}"),
+        }
+    }
 }
@@ -10,14 +10,16 @@ use super::volume::{
     MOUNT_CACHE, MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, MOUNT_SSH, MOUNT_STATE, MOUNT_USERS,
 };
 use super::{DockerClient, DockerError};
+use crate::config::validate_container_label_key;
 use bollard::models::ContainerCreateBody;
 use bollard::query_parameters::{
-    CreateContainerOptions, RemoveContainerOptions, StartContainerOptions, StopContainerOptions,
+    CreateContainerOptions, ListContainersOptions, RemoveContainerOptions,
+    RenameContainerOptionsBuilder, StartContainerOptions, StopContainerOptions,
 };
 use bollard::service::{
-    HostConfig, Mount, MountPointTypeEnum, MountTypeEnum, PortBinding, PortMap,
+    ContainerSummary, HostConfig, Mount, MountPointTypeEnum, MountTypeEnum, PortBinding, PortMap,
 };
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use tracing::debug;
 
 /// Default container name
@@ -26,11 +28,57 @@ pub const CONTAINER_NAME: &str = "opencode-cloud-sandbox";
 /// Default port for opencode web UI
 pub const OPENCODE_WEB_PORT: u16 = 3000;
 
+/// XDG base directories defaulted in the container environment
+const XDG_ENV_DEFAULTS: [(&str, &str); 4] = [
+    ("XDG_DATA_HOME", "/home/opencoder/.local/share"),
+    ("XDG_STATE_HOME", "/home/opencoder/.local/state"),
+    ("XDG_CONFIG_HOME", "/home/opencoder/.config"),
+    ("XDG_CACHE_HOME", "/home/opencoder/.cache"),
+];
+
+/// Environment keys `create_container` sets itself when not already provided
+///
+/// Overriding these moves where opencode keeps its data inside the container,
+/// or switches the entrypoint's init mode.
+pub const RESERVED_ENV_KEYS: [&str; 5] = [
+    "XDG_DATA_HOME",
+    "XDG_STATE_HOME",
+    "XDG_CONFIG_HOME",
+    "XDG_CACHE_HOME",
+    "USE_SYSTEMD",
+];
+
+/// Label recording which environment keys came from the caller of `create_container`
+///
+/// Lets drift checks tell configured variables apart from image and default
+/// ones, so removing a variable from config is detected too.
+pub const ENV_KEYS_LABEL_KEY: &str = "opencode-cloud.env-keys";
+
+/// Environment of an existing container
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContainerEnv {
+    /// All `KEY=value` entries, including image and default variables
+    pub env: Vec<String>,
+    /// Keys recorded in `ENV_KEYS_LABEL_KEY`, or `None` for containers created
+    /// before the label existed
+    pub configured_keys: Option<Vec<String>>,
+}
+
 fn has_env_key(env: &[String], key: &str) -> bool {
     let prefix = format!("{key}=");
     env.iter().any(|entry| entry.starts_with(&prefix))
 }
 
+/// Host IP for Docker port bindings
+///
+/// Docker expects a bare IP, so `localhost` becomes `127.0.0.1` and IPv6
+/// literals lose any surrounding brackets.
+fn port_binding_host_ip(bind_addr: &str) -> String {
+    crate::config::validate_bind_address(bind_addr)
+        .map(|ip| ip.to_string())
+        .unwrap_or_else(|_| bind_addr.to_string())
+}
+
 fn resolved_container_name(name: &str) -> String {
     remap_container_name(name)
 }
@@ -51,6 +99,8 @@ fn resolved_container_name(name: &str) -> String {
 /// * `cockpit_enabled` - Whether to enable Cockpit port mapping (defaults to false)
 /// * `systemd_enabled` - Whether to use systemd as init (defaults to false)
 /// * `bind_mounts` - User-defined bind mounts from config and CLI flags (optional)
+/// * `container_labels` - Extra labels from config; occ's own labels take precedence (optional)
+/// * `platform` - Image platform such as "linux/arm64" (defaults to the daemon's)
 #[allow(clippy::too_many_arguments)]
 pub async fn create_container(
     client: &DockerClient,
@@ -63,6 +113,85 @@ pub async fn create_container(
     cockpit_enabled: Option<bool>,
     systemd_enabled: Option<bool>,
     bind_mounts: Option<Vec<ParsedMount>>,
+    container_labels: Option<&BTreeMap<String, String>>,
+    platform: Option<&str>,
+) -> Result<String, DockerError> {
+    create_container_with_storage(
+        client,
+        name,
+        image,
+        opencode_web_port,
+        env_vars,
+        bind_address,
+        cockpit_port,
+        cockpit_enabled,
+        systemd_enabled,
+        bind_mounts,
+        container_labels,
+        platform,
+        ContainerStorage::Managed,
+    )
+    .await
+}
+
+/// Create a throwaway opencode container that mounts no persistent storage
+///
+/// Used to check that an image and configuration boot healthy without two
+/// servers writing the same session and state directories. Managed volumes
+/// and bind mounts are skipped, so everything the container writes lives in
+/// its own layer and disappears when it is removed. Cockpit is disabled and
+/// no extra labels are applied.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_scratch_container(
+    client: &DockerClient,
+    name: &str,
+    image: Option<&str>,
+    opencode_web_port: u16,
+    env_vars: Option<Vec<String>>,
+    bind_address: Option<&str>,
+    systemd_enabled: bool,
+    platform: Option<&str>,
+) -> Result<String, DockerError> {
+    create_container_with_storage(
+        client,
+        Some(name),
+        image,
+        Some(opencode_web_port),
+        env_vars,
+        bind_address,
+        None,
+        Some(false),
+        Some(systemd_enabled),
+        None,
+        None,
+        platform,
+        ContainerStorage::Scratch,
+    )
+    .await
+}
+
+/// Whether a new container mounts the managed volumes
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ContainerStorage {
+    Managed,
+    Scratch,
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn create_container_with_storage(
+    client: &DockerClient,
+    name: Option<&str>,
+    image: Option<&str>,
+    opencode_web_port: Option<u16>,
+    env_vars: Option<Vec<String>>,
+    bind_address: Option<&str>,
+    cockpit_port: Option<u16>,
+    cockpit_enabled: Option<bool>,
+    systemd_enabled: Option<bool>,
+    bind_mounts: Option<Vec<ParsedMount>>,
+    container_labels: Option<&BTreeMap<String, String>>,
+    platform: Option<&str>,
+    storage: ContainerStorage,
 ) -> Result<String, DockerError> {
     let names = active_resource_names();
     let container_name = name
@@ -116,6 +245,9 @@ pub async fn create_container(
     // Create volume mounts (skip if overridden by bind mounts)
     let mut mounts = Vec::new();
     let mut add_volume_mount = |target: &str, source: &str| {
+        if storage == ContainerStorage::Scratch {
+            return;
+        }
         if bind_targets.contains(target) {
             tracing::trace!(
                 "Skipping volume mount for {} (overridden by bind mount)",
@@ -147,14 +279,14 @@ pub async fn create_container(
     }
 
     // Create port bindings (default to localhost for security)
-    let bind_addr = bind_address.unwrap_or("127.0.0.1");
+    let bind_addr = port_binding_host_ip(bind_address.unwrap_or("127.0.0.1"));
     let mut port_bindings: PortMap = HashMap::new();
 
     // opencode web port
     port_bindings.insert(
         "3000/tcp".to_string(),
         Some(vec![PortBinding {
-            host_ip: Some(bind_addr.to_string()),
+            host_ip: Some(bind_addr.clone()),
             host_port: Some(port.to_string()),
         }]),
     );
@@ -165,7 +297,7 @@ pub async fn create_container(
         port_bindings.insert(
             "9090/tcp".to_string(),
             Some(vec![PortBinding {
-                host_ip: Some(bind_addr.to_string()),
+                host_ip: Some(bind_addr.clone()),
                 host_port: Some(cockpit_port_val.to_string()),
             }]),
         );
@@ -221,17 +353,15 @@ pub async fn create_container(
 
     // Build environment variables
     let mut env = env_vars.unwrap_or_default();
-    if !has_env_key(&env, "XDG_DATA_HOME") {
-        env.push("XDG_DATA_HOME=/home/opencoder/.local/share".to_string());
-    }
-    if !has_env_key(&env, "XDG_STATE_HOME") {
-        env.push("XDG_STATE_HOME=/home/opencoder/.local/state".to_string());
-    }
-    if !has_env_key(&env, "XDG_CONFIG_HOME") {
-        env.push("XDG_CONFIG_HOME=/home/opencoder/.config".to_string());
-    }
-    if !has_env_key(&env, "XDG_CACHE_HOME") {
-        env.push("XDG_CACHE_HOME=/home/opencoder/.cache".to_string());
+    let configured_keys = env
+        .iter()
+        .filter_map(|entry| entry.split_once('=').map(|(key, _)| key))
+        .collect::<Vec<_>>()
+        .join(",");
+    for (key, value) in XDG_ENV_DEFAULTS {
+        if !has_env_key(&env, key) {
+            env.push(format!("{key}={value}"));
+        }
     }
     // Add USE_SYSTEMD=1 when systemd is enabled to tell entrypoint to use systemd
     if systemd_enabled_val && !has_env_key(&env, "USE_SYSTEMD") {
@@ -240,16 +370,22 @@ pub async fn create_container(
     let final_env = if env.is_empty() { None } else { Some(env) };
 
     // Create container config (bollard v0.20+ uses ContainerCreateBody)
-    let mut labels = HashMap::from([("managed-by".to_string(), "opencode-cloud".to_string())]);
+    let mut labels: HashMap<String, String> = container_labels
+        .map(|extra| extra.clone().into_iter().collect())
+        .unwrap_or_default();
+    labels.insert("managed-by".to_string(), "opencode-cloud".to_string());
     if let Some(instance_id) = names.instance_id.as_deref() {
         // This label helps profile-aware cleanup target only the active isolated resources.
         labels.insert(INSTANCE_LABEL_KEY.to_string(), instance_id.to_string());
     }
+    if !configured_keys.is_empty() {
+        labels.insert(ENV_KEYS_LABEL_KEY.to_string(), configured_keys);
+    }
 
     let config = ContainerCreateBody {
         image: Some(image_name.to_string()),
         hostname: Some(names.hostname),
-        working_dir: Some("/home/opencoder/workspace".to_string()),
+        working_dir: Some(MOUNT_PROJECTS.to_string()),
         exposed_ports: Some(exposed_ports),
         env: final_env,
         labels: Some(labels),
@@ -260,7 +396,7 @@ pub async fn create_container(
     // Create container
     let options = CreateContainerOptions {
         name: Some(container_name.clone()),
-        platform: String::new(),
+        platform: platform.unwrap_or_default().to_string(),
     };
 
     let response = client
@@ -374,12 +510,73 @@ pub async fn remove_container(
     Ok(())
 }
 
+/// Rename a container
+///
+/// # Arguments
+/// * `client` - Docker client
+/// * `name` - Current container name
+/// * `new_name` - Name to assign
+pub async fn rename_container(
+    client: &DockerClient,
+    name: &str,
+    new_name: &str,
+) -> Result<(), DockerError> {
+    let resolved_name = resolved_container_name(name);
+    let resolved_new_name = resolved_container_name(new_name);
+    debug!(
+        "Renaming container {} to {}",
+        resolved_name, resolved_new_name
+    );
+
+    let options = RenameContainerOptionsBuilder::default()
+        .name(&resolved_new_name)
+        .build();
+
+    client
+        .inner()
+        .rename_container(&resolved_name, options)
+        .await
+        .map_err(|e| {
+            DockerError::Container(format!(
+                "Failed to rename container {resolved_name} to {resolved_new_name}: {e}"
+            ))
+        })?;
+
+    debug!(
+        "Container {} renamed to {}",
+        resolved_name, resolved_new_name
+    );
+    Ok(())
+}
+
+/// List all containers (running or not) carrying the sandbox instance label
+pub async fn list_instance_containers(
+    client: &DockerClient,
+) -> Result<Vec<ContainerSummary>, DockerError> {
+    debug!("Listing sandbox instance containers");
+
+    let options = ListContainersOptions {
+        all: true,
+        filters: Some(HashMap::from([(
+            "label".to_string(),
+            vec![INSTANCE_LABEL_KEY.to_string()],
+        )])),
+        ..Default::default()
+    };
+
+    client
+        .inner()
+        .list_containers(Some(options))
+        .await
+        .map_err(|e| DockerError::Container(format!("Failed to list containers: {e}")))
+}
+
 /// Check if container exists
 pub async fn container_exists(client: &DockerClient, name: &str) -> Result<bool, DockerError> {
     let resolved_name = resolved_container_name(name);
     debug!("Checking if container exists: {}", resolved_name);
 
-    match client.inner().inspect_container(&resolved_name, None).await {
+    match client.inspect_container(&resolved_name).await {
         Ok(_) => Ok(true),
         Err(bollard::errors::Error::DockerResponseServerError {
             status_code: 404, ..
@@ -395,7 +592,7 @@ pub async fn container_is_running(client: &DockerClient, name: &str) -> Result<b
     let resolved_name = resolved_container_name(name);
     debug!("Checking if container is running: {}", resolved_name);
 
-    match client.inner().inspect_container(&resolved_name, None).await {
+    match client.inspect_container(&resolved_name).await {
         Ok(info) => {
             let running = info.state.and_then(|s| s.running).unwrap_or(false);
             Ok(running)
@@ -414,7 +611,7 @@ pub async fn container_state(client: &DockerClient, name: &str) -> Result<String
     let resolved_name = resolved_container_name(name);
     debug!("Getting container state: {}", resolved_name);
 
-    match client.inner().inspect_container(&resolved_name, None).await {
+    match client.inspect_container(&resolved_name).await {
         Ok(info) => {
             let state = info
                 .state
@@ -435,7 +632,7 @@ pub async fn container_state(client: &DockerClient, name: &str) -> Result<String
 }
 
 /// Container port configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ContainerPorts {
     /// Host port for opencode web UI (mapped from container port 3000)
     pub opencode_port: Option<u16>,
@@ -466,8 +663,7 @@ pub async fn get_container_ports(
     debug!("Getting container ports: {}", resolved_name);
 
     let info = client
-        .inner()
-        .inspect_container(&resolved_name, None)
+        .inspect_container(&resolved_name)
         .await
         .map_err(|e| {
             DockerError::Container(format!("Failed to inspect container {resolved_name}: {e}"))
@@ -511,8 +707,7 @@ pub async fn get_container_bind_mounts(
     debug!("Getting container bind mounts: {}", resolved_name);
 
     let info = client
-        .inner()
-        .inspect_container(&resolved_name, None)
+        .inspect_container(&resolved_name)
         .await
         .map_err(|e| {
             DockerError::Container(format!("Failed to inspect container {resolved_name}: {e}"))
@@ -539,11 +734,99 @@ pub async fn get_container_bind_mounts(
     Ok(bind_mounts)
 }
 
+/// Labels an existing container got from `container_labels`
+///
+/// occ's own labels and the ones inherited from the image are left out.
+pub async fn get_container_custom_labels(
+    client: &DockerClient,
+    name: &str,
+) -> Result<BTreeMap<String, String>, DockerError> {
+    let resolved_name = resolved_container_name(name);
+    let info = client
+        .inspect_container(&resolved_name)
+        .await
+        .map_err(|e| {
+            DockerError::Container(format!("Failed to inspect container {resolved_name}: {e}"))
+        })?;
+    Ok(info
+        .config
+        .and_then(|config| config.labels)
+        .map(|labels| custom_labels(&labels))
+        .unwrap_or_default())
+}
+
+/// Keep only labels a user could have configured in `container_labels`
+pub fn custom_labels(labels: &HashMap<String, String>) -> BTreeMap<String, String> {
+    labels
+        .iter()
+        .filter(|(key, _)| validate_container_label_key(key).is_ok())
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+/// Get the environment of an existing container
+pub async fn get_container_env(
+    client: &DockerClient,
+    name: &str,
+) -> Result<ContainerEnv, DockerError> {
+    let resolved_name = resolved_container_name(name);
+    debug!("Getting container env: {}", resolved_name);
+
+    let info = client
+        .inspect_container(&resolved_name)
+        .await
+        .map_err(|e| {
+            DockerError::Container(format!("Failed to inspect container {resolved_name}: {e}"))
+        })?;
+
+    let config = info.config.unwrap_or_default();
+    let configured_keys = config
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(ENV_KEYS_LABEL_KEY))
+        .map(|keys| {
+            keys.split(',')
+                .filter(|key| !key.is_empty())
+                .map(str::to_string)
+                .collect()
+        });
+
+    Ok(ContainerEnv {
+        env: config.env.unwrap_or_default(),
+        configured_keys,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::docker::IMAGE_TAG_DEFAULT;
 
+    #[test]
+    fn port_binding_host_ip_normalizes_addresses() {
+        assert_eq!(port_binding_host_ip("127.0.0.1"), "127.0.0.1");
+        assert_eq!(port_binding_host_ip("localhost"), "127.0.0.1");
+        assert_eq!(port_binding_host_ip("[::1]"), "::1");
+        assert_eq!(port_binding_host_ip("fd00::10"), "fd00::10");
+    }
+
+    #[test]
+    fn custom_labels_skips_managed_and_image_labels() {
+        let labels = HashMap::from([
+            ("managed-by".to_string(), "opencode-cloud".to_string()),
+            (INSTANCE_LABEL_KEY.to_string(), "wt-a".to_string()),
+            (
+                "org.opencontainers.image.title".to_string(),
+                "opencode-cloud-sandbox".to_string(),
+            ),
+            ("com.example.team".to_string(), "infra".to_string()),
+        ]);
+        assert_eq!(
+            custom_labels(&labels),
+            BTreeMap::from([("com.example.team".to_string(), "infra".to_string())])
+        );
+    }
+
     #[test]
     fn container_constants_are_correct() {
         assert_eq!(CONTAINER_NAME, "opencode-cloud-sandbox");
@@ -555,4 +838,11 @@ mod tests {
         let expected = format!("{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}");
         assert_eq!(expected, "ghcr.io/prizz/opencode-cloud-sandbox:latest");
     }
+
+    #[test]
+    fn xdg_defaults_are_reserved() {
+        for (key, _) in XDG_ENV_DEFAULTS {
+            assert!(RESERVED_ENV_KEYS.contains(&key), "{key} not reserved");
+        }
+    }
 }
@@ -16,6 +16,10 @@ pub enum DockerError {
     #[error("Docker daemon not running. Start Docker Desktop or the Docker service.")]
     NotRunning,
 
+    /// Docker Desktop's VM is paused, so the daemon rejects every request
+    #[error("Docker Desktop is paused. Resume it from the whale menu or the Dashboard and retry.")]
+    DesktopPaused,
+
     /// Docker socket not found
     #[error("Docker socket not found. Is Docker installed and running?")]
     SocketNotFound,
@@ -56,7 +60,9 @@ impl From<bollard::errors::Error> for DockerError {
         let msg = err.to_string();
 
         // Detect common error patterns and provide better messages
-        if msg.contains("No such file or directory") {
+        if is_desktop_paused_message(&msg) {
+            DockerError::DesktopPaused
+        } else if msg.contains("No such file or directory") {
             DockerError::SocketNotFound
         } else if msg.contains("Cannot connect to the Docker daemon")
             || msg.contains("connection refused")
@@ -70,6 +76,13 @@ impl From<bollard::errors::Error> for DockerError {
     }
 }
 
+/// Docker Desktop answers with e.g. "Docker Desktop is manually paused.
+/// Unpause it through the Whale menu or the Dashboard." while its VM is paused.
+fn is_desktop_paused_message(msg: &str) -> bool {
+    let lower = msg.to_ascii_lowercase();
+    lower.contains("docker desktop") && lower.contains("paused")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,4 +101,19 @@ mod tests {
         let err = DockerError::Build("layer failed".to_string());
         assert!(err.to_string().contains("layer failed"));
     }
+
+    #[test]
+    fn paused_docker_desktop_response_is_recognized() {
+        let err = bollard::errors::Error::DockerResponseServerError {
+            status_code: 500,
+            message: "Docker Desktop is manually paused. Unpause it through the Whale menu or the Dashboard.".to_string(),
+        };
+        assert!(matches!(DockerError::from(err), DockerError::DesktopPaused));
+
+        let err = bollard::errors::Error::DockerResponseServerError {
+            status_code: 500,
+            message: "container is paused".to_string(),
+        };
+        assert!(matches!(DockerError::from(err), DockerError::Connection(_)));
+    }
 }
@@ -0,0 +1,135 @@
+//! Health transition history
+//!
+//! Keeps the most recent health status changes of the local service so
+//! `occ status --history` can show when it went unhealthy or recovered.
+//! Only changes are stored; repeated checks with the same result are dropped.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::profile::{DockerResourceNames, active_resource_names};
+
+/// Maximum number of transitions kept on disk
+pub const HEALTH_HISTORY_LIMIT: usize = 20;
+
+/// A single observed health status change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthTransition {
+    /// When the new status was first observed (ISO8601)
+    pub at: String,
+    /// Status label, e.g. "healthy", "starting", "stopped"
+    pub status: String,
+}
+
+/// Recent health transitions, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthHistory {
+    pub transitions: Vec<HealthTransition>,
+}
+
+impl HealthHistory {
+    /// Last recorded status, if any
+    pub fn current_status(&self) -> Option<&str> {
+        self.transitions.last().map(|t| t.status.as_str())
+    }
+
+    /// Append `status` observed at `at` if it differs from the last entry
+    ///
+    /// Returns true when a transition was added. Older entries are dropped
+    /// beyond [`HEALTH_HISTORY_LIMIT`].
+    pub fn record_at(&mut self, status: &str, at: String) -> bool {
+        if self.current_status() == Some(status) {
+            return false;
+        }
+        self.transitions.push(HealthTransition {
+            at,
+            status: status.to_string(),
+        });
+        if self.transitions.len() > HEALTH_HISTORY_LIMIT {
+            let excess = self.transitions.len() - HEALTH_HISTORY_LIMIT;
+            self.transitions.drain(..excess);
+        }
+        true
+    }
+}
+
+/// Get the path to the health history file
+pub fn get_health_history_path() -> Option<PathBuf> {
+    let names = active_resource_names();
+    get_health_history_path_for_names(&names)
+}
+
+pub fn get_health_history_path_for_names(names: &DockerResourceNames) -> Option<PathBuf> {
+    crate::config::paths::get_data_dir().map(|p| p.join(&names.health_history_file))
+}
+
+/// Save health history to disk
+pub fn save_health_history(history: &HealthHistory) -> anyhow::Result<()> {
+    let path = get_health_history_path()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine health history path"))?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let json = serde_json::to_string_pretty(history)?;
+    std::fs::write(&path, json)?;
+    Ok(())
+}
+
+/// Load health history from disk (empty if missing or unreadable)
+pub fn load_health_history() -> HealthHistory {
+    get_health_history_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Record the current health status, writing only when it changed
+pub fn record_health_status(status: &str) -> anyhow::Result<()> {
+    let mut history = load_health_history();
+    if history.record_at(status, Utc::now().to_rfc3339()) {
+        save_health_history(&history)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_skips_repeated_status() {
+        let mut history = HealthHistory::default();
+        assert!(history.record_at("healthy", "t1".to_string()));
+        assert!(!history.record_at("healthy", "t2".to_string()));
+        assert!(history.record_at("unhealthy", "t3".to_string()));
+        assert!(history.record_at("healthy", "t4".to_string()));
+
+        let statuses: Vec<_> = history
+            .transitions
+            .iter()
+            .map(|t| t.status.as_str())
+            .collect();
+        assert_eq!(statuses, ["healthy", "unhealthy", "healthy"]);
+        assert_eq!(history.transitions[0].at, "t1");
+    }
+
+    #[test]
+    fn record_caps_history_length() {
+        let mut history = HealthHistory::default();
+        for i in 0..HEALTH_HISTORY_LIMIT + 5 {
+            let status = if i % 2 == 0 { "healthy" } else { "stopped" };
+            history.record_at(status, format!("t{i}"));
+        }
+        assert_eq!(history.transitions.len(), HEALTH_HISTORY_LIMIT);
+        assert_eq!(history.transitions[0].at, "t5");
+    }
+
+    #[test]
+    fn test_get_health_history_path() {
+        let path = get_health_history_path().unwrap();
+        assert!(path.to_string_lossy().contains("health-history"));
+    }
+}
@@ -31,10 +31,123 @@ use tar::Builder as TarBuilder;
 use tracing::{debug, warn};
 
 /// Default number of recent build log lines to capture for error context
-const DEFAULT_BUILD_LOG_BUFFER_SIZE: usize = 20;
+pub const DEFAULT_BUILD_LOG_BUFFER_SIZE: usize = 20;
 
 /// Default number of error lines to capture separately
-const DEFAULT_ERROR_LOG_BUFFER_SIZE: usize = 10;
+pub const DEFAULT_ERROR_LOG_BUFFER_SIZE: usize = 10;
+
+/// Accepted range for the build log tail sizes
+pub const BUILD_LOG_TAIL_RANGE: std::ops::RangeInclusive<usize> = 5..=500;
+
+/// Default attempts per registry when pulling the prebuilt image
+pub const DEFAULT_PULL_RETRIES: u32 = 3;
+
+/// Accepted range for pull attempts per registry
+pub const PULL_RETRIES_RANGE: std::ops::RangeInclusive<u32> = 1..=10;
+
+/// Default delay before the first pull retry; later retries double it
+pub const DEFAULT_PULL_RETRY_DELAY_SECS: u64 = 1;
+
+/// Accepted range for the pull retry delay, in seconds
+pub const PULL_RETRY_DELAY_RANGE: std::ops::RangeInclusive<u64> = 0..=60;
+
+/// Directory under the data dir for full logs of failed builds
+const BUILD_LOG_DIR: &str = "build-logs";
+
+/// How `build_image` keeps the build output
+#[derive(Debug, Clone)]
+pub struct BuildLogOptions {
+    /// Where to write the full build output if the build fails
+    /// (default: a timestamped file in `build-logs/` under the data dir)
+    pub save_path: Option<PathBuf>,
+    /// Recent log lines shown when a build fails (`OPENCODE_DOCKER_BUILD_LOG_TAIL` wins)
+    pub log_tail: usize,
+    /// Error lines shown when a build fails (`OPENCODE_DOCKER_BUILD_ERROR_TAIL` wins)
+    pub error_tail: usize,
+}
+
+impl Default for BuildLogOptions {
+    fn default() -> Self {
+        Self {
+            save_path: None,
+            log_tail: DEFAULT_BUILD_LOG_BUFFER_SIZE,
+            error_tail: DEFAULT_ERROR_LOG_BUFFER_SIZE,
+        }
+    }
+}
+
+/// Registry to pull the prebuilt image from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PullRegistry {
+    Ghcr,
+    DockerHub,
+}
+
+impl PullRegistry {
+    fn image_name(self) -> &'static str {
+        match self {
+            Self::Ghcr => IMAGE_NAME_GHCR,
+            Self::DockerHub => IMAGE_NAME_DOCKERHUB,
+        }
+    }
+}
+
+impl std::fmt::Display for PullRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ghcr => write!(f, "GHCR"),
+            Self::DockerHub => write!(f, "Docker Hub"),
+        }
+    }
+}
+
+impl std::str::FromStr for PullRegistry {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "ghcr" | "ghcr.io" => Ok(Self::Ghcr),
+            "dockerhub" | "docker-hub" | "docker.io" => Ok(Self::DockerHub),
+            other => Err(format!(
+                "Unknown registry '{other}'. Use 'ghcr' or 'dockerhub'."
+            )),
+        }
+    }
+}
+
+/// How `pull_image` picks registries and retries
+#[derive(Debug, Clone)]
+pub struct PullOptions {
+    /// Only pull from this registry (default: GHCR, then Docker Hub on failure)
+    pub registry: Option<PullRegistry>,
+    /// Attempts per registry (`OPENCODE_DOCKER_PULL_RETRIES` wins)
+    pub retries: u32,
+    /// Delay before the first retry in seconds, doubled after each attempt
+    /// (`OPENCODE_DOCKER_PULL_RETRY_DELAY` wins)
+    pub retry_delay_secs: u64,
+    /// Platform to pull, e.g. "linux/arm64" (default: the daemon's own)
+    pub platform: Option<String>,
+}
+
+impl Default for PullOptions {
+    fn default() -> Self {
+        Self {
+            registry: None,
+            retries: DEFAULT_PULL_RETRIES,
+            retry_delay_secs: DEFAULT_PULL_RETRY_DELAY_SECS,
+            platform: None,
+        }
+    }
+}
+
+impl PullOptions {
+    fn registries(&self) -> Vec<PullRegistry> {
+        match self.registry {
+            Some(registry) => vec![registry],
+            None => vec![PullRegistry::Ghcr, PullRegistry::DockerHub],
+        }
+    }
+}
 
 const LOCAL_OPENCODE_SUBMODULE_RELATIVE_PATH: &str = "packages/opencode";
 // Keep local source excludes aligned with Dockerfile Build Hygiene Rules and
@@ -88,15 +201,21 @@ fn profile_scoped_image_ids(images: &[bollard::models::ImageSummary]) -> Option<
     Some(ids)
 }
 
-/// Read a log buffer size from env with bounds
-fn read_log_buffer_size(var_name: &str, default: usize) -> usize {
-    let Ok(value) = env::var(var_name) else {
-        return default;
-    };
-    let Ok(parsed) = value.trim().parse::<usize>() else {
-        return default;
-    };
-    parsed.clamp(5, 500)
+/// Read a log buffer size from env with bounds, falling back to `configured`
+fn read_log_buffer_size(var_name: &str, configured: usize) -> usize {
+    read_env_setting(var_name, configured, &BUILD_LOG_TAIL_RANGE)
+}
+
+/// Read a numeric setting from env, falling back to `configured`, clamped to `range`
+fn read_env_setting<T>(var_name: &str, configured: T, range: &std::ops::RangeInclusive<T>) -> T
+where
+    T: std::str::FromStr + Ord + Copy,
+{
+    let parsed = env::var(var_name)
+        .ok()
+        .and_then(|value| value.trim().parse::<T>().ok())
+        .unwrap_or(configured);
+    parsed.clamp(*range.start(), *range.end())
 }
 
 /// Check if a line looks like an error message
@@ -156,7 +275,6 @@ async fn list_docker_images(
 ) -> Result<Vec<bollard::models::ImageSummary>, DockerError> {
     let list_options = ListImagesOptionsBuilder::new().all(true).build();
     client
-        .inner()
         .list_images(Some(list_options))
         .await
         .map_err(|e| DockerError::Image(format!("Failed to list images: {e}")))
@@ -258,12 +376,14 @@ async fn remove_image_ids(
 /// * `tag` - Image tag (defaults to IMAGE_TAG_DEFAULT)
 /// * `progress` - Progress reporter for build feedback
 /// * `no_cache` - If true, build without using Docker layer cache
+/// * `log_options` - Where the full output goes if the build fails
 pub async fn build_image(
     client: &DockerClient,
     tag: Option<&str>,
     progress: &mut ProgressReporter,
     no_cache: bool,
     build_args: Option<HashMap<String, String>>,
+    log_options: &BuildLogOptions,
 ) -> Result<String, DockerError> {
     let tag = effective_image_tag(tag.unwrap_or(IMAGE_TAG_DEFAULT));
     let full_name = format!("{IMAGE_NAME_GHCR}:{tag}");
@@ -323,7 +443,7 @@ pub async fn build_image(
     progress.update_spinner("build", "Waiting for Docker build to start");
 
     let mut maybe_image_id = None;
-    let mut log_state = BuildLogState::new();
+    let mut log_state = BuildLogState::new(log_options);
 
     while let Some(result) = stream.next().await {
         let Ok(info) = result else {
@@ -347,7 +467,7 @@ pub async fn build_image(
                 &log_state.error_logs,
                 &log_state.recent_buildkit_logs,
             );
-            return Err(DockerError::Build(context));
+            return Err(DockerError::Build(with_saved_log_path(context, &log_state)));
         }
 
         if let Some(aux) = info.aux {
@@ -383,18 +503,21 @@ struct BuildLogState {
     export_vertex_name: Option<String>,
     buildkit_logs_by_vertex_id: HashMap<String, String>,
     vertex_name_by_vertex_id: HashMap<String, String>,
+    /// Every build output line, saved to a file if the build fails
+    full_log: Vec<String>,
+    save_path: Option<PathBuf>,
+    /// Step count of each Dockerfile stage seen in BuildKit vertex names
+    step_totals_by_stage: HashMap<String, u32>,
+    /// `(stage, step)` pairs BuildKit reported as completed
+    completed_steps: HashSet<(String, u32)>,
 }
 
 impl BuildLogState {
-    fn new() -> Self {
-        let build_log_buffer_size = read_log_buffer_size(
-            "OPENCODE_DOCKER_BUILD_LOG_TAIL",
-            DEFAULT_BUILD_LOG_BUFFER_SIZE,
-        );
-        let error_log_buffer_size = read_log_buffer_size(
-            "OPENCODE_DOCKER_BUILD_ERROR_TAIL",
-            DEFAULT_ERROR_LOG_BUFFER_SIZE,
-        );
+    fn new(options: &BuildLogOptions) -> Self {
+        let build_log_buffer_size =
+            read_log_buffer_size("OPENCODE_DOCKER_BUILD_LOG_TAIL", options.log_tail);
+        let error_log_buffer_size =
+            read_log_buffer_size("OPENCODE_DOCKER_BUILD_ERROR_TAIL", options.error_tail);
         Self {
             recent_logs: VecDeque::with_capacity(build_log_buffer_size),
             error_logs: VecDeque::with_capacity(error_log_buffer_size),
@@ -407,8 +530,22 @@ impl BuildLogState {
             export_vertex_name: None,
             buildkit_logs_by_vertex_id: HashMap::new(),
             vertex_name_by_vertex_id: HashMap::new(),
+            full_log: Vec::new(),
+            save_path: options.save_path.clone(),
+            step_totals_by_stage: HashMap::new(),
+            completed_steps: HashSet::new(),
         }
     }
+
+    /// Completed and total steps across every stage seen so far
+    fn step_progress(&self) -> Option<(u64, u64)> {
+        let total: u64 = self
+            .step_totals_by_stage
+            .values()
+            .map(|&n| u64::from(n))
+            .sum();
+        (total > 0).then_some((self.completed_steps.len() as u64, total))
+    }
 }
 
 /// Clean up raw BuildKit vertex labels for user-friendly display.
@@ -467,6 +604,7 @@ fn handle_stream_message(
         state.recent_logs.pop_front();
     }
     state.recent_logs.push_back(msg.to_string());
+    state.full_log.push(msg.to_string());
 
     if is_error_line(msg) {
         if state.error_logs.len() >= state.error_log_buffer_size {
@@ -477,6 +615,10 @@ fn handle_stream_message(
 
     if msg.starts_with("Step ") {
         debug!("Build step: {}", msg);
+        // The legacy builder announces a step as it starts it
+        if let Some((_, current, total)) = parse_step_counts(msg) {
+            progress.set_step_progress("build", u64::from(current - 1), u64::from(total));
+        }
     }
 }
 
@@ -487,6 +629,10 @@ fn handle_buildkit_status(
 ) {
     let latest_logs = append_buildkit_logs(&mut state.buildkit_logs_by_vertex_id, status);
     update_buildkit_vertex_names(&mut state.vertex_name_by_vertex_id, status);
+    record_step_progress(state, status);
+    if let Some((done, total)) = state.step_progress() {
+        progress.set_step_progress("build", done, total);
+    }
     update_export_vertex_from_logs(
         &latest_logs,
         &state.vertex_name_by_vertex_id,
@@ -587,7 +733,47 @@ fn handle_stream_error(
         ),
         buildkit_hint
     );
-    DockerError::Build(context)
+    DockerError::Build(with_saved_log_path(context, state))
+}
+
+/// Save the full build output and point the error message at it
+fn with_saved_log_path(context: String, state: &BuildLogState) -> String {
+    match save_full_build_log(state) {
+        Ok(path) => format!("{context}\n\nFull build log: {}", path.display()),
+        Err(e) => {
+            warn!("Failed to save full build log: {e}");
+            context
+        }
+    }
+}
+
+fn save_full_build_log(state: &BuildLogState) -> Result<PathBuf, io::Error> {
+    let path = match &state.save_path {
+        Some(path) => path.clone(),
+        None => default_build_log_path().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "could not determine data directory",
+            )
+        })?,
+    };
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    let mut contents = state.full_log.join("\n");
+    contents.push('\n');
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+/// Timestamped file in `build-logs/` under the data dir
+fn default_build_log_path() -> Option<PathBuf> {
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    crate::config::paths::get_data_dir()
+        .map(|dir| dir.join(BUILD_LOG_DIR).join(format!("build-{stamp}.log")))
 }
 
 fn update_buildkit_vertex_names(
@@ -662,6 +848,45 @@ fn select_latest_buildkit_vertex(
     }
 }
 
+/// Track stage step counts and completions from BuildKit vertexes
+fn record_step_progress(state: &mut BuildLogState, status: &BuildkitStatusResponse) {
+    for vertex in &status.vertexes {
+        let name = if vertex.name.is_empty() {
+            state.vertex_name_by_vertex_id.get(&vertex.digest)
+        } else {
+            Some(&vertex.name)
+        };
+        let Some((stage, step, total)) = name.and_then(|name| parse_step_counts(name)) else {
+            continue;
+        };
+        let stage_total = state
+            .step_totals_by_stage
+            .entry(stage.to_string())
+            .or_default();
+        *stage_total = (*stage_total).max(total);
+        if vertex.completed.is_some() && vertex.error.is_empty() {
+            state.completed_steps.insert((stage.to_string(), step));
+        }
+    }
+}
+
+/// Parse `Step X/Y` (legacy builder) or `[stage N/M]` (BuildKit) into `(stage, step, total)`
+///
+/// The legacy builder has no stage names, so its stage is empty.
+fn parse_step_counts(name: &str) -> Option<(&str, u32, u32)> {
+    let name = name.trim();
+    let (stage, counts) = if let Some(rest) = name.strip_prefix("Step ") {
+        ("", rest.split_whitespace().next()?)
+    } else {
+        let label = name.strip_prefix('[')?.split(']').next()?;
+        label.rsplit_once(' ')?
+    };
+    let (step, total) = counts.split_once('/')?;
+    let step: u32 = step.parse().ok()?;
+    let total: u32 = total.parse().ok()?;
+    (step >= 1 && step <= total).then_some((stage, step, total))
+}
+
 fn parse_runtime_step(name: &str) -> Option<u32> {
     let prefix = "[runtime ";
     let start = name.find(prefix)? + prefix.len();
@@ -723,12 +948,12 @@ fn record_buildkit_logs(
             continue;
         }
 
+        let line = format!("[{name}] {message}");
         if state.recent_buildkit_logs.len() >= state.build_log_buffer_size {
             state.recent_buildkit_logs.pop_front();
         }
-        state
-            .recent_buildkit_logs
-            .push_back(format!("[{name}] {message}"));
+        state.full_log.push(line.clone());
+        state.recent_buildkit_logs.push_back(line);
     }
 }
 
@@ -757,12 +982,14 @@ fn append_buildkit_logs(
 
 /// Pull the opencode image from registry with automatic fallback
 ///
-/// Tries GHCR first, falls back to Docker Hub on failure.
+/// Tries GHCR first, falls back to Docker Hub on failure, unless
+/// `options.registry` pins one of them.
 /// Returns the full image:tag string on success.
 pub async fn pull_image(
     client: &DockerClient,
     tag: Option<&str>,
     progress: &mut ProgressReporter,
+    options: &PullOptions,
 ) -> Result<String, DockerError> {
     let requested_tag = tag.unwrap_or(IMAGE_TAG_DEFAULT);
     let resolved_tag = effective_image_tag(requested_tag);
@@ -774,56 +1001,42 @@ pub async fn pull_image(
         requested_tag
     };
 
-    // Try GHCR first
-    debug!(
-        "Attempting to pull from GHCR: {}:{}",
-        IMAGE_NAME_GHCR, registry_pull_tag
-    );
-    let ghcr_err =
-        match pull_from_registry(client, IMAGE_NAME_GHCR, registry_pull_tag, progress).await {
+    let mut failures = Vec::new();
+    for registry in options.registries() {
+        let image = registry.image_name();
+        debug!("Attempting to pull from {registry}: {image}:{registry_pull_tag}");
+        match pull_from_registry(client, image, registry_pull_tag, progress, options).await {
             Ok(()) => {
                 if isolated_default_tag {
                     retag_local_image(
                         client,
-                        &format!("{IMAGE_NAME_GHCR}:{registry_pull_tag}"),
+                        &format!("{image}:{registry_pull_tag}"),
                         &resolved_tag,
                     )
                     .await?;
+                    return Ok(format!("{IMAGE_NAME_GHCR}:{resolved_tag}"));
                 }
-                let full_name = format!("{IMAGE_NAME_GHCR}:{resolved_tag}");
-                return Ok(full_name);
+                return Ok(format!("{image}:{resolved_tag}"));
             }
-            Err(e) => e,
-        };
-
-    warn!(
-        "GHCR pull failed: {}. Trying Docker Hub fallback...",
-        ghcr_err
-    );
-
-    // Try Docker Hub as fallback
-    debug!(
-        "Attempting to pull from Docker Hub: {}:{}",
-        IMAGE_NAME_DOCKERHUB, registry_pull_tag
-    );
-    match pull_from_registry(client, IMAGE_NAME_DOCKERHUB, registry_pull_tag, progress).await {
-        Ok(()) => {
-            if isolated_default_tag {
-                retag_local_image(
-                    client,
-                    &format!("{IMAGE_NAME_DOCKERHUB}:{registry_pull_tag}"),
-                    &resolved_tag,
-                )
-                .await?;
-                return Ok(format!("{IMAGE_NAME_GHCR}:{resolved_tag}"));
+            Err(e) => {
+                warn!("{registry} pull failed: {e}");
+                failures.push((registry, e));
             }
-            let full_name = format!("{IMAGE_NAME_DOCKERHUB}:{resolved_tag}");
-            Ok(full_name)
         }
-        Err(dockerhub_err) => Err(DockerError::Pull(format!(
-            "Failed to pull from both registries. GHCR: {ghcr_err}. Docker Hub: {dockerhub_err}"
-        ))),
     }
+
+    if failures.len() == 1 {
+        let (_, err) = failures.remove(0);
+        return Err(err);
+    }
+    let details = failures
+        .iter()
+        .map(|(registry, err)| format!("{registry}: {err}"))
+        .collect::<Vec<_>>()
+        .join(". ");
+    Err(DockerError::Pull(format!(
+        "Failed to pull from both registries. {details}"
+    )))
 }
 
 async fn retag_local_image(
@@ -847,8 +1060,44 @@ async fn retag_local_image(
     Ok(())
 }
 
-/// Maximum number of retry attempts for pull operations
-const MAX_PULL_RETRIES: usize = 3;
+/// Layers seen across the attempts of one registry pull
+#[derive(Debug, Default)]
+struct PullLayers {
+    /// Layers that exist locally (already present or fully pulled)
+    complete: HashSet<String>,
+    /// Layers that started but did not finish
+    pending: HashSet<String>,
+}
+
+impl PullLayers {
+    fn record(&mut self, layer_id: &str, status: &str) {
+        match status {
+            "Already exists" | "Pull complete" => {
+                self.pending.remove(layer_id);
+                self.complete.insert(layer_id.to_string());
+            }
+            "Pulling fs layer" | "Waiting" | "Downloading" | "Verifying Checksum"
+            | "Download complete" | "Extracting"
+                if !self.complete.contains(layer_id) =>
+            {
+                self.pending.insert(layer_id.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    /// Shown before a retry so it's clear how much work is left
+    fn retry_summary(&self) -> String {
+        if self.complete.is_empty() && self.pending.is_empty() {
+            return "no layers downloaded yet".to_string();
+        }
+        format!(
+            "{} layer(s) already present, {} to re-download",
+            self.complete.len(),
+            self.pending.len()
+        )
+    }
+}
 
 /// Pull from a specific registry with retry logic
 async fn pull_from_registry(
@@ -856,27 +1105,53 @@ async fn pull_from_registry(
     image: &str,
     tag: &str,
     progress: &mut ProgressReporter,
+    options: &PullOptions,
 ) -> Result<(), DockerError> {
     let full_name = format!("{image}:{tag}");
+    let max_attempts = read_env_setting(
+        "OPENCODE_DOCKER_PULL_RETRIES",
+        options.retries,
+        &PULL_RETRIES_RANGE,
+    );
+    let base_delay_secs = read_env_setting(
+        "OPENCODE_DOCKER_PULL_RETRY_DELAY",
+        options.retry_delay_secs,
+        &PULL_RETRY_DELAY_RANGE,
+    );
 
     // Manual retry loop since async closures can't capture mutable references
+    let mut layers = PullLayers::default();
     let mut last_error = None;
-    for attempt in 1..=MAX_PULL_RETRIES {
-        debug!(
-            "Pull attempt {}/{} for {}",
-            attempt, MAX_PULL_RETRIES, full_name
-        );
-
-        match do_pull(client, image, tag, progress).await {
+    for attempt in 1..=max_attempts {
+        debug!("Pull attempt {attempt}/{max_attempts} for {full_name}");
+
+        match do_pull(
+            client,
+            image,
+            tag,
+            options.platform.as_deref(),
+            progress,
+            &mut layers,
+        )
+        .await
+        {
             Ok(()) => return Ok(()),
             Err(e) => {
-                warn!("Pull attempt {} failed: {}", attempt, e);
+                warn!("Pull attempt {attempt} failed: {e}");
                 last_error = Some(e);
 
-                if attempt < MAX_PULL_RETRIES {
-                    // Exponential backoff: 1s, 2s, 4s
-                    let delay_ms = 1000 * (1 << (attempt - 1));
-                    tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+                if attempt < max_attempts {
+                    // Exponential backoff: base, 2x base, 4x base, ...
+                    let delay_secs = base_delay_secs.saturating_mul(1 << (attempt - 1).min(16));
+                    let message = format!(
+                        "Retrying {full_name} in {delay_secs}s (attempt {}/{max_attempts}): {}",
+                        attempt + 1,
+                        layers.retry_summary()
+                    );
+                    let id = format!("pull-retry-{attempt}");
+                    progress.update_spinner(&id, &message);
+                    progress.finish(&id, &message);
+                    tokio::time::sleep(std::time::Duration::from_secs(delay_secs)).await;
                 }
             }
         }
@@ -884,7 +1159,7 @@ async fn pull_from_registry(
 
     Err(last_error.unwrap_or_else(|| {
         DockerError::Pull(format!(
-            "Pull failed for {full_name} after {MAX_PULL_RETRIES} attempts"
+            "Pull failed for {full_name} after {max_attempts} attempts"
         ))
     }))
 }
@@ -894,14 +1169,16 @@ async fn do_pull(
     client: &DockerClient,
     image: &str,
     tag: &str,
+    platform: Option<&str>,
     progress: &mut ProgressReporter,
+    layers: &mut PullLayers,
 ) -> Result<(), DockerError> {
     let full_name = format!("{image}:{tag}");
 
     let options = CreateImageOptions {
         from_image: Some(image.to_string()),
         tag: Some(tag.to_string()),
-        platform: String::new(),
+        platform: platform.unwrap_or_default().to_string(),
         ..Default::default()
     };
 
@@ -924,6 +1201,7 @@ async fn do_pull(
                 // Handle layer progress
                 if let Some(layer_id) = &info.id {
                     let status = info.status.as_deref().unwrap_or("");
+                    layers.record(layer_id, status);
 
                     match status {
                         "Already exists" => {
@@ -959,6 +1237,7 @@ async fn do_pull(
         }
     }
 
+    progress.flush_layers();
     progress.finish("pull", &format!("Pull complete: {full_name}"));
     Ok(())
 }
@@ -1842,4 +2121,116 @@ mod tests {
             "Loading build context"
         );
     }
+
+    #[test]
+    fn parse_step_counts_reads_legacy_and_buildkit_labels() {
+        assert_eq!(parse_step_counts("Step 3/10 : COPY . ."), Some(("", 3, 10)));
+        assert_eq!(
+            parse_step_counts("[runtime 1/15] RUN apt-get update"),
+            Some(("runtime", 1, 15))
+        );
+        assert_eq!(
+            parse_step_counts("[broker-build 2/4] RUN cargo build"),
+            Some(("broker-build", 2, 4))
+        );
+        assert_eq!(parse_step_counts("[internal] load build context"), None);
+        assert_eq!(parse_step_counts("Step 11/10 : RUN x"), None);
+        assert_eq!(parse_step_counts("exporting to image"), None);
+    }
+
+    #[test]
+    fn step_progress_sums_stages_and_counts_completed_vertexes() {
+        use bollard::moby::buildkit::v1::Vertex;
+
+        let vertex = |digest: &str, name: &str, completed: bool| Vertex {
+            digest: digest.to_string(),
+            name: name.to_string(),
+            completed: completed.then(Default::default),
+            ..Default::default()
+        };
+        let mut state = BuildLogState::new(&BuildLogOptions::default());
+        assert_eq!(state.step_progress(), None);
+
+        let status = BuildkitStatusResponse {
+            vertexes: vec![
+                vertex("a", "[base 1/2] RUN apt-get update", true),
+                vertex("b", "[base 2/2] RUN apt-get install", false),
+                vertex("c", "[runtime 1/3] COPY --from=base", false),
+                vertex("d", "[internal] load build context", true),
+            ],
+            ..Default::default()
+        };
+        update_buildkit_vertex_names(&mut state.vertex_name_by_vertex_id, &status);
+        record_step_progress(&mut state, &status);
+        assert_eq!(state.step_progress(), Some((1, 5)));
+
+        let status = BuildkitStatusResponse {
+            vertexes: vec![vertex("b", "", true)],
+            ..Default::default()
+        };
+        update_buildkit_vertex_names(&mut state.vertex_name_by_vertex_id, &status);
+        record_step_progress(&mut state, &status);
+        assert_eq!(state.step_progress(), Some((2, 5)));
+    }
+
+    #[test]
+    fn failed_build_saves_full_log_and_mentions_path() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("logs").join("build.log");
+        let mut state = BuildLogState::new(&BuildLogOptions {
+            save_path: Some(path.clone()),
+            ..BuildLogOptions::default()
+        });
+        state.full_log = (1..=50).map(|n| format!("line {n}")).collect();
+
+        let message = with_saved_log_path("Build failed".to_string(), &state);
+        assert!(message.ends_with(&format!("Full build log: {}", path.display())));
+        let saved = fs::read_to_string(&path).unwrap();
+        assert_eq!(saved.lines().count(), 50);
+        assert!(saved.starts_with("line 1\n"));
+    }
+
+    #[test]
+    fn log_buffer_size_falls_back_to_configured_value() {
+        let unset = "OPENCODE_TEST_UNSET_BUILD_TAIL";
+        assert_eq!(read_log_buffer_size(unset, 42), 42);
+        assert_eq!(read_log_buffer_size(unset, 1000), 500);
+        assert_eq!(read_log_buffer_size(unset, 0), 5);
+    }
+
+    #[test]
+    fn pull_layers_summarize_what_a_retry_still_needs() {
+        let mut layers = PullLayers::default();
+        assert_eq!(layers.retry_summary(), "no layers downloaded yet");
+
+        layers.record("a", "Already exists");
+        layers.record("b", "Downloading");
+        layers.record("c", "Downloading");
+        layers.record("c", "Pull complete");
+        layers.record("c", "Extracting");
+        assert_eq!(
+            layers.retry_summary(),
+            "2 layer(s) already present, 1 to re-download"
+        );
+    }
+
+    #[test]
+    fn pull_registry_parses_and_limits_fallback() {
+        assert_eq!("ghcr".parse::<PullRegistry>(), Ok(PullRegistry::Ghcr));
+        assert_eq!(
+            "DockerHub".parse::<PullRegistry>(),
+            Ok(PullRegistry::DockerHub)
+        );
+        assert!("quay".parse::<PullRegistry>().is_err());
+
+        assert_eq!(
+            PullOptions::default().registries(),
+            vec![PullRegistry::Ghcr, PullRegistry::DockerHub]
+        );
+        let pinned = PullOptions {
+            registry: Some(PullRegistry::DockerHub),
+            ..Default::default()
+        };
+        assert_eq!(pinned.registries(), vec![PullRegistry::DockerHub]);
+    }
 }
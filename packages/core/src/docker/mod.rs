@@ -11,6 +11,7 @@
 //! - Container exec for running commands inside containers
 //! - User management operations (create, delete, lock/unlock users)
 //! - Image update and rollback operations
+//! - Image platform and architecture checks
 
 mod assets;
 mod client;
@@ -19,21 +20,29 @@ mod dockerfile;
 mod error;
 pub mod exec;
 mod health;
+pub mod health_history;
 pub mod image;
 pub mod mount;
+pub mod opencode_state;
+pub mod platform;
 pub mod profile;
 pub mod progress;
 mod registry;
+mod retry;
 pub mod state;
+mod stats;
 pub mod update;
 pub mod users;
 mod version;
+pub mod version_cache;
 pub mod volume;
 
 // Core types
 pub use client::{DockerClient, DockerEndpoint};
 pub use error::DockerError;
-pub use progress::ProgressReporter;
+pub use progress::{
+    DOCKER_DEFAULT_MAX_CONCURRENT_DOWNLOADS, ProgressReporter, abandon_live_bars, track_live_bar,
+};
 
 // Health check operations
 pub use health::{
@@ -44,21 +53,39 @@ pub use health::{
 pub use assets::{ENTRYPOINT_SH, HEALTHCHECK_SH, OPENCODE_CLOUD_BOOTSTRAP_SH};
 pub use dockerfile::{DOCKERFILE, IMAGE_NAME_DOCKERHUB, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT};
 
+// Transient error retries for idempotent reads
+pub use retry::is_transient_docker_error;
+
+// Resource statistics
+pub use stats::{ContainerStatsSample, container_stats};
+
 // Image operations
-pub use image::{build_image, image_exists, pull_image, remove_images_by_name};
+pub use image::{
+    BUILD_LOG_TAIL_RANGE, BuildLogOptions, DEFAULT_BUILD_LOG_BUFFER_SIZE,
+    DEFAULT_ERROR_LOG_BUFFER_SIZE, DEFAULT_PULL_RETRIES, DEFAULT_PULL_RETRY_DELAY_SECS,
+    PULL_RETRIES_RANGE, PULL_RETRY_DELAY_RANGE, PullOptions, PullRegistry, build_image,
+    image_exists, pull_image, remove_images_by_name,
+};
 pub use profile::{
     DockerResourceNames, INSTANCE_LABEL_KEY, SANDBOX_INSTANCE_ENV, active_resource_names,
-    env_instance_id, remap_container_name, remap_image_tag, resource_names_for_instance,
+    container_prefix, env_instance_id, remap_container_name, remap_image_tag,
+    resource_names_for_instance, set_container_prefix, validate_container_prefix,
+    validate_sandbox_instance,
 };
 
 // Update operations
-pub use update::{UpdateResult, has_previous_image, rollback_image, update_image};
+pub use update::{
+    DEFAULT_ROLLBACK_DEPTH, PreviousImage, ROLLBACK_DEPTH_RANGE, UpdateResult, has_previous_image,
+    list_previous_images, previous_generation_tag, resolve_rollback_tag, rollback_image,
+    rollback_to_image, update_image,
+};
 
 // Version detection
 pub use version::{
     VERSION_LABEL, get_cli_version, get_image_version, get_registry_latest_version,
-    versions_compatible,
+    get_registry_latest_version_cached, versions_compatible,
 };
+pub use version_cache::{VERSION_CACHE_TTL, cached_version, store_version};
 
 // Container exec operations
 pub use exec::{
@@ -67,23 +94,66 @@ pub use exec::{
 
 // User management operations
 pub use users::{
-    UserInfo, create_user, delete_user, list_users, lock_user, persist_user, remove_persisted_user,
-    restore_persisted_users, set_user_password, unlock_user, user_exists,
+    UserInfo, create_user, delete_user, is_builtin_system_user, last_login, list_users, lock_user,
+    parse_lastlog_output, persist_user, remove_persisted_user, restore_persisted_users,
+    set_user_password, unlock_user, user_exists,
 };
 
 // Volume management
 pub use volume::{
     MOUNT_CACHE, MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, MOUNT_SSH, MOUNT_STATE, MOUNT_USERS,
-    VOLUME_CACHE, VOLUME_CONFIG, VOLUME_NAMES, VOLUME_PROJECTS, VOLUME_SESSION, VOLUME_SSH,
-    VOLUME_STATE, VOLUME_USERS, ensure_volumes_exist, remove_all_volumes, remove_volume,
-    volume_exists,
+    UnattachedVolumes, VOLUME_CACHE, VOLUME_CONFIG, VOLUME_NAMES, VOLUME_PROJECTS, VOLUME_SESSION,
+    VOLUME_SSH, VOLUME_STATE, VOLUME_USERS, VolumeRemovalOutcome, VolumeRemovalResult,
+    bind_mount_targets, clear_volume_contents, ensure_volumes_exist, find_orphaned_volumes,
+    list_instance_volumes, remove_all_volumes, remove_volume, remove_volumes_reporting,
+    volume_exists, volume_mount_targets, volume_removal_error,
 };
 
+/// Docker daemon details worth including in bug reports
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaemonInfo {
+    pub server_version: Option<String>,
+    pub operating_system: Option<String>,
+    pub architecture: Option<String>,
+    pub kernel_version: Option<String>,
+    pub storage_driver: Option<String>,
+    /// "1" or "2"
+    pub cgroup_version: Option<String>,
+    pub cgroup_driver: Option<String>,
+    pub cpus: Option<i64>,
+    /// Total memory available to the daemon, in bytes
+    pub total_memory: Option<i64>,
+}
+
+impl From<bollard::models::SystemInfo> for DaemonInfo {
+    fn from(info: bollard::models::SystemInfo) -> Self {
+        // The enum fields have an empty variant for "not reported"
+        let non_empty = |value: String| (!value.is_empty()).then_some(value);
+        Self {
+            server_version: info.server_version,
+            operating_system: info.operating_system,
+            architecture: info.architecture,
+            kernel_version: info.kernel_version,
+            storage_driver: info.driver,
+            cgroup_version: info.cgroup_version.and_then(|v| non_empty(v.to_string())),
+            cgroup_driver: info.cgroup_driver.and_then(|d| non_empty(d.to_string())),
+            cpus: info.ncpu,
+            total_memory: info.mem_total,
+        }
+    }
+}
+
+/// Read version, storage, cgroup, and host details from the Docker daemon
+pub async fn daemon_info(client: &DockerClient) -> Result<DaemonInfo, DockerError> {
+    let info = client.info().await.map_err(DockerError::from)?;
+    Ok(info.into())
+}
+
 /// Determine whether the Docker host supports systemd-in-container.
 ///
 /// Returns true only for Linux hosts that are not Docker Desktop and not rootless.
 pub async fn docker_supports_systemd(client: &DockerClient) -> Result<bool, DockerError> {
-    let info = client.inner().info().await.map_err(DockerError::from)?;
+    let info = client.info().await.map_err(DockerError::from)?;
 
     let os_type = info.os_type.unwrap_or_default();
     if os_type.to_lowercase() != "linux" {
@@ -113,17 +183,31 @@ pub async fn docker_supports_systemd(client: &DockerClient) -> Result<bool, Dock
 }
 
 // Bind mount parsing and validation
-pub use mount::{MountError, ParsedMount, check_container_path_warning, validate_mount_path};
+pub use mount::{
+    MountError, ParsedMount, check_container_path_warning, create_mount_dir, validate_mount_path,
+};
 
 // Container lifecycle
 pub use container::{
-    CONTAINER_NAME, ContainerBindMount, ContainerPorts, OPENCODE_WEB_PORT, container_exists,
-    container_is_running, container_state, create_container, get_container_bind_mounts,
-    get_container_ports, remove_container, start_container, stop_container,
+    CONTAINER_NAME, ContainerBindMount, ContainerEnv, ContainerPorts, OPENCODE_WEB_PORT,
+    RESERVED_ENV_KEYS, container_exists, container_is_running, container_state, create_container,
+    create_scratch_container, custom_labels, get_container_bind_mounts,
+    get_container_custom_labels, get_container_env, get_container_ports, list_instance_containers,
+    remove_container, rename_container, start_container, stop_container,
 };
 
 // Image state tracking
-pub use state::{ImageState, clear_state, get_state_path, load_state, save_state};
+pub use health_history::{
+    HEALTH_HISTORY_LIMIT, HealthHistory, HealthTransition, load_health_history,
+    record_health_status,
+};
+pub use opencode_state::{OpencodeState, load_opencode_state, save_opencode_state};
+pub use state::{
+    ImageState, clear_state, get_state_path, load_state, record_image_applied, save_state,
+};
+
+// Image platform checks
+pub use platform::{ArchMismatch, check_image_architecture, validate_platform};
 
 /// Full setup: ensure volumes exist, create container if needed, start it
 ///
@@ -139,6 +223,8 @@ pub use state::{ImageState, clear_state, get_state_path, load_state, save_state}
 /// * `cockpit_enabled` - Whether to enable Cockpit port mapping (defaults to false)
 /// * `systemd_enabled` - Whether to use systemd as init (defaults to false)
 /// * `bind_mounts` - User-defined bind mounts from config and CLI flags (optional)
+/// * `container_labels` - Extra labels from config (optional)
+/// * `platform` - Image platform such as "linux/arm64" (defaults to the daemon's)
 #[allow(clippy::too_many_arguments)]
 pub async fn setup_and_start(
     client: &DockerClient,
@@ -149,6 +235,8 @@ pub async fn setup_and_start(
     cockpit_enabled: Option<bool>,
     systemd_enabled: Option<bool>,
     bind_mounts: Option<Vec<mount::ParsedMount>>,
+    container_labels: Option<&std::collections::BTreeMap<String, String>>,
+    platform: Option<&str>,
 ) -> Result<String, DockerError> {
     let names = active_resource_names();
 
@@ -159,8 +247,7 @@ pub async fn setup_and_start(
     let container_id = if container::container_exists(client, &names.container_name).await? {
         // Get existing container ID
         let info = client
-            .inner()
-            .inspect_container(&names.container_name, None)
+            .inspect_container(&names.container_name)
             .await
             .map_err(|e| {
                 DockerError::Container(format!("Failed to inspect existing container: {e}"))
@@ -168,7 +255,7 @@ pub async fn setup_and_start(
         info.id.unwrap_or_else(|| names.container_name.to_string())
     } else {
         // Create new container
-        container::create_container(
+        let id = container::create_container(
             client,
             None,
             None,
@@ -179,8 +266,21 @@ pub async fn setup_and_start(
             cockpit_enabled,
             systemd_enabled,
             bind_mounts,
+            container_labels,
+            platform,
         )
-        .await?
+        .await?;
+
+        // Remember which image version the container was created from.
+        // Provenance is informational, so failures here never block startup.
+        let image = format!("{IMAGE_NAME_GHCR}:{}", names.image_tag);
+        let image_version = version::get_image_version(client, &image)
+            .await
+            .ok()
+            .flatten();
+        let _ = state::record_image_applied(image_version.as_deref());
+
+        id
     };
 
     // Start if not running
@@ -3,6 +3,7 @@
 //! This module provides functionality to:
 //! - Parse mount strings in Docker format (`/host:/container[:ro|rw]`)
 //! - Validate mount paths (existence, type, permissions)
+//! - Create missing host directories on request
 //! - Convert parsed mounts to Bollard's Mount type for Docker API
 //! - Warn about potentially dangerous container mount points
 
@@ -32,6 +33,10 @@ pub enum MountError {
     /// Permission denied accessing path.
     #[error("Cannot access path (permission denied): {0}")]
     PermissionDenied(String),
+
+    /// Host directory could not be created.
+    #[error("Failed to create directory: {0} ({1})")]
+    CreateFailed(String, String),
 }
 
 /// A parsed bind mount specification.
@@ -168,6 +173,43 @@ pub fn validate_mount_path(path: &std::path::Path) -> Result<PathBuf, MountError
     Ok(canonical)
 }
 
+/// Create a missing mount host directory.
+///
+/// Parent directories are created as needed. On Unix new directories get mode
+/// `0755` (before the umask), so the container user can read them.
+///
+/// # Arguments
+/// * `path` - The host path to create.
+///
+/// # Returns
+/// * `Ok(true)` - The directory was created.
+/// * `Ok(false)` - The path already existed; it is left untouched.
+/// * `Err(MountError)` - The path is relative or could not be created.
+pub fn create_mount_dir(path: &std::path::Path) -> Result<bool, MountError> {
+    if !path.is_absolute() {
+        return Err(MountError::RelativePath(path.display().to_string()));
+    }
+    if path.exists() {
+        return Ok(false);
+    }
+
+    let mut builder = std::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o755);
+    }
+    builder.create(path).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::PermissionDenied {
+            MountError::PermissionDenied(path.display().to_string())
+        } else {
+            MountError::CreateFailed(path.display().to_string(), e.to_string())
+        }
+    })?;
+    Ok(true)
+}
+
 /// System paths that should typically not be mounted over.
 const SYSTEM_PATHS: &[&str] = &["/etc", "/usr", "/bin", "/sbin", "/lib", "/var"];
 
@@ -327,4 +369,18 @@ mod tests {
         let result = validate_mount_path(std::path::Path::new("/tmp"));
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn create_mount_dir_creates_missing_parents() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("projects").join("new");
+
+        assert!(create_mount_dir(&path).unwrap());
+        assert!(validate_mount_path(&path).is_ok());
+        assert!(!create_mount_dir(&path).unwrap());
+        assert!(matches!(
+            create_mount_dir(std::path::Path::new("relative")),
+            Err(MountError::RelativePath(_))
+        ));
+    }
 }
@@ -0,0 +1,80 @@
+//! Opencode runtime state
+//!
+//! Records which opencode commit was running before the last in-place
+//! `occ update opencode`, so `occ update opencode --rollback` can rebuild it.
+//! The image path keeps its own safety net via the `previous` image tag.
+//! The file lives in the local data dir, so it only describes the local service.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::profile::{DockerResourceNames, active_resource_names};
+
+/// Commits involved in the last opencode update
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpencodeState {
+    /// Commit that was running before the update (short SHA)
+    pub previous_commit: String,
+    /// Commit the update moved to, if it was known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_commit: Option<String>,
+    /// When the update started (ISO8601)
+    pub updated_at: String,
+}
+
+impl OpencodeState {
+    /// Record an update from `previous_commit` to `current_commit`
+    pub fn new(previous_commit: &str, current_commit: Option<&str>) -> Self {
+        Self {
+            previous_commit: previous_commit.to_string(),
+            current_commit: current_commit.map(ToString::to_string),
+            updated_at: Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Get the path to the opencode state file
+pub fn get_opencode_state_path() -> Option<PathBuf> {
+    let names = active_resource_names();
+    get_opencode_state_path_for_names(&names)
+}
+
+pub fn get_opencode_state_path_for_names(names: &DockerResourceNames) -> Option<PathBuf> {
+    crate::config::paths::get_data_dir().map(|p| p.join(&names.opencode_state_file))
+}
+
+/// Save opencode state to disk
+pub fn save_opencode_state(state: &OpencodeState) -> anyhow::Result<()> {
+    let path = get_opencode_state_path()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine opencode state path"))?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let json = serde_json::to_string_pretty(state)?;
+    std::fs::write(&path, json)?;
+    Ok(())
+}
+
+/// Load opencode state from disk
+pub fn load_opencode_state() -> Option<OpencodeState> {
+    let path = get_opencode_state_path()?;
+    let content = std::fs::read_to_string(&path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opencode_state_round_trips_without_current_commit() {
+        let state = OpencodeState::new("abc1234", None);
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(!json.contains("current_commit"));
+        let parsed: OpencodeState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+    }
+}
@@ -0,0 +1,123 @@
+//! Image platform handling
+//!
+//! Compares the architecture of the sandbox image with the Docker daemon's so
+//! users on ARM hosts learn when an amd64-only image would run under emulation,
+//! and validates the optional `platform` setting passed to pulls and creates.
+
+use super::{DockerClient, DockerError};
+
+/// Architecture mismatch between an image and the Docker daemon
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchMismatch {
+    /// Image architecture in Docker platform form, e.g. "amd64"
+    pub image_arch: String,
+    /// Daemon architecture in Docker platform form, e.g. "arm64"
+    pub daemon_arch: String,
+}
+
+impl ArchMismatch {
+    /// Platform string matching the daemon, e.g. "linux/arm64"
+    pub fn suggested_platform(&self) -> String {
+        format!("linux/{}", self.daemon_arch)
+    }
+}
+
+/// Map a kernel or Go architecture name to Docker's platform naming
+///
+/// `docker info` reports the kernel name (`x86_64`, `aarch64`) while images
+/// use Go names (`amd64`, `arm64`).
+pub fn normalize_arch(arch: &str) -> String {
+    let arch = arch.trim().to_ascii_lowercase();
+    match arch.as_str() {
+        "x86_64" | "x86-64" => "amd64".to_string(),
+        "aarch64" | "arm64v8" => "arm64".to_string(),
+        "armv7l" | "armhf" => "arm".to_string(),
+        "i386" | "i686" => "386".to_string(),
+        _ => arch,
+    }
+}
+
+/// Compare two architectures after normalizing them
+///
+/// Returns `None` when they match or either is unknown.
+pub fn arch_mismatch(image_arch: &str, daemon_arch: &str) -> Option<ArchMismatch> {
+    let image_arch = normalize_arch(image_arch);
+    let daemon_arch = normalize_arch(daemon_arch);
+    if image_arch.is_empty() || daemon_arch.is_empty() || image_arch == daemon_arch {
+        return None;
+    }
+    Some(ArchMismatch {
+        image_arch,
+        daemon_arch,
+    })
+}
+
+/// Check whether a local image was built for a different architecture than the daemon
+///
+/// Returns `Ok(None)` when they match or either side does not report an architecture.
+pub async fn check_image_architecture(
+    client: &DockerClient,
+    image: &str,
+) -> Result<Option<ArchMismatch>, DockerError> {
+    let inspect = client
+        .inner()
+        .inspect_image(image)
+        .await
+        .map_err(|e| DockerError::Container(format!("Failed to inspect image {image}: {e}")))?;
+    let info = client.info().await.map_err(DockerError::from)?;
+
+    let (Some(image_arch), Some(daemon_arch)) = (inspect.architecture, info.architecture) else {
+        return Ok(None);
+    };
+    Ok(arch_mismatch(&image_arch, &daemon_arch))
+}
+
+/// Check a `platform` value such as "linux/amd64" or "linux/arm64/v8"
+pub fn validate_platform(platform: &str) -> Result<(), String> {
+    let parts: Vec<&str> = platform.split('/').collect();
+    if !(2..=3).contains(&parts.len()) || parts.iter().any(|part| part.is_empty()) {
+        return Err(format!(
+            "platform '{platform}' must look like os/arch, e.g. linux/amd64 or linux/arm64"
+        ));
+    }
+    if let Some(bad) = platform
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '/' && *c != '_')
+    {
+        return Err(format!("platform '{platform}' contains '{bad}'"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_arch_maps_kernel_names() {
+        assert_eq!(normalize_arch("x86_64"), "amd64");
+        assert_eq!(normalize_arch("aarch64"), "arm64");
+        assert_eq!(normalize_arch("arm64"), "arm64");
+        assert_eq!(normalize_arch("AMD64"), "amd64");
+    }
+
+    #[test]
+    fn arch_mismatch_detects_emulation() {
+        let mismatch = arch_mismatch("amd64", "aarch64").unwrap();
+        assert_eq!(mismatch.image_arch, "amd64");
+        assert_eq!(mismatch.daemon_arch, "arm64");
+        assert_eq!(mismatch.suggested_platform(), "linux/arm64");
+
+        assert!(arch_mismatch("amd64", "x86_64").is_none());
+        assert!(arch_mismatch("", "x86_64").is_none());
+    }
+
+    #[test]
+    fn validate_platform_accepts_os_arch_variant() {
+        assert!(validate_platform("linux/amd64").is_ok());
+        assert!(validate_platform("linux/arm64/v8").is_ok());
+        assert!(validate_platform("amd64").is_err());
+        assert!(validate_platform("linux/").is_err());
+        assert!(validate_platform("linux/arm64 ").is_err());
+    }
+}
@@ -3,6 +3,10 @@
 //! Legacy behavior uses shared resource names (`opencode-cloud-sandbox`, `latest`, etc.).
 //! When `OPENCODE_SANDBOX_INSTANCE` is set to a valid instance ID, names are derived with
 //! profile-specific suffixes/tags so concurrent worktrees can run independently.
+//!
+//! A `container_prefix` from the config replaces the `opencode-cloud` / `opencode`
+//! stem of the container and volume names, so unrelated stacks on one host never
+//! share a container or data. The CLI installs it with [`set_container_prefix`].
 
 use super::container::CONTAINER_NAME;
 use super::dockerfile::IMAGE_TAG_DEFAULT;
@@ -11,6 +15,7 @@ use super::volume::{
     VOLUME_USERS,
 };
 use std::env;
+use std::sync::RwLock;
 
 /// Environment variable carrying the active sandbox instance id.
 pub const SANDBOX_INSTANCE_ENV: &str = "OPENCODE_SANDBOX_INSTANCE";
@@ -21,6 +26,24 @@ pub const INSTANCE_LABEL_KEY: &str = "opencode-cloud.instance";
 /// Legacy rollback tag for shared mode.
 const PREVIOUS_TAG_DEFAULT: &str = "previous";
 
+/// Stem of the default container name that `container_prefix` replaces.
+const CONTAINER_NAME_STEM: &str = "opencode-cloud";
+
+/// Stem of the default volume names that `container_prefix` replaces.
+const VOLUME_NAME_STEM: &str = "opencode";
+
+/// Maximum prefix length.
+///
+/// This alone does not bound the hostname: an instance suffix can add another
+/// 33 characters, so [`clamp_hostname`] truncates it to [`MAX_HOSTNAME_LEN`].
+const MAX_CONTAINER_PREFIX_LEN: usize = 48;
+
+/// Longest hostname a container accepts (one DNS label).
+const MAX_HOSTNAME_LEN: usize = 63;
+
+/// Prefix from the config's `container_prefix`, installed once at startup.
+static CONTAINER_PREFIX: RwLock<Option<String>> = RwLock::new(None);
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DockerResourceNames {
     pub instance_id: Option<String>,
@@ -37,6 +60,8 @@ pub struct DockerResourceNames {
     pub volume_users: String,
     pub volume_ssh: String,
     pub image_state_file: String,
+    pub health_history_file: String,
+    pub opencode_state_file: String,
 }
 
 impl DockerResourceNames {
@@ -60,6 +85,29 @@ pub fn active_resource_names() -> DockerResourceNames {
 
 /// Resolve resource names for an optional instance id.
 pub fn resource_names_for_instance(instance_id: Option<&str>) -> DockerResourceNames {
+    let prefix = container_prefix();
+    let mut names = default_resource_names(instance_id);
+    if let Some(prefix) = prefix.as_deref() {
+        apply_container_prefix(&mut names, prefix);
+    }
+    clamp_hostname(&mut names.hostname);
+    names
+}
+
+/// Truncate a derived hostname to one DNS label, ending on a letter or digit.
+fn clamp_hostname(hostname: &mut String) {
+    if hostname.len() <= MAX_HOSTNAME_LEN {
+        return;
+    }
+    // Names are ASCII (validated prefix and instance id), so bytes are chars
+    hostname.truncate(MAX_HOSTNAME_LEN);
+    let kept = hostname
+        .trim_end_matches(|c: char| !c.is_ascii_alphanumeric())
+        .len();
+    hostname.truncate(kept);
+}
+
+fn default_resource_names(instance_id: Option<&str>) -> DockerResourceNames {
     if let Some(instance_id) = instance_id {
         let suffix = format!("-{instance_id}");
         // Keep default names untouched for backward compatibility; profile mode only appends.
@@ -78,6 +126,8 @@ pub fn resource_names_for_instance(instance_id: Option<&str>) -> DockerResourceN
             volume_users: format!("{VOLUME_USERS}{suffix}"),
             volume_ssh: format!("{VOLUME_SSH}{suffix}"),
             image_state_file: format!("image-state-{instance_id}.json"),
+            health_history_file: format!("health-history-{instance_id}.json"),
+            opencode_state_file: format!("opencode-state-{instance_id}.json"),
         }
     } else {
         DockerResourceNames {
@@ -95,8 +145,92 @@ pub fn resource_names_for_instance(instance_id: Option<&str>) -> DockerResourceN
             volume_users: VOLUME_USERS.to_string(),
             volume_ssh: VOLUME_SSH.to_string(),
             image_state_file: "image-state.json".to_string(),
+            health_history_file: "health-history.json".to_string(),
+            opencode_state_file: "opencode-state.json".to_string(),
+        }
+    }
+}
+
+/// Swap the default name stems for `prefix`.
+///
+/// Image tags and the image state file stay shared: stacks can reuse one image.
+fn apply_container_prefix(names: &mut DockerResourceNames, prefix: &str) {
+    let restem = |name: &mut String, stem: &str| {
+        if let Some(rest) = name.strip_prefix(stem) {
+            *name = format!("{prefix}{rest}");
         }
+    };
+    restem(&mut names.container_name, CONTAINER_NAME_STEM);
+    restem(&mut names.hostname, CONTAINER_NAME_STEM);
+    restem(&mut names.volume_session, VOLUME_NAME_STEM);
+    restem(&mut names.volume_state, VOLUME_NAME_STEM);
+    restem(&mut names.volume_cache, VOLUME_NAME_STEM);
+    restem(&mut names.volume_projects, VOLUME_NAME_STEM);
+    restem(&mut names.volume_config, VOLUME_NAME_STEM);
+    restem(&mut names.volume_users, VOLUME_NAME_STEM);
+    restem(&mut names.volume_ssh, VOLUME_NAME_STEM);
+    names.health_history_file = format!("{prefix}-{}", names.health_history_file);
+    names.opencode_state_file = format!("{prefix}-{}", names.opencode_state_file);
+}
+
+/// Install the config's `container_prefix` for every later name lookup.
+///
+/// `None` or an empty string restores the default names. A name Docker would
+/// reject is an error and leaves the installed prefix unchanged, so commands
+/// never fall back to acting on the default stack's container and volumes.
+pub fn set_container_prefix(prefix: Option<&str>) -> Result<(), String> {
+    let prefix = prefix.map(str::trim).filter(|prefix| !prefix.is_empty());
+    if let Some(prefix) = prefix {
+        validate_container_prefix(prefix)?;
+    }
+    if let Ok(mut slot) = CONTAINER_PREFIX.write() {
+        *slot = prefix.map(str::to_string);
+    }
+    Ok(())
+}
+
+/// The prefix installed by [`set_container_prefix`], if any.
+pub fn container_prefix() -> Option<String> {
+    CONTAINER_PREFIX.read().ok().and_then(|slot| slot.clone())
+}
+
+/// Check a container prefix against Docker's container and volume naming rules.
+///
+/// Docker accepts `[a-zA-Z0-9][a-zA-Z0-9_.-]*`; the length is capped to keep
+/// derived names readable, and the hostname is clamped separately.
+pub fn validate_container_prefix(prefix: &str) -> Result<(), String> {
+    let Some(first) = prefix.chars().next() else {
+        return Err("container_prefix cannot be empty".to_string());
+    };
+    if !first.is_ascii_alphanumeric() {
+        return Err(format!(
+            "container_prefix '{prefix}' must start with a letter or digit"
+        ));
+    }
+    if let Some(bad) = prefix
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '_' | '.' | '-'))
+    {
+        return Err(format!(
+            "container_prefix '{prefix}' contains '{bad}'; use letters, digits, '_', '.', or '-'"
+        ));
     }
+    if prefix.len() > MAX_CONTAINER_PREFIX_LEN {
+        return Err(format!(
+            "container_prefix must be at most {MAX_CONTAINER_PREFIX_LEN} characters"
+        ));
+    }
+    Ok(())
+}
+
+/// Check a persisted `sandbox_instance` selection: `auto` or a valid instance id.
+pub fn validate_sandbox_instance(value: &str) -> Result<(), String> {
+    if value == "auto" || is_valid_instance_id(value) {
+        return Ok(());
+    }
+    Err(format!(
+        "sandbox_instance '{value}' must be 'auto' or match [a-z0-9][a-z0-9-]{{0,31}}"
+    ))
 }
 
 /// Remap legacy container name to active profile container name.
@@ -147,6 +281,14 @@ fn is_valid_instance_id(value: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn validate_sandbox_instance_accepts_auto_and_ids() {
+        assert!(validate_sandbox_instance("auto").is_ok());
+        assert!(validate_sandbox_instance("mytree-2").is_ok());
+        assert!(validate_sandbox_instance("My_Tree").is_err());
+        assert!(validate_sandbox_instance("").is_err());
+    }
+
     #[test]
     fn legacy_names_remain_unchanged() {
         let names = resource_names_for_instance(None);
@@ -154,6 +296,8 @@ mod tests {
         assert_eq!(names.image_tag, IMAGE_TAG_DEFAULT);
         assert_eq!(names.volume_users, VOLUME_USERS);
         assert_eq!(names.image_state_file, "image-state.json");
+        assert_eq!(names.health_history_file, "health-history.json");
+        assert_eq!(names.opencode_state_file, "opencode-state.json");
         assert!(names.instance_id.is_none());
     }
 
@@ -166,9 +310,57 @@ mod tests {
         assert_eq!(names.volume_users, "opencode-users-foo");
         assert_eq!(names.volume_ssh, "opencode-ssh-foo");
         assert_eq!(names.image_state_file, "image-state-foo.json");
+        assert_eq!(names.health_history_file, "health-history-foo.json");
+        assert_eq!(names.opencode_state_file, "opencode-state-foo.json");
         assert_eq!(names.instance_id.as_deref(), Some("foo"));
     }
 
+    #[test]
+    fn container_prefix_replaces_name_stems() {
+        let mut names = default_resource_names(Some("foo"));
+        apply_container_prefix(&mut names, "work");
+        assert_eq!(names.container_name, "work-sandbox-foo");
+        assert_eq!(names.hostname, "work-sandbox-foo");
+        assert_eq!(names.volume_session, "work-data-foo");
+        assert_eq!(names.volume_ssh, "work-ssh-foo");
+        assert_eq!(names.image_tag, "instance-foo");
+        assert_eq!(names.image_state_file, "image-state-foo.json");
+        assert_eq!(names.health_history_file, "work-health-history-foo.json");
+    }
+
+    #[test]
+    fn long_prefix_and_instance_keep_hostname_within_a_dns_label() {
+        let prefix = "a".repeat(MAX_CONTAINER_PREFIX_LEN);
+        let instance = format!("b{}", "-c".repeat(15));
+        let mut names = default_resource_names(Some(&instance));
+        apply_container_prefix(&mut names, &prefix);
+        assert!(names.hostname.len() > MAX_HOSTNAME_LEN);
+
+        clamp_hostname(&mut names.hostname);
+        assert!(names.hostname.len() <= MAX_HOSTNAME_LEN);
+        assert!(names.hostname.starts_with(&format!("{prefix}-sandbox-b")));
+        assert!(
+            names
+                .hostname
+                .ends_with(|c: char| c.is_ascii_alphanumeric())
+        );
+        // The container name keeps the full suffix so instances stay distinct
+        assert!(names.container_name.ends_with(&instance));
+    }
+
+    #[test]
+    fn container_prefix_follows_docker_naming_rules() {
+        assert!(validate_container_prefix("work").is_ok());
+        assert!(validate_container_prefix("Team_1.dev-a").is_ok());
+        assert!(validate_container_prefix("").is_err());
+        assert!(validate_container_prefix("-work").is_err());
+        assert!(validate_container_prefix("my stack").is_err());
+        assert!(validate_container_prefix("a/b").is_err());
+        assert!(validate_container_prefix(&"a".repeat(49)).is_err());
+        // Rejected prefixes are reported, never silently replaced by the defaults
+        assert!(set_container_prefix(Some("my stack")).is_err());
+    }
+
     #[test]
     fn env_instance_id_rejects_invalid_values() {
         assert!(is_valid_instance_id("foo-123"));
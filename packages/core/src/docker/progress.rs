@@ -3,13 +3,60 @@
 //! This module provides progress bars and spinners for Docker image
 //! builds and pulls, using indicatif for terminal output.
 
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
-use std::collections::HashMap;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle, WeakProgressBar};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 /// Minimum time between spinner message updates to prevent flickering
 const SPINNER_UPDATE_THROTTLE: Duration = Duration::from_millis(150);
 
+/// Interval at which batched layer byte counts are pushed to their bars
+///
+/// Pulls emit a progress event per chunk per layer; applying each one locks
+/// the bar and the shared draw state, which dominates time on large images.
+const LAYER_FLUSH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Docker daemon default for parallel layer downloads
+pub const DOCKER_DEFAULT_MAX_CONCURRENT_DOWNLOADS: usize = 3;
+
+/// Bars that may still be drawing, so an interrupt can stop them cleanly
+static LIVE_BARS: Mutex<Vec<WeakProgressBar>> = Mutex::new(Vec::new());
+
+/// Remember a bar so [`abandon_live_bars`] can stop it on interrupt
+pub fn track_live_bar(bar: &ProgressBar) {
+    let mut live = LIVE_BARS.lock().unwrap_or_else(|e| e.into_inner());
+    live.retain(|weak| weak.upgrade().is_some_and(|bar| !bar.is_finished()));
+    live.push(bar.downgrade());
+}
+
+/// Abandon every tracked bar that is still drawing
+///
+/// Leaves each bar's last line on screen with `message` appended, instead of a
+/// half-drawn frame. Returns how many bars were abandoned.
+pub fn abandon_live_bars(message: &str) -> usize {
+    let mut live = LIVE_BARS.lock().unwrap_or_else(|e| e.into_inner());
+    abandon_bars(&mut live, message)
+}
+
+fn abandon_bars(live: &mut Vec<WeakProgressBar>, message: &str) -> usize {
+    let mut abandoned = 0;
+    for bar in live.drain(..).filter_map(|weak| weak.upgrade()) {
+        if !bar.is_finished() {
+            bar.abandon_with_message(message.to_string());
+            abandoned += 1;
+        }
+    }
+    abandoned
+}
+
+/// Latest byte counts for a layer not yet pushed to its bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PendingLayer {
+    current: u64,
+    total: u64,
+}
+
 /// Strip ANSI escape codes from a string
 ///
 /// Docker build output often contains ANSI color codes that can interfere
@@ -49,6 +96,13 @@ pub struct ProgressReporter {
     bars: HashMap<String, ProgressBar>,
     last_update_by_id: HashMap<String, Instant>,
     last_message_by_id: HashMap<String, String>,
+    /// Layer updates coalesced until the next flush
+    pending_layers: HashMap<String, PendingLayer>,
+    last_layer_flush: Instant,
+    /// Status last shown on each layer bar
+    layer_status: HashMap<String, String>,
+    /// Spinners switched to a determinate bar by `set_step_progress`
+    step_bars: HashSet<String>,
     /// Optional context prefix shown before step messages (e.g., "Building Docker image")
     context: Option<String>,
     /// When true, print build output lines directly instead of spinners
@@ -69,6 +123,10 @@ impl ProgressReporter {
             bars: HashMap::new(),
             last_update_by_id: HashMap::new(),
             last_message_by_id: HashMap::new(),
+            pending_layers: HashMap::new(),
+            last_layer_flush: Instant::now(),
+            layer_status: HashMap::new(),
+            step_bars: HashSet::new(),
             context: None,
             plain_output: false,
         }
@@ -83,6 +141,10 @@ impl ProgressReporter {
             bars: HashMap::new(),
             last_update_by_id: HashMap::new(),
             last_message_by_id: HashMap::new(),
+            pending_layers: HashMap::new(),
+            last_layer_flush: Instant::now(),
+            layer_status: HashMap::new(),
+            step_bars: HashSet::new(),
             context: Some(context.to_string()),
             plain_output: false,
         }
@@ -95,6 +157,10 @@ impl ProgressReporter {
             bars: HashMap::new(),
             last_update_by_id: HashMap::new(),
             last_message_by_id: HashMap::new(),
+            pending_layers: HashMap::new(),
+            last_layer_flush: Instant::now(),
+            layer_status: HashMap::new(),
+            step_bars: HashSet::new(),
             context: Some(context.to_string()),
             plain_output: true,
         }
@@ -139,6 +205,7 @@ impl ProgressReporter {
         );
         spinner.set_message(self.format_message(message));
         spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+        track_live_bar(&spinner);
         self.bars.insert(id.to_string(), spinner);
         self.bars.get(id).expect("just inserted")
     }
@@ -163,31 +230,63 @@ impl ProgressReporter {
                 .progress_chars("=>-"),
         );
         bar.enable_steady_tick(std::time::Duration::from_millis(100));
+        track_live_bar(&bar);
         self.bars.insert(id.to_string(), bar);
         self.bars.get(id).expect("just inserted")
     }
 
     /// Update progress for a layer (used during image pull)
     ///
-    /// `current` and `total` are in bytes, `status` is the Docker status message
+    /// `current` and `total` are in bytes, `status` is the Docker status message.
+    /// Byte counts are batched and applied every [`LAYER_FLUSH_INTERVAL`];
+    /// new layers and status changes (Downloading → Extracting) show at once.
     pub fn update_layer(&mut self, layer_id: &str, current: u64, total: u64, status: &str) {
         if self.plain_output {
             return;
         }
 
-        if let Some(bar) = self.bars.get(layer_id) {
-            // Update total if it changed (Docker sometimes updates this)
-            if bar.length() != Some(total) && total > 0 {
-                bar.set_length(total);
-            }
-            bar.set_position(current);
-            bar.set_message(status.to_string());
-        } else {
-            // Create new bar for this layer
-            let bar = self.add_bar(layer_id, total);
-            bar.set_position(current);
+        let status_changed = self.layer_status.get(layer_id).map(String::as_str) != Some(status);
+        if !self.bars.contains_key(layer_id) || status_changed {
+            self.pending_layers.remove(layer_id);
+            self.apply_layer(layer_id, PendingLayer { current, total });
+            let bar = self.bars.get(layer_id).expect("created by apply_layer");
             bar.set_message(status.to_string());
+            self.layer_status
+                .insert(layer_id.to_string(), status.to_string());
+            return;
+        }
+
+        self.pending_layers
+            .insert(layer_id.to_string(), PendingLayer { current, total });
+        if self.last_layer_flush.elapsed() >= LAYER_FLUSH_INTERVAL {
+            self.flush_layers();
+        }
+    }
+
+    /// Push all batched layer byte counts to their bars
+    pub fn flush_layers(&mut self) {
+        let pending: Vec<_> = self.pending_layers.drain().collect();
+        for (layer_id, update) in pending {
+            self.apply_layer(&layer_id, update);
+        }
+        self.last_layer_flush = Instant::now();
+    }
+
+    /// Number of distinct layers that reported download progress
+    pub fn layer_count(&self) -> usize {
+        self.layer_status.len()
+    }
+
+    fn apply_layer(&mut self, layer_id: &str, update: PendingLayer) {
+        let bar = match self.bars.get(layer_id) {
+            Some(bar) => bar,
+            None => self.add_bar(layer_id, update.total),
+        };
+        // Update total if it changed (Docker sometimes updates this)
+        if bar.length() != Some(update.total) && update.total > 0 {
+            bar.set_length(update.total);
         }
+        bar.set_position(update.current);
     }
 
     /// Update spinner message (used during build)
@@ -240,8 +339,35 @@ impl ProgressReporter {
             .insert(id.to_string(), message.to_string());
     }
 
+    /// Show overall completion on a step spinner (used during build)
+    ///
+    /// The spinner becomes a determinate bar the first time step counts are
+    /// known and stays an indeterminate spinner until then.
+    pub fn set_step_progress(&mut self, id: &str, done: u64, total: u64) {
+        if self.plain_output || total == 0 {
+            return;
+        }
+        let Some(bar) = self.bars.get(id) else {
+            return;
+        };
+        if self.step_bars.insert(id.to_string()) {
+            bar.set_style(
+                ProgressStyle::default_bar()
+                    .template(
+                        "{spinner:.green} [{elapsed}] [{bar:30.cyan/blue}] {percent:>3}% {msg}",
+                    )
+                    .expect("valid template")
+                    .progress_chars("=>-"),
+            );
+        }
+        bar.set_length(total);
+        bar.set_position(done.min(total));
+    }
+
     /// Mark a layer/step as complete
     pub fn finish(&mut self, id: &str, message: &str) {
+        // A batched update arriving later must not rewind a finished bar
+        self.pending_layers.remove(id);
         if let Some(bar) = self.bars.get(id) {
             bar.finish_with_message(message.to_string());
         }
@@ -266,6 +392,24 @@ impl ProgressReporter {
 mod tests {
     use super::*;
 
+    #[test]
+    fn abandon_live_bars_skips_finished_bars() {
+        let running = ProgressBar::hidden();
+        let finished = ProgressBar::hidden();
+        let dropped = ProgressBar::hidden();
+        let mut live = vec![
+            running.downgrade(),
+            finished.downgrade(),
+            dropped.downgrade(),
+        ];
+        finished.finish();
+        drop(dropped);
+
+        assert_eq!(abandon_bars(&mut live, "Interrupted"), 1);
+        assert!(running.is_finished());
+        assert!(live.is_empty());
+    }
+
     #[test]
     fn progress_reporter_creation() {
         let reporter = ProgressReporter::new();
@@ -299,6 +443,61 @@ mod tests {
         assert!(reporter.bars.contains_key("layer1"));
     }
 
+    #[test]
+    fn update_layer_batches_byte_counts() {
+        let mut reporter = ProgressReporter::new();
+        reporter.update_layer("layer1", 100, 1000, "Downloading");
+        reporter.update_layer("layer1", 200, 1000, "Downloading");
+        reporter.update_layer("layer1", 300, 1000, "Downloading");
+
+        // Only the first update is applied until the flush interval passes
+        assert_eq!(reporter.bars["layer1"].position(), 100);
+        assert_eq!(
+            reporter.pending_layers["layer1"],
+            PendingLayer {
+                current: 300,
+                total: 1000
+            }
+        );
+
+        reporter.flush_layers();
+        assert_eq!(reporter.bars["layer1"].position(), 300);
+        assert!(reporter.pending_layers.is_empty());
+    }
+
+    #[test]
+    fn update_layer_applies_status_change_immediately() {
+        let mut reporter = ProgressReporter::new();
+        reporter.update_layer("layer1", 1000, 1000, "Downloading");
+        reporter.update_layer("layer1", 10, 500, "Extracting");
+        let bar = &reporter.bars["layer1"];
+        assert_eq!(bar.position(), 10);
+        assert_eq!(bar.length(), Some(500));
+        assert_eq!(bar.message(), "Extracting");
+    }
+
+    #[test]
+    fn update_layer_flushes_after_interval() {
+        let mut reporter = ProgressReporter::new();
+        reporter.update_layer("layer1", 100, 1000, "Downloading");
+        reporter.last_layer_flush = Instant::now() - LAYER_FLUSH_INTERVAL;
+        reporter.update_layer("layer1", 400, 1000, "Downloading");
+        assert_eq!(reporter.bars["layer1"].position(), 400);
+        assert!(reporter.pending_layers.is_empty());
+    }
+
+    #[test]
+    fn finish_discards_pending_layer_update() {
+        let mut reporter = ProgressReporter::new();
+        reporter.update_layer("layer1", 100, 1000, "Downloading");
+        reporter.update_layer("layer1", 200, 1000, "Downloading");
+        reporter.finish("layer1", "Pull complete");
+        reporter.flush_layers();
+        // Finishing fills the bar; the stale 200 must not be applied afterwards
+        assert_eq!(reporter.bars["layer1"].position(), 1000);
+        assert_eq!(reporter.layer_count(), 1);
+    }
+
     #[test]
     fn update_spinner_creates_if_missing() {
         let mut reporter = ProgressReporter::new();
@@ -306,6 +505,20 @@ mod tests {
         assert!(reporter.bars.contains_key("step1"));
     }
 
+    #[test]
+    fn set_step_progress_turns_spinner_into_bar() {
+        let mut reporter = ProgressReporter::new();
+        reporter.set_step_progress("build", 1, 4);
+        assert!(reporter.step_bars.is_empty());
+
+        reporter.update_spinner("build", "Building...");
+        reporter.set_step_progress("build", 3, 4);
+        let bar = &reporter.bars["build"];
+        assert_eq!(bar.length(), Some(4));
+        assert_eq!(bar.position(), 3);
+        assert!(reporter.step_bars.contains("build"));
+    }
+
     #[test]
     fn finish_handles_missing_id() {
         let mut reporter = ProgressReporter::new();
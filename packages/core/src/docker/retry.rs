@@ -0,0 +1,174 @@
+//! Retries for idempotent Docker reads
+//!
+//! A flaky daemon socket (or SSH tunnel) occasionally resets a connection
+//! mid-request. Reads such as inspect, list, and info are safe to repeat, so
+//! they are retried a couple of times on connection-level failures. Responses
+//! from the daemon itself, including 404s, are returned immediately.
+
+use bollard::errors::Error as BollardError;
+use std::future::Future;
+use std::io::ErrorKind;
+use std::time::Duration;
+use tracing::debug;
+
+/// Attempts for a read operation, including the first one
+const MAX_READ_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry; doubled for each further retry
+const READ_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
+/// Error text that marks a dropped connection when no `io::Error` is attached
+const TRANSIENT_MESSAGES: &[&str] = &[
+    "connection reset",
+    "connection closed before message completed",
+    "connection aborted",
+    "broken pipe",
+    "unexpected eof",
+];
+
+/// Whether a Docker API error is a connection hiccup worth retrying
+///
+/// Server responses (any status code) are never transient: the daemon
+/// answered, and repeating the request would get the same answer.
+pub fn is_transient_docker_error(err: &BollardError) -> bool {
+    match err {
+        BollardError::DockerResponseServerError { .. }
+        | BollardError::JsonDataError { .. }
+        | BollardError::JsonSerdeError { .. }
+        | BollardError::RequestTimeoutError
+        | BollardError::SocketNotFoundError(_) => false,
+        BollardError::IOError { err } => is_transient_io_kind(err.kind()),
+        other => error_chain_is_transient(other),
+    }
+}
+
+fn is_transient_io_kind(kind: ErrorKind) -> bool {
+    matches!(
+        kind,
+        ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::BrokenPipe
+            | ErrorKind::UnexpectedEof
+    )
+}
+
+fn error_chain_is_transient(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut current = Some(err);
+    while let Some(e) = current {
+        if let Some(io) = e.downcast_ref::<std::io::Error>()
+            && is_transient_io_kind(io.kind())
+        {
+            return true;
+        }
+        let message = e.to_string().to_lowercase();
+        if TRANSIENT_MESSAGES.iter().any(|m| message.contains(m)) {
+            return true;
+        }
+        current = e.source();
+    }
+    false
+}
+
+/// Run an idempotent Docker read, retrying transient connection errors
+pub async fn retry_transient<T, F, Fut>(what: &str, mut op: F) -> Result<T, BollardError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, BollardError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Err(e) if attempt < MAX_READ_ATTEMPTS && is_transient_docker_error(&e) => {
+                let delay = READ_RETRY_BASE_DELAY * 2u32.pow(attempt - 1);
+                debug!(
+                    "Docker {what} failed (attempt {attempt}/{MAX_READ_ATTEMPTS}): {e}; retrying in {delay:?}"
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn io_error(kind: ErrorKind) -> BollardError {
+        BollardError::IOError {
+            err: std::io::Error::new(kind, "socket trouble"),
+        }
+    }
+
+    fn server_error(status_code: u16) -> BollardError {
+        BollardError::DockerResponseServerError {
+            status_code,
+            message: "No such container".to_string(),
+        }
+    }
+
+    #[test]
+    fn connection_resets_and_eof_are_transient() {
+        assert!(is_transient_docker_error(&io_error(
+            ErrorKind::ConnectionReset
+        )));
+        assert!(is_transient_docker_error(&io_error(
+            ErrorKind::UnexpectedEof
+        )));
+        assert!(is_transient_docker_error(&io_error(ErrorKind::BrokenPipe)));
+    }
+
+    #[test]
+    fn server_responses_and_missing_sockets_are_not_transient() {
+        assert!(!is_transient_docker_error(&server_error(404)));
+        assert!(!is_transient_docker_error(&server_error(500)));
+        assert!(!is_transient_docker_error(&io_error(ErrorKind::NotFound)));
+        assert!(!is_transient_docker_error(
+            &BollardError::RequestTimeoutError
+        ));
+        assert!(!is_transient_docker_error(
+            &BollardError::SocketNotFoundError("/var/run/docker.sock".to_string())
+        ));
+    }
+
+    #[tokio::test]
+    async fn retry_transient_retries_then_succeeds() {
+        let calls = AtomicU32::new(0);
+        let result = retry_transient("test", || {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if call == 0 {
+                    Err(io_error(ErrorKind::ConnectionReset))
+                } else {
+                    Ok(call)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn retry_transient_passes_through_404_and_gives_up_eventually() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), _> = retry_transient("test", || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err(server_error(404)) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        calls.store(0, Ordering::SeqCst);
+        let result: Result<(), _> = retry_transient("test", || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err(io_error(ErrorKind::ConnectionReset)) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), MAX_READ_ATTEMPTS);
+    }
+}
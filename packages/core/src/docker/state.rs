@@ -1,7 +1,8 @@
 //! Image state tracking for provenance information
 //!
-//! Tracks where the current Docker image came from (prebuilt or built)
-//! and which registry it was pulled from.
+//! Tracks where the current Docker image came from (prebuilt or built),
+//! which registry it was pulled from, and when a container was last created
+//! from it.
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,11 @@ pub struct ImageState {
     pub registry: Option<String>,
     /// When the image was acquired (ISO8601)
     pub acquired_at: String,
+    /// When a container was last created from this image (ISO8601)
+    ///
+    /// Absent in state files written before this was tracked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_at: Option<String>,
 }
 
 impl ImageState {
@@ -31,6 +37,7 @@ impl ImageState {
             source: "prebuilt".to_string(),
             registry: Some(registry.to_string()),
             acquired_at: Utc::now().to_rfc3339(),
+            applied_at: None,
         }
     }
 
@@ -41,6 +48,7 @@ impl ImageState {
             source: "build".to_string(),
             registry: None,
             acquired_at: Utc::now().to_rfc3339(),
+            applied_at: None,
         }
     }
 }
@@ -76,6 +84,31 @@ pub fn load_state() -> Option<ImageState> {
     serde_json::from_str(&content).ok()
 }
 
+/// Record that a container was just created from the current image
+///
+/// Updates the version when the image label provides one. Without an
+/// existing state file, a new one is started with an "unknown" source.
+pub fn record_image_applied(version: Option<&str>) -> anyhow::Result<()> {
+    let now = Utc::now().to_rfc3339();
+    let state = match load_state() {
+        Some(mut state) => {
+            if let Some(version) = version {
+                state.version = version.to_string();
+            }
+            state.applied_at = Some(now);
+            state
+        }
+        None => ImageState {
+            version: version.unwrap_or("unknown").to_string(),
+            source: "unknown".to_string(),
+            registry: None,
+            acquired_at: now.clone(),
+            applied_at: Some(now),
+        },
+    };
+    save_state(&state)
+}
+
 /// Clear image state (e.g., after image removal)
 pub fn clear_state() -> anyhow::Result<()> {
     if let Some(path) = get_state_path()
@@ -117,6 +150,19 @@ mod tests {
         assert_eq!(state.registry, parsed.registry);
     }
 
+    #[test]
+    fn test_image_state_without_applied_at_still_loads() {
+        let json = r#"{"version":"1.0.12","source":"build","acquired_at":"2024-01-15T10:30:00Z"}"#;
+        let parsed: ImageState = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.version, "1.0.12");
+        assert!(parsed.applied_at.is_none());
+        assert!(
+            !serde_json::to_string(&parsed)
+                .unwrap()
+                .contains("applied_at")
+        );
+    }
+
     #[test]
     fn test_get_state_path() {
         let path = get_state_path();
@@ -0,0 +1,216 @@
+//! Container resource statistics
+//!
+//! Wraps the Docker stats API and reduces each raw sample to the CPU, memory,
+//! and network figures shown by `occ stats`, using the same formulas as
+//! `docker stats`.
+
+use bollard::models::ContainerStatsResponse;
+use bollard::query_parameters::StatsOptions;
+use futures_util::{Stream, StreamExt};
+use serde::Serialize;
+
+use super::{DockerClient, DockerError};
+
+/// One resource usage sample for a container
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ContainerStatsSample {
+    /// When Docker collected the sample (RFC3339)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read: Option<String>,
+    /// CPU usage as a percentage of one core (can exceed 100 on multi-core hosts)
+    pub cpu_percent: f64,
+    /// Memory in use, excluding reclaimable page cache
+    pub memory_usage_bytes: u64,
+    /// Memory limit (host memory when the container has no limit)
+    pub memory_limit_bytes: u64,
+    /// Memory usage as a percentage of the limit
+    pub memory_percent: f64,
+    /// Bytes received across all network interfaces
+    pub net_rx_bytes: u64,
+    /// Bytes sent across all network interfaces
+    pub net_tx_bytes: u64,
+    /// Number of processes in the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pids: Option<u64>,
+}
+
+impl From<&ContainerStatsResponse> for ContainerStatsSample {
+    fn from(stats: &ContainerStatsResponse) -> Self {
+        let (memory_usage_bytes, memory_limit_bytes) = memory_usage(stats);
+        let memory_percent = if memory_limit_bytes > 0 {
+            memory_usage_bytes as f64 / memory_limit_bytes as f64 * 100.0
+        } else {
+            0.0
+        };
+        let (net_rx_bytes, net_tx_bytes) = stats
+            .networks
+            .iter()
+            .flat_map(|networks| networks.values())
+            .fold((0u64, 0u64), |(rx, tx), net| {
+                (
+                    rx.saturating_add(net.rx_bytes.unwrap_or(0)),
+                    tx.saturating_add(net.tx_bytes.unwrap_or(0)),
+                )
+            });
+
+        Self {
+            read: stats.read.map(|read| read.to_rfc3339()),
+            cpu_percent: cpu_percent(stats),
+            memory_usage_bytes,
+            memory_limit_bytes,
+            memory_percent,
+            net_rx_bytes,
+            net_tx_bytes,
+            pids: stats.pids_stats.as_ref().and_then(|p| p.current),
+        }
+    }
+}
+
+/// CPU usage between the sample and the daemon's previous one
+fn cpu_percent(stats: &ContainerStatsResponse) -> f64 {
+    let (Some(cpu), Some(precpu)) = (&stats.cpu_stats, &stats.precpu_stats) else {
+        return 0.0;
+    };
+    let total = |s: &bollard::models::ContainerCpuStats| {
+        s.cpu_usage
+            .as_ref()
+            .and_then(|u| u.total_usage)
+            .unwrap_or(0)
+    };
+    let cpu_delta = total(cpu).saturating_sub(total(precpu));
+    let system_delta = cpu
+        .system_cpu_usage
+        .unwrap_or(0)
+        .saturating_sub(precpu.system_cpu_usage.unwrap_or(0));
+    if cpu_delta == 0 || system_delta == 0 {
+        return 0.0;
+    }
+
+    let online_cpus = cpu
+        .online_cpus
+        .filter(|n| *n > 0)
+        .map(u64::from)
+        .or_else(|| {
+            cpu.cpu_usage
+                .as_ref()
+                .and_then(|u| u.percpu_usage.as_ref())
+                .map(|per_cpu| per_cpu.len() as u64)
+        })
+        .unwrap_or(1);
+    cpu_delta as f64 / system_delta as f64 * online_cpus as f64 * 100.0
+}
+
+/// Memory usage and limit, subtracting inactive page cache like `docker stats`
+fn memory_usage(stats: &ContainerStatsResponse) -> (u64, u64) {
+    let Some(memory) = &stats.memory_stats else {
+        return (0, 0);
+    };
+    let usage = memory.usage.unwrap_or(0);
+    // cgroup v1 reports total_inactive_file, cgroup v2 reports inactive_file
+    let cache = memory
+        .stats
+        .as_ref()
+        .and_then(|s| {
+            s.get("total_inactive_file")
+                .or_else(|| s.get("inactive_file"))
+        })
+        .copied()
+        .unwrap_or(0);
+    let usage = if cache < usage { usage - cache } else { usage };
+    (usage, memory.limit.unwrap_or(0))
+}
+
+/// Stream resource samples for a container
+///
+/// With `stream` false Docker returns a single sample and the stream ends.
+/// A streaming request ends when the container stops.
+pub fn container_stats(
+    client: &DockerClient,
+    container_name: &str,
+    stream: bool,
+) -> impl Stream<Item = Result<ContainerStatsSample, DockerError>> {
+    let options = StatsOptions {
+        stream,
+        one_shot: false,
+    };
+    let container_name = container_name.to_string();
+    client
+        .inner()
+        .stats(&container_name, Some(options))
+        .map(move |result| {
+            result
+                .map(|stats| ContainerStatsSample::from(&stats))
+                .map_err(|e| {
+                    DockerError::Container(format!(
+                        "Failed to read stats for container {container_name}: {e}"
+                    ))
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{
+        ContainerCpuStats, ContainerCpuUsage, ContainerMemoryStats, ContainerNetworkStats,
+    };
+    use std::collections::HashMap;
+
+    fn cpu_stats(total: u64, system: u64) -> ContainerCpuStats {
+        ContainerCpuStats {
+            cpu_usage: Some(ContainerCpuUsage {
+                total_usage: Some(total),
+                ..Default::default()
+            }),
+            system_cpu_usage: Some(system),
+            online_cpus: Some(4),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sample_computes_cpu_memory_and_network() {
+        let stats = ContainerStatsResponse {
+            cpu_stats: Some(cpu_stats(300, 2_000)),
+            precpu_stats: Some(cpu_stats(100, 1_000)),
+            memory_stats: Some(ContainerMemoryStats {
+                usage: Some(600),
+                limit: Some(1_000),
+                stats: Some(HashMap::from([("inactive_file".to_string(), 100)])),
+                ..Default::default()
+            }),
+            networks: Some(HashMap::from([
+                (
+                    "eth0".to_string(),
+                    ContainerNetworkStats {
+                        rx_bytes: Some(10),
+                        tx_bytes: Some(20),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "eth1".to_string(),
+                    ContainerNetworkStats {
+                        rx_bytes: Some(5),
+                        tx_bytes: Some(1),
+                        ..Default::default()
+                    },
+                ),
+            ])),
+            ..Default::default()
+        };
+
+        let sample = ContainerStatsSample::from(&stats);
+        assert!((sample.cpu_percent - 80.0).abs() < f64::EPSILON);
+        assert_eq!(sample.memory_usage_bytes, 500);
+        assert_eq!(sample.memory_limit_bytes, 1_000);
+        assert!((sample.memory_percent - 50.0).abs() < f64::EPSILON);
+        assert_eq!((sample.net_rx_bytes, sample.net_tx_bytes), (15, 21));
+    }
+
+    #[test]
+    fn empty_response_yields_zeroed_sample() {
+        let sample = ContainerStatsSample::from(&ContainerStatsResponse::default());
+        assert_eq!(sample, ContainerStatsSample::default());
+    }
+}
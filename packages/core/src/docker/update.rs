@@ -3,16 +3,68 @@
 //! This module provides functionality to update the opencode image to the latest
 //! version and rollback to a previous version if needed.
 
-use super::image::{image_exists, pull_image};
+use super::image::{PullOptions, image_exists, pull_image};
 use super::profile::active_resource_names;
 use super::progress::ProgressReporter;
+use super::version::get_image_version;
 use super::{DockerClient, DockerError, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT};
-use bollard::query_parameters::TagImageOptions;
+use bollard::query_parameters::{RemoveImageOptions, TagImageOptions};
 use tracing::debug;
 
 /// Tag for the previous image version (used for rollback)
 pub const PREVIOUS_TAG: &str = "previous";
 
+/// Previous image generations kept for rollback by default
+pub const DEFAULT_ROLLBACK_DEPTH: usize = 3;
+
+/// Allowed range for the `rollback_depth` config setting
+pub const ROLLBACK_DEPTH_RANGE: std::ops::RangeInclusive<usize> = 1..=10;
+
+/// One image kept for rollback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreviousImage {
+    /// 1 for the image replaced by the last update, 2 for the one before, ...
+    pub generation: usize,
+    pub tag: String,
+    pub version: Option<String>,
+    /// Image creation time as reported by Docker
+    pub created: Option<String>,
+}
+
+/// Tag for a rollback generation
+///
+/// Generation 1 keeps the plain `previous` tag so images tagged by older
+/// releases stay usable; older generations get `previous-2`, `previous-3`, ...
+pub fn previous_generation_tag(previous_tag: &str, generation: usize) -> String {
+    if generation <= 1 {
+        previous_tag.to_string()
+    } else {
+        format!("{previous_tag}-{generation}")
+    }
+}
+
+/// Resolve `occ update --rollback --to <TAG>` to a generation tag
+///
+/// Accepts a tag as shown by `--list` or a bare generation number.
+/// Returns `None` for anything outside the configured depth.
+pub fn resolve_rollback_tag(input: &str, previous_tag: &str, depth: usize) -> Option<String> {
+    let input = input.trim();
+    let generation = if let Ok(generation) = input.parse::<usize>() {
+        generation
+    } else if input == previous_tag {
+        1
+    } else {
+        input
+            .strip_prefix(previous_tag)?
+            .strip_prefix('-')?
+            .parse()
+            .ok()?
+    };
+    (1..=depth)
+        .contains(&generation)
+        .then(|| previous_generation_tag(previous_tag, generation))
+}
+
 /// Result of an update operation
 #[derive(Debug, Clone, PartialEq)]
 pub enum UpdateResult {
@@ -25,11 +77,17 @@ pub enum UpdateResult {
 /// Tag the current image as "previous" for rollback support
 ///
 /// This allows users to rollback to the version they had before updating.
-/// If the current image doesn't exist, this is silently skipped.
+/// Older generations shift down the ring (`previous` -> `previous-2`, ...)
+/// and the generation past `depth` is untagged. If the current image
+/// doesn't exist, this is silently skipped.
 ///
 /// # Arguments
 /// * `client` - Docker client
-pub async fn tag_current_as_previous(client: &DockerClient) -> Result<(), DockerError> {
+/// * `depth` - Number of previous generations to keep
+pub async fn tag_current_as_previous(
+    client: &DockerClient,
+    depth: usize,
+) -> Result<(), DockerError> {
     let names = active_resource_names();
     let current_image = format!("{IMAGE_NAME_GHCR}:{}", names.image_tag);
     let previous_image = format!("{IMAGE_NAME_GHCR}:{}", names.previous_image_tag);
@@ -45,6 +103,8 @@ pub async fn tag_current_as_previous(client: &DockerClient) -> Result<(), Docker
         return Ok(());
     }
 
+    shift_previous_generations(client, &names.previous_image_tag, depth.max(1)).await?;
+
     // Tag current as previous
     let options = TagImageOptions {
         repo: Some(IMAGE_NAME_GHCR.to_string()),
@@ -63,6 +123,91 @@ pub async fn tag_current_as_previous(client: &DockerClient) -> Result<(), Docker
     Ok(())
 }
 
+/// Move each kept generation one step older, dropping the oldest
+async fn shift_previous_generations(
+    client: &DockerClient,
+    previous_tag: &str,
+    depth: usize,
+) -> Result<(), DockerError> {
+    // The oldest kept generation falls out of the ring; also drop any left
+    // over from a larger depth setting
+    for generation in depth..=*ROLLBACK_DEPTH_RANGE.end() {
+        remove_generation_tag(client, &previous_generation_tag(previous_tag, generation)).await;
+    }
+
+    for generation in (1..depth).rev() {
+        let tag = previous_generation_tag(previous_tag, generation);
+        if !image_exists(client, IMAGE_NAME_GHCR, &tag).await? {
+            continue;
+        }
+        let options = TagImageOptions {
+            repo: Some(IMAGE_NAME_GHCR.to_string()),
+            tag: Some(previous_generation_tag(previous_tag, generation + 1)),
+        };
+        client
+            .inner()
+            .tag_image(&format!("{IMAGE_NAME_GHCR}:{tag}"), Some(options))
+            .await
+            .map_err(|e| {
+                DockerError::Container(format!("Failed to keep {tag} for rollback: {e}"))
+            })?;
+    }
+    Ok(())
+}
+
+/// Remove a rollback tag; the image is deleted only if nothing else uses it
+async fn remove_generation_tag(client: &DockerClient, tag: &str) {
+    if !image_exists(client, IMAGE_NAME_GHCR, tag)
+        .await
+        .unwrap_or(false)
+    {
+        return;
+    }
+    let image = format!("{IMAGE_NAME_GHCR}:{tag}");
+    if let Err(e) = client
+        .inner()
+        .remove_image(&image, None::<RemoveImageOptions>, None)
+        .await
+    {
+        debug!("Could not remove old rollback image {image}: {e}");
+    }
+}
+
+/// List the kept rollback images, newest first
+///
+/// # Arguments
+/// * `client` - Docker client
+/// * `depth` - Number of previous generations to look for
+pub async fn list_previous_images(
+    client: &DockerClient,
+    depth: usize,
+) -> Result<Vec<PreviousImage>, DockerError> {
+    let names = active_resource_names();
+    let mut images = Vec::new();
+    for generation in 1..=depth.max(1) {
+        let tag = previous_generation_tag(&names.previous_image_tag, generation);
+        if !image_exists(client, IMAGE_NAME_GHCR, &tag).await? {
+            continue;
+        }
+        let image = format!("{IMAGE_NAME_GHCR}:{tag}");
+        let version = get_image_version(client, &image).await.ok().flatten();
+        let created = client
+            .inner()
+            .inspect_image(&image)
+            .await
+            .ok()
+            .and_then(|info| info.created)
+            .map(|created| created.format("%Y-%m-%d %H:%M UTC").to_string());
+        images.push(PreviousImage {
+            generation,
+            tag,
+            version,
+            created,
+        });
+    }
+    Ok(images)
+}
+
 /// Check if a previous image exists for rollback
 ///
 /// Returns true if a rollback is possible, false otherwise.
@@ -91,12 +236,18 @@ pub async fn update_image(
 ) -> Result<UpdateResult, DockerError> {
     // Step 1: Tag current image as previous for rollback
     progress.add_spinner("backup", "Backing up current image");
-    tag_current_as_previous(client).await?;
+    tag_current_as_previous(client, DEFAULT_ROLLBACK_DEPTH).await?;
     progress.finish("backup", "Current image backed up");
 
     // Step 2: Pull latest image
     progress.add_spinner("pull", "Pulling latest image");
-    pull_image(client, Some(IMAGE_TAG_DEFAULT), progress).await?;
+    pull_image(
+        client,
+        Some(IMAGE_TAG_DEFAULT),
+        progress,
+        &PullOptions::default(),
+    )
+    .await?;
     progress.finish("pull", "Latest image pulled");
 
     Ok(UpdateResult::Success)
@@ -112,16 +263,29 @@ pub async fn update_image(
 /// # Arguments
 /// * `client` - Docker client
 pub async fn rollback_image(client: &DockerClient) -> Result<(), DockerError> {
-    // Check if previous image exists
-    if !has_previous_image(client).await? {
-        return Err(DockerError::Container(
-            "No previous image available for rollback. Update at least once before using rollback."
-                .to_string(),
-        ));
+    let names = active_resource_names();
+    rollback_to_image(client, &names.previous_image_tag).await
+}
+
+/// Rollback to a specific kept generation, e.g. `previous-2`
+///
+/// Re-tags that image as "latest". Returns an error if the tag is missing.
+///
+/// # Arguments
+/// * `client` - Docker client
+/// * `previous_tag` - Rollback tag to restore
+pub async fn rollback_to_image(
+    client: &DockerClient,
+    previous_tag: &str,
+) -> Result<(), DockerError> {
+    if !image_exists(client, IMAGE_NAME_GHCR, previous_tag).await? {
+        return Err(DockerError::Container(format!(
+            "No image tagged {previous_tag} available for rollback. Update at least once before using rollback."
+        )));
     }
 
     let names = active_resource_names();
-    let previous_image = format!("{IMAGE_NAME_GHCR}:{}", names.previous_image_tag);
+    let previous_image = format!("{IMAGE_NAME_GHCR}:{previous_tag}");
     let current_image = format!("{IMAGE_NAME_GHCR}:{}", names.image_tag);
 
     debug!("Rolling back from {} to {}", current_image, previous_image);
@@ -157,4 +321,42 @@ mod tests {
         assert_eq!(UpdateResult::AlreadyLatest, UpdateResult::AlreadyLatest);
         assert_ne!(UpdateResult::Success, UpdateResult::AlreadyLatest);
     }
+
+    #[test]
+    fn previous_generation_tags() {
+        assert_eq!(previous_generation_tag("previous", 1), "previous");
+        assert_eq!(previous_generation_tag("previous", 2), "previous-2");
+        assert_eq!(
+            previous_generation_tag("instance-foo-previous", 3),
+            "instance-foo-previous-3"
+        );
+    }
+
+    #[test]
+    fn resolve_rollback_tag_accepts_tags_and_generations() {
+        assert_eq!(
+            resolve_rollback_tag("previous", "previous", 3).as_deref(),
+            Some("previous")
+        );
+        assert_eq!(
+            resolve_rollback_tag("previous-3", "previous", 3).as_deref(),
+            Some("previous-3")
+        );
+        assert_eq!(
+            resolve_rollback_tag("2", "previous", 3).as_deref(),
+            Some("previous-2")
+        );
+        assert_eq!(
+            resolve_rollback_tag("1", "previous", 3).as_deref(),
+            Some("previous")
+        );
+    }
+
+    #[test]
+    fn resolve_rollback_tag_rejects_out_of_range_and_unknown() {
+        assert_eq!(resolve_rollback_tag("previous-4", "previous", 3), None);
+        assert_eq!(resolve_rollback_tag("0", "previous", 3), None);
+        assert_eq!(resolve_rollback_tag("latest", "previous", 3), None);
+        assert_eq!(resolve_rollback_tag("previous-x", "previous", 3), None);
+    }
 }
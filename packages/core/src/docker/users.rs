@@ -265,6 +265,33 @@ pub async fn list_users(
     Ok(users)
 }
 
+/// Most recent login recorded by `lastlog` for a user.
+///
+/// Returns `None` when the user never logged in, or when the image has no
+/// `lastlog` binary; login history is informational and never an error.
+pub async fn last_login(client: &DockerClient, container: &str, username: &str) -> Option<String> {
+    let output = exec_command(client, container, vec!["lastlog", "-u", username])
+        .await
+        .ok()?;
+    parse_lastlog_output(&output)
+}
+
+/// Extract the "Latest" column from `lastlog -u <user>` output.
+pub fn parse_lastlog_output(output: &str) -> Option<String> {
+    let mut lines = output.lines();
+    let header = lines.next()?;
+    if !header.starts_with("Username") {
+        return None;
+    }
+    let column = header.find("Latest")?;
+    let row = lines.next()?;
+    if row.contains("Never logged in") {
+        return None;
+    }
+    let latest = row.get(column..)?.trim();
+    (!latest.is_empty()).then(|| latest.to_string())
+}
+
 /// Persist a user's credentials and lock state to the managed volume.
 ///
 /// Stores the shadow hash (not plaintext) and lock status in a JSON record.
@@ -481,7 +508,10 @@ fn user_record_path(username: &str) -> String {
     format!("{USERS_STORE_DIR}/{username}.json")
 }
 
-fn is_builtin_system_user(username: &str) -> bool {
+/// Whether a username belongs to the image's built-in accounts
+///
+/// Built-in users are never listed, persisted, or changed by bulk operations.
+pub fn is_builtin_system_user(username: &str) -> bool {
     HIDDEN_BUILTIN_USERS.contains(&username)
 }
 
@@ -566,6 +596,21 @@ mod tests {
         assert!(parse_passwd_line("user:x:not_a_number:1000::/home/user:/bin/bash").is_none());
     }
 
+    #[test]
+    fn test_parse_lastlog_output() {
+        let output = "Username         Port     From             Latest\n\
+                      admin            pts/0    10.0.0.5         Mon Jan 15 10:30:00 +0000 2024\n";
+        assert_eq!(
+            parse_lastlog_output(output).as_deref(),
+            Some("Mon Jan 15 10:30:00 +0000 2024")
+        );
+
+        let never = "Username         Port     From             Latest\n\
+                     admin                                       **Never logged in**\n";
+        assert!(parse_lastlog_output(never).is_none());
+        assert!(parse_lastlog_output("exec: \"lastlog\": executable file not found").is_none());
+    }
+
     #[test]
     fn test_protected_system_user_constant() {
         assert_eq!(PROTECTED_SYSTEM_USER, "opencoder");
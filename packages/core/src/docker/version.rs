@@ -3,6 +3,7 @@
 //! Reads version information from Docker image labels.
 
 use super::registry::fetch_registry_version;
+use super::version_cache::{cached_version, store_version};
 use super::{DockerClient, DockerError, IMAGE_NAME_DOCKERHUB, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT};
 
 /// Version label key in Docker image
@@ -52,6 +53,24 @@ pub async fn get_registry_latest_version(
     }
 }
 
+/// Like [`get_registry_latest_version`], but served from the version cache
+/// when a recent lookup exists. `refresh` forces a registry query.
+///
+/// Only successful lookups are cached, so a failing registry is retried
+/// on the next run.
+pub async fn get_registry_latest_version_cached(
+    client: &DockerClient,
+    refresh: bool,
+) -> Result<Option<String>, DockerError> {
+    let key = format!("registry:{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}");
+    if !refresh && let Some(version) = cached_version(&key) {
+        return Ok(version);
+    }
+    let version = get_registry_latest_version(client).await?;
+    store_version(&key, version.clone());
+    Ok(version)
+}
+
 async fn fetch_ghcr_registry_version(client: &DockerClient) -> Result<Option<String>, DockerError> {
     let repo = IMAGE_NAME_GHCR
         .strip_prefix("ghcr.io/")
@@ -0,0 +1,134 @@
+//! Short-lived cache for remote version lookups
+//!
+//! `occ update` asks the image registry and GitHub for the latest versions on
+//! every run. Results are kept in the data dir for [`VERSION_CACHE_TTL`] so
+//! repeated runs render instantly and stay clear of API rate limits.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long a cached lookup is considered fresh
+pub const VERSION_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+const VERSION_CACHE_FILE: &str = "version-cache.json";
+
+/// A cached lookup result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedLookup {
+    /// Looked-up value (`None` when the remote had no answer, e.g. a missing label)
+    pub value: Option<String>,
+    /// When the value was fetched (unix seconds)
+    pub fetched_at: i64,
+}
+
+/// Cached lookups keyed by what was asked, e.g. `registry:<image>:<tag>`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionCache {
+    pub entries: HashMap<String, CachedLookup>,
+}
+
+impl VersionCache {
+    /// Cached value for `key` if fetched within the TTL of `now` (unix seconds)
+    pub fn fresh(&self, key: &str, now: i64) -> Option<&CachedLookup> {
+        self.entries.get(key).filter(|entry| {
+            let age = now.saturating_sub(entry.fetched_at);
+            (0..VERSION_CACHE_TTL.as_secs() as i64).contains(&age)
+        })
+    }
+
+    /// Record a fetched value
+    pub fn insert(&mut self, key: &str, value: Option<String>, now: i64) {
+        self.entries.insert(
+            key.to_string(),
+            CachedLookup {
+                value,
+                fetched_at: now,
+            },
+        );
+    }
+}
+
+/// Get the path to the version cache file
+pub fn get_version_cache_path() -> Option<PathBuf> {
+    crate::config::paths::get_data_dir().map(|p| p.join(VERSION_CACHE_FILE))
+}
+
+/// Save the version cache to disk
+pub fn save_version_cache(cache: &VersionCache) -> anyhow::Result<()> {
+    let path = get_version_cache_path()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine version cache path"))?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let json = serde_json::to_string_pretty(cache)?;
+    std::fs::write(&path, json)?;
+    Ok(())
+}
+
+/// Load the version cache from disk (empty if missing or unreadable)
+pub fn load_version_cache() -> VersionCache {
+    get_version_cache_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Fresh cached value for `key`, if any
+///
+/// The outer `Option` is whether the cache had a fresh entry.
+pub fn cached_version(key: &str) -> Option<Option<String>> {
+    load_version_cache()
+        .fresh(key, Utc::now().timestamp())
+        .map(|entry| entry.value.clone())
+}
+
+/// Store a looked-up value; failures to write the cache are only logged
+pub fn store_version(key: &str, value: Option<String>) {
+    let mut cache = load_version_cache();
+    cache.insert(key, value, Utc::now().timestamp());
+    if let Err(e) = save_version_cache(&cache) {
+        tracing::debug!("Failed to write version cache: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_respects_ttl() {
+        let mut cache = VersionCache::default();
+        cache.insert("registry:img:latest", Some("1.2.3".to_string()), 1_000);
+
+        let ttl = VERSION_CACHE_TTL.as_secs() as i64;
+        assert!(cache.fresh("registry:img:latest", 1_000).is_some());
+        assert!(
+            cache
+                .fresh("registry:img:latest", 1_000 + ttl - 1)
+                .is_some()
+        );
+        assert!(cache.fresh("registry:img:latest", 1_000 + ttl).is_none());
+        assert!(cache.fresh("other", 1_000).is_none());
+    }
+
+    #[test]
+    fn fresh_rejects_entries_from_the_future() {
+        let mut cache = VersionCache::default();
+        cache.insert("key", None, 5_000);
+        assert!(cache.fresh("key", 4_000).is_none());
+    }
+
+    #[test]
+    fn cache_roundtrips_through_json() {
+        let mut cache = VersionCache::default();
+        cache.insert("key", Some("abc".to_string()), 42);
+        let json = serde_json::to_string(&cache).unwrap();
+        let parsed: VersionCache = serde_json::from_str(&json).unwrap();
+        assert_eq!(cache, parsed);
+    }
+}
@@ -4,10 +4,17 @@
 //! for persistent storage across container restarts.
 
 use super::{DockerClient, DockerError};
-use crate::docker::{INSTANCE_LABEL_KEY, active_resource_names};
-use bollard::models::VolumeCreateRequest;
-use bollard::query_parameters::RemoveVolumeOptions;
-use std::collections::HashMap;
+use crate::docker::{DockerResourceNames, INSTANCE_LABEL_KEY, ParsedMount, active_resource_names};
+use bollard::models::{ContainerCreateBody, HostConfig, Mount, MountPointTypeEnum, MountTypeEnum};
+use bollard::models::{Volume, VolumeCreateRequest};
+use bollard::query_parameters::{
+    CreateContainerOptions, ListVolumesOptions, RemoveContainerOptions, RemoveVolumeOptions,
+    StartContainerOptions,
+};
+use futures_util::StreamExt;
+use futures_util::stream;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
 use tracing::debug;
 
 /// Volume name for opencode data
@@ -128,6 +135,133 @@ pub async fn volume_exists(client: &DockerClient, name: &str) -> Result<bool, Do
     }
 }
 
+/// List all volumes carrying the sandbox instance label
+pub async fn list_instance_volumes(client: &DockerClient) -> Result<Vec<Volume>, DockerError> {
+    debug!("Listing sandbox instance volumes");
+
+    let options = ListVolumesOptions {
+        filters: Some(HashMap::from([(
+            "label".to_string(),
+            vec![INSTANCE_LABEL_KEY.to_string()],
+        )])),
+    };
+
+    let response = client
+        .inner()
+        .list_volumes(Some(options))
+        .await
+        .map_err(|e| DockerError::Volume(format!("Failed to list volumes: {e}")))?;
+    Ok(response.volumes.unwrap_or_default())
+}
+
+/// Each managed volume paired with the container path it is mounted at
+pub fn volume_mount_targets(names: &DockerResourceNames) -> [(&str, &'static str); 7] {
+    [
+        (&names.volume_session, MOUNT_SESSION),
+        (&names.volume_state, MOUNT_STATE),
+        (&names.volume_cache, MOUNT_CACHE),
+        (&names.volume_projects, MOUNT_PROJECTS),
+        (&names.volume_config, MOUNT_CONFIG),
+        (&names.volume_users, MOUNT_USERS),
+        (&names.volume_ssh, MOUNT_SSH),
+    ]
+}
+
+/// Managed volumes that exist but are not mounted by the sandbox container
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnattachedVolumes {
+    /// Not mounted and not replaced by anything; candidates for removal
+    pub orphaned: Vec<String>,
+    /// Not mounted because a bind mount covers the volume's target
+    ///
+    /// These may still hold data written before the bind mount was configured,
+    /// and `occ start` recreates them if removed, so they are never pruned.
+    pub shadowed: Vec<String>,
+}
+
+/// Split unattached volumes into orphaned and shadowed by bind mounts
+fn classify_unattached(
+    names: &DockerResourceNames,
+    attached: &[String],
+    bind_targets: &HashSet<String>,
+) -> UnattachedVolumes {
+    let mut result = UnattachedVolumes::default();
+    for (volume, target) in volume_mount_targets(names) {
+        if attached.iter().any(|a| a == volume) {
+            continue;
+        }
+        if bind_targets.contains(target) {
+            result.shadowed.push(volume.to_string());
+        } else {
+            result.orphaned.push(volume.to_string());
+        }
+    }
+    result
+}
+
+/// Managed volumes that exist but are not mounted by the sandbox container
+///
+/// `bind_mounts` are the configured mount specs (`config.mounts`); volumes
+/// whose target they, or the container's own bind mounts, cover are reported
+/// as shadowed rather than orphaned.
+///
+/// Returns `None` when the container does not exist, since the volumes are
+/// then simply waiting for the next `occ start` rather than orphaned.
+pub async fn find_orphaned_volumes(
+    client: &DockerClient,
+    bind_mounts: &[String],
+) -> Result<Option<UnattachedVolumes>, DockerError> {
+    let names = active_resource_names();
+    let info = match client.inspect_container(&names.container_name).await {
+        Ok(info) => info,
+        Err(bollard::errors::Error::DockerResponseServerError {
+            status_code: 404, ..
+        }) => return Ok(None),
+        Err(e) => {
+            return Err(DockerError::Container(format!(
+                "Failed to inspect container {}: {e}",
+                names.container_name
+            )));
+        }
+    };
+
+    let container_mounts = info.mounts.unwrap_or_default();
+    let attached: Vec<String> = container_mounts
+        .iter()
+        .filter(|m| m.typ == Some(MountPointTypeEnum::VOLUME))
+        .filter_map(|m| m.name.clone())
+        .collect();
+    let mut bind_targets = bind_mount_targets(bind_mounts);
+    bind_targets.extend(
+        container_mounts
+            .into_iter()
+            .filter(|m| m.typ == Some(MountPointTypeEnum::BIND))
+            .filter_map(|m| m.destination),
+    );
+
+    let mut unattached = classify_unattached(&names, &attached, &bind_targets);
+    for list in [&mut unattached.orphaned, &mut unattached.shadowed] {
+        let mut existing = Vec::with_capacity(list.len());
+        for volume_name in list.drain(..) {
+            if volume_exists(client, &volume_name).await? {
+                existing.push(volume_name);
+            }
+        }
+        *list = existing;
+    }
+    debug!("Unattached volumes: {:?}", unattached);
+    Ok(Some(unattached))
+}
+
+/// Container paths covered by the given bind mount specs (unparseable specs are skipped)
+pub fn bind_mount_targets(bind_mounts: &[String]) -> HashSet<String> {
+    bind_mounts
+        .iter()
+        .filter_map(|spec| ParsedMount::parse(spec).ok())
+        .map(|parsed| parsed.container_path)
+        .collect()
+}
+
 /// Remove a volume
 ///
 /// Returns error if volume is in use by a container.
@@ -145,21 +279,190 @@ pub async fn remove_volume(client: &DockerClient, name: &str) -> Result<(), Dock
     Ok(())
 }
 
+/// How many volumes [`remove_volumes_reporting`] removes at once
+const VOLUME_REMOVAL_CONCURRENCY: usize = 4;
+
+/// What happened to one volume during bulk removal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VolumeRemovalOutcome {
+    Removed,
+    /// The volume did not exist
+    Missing,
+    /// Removal failed, e.g. because a container still uses the volume
+    Failed(String),
+}
+
+/// Per-volume result of [`remove_volumes_reporting`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeRemovalResult {
+    pub name: String,
+    pub outcome: VolumeRemovalOutcome,
+}
+
 /// Remove all opencode-cloud volumes
 ///
-/// Used during uninstall. Fails if any volume is in use.
+/// Used during uninstall. Every volume is attempted; fails afterwards if any
+/// volume could not be removed (e.g. because it is in use).
 pub async fn remove_all_volumes(client: &DockerClient) -> Result<(), DockerError> {
+    volume_removal_error(&remove_volumes_reporting(client).await).map_or(Ok(()), Err)
+}
+
+/// Remove all opencode-cloud volumes and report each volume's result
+///
+/// Volumes are removed concurrently; one failure does not stop the others.
+/// Results are in the order of the managed volume names.
+pub async fn remove_volumes_reporting(client: &DockerClient) -> Vec<VolumeRemovalResult> {
     debug!("Removing all opencode-cloud volumes");
     let names = active_resource_names();
-
-    for volume_name in names.volume_names() {
-        // Check if volume exists before trying to remove
-        if volume_exists(client, volume_name).await? {
-            remove_volume(client, volume_name).await?;
+    let results = remove_each(&names.volume_names(), |name| async move {
+        match volume_exists(client, name).await {
+            Ok(false) => VolumeRemovalOutcome::Missing,
+            Ok(true) => match remove_volume(client, name).await {
+                Ok(()) => VolumeRemovalOutcome::Removed,
+                Err(e) => VolumeRemovalOutcome::Failed(e.to_string()),
+            },
+            Err(e) => VolumeRemovalOutcome::Failed(e.to_string()),
         }
+    })
+    .await;
+    debug!("Volume removal results: {:?}", results);
+    results
+}
+
+/// Combine failed removals into one error, or `None` if all succeeded
+pub fn volume_removal_error(results: &[VolumeRemovalResult]) -> Option<DockerError> {
+    let failures: Vec<String> = results
+        .iter()
+        .filter_map(|result| match &result.outcome {
+            VolumeRemovalOutcome::Failed(err) => Some(format!("{}: {err}", result.name)),
+            _ => None,
+        })
+        .collect();
+    if failures.is_empty() {
+        return None;
     }
+    Some(DockerError::Volume(format!(
+        "Failed to remove {} of {} volume(s): {}",
+        failures.len(),
+        results.len(),
+        failures.join("; ")
+    )))
+}
+
+/// Run `remove` for every name with bounded concurrency, keeping input order
+async fn remove_each<'a, F, Fut>(names: &[&'a str], remove: F) -> Vec<VolumeRemovalResult>
+where
+    F: Fn(&'a str) -> Fut,
+    Fut: Future<Output = VolumeRemovalOutcome>,
+{
+    let mut results: Vec<(usize, VolumeRemovalResult)> =
+        stream::iter(names.iter().copied().enumerate())
+            .map(|(index, name)| {
+                let removal = remove(name);
+                async move {
+                    let outcome = removal.await;
+                    (
+                        index,
+                        VolumeRemovalResult {
+                            name: name.to_string(),
+                            outcome,
+                        },
+                    )
+                }
+            })
+            .buffer_unordered(VOLUME_REMOVAL_CONCURRENCY)
+            .collect()
+            .await;
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Where [`clear_volume_contents`] mounts the volume in its helper container
+const CLEAR_MOUNT: &str = "/clear";
+
+/// Delete everything inside a volume while keeping the volume itself
+///
+/// Runs a short-lived helper container from `image` with the volume mounted,
+/// so it works whether or not the sandbox container is running. The image
+/// must provide `sh` and `find`; the sandbox image does.
+pub async fn clear_volume_contents(
+    client: &DockerClient,
+    volume: &str,
+    image: &str,
+) -> Result<(), DockerError> {
+    debug!("Clearing contents of volume {volume} using {image}");
+    let helper_name = format!("{volume}-clear");
+
+    let config = ContainerCreateBody {
+        image: Some(image.to_string()),
+        user: Some("root".to_string()),
+        entrypoint: Some(vec!["sh".to_string(), "-c".to_string()]),
+        cmd: Some(vec![format!(
+            "find {CLEAR_MOUNT} -mindepth 1 -maxdepth 1 -exec rm -rf {{}} +"
+        )]),
+        labels: Some(HashMap::from([(
+            "managed-by".to_string(),
+            "opencode-cloud".to_string(),
+        )])),
+        host_config: Some(HostConfig {
+            mounts: Some(vec![Mount {
+                target: Some(CLEAR_MOUNT.to_string()),
+                source: Some(volume.to_string()),
+                typ: Some(MountTypeEnum::VOLUME),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let options = CreateContainerOptions {
+        name: Some(helper_name.clone()),
+        platform: String::new(),
+    };
+    client
+        .inner()
+        .create_container(Some(options), config)
+        .await
+        .map_err(|e| DockerError::Volume(format!("Failed to create helper container: {e}")))?;
 
-    debug!("All volumes removed");
+    let result = run_to_completion(client, &helper_name).await;
+
+    let remove = RemoveContainerOptions {
+        force: true,
+        v: false,
+        link: false,
+    };
+    if let Err(e) = client
+        .inner()
+        .remove_container(&helper_name, Some(remove))
+        .await
+    {
+        debug!("Failed to remove helper container {helper_name}: {e}");
+    }
+
+    result.map_err(|e| DockerError::Volume(format!("Failed to clear volume {volume}: {e}")))
+}
+
+async fn run_to_completion(client: &DockerClient, container: &str) -> Result<(), String> {
+    client
+        .inner()
+        .start_container(container, None::<StartContainerOptions>)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut wait = client.inner().wait_container(container, None);
+    while let Some(response) = wait.next().await {
+        match response {
+            Ok(response) if response.status_code != 0 => {
+                return Err(format!("helper exited with code {}", response.status_code));
+            }
+            Ok(_) => {}
+            Err(bollard::errors::Error::DockerContainerWaitError { code, .. }) => {
+                return Err(format!("helper exited with code {code}"));
+            }
+            Err(e) => return Err(e.to_string()),
+        }
+    }
     Ok(())
 }
 
@@ -200,4 +503,80 @@ mod tests {
         assert_eq!(MOUNT_USERS, "/var/lib/opencode-users");
         assert_eq!(MOUNT_SSH, "/home/opencoder/.ssh");
     }
+
+    #[test]
+    fn default_config_bind_mounts_shadow_volumes_instead_of_orphaning_them() {
+        let names = crate::docker::resource_names_for_instance(None);
+        let config = crate::config::Config::default();
+        let bind_targets = bind_mount_targets(&config.mounts);
+
+        // A container created from the default config mounts only the volumes
+        // that no default bind mount replaces.
+        let attached: Vec<String> = volume_mount_targets(&names)
+            .iter()
+            .filter(|(_, target)| !bind_targets.contains(*target))
+            .map(|(volume, _)| volume.to_string())
+            .collect();
+
+        let unattached = classify_unattached(&names, &attached, &bind_targets);
+        assert!(unattached.orphaned.is_empty(), "{unattached:?}");
+        assert_eq!(unattached.shadowed.len(), config.mounts.len());
+        assert!(unattached.shadowed.contains(&names.volume_cache));
+    }
+
+    #[test]
+    fn unattached_volume_without_bind_mount_is_orphaned() {
+        let names = crate::docker::resource_names_for_instance(None);
+        let attached = vec![names.volume_session.clone()];
+        let bind_targets = bind_mount_targets(&[format!("/host/cache:{MOUNT_CACHE}")]);
+
+        let unattached = classify_unattached(&names, &attached, &bind_targets);
+        assert_eq!(unattached.shadowed, vec![names.volume_cache.clone()]);
+        assert_eq!(unattached.orphaned.len(), 5);
+        assert!(!unattached.orphaned.contains(&names.volume_session));
+    }
+
+    #[tokio::test]
+    async fn remove_each_attempts_every_volume_after_a_failure() {
+        let names = ["a", "b", "c", "d", "e"];
+        let results = remove_each(&names, |name| async move {
+            match name {
+                "b" => VolumeRemovalOutcome::Failed("volume is in use".to_string()),
+                "d" => VolumeRemovalOutcome::Missing,
+                _ => VolumeRemovalOutcome::Removed,
+            }
+        })
+        .await;
+
+        let order: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(order, names);
+        assert_eq!(results[0].outcome, VolumeRemovalOutcome::Removed);
+        assert_eq!(
+            results[1].outcome,
+            VolumeRemovalOutcome::Failed("volume is in use".to_string())
+        );
+        assert_eq!(results[3].outcome, VolumeRemovalOutcome::Missing);
+        assert_eq!(results[4].outcome, VolumeRemovalOutcome::Removed);
+    }
+
+    #[test]
+    fn volume_removal_error_lists_each_failure() {
+        let result = |name: &str, outcome| VolumeRemovalResult {
+            name: name.to_string(),
+            outcome,
+        };
+        let results = vec![
+            result("a", VolumeRemovalOutcome::Removed),
+            result("b", VolumeRemovalOutcome::Failed("in use".to_string())),
+            result("c", VolumeRemovalOutcome::Missing),
+            result("d", VolumeRemovalOutcome::Failed("timeout".to_string())),
+        ];
+
+        let err = volume_removal_error(&results).unwrap().to_string();
+        assert!(err.contains("2 of 4 volume(s)"), "{err}");
+        assert!(err.contains("b: in use"), "{err}");
+        assert!(err.contains("d: timeout"), "{err}");
+        assert!(volume_removal_error(&results[..1]).is_none());
+        assert!(volume_removal_error(&[]).is_none());
+    }
 }
@@ -20,7 +20,7 @@ pub use provision::{
     DistroFamily, DistroInfo, detect_distro, get_docker_install_commands, install_docker,
     verify_docker_installed,
 };
-pub use schema::{HostConfig, HostsFile};
+pub use schema::{DEFAULT_DOCKER_TLS_PORT, HostConfig, HostsFile, expand_home};
 pub use ssh_config::{
     SshConfigMatch, get_ssh_config_path, host_exists_in_ssh_config, query_ssh_config,
     write_ssh_config_entry,
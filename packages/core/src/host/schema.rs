@@ -2,8 +2,10 @@
 //!
 //! Data structures for storing remote host configurations.
 
+use crate::config::Config;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Configuration for a remote host
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -28,13 +30,70 @@ pub struct HostConfig {
     #[serde(default)]
     pub jump_host: Option<String>,
 
-    /// Organization groups/tags for this host
+    /// Organization groups for this host
     #[serde(default)]
     pub groups: Vec<String>,
 
+    /// Free-form tags for filtering hosts (e.g., "gpu", "eu-west")
+    #[serde(default)]
+    pub tags: Vec<String>,
+
     /// Optional description
     #[serde(default)]
     pub description: Option<String>,
+
+    /// SSH connect timeout in seconds (default: 10)
+    #[serde(default)]
+    pub connect_timeout: Option<u32>,
+
+    /// CA certificate (PEM) for reaching Docker directly over TLS
+    ///
+    /// When all three TLS paths are set the host is reached at
+    /// `tcp://hostname:docker_port` instead of through SSH. A leading `~`
+    /// is expanded to the home directory.
+    #[serde(default)]
+    pub tls_ca: Option<String>,
+
+    /// Client certificate (PEM) for Docker over TLS
+    #[serde(default)]
+    pub tls_cert: Option<String>,
+
+    /// Client private key (PEM) for Docker over TLS
+    #[serde(default)]
+    pub tls_key: Option<String>,
+
+    /// Docker TLS port (default: 2376); `port` stays the SSH port
+    #[serde(default)]
+    pub docker_port: Option<u16>,
+
+    /// opencode web port used when this host is the target (overrides `opencode_web_port`)
+    #[serde(default)]
+    pub web_port: Option<u16>,
+
+    /// Bind address used when this host is the target (overrides `bind_address`)
+    #[serde(default)]
+    pub bind_address: Option<String>,
+}
+
+/// Port Docker listens on for TLS connections by convention
+pub const DEFAULT_DOCKER_TLS_PORT: u16 = 2376;
+
+/// SSH connect timeout used when a host does not set one
+const DEFAULT_SSH_CONNECT_TIMEOUT_SECS: u32 = 10;
+
+/// Expand a leading `~` or `~/` to the home directory
+///
+/// Other paths, including `~user/...`, are returned unchanged.
+pub fn expand_home(path: &str) -> PathBuf {
+    let rest = match path.strip_prefix('~') {
+        Some("") => "",
+        Some(rest) if rest.starts_with('/') => &rest[1..],
+        _ => return PathBuf::from(path),
+    };
+    match dirs::home_dir() {
+        Some(home) => home.join(rest),
+        None => PathBuf::from(path),
+    }
 }
 
 fn default_user() -> String {
@@ -50,7 +109,15 @@ impl Default for HostConfig {
             identity_file: None,
             jump_host: None,
             groups: Vec::new(),
+            tags: Vec::new(),
             description: None,
+            connect_timeout: None,
+            tls_ca: None,
+            tls_cert: None,
+            tls_key: None,
+            docker_port: None,
+            web_port: None,
+            bind_address: None,
         }
     }
 }
@@ -94,12 +161,97 @@ impl HostConfig {
         self
     }
 
+    /// Builder pattern: add tag (duplicates are ignored)
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    /// Check if the host carries the given tag
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
     /// Builder pattern: set description
     pub fn with_description(mut self, desc: impl Into<String>) -> Self {
         self.description = Some(desc.into());
         self
     }
 
+    /// Builder pattern: reach Docker over TLS with these certificate paths
+    pub fn with_tls(
+        mut self,
+        ca: impl Into<String>,
+        cert: impl Into<String>,
+        key: impl Into<String>,
+    ) -> Self {
+        self.tls_ca = Some(ca.into());
+        self.tls_cert = Some(cert.into());
+        self.tls_key = Some(key.into());
+        self
+    }
+
+    /// Builder pattern: set the Docker TLS port
+    pub fn with_docker_port(mut self, port: u16) -> Self {
+        self.docker_port = Some(port);
+        self
+    }
+
+    /// Whether this host is reached over Docker TLS rather than SSH
+    pub fn uses_tls(&self) -> bool {
+        self.tls_paths().is_some()
+    }
+
+    /// CA, certificate, and key paths when all three are configured, with `~` expanded
+    pub fn tls_paths(&self) -> Option<(PathBuf, PathBuf, PathBuf)> {
+        Some((
+            expand_home(self.tls_ca.as_deref()?),
+            expand_home(self.tls_cert.as_deref()?),
+            expand_home(self.tls_key.as_deref()?),
+        ))
+    }
+
+    /// HTTPS base URL of the Docker daemon for TLS hosts, `None` for SSH hosts
+    pub fn docker_tls_url(&self) -> Option<String> {
+        if !self.uses_tls() {
+            return None;
+        }
+        let port = self.docker_port.unwrap_or(DEFAULT_DOCKER_TLS_PORT);
+        let host = if self.hostname.contains(':') {
+            format!("[{}]", self.hostname)
+        } else {
+            self.hostname.clone()
+        };
+        Some(format!("https://{host}:{port}"))
+    }
+
+    /// Whether this host overrides any global config setting
+    pub fn has_overrides(&self) -> bool {
+        self.web_port.is_some() || self.bind_address.is_some()
+    }
+
+    /// Layer this host's overrides over the global config
+    ///
+    /// Precedence is CLI flag > host override > global config; callers apply
+    /// their flags after this.
+    pub fn apply_overrides(&self, config: &mut Config) {
+        if let Some(port) = self.web_port {
+            config.opencode_web_port = port;
+        }
+        if let Some(bind_address) = &self.bind_address {
+            config.bind_address = bind_address.clone();
+        }
+    }
+
+    /// SSH connect timeout for this host, in seconds
+    pub fn connect_timeout_secs(&self) -> u32 {
+        self.connect_timeout
+            .unwrap_or(DEFAULT_SSH_CONNECT_TIMEOUT_SECS)
+    }
+
     /// Get SSH command arguments for this host
     ///
     /// Returns arguments for port, identity file, jump host, and target (user@hostname).
@@ -242,7 +394,9 @@ mod tests {
         assert!(config.identity_file.is_none());
         assert!(config.jump_host.is_none());
         assert!(config.groups.is_empty());
+        assert!(config.tags.is_empty());
         assert!(config.description.is_none());
+        assert_eq!(config.connect_timeout_secs(), 10);
     }
 
     #[test]
@@ -260,6 +414,66 @@ mod tests {
         assert_eq!(config.groups, vec!["production"]);
     }
 
+    #[test]
+    fn test_host_config_tags() {
+        let config = HostConfig::new("example.com")
+            .with_tag("gpu")
+            .with_tag("eu-west")
+            .with_tag("gpu");
+
+        assert_eq!(config.tags, vec!["gpu", "eu-west"]);
+        assert!(config.has_tag("gpu"));
+        assert!(!config.has_tag("us-east"));
+    }
+
+    #[test]
+    fn test_host_config_tls() {
+        let ssh = HostConfig::new("example.com");
+        assert!(!ssh.uses_tls());
+        assert!(ssh.docker_tls_url().is_none());
+
+        let tls = HostConfig::new("example.com").with_tls("ca.pem", "cert.pem", "key.pem");
+        assert!(tls.uses_tls());
+        assert_eq!(
+            tls.docker_tls_url().as_deref(),
+            Some("https://example.com:2376")
+        );
+        assert_eq!(
+            tls.clone().with_port(2222).docker_tls_url().as_deref(),
+            Some("https://example.com:2376")
+        );
+        assert_eq!(
+            HostConfig::new("2001:db8::1")
+                .with_docker_port(3376)
+                .with_tls("ca.pem", "cert.pem", "key.pem")
+                .docker_tls_url()
+                .as_deref(),
+            Some("https://[2001:db8::1]:3376")
+        );
+    }
+
+    #[test]
+    fn test_tls_paths_expand_home() {
+        let home = dirs::home_dir().unwrap();
+        let host = HostConfig::new("example.com").with_tls("~/certs/ca.pem", "/c/cert.pem", "~");
+        let (ca, cert, key) = host.tls_paths().unwrap();
+        assert_eq!(ca, home.join("certs/ca.pem"));
+        assert_eq!(cert, PathBuf::from("/c/cert.pem"));
+        assert_eq!(key, home);
+        assert_eq!(
+            expand_home("~other/key.pem"),
+            PathBuf::from("~other/key.pem")
+        );
+    }
+
+    #[test]
+    fn test_deserialize_host_without_tags() {
+        let json = r#"{"hostname": "example.com", "user": "admin"}"#;
+        let config: HostConfig = serde_json::from_str(json).unwrap();
+        assert!(config.tags.is_empty());
+        assert!(config.connect_timeout.is_none());
+    }
+
     #[test]
     fn test_hosts_file_operations() {
         let mut hosts = HostsFile::new();
@@ -296,6 +510,24 @@ mod tests {
         assert_eq!(hosts, parsed);
     }
 
+    #[test]
+    fn test_apply_overrides() {
+        let mut config = Config::default();
+        HostConfig::new("example.com").apply_overrides(&mut config);
+        assert_eq!(config, Config::default());
+
+        let host = HostConfig {
+            web_port: Some(3100),
+            bind_address: Some("0.0.0.0".to_string()),
+            ..HostConfig::new("example.com")
+        };
+        assert!(host.has_overrides());
+        host.apply_overrides(&mut config);
+        assert_eq!(config.opencode_web_port, 3100);
+        assert_eq!(config.bind_address, "0.0.0.0");
+        assert_eq!(config.cockpit_port, Config::default().cockpit_port);
+    }
+
     #[test]
     fn test_deserialize_minimal() {
         // Minimal JSON should work with defaults
@@ -3,6 +3,7 @@
 //! Creates and manages SSH tunnels to remote Docker daemons.
 
 use std::net::TcpListener;
+use std::path::Path;
 use std::process::{Child, Command, Stdio};
 use std::time::Duration;
 
@@ -47,7 +48,8 @@ impl SshTunnel {
         cmd.arg("-o").arg("StrictHostKeyChecking=accept-new");
 
         // Connection timeout
-        cmd.arg("-o").arg("ConnectTimeout=10");
+        cmd.arg("-o")
+            .arg(format!("ConnectTimeout={}", host.connect_timeout_secs()));
 
         // Prevent SSH from reading stdin (fixes issues with background operation)
         cmd.arg("-o").arg("RequestTTY=no");
@@ -188,14 +190,20 @@ fn find_available_port() -> Result<u16, HostError> {
 /// Runs `ssh user@host docker version` to verify:
 /// 1. SSH connection works
 /// 2. Docker is available on remote
+///
+/// Hosts with TLS certificates are checked by querying the daemon directly.
 pub async fn test_connection(host: &HostConfig) -> Result<String, HostError> {
+    if let (Some(url), Some((ca, cert, key))) = (host.docker_tls_url(), host.tls_paths()) {
+        return test_tls_connection(&url, &ca, &cert, &key, host.connect_timeout_secs()).await;
+    }
+
     let mut cmd = Command::new("ssh");
 
     // Standard options
     cmd.arg("-o")
         .arg("BatchMode=yes")
         .arg("-o")
-        .arg("ConnectTimeout=10")
+        .arg(format!("ConnectTimeout={}", host.connect_timeout_secs()))
         .arg("-o")
         .arg("StrictHostKeyChecking=accept-new");
 
@@ -245,6 +253,33 @@ pub async fn test_connection(host: &HostConfig) -> Result<String, HostError> {
     }
 }
 
+/// Query the server version from a TLS-secured Docker daemon
+async fn test_tls_connection(
+    url: &str,
+    ca: &Path,
+    cert: &Path,
+    key: &Path,
+    timeout_secs: u32,
+) -> Result<String, HostError> {
+    let docker = bollard::Docker::connect_with_ssl(
+        url,
+        key,
+        cert,
+        ca,
+        u64::from(timeout_secs),
+        bollard::API_DEFAULT_VERSION,
+    )
+    .map_err(|e| HostError::InvalidConfig(format!("TLS setup failed: {e}")))?;
+
+    let version = docker
+        .version()
+        .await
+        .map_err(|e| HostError::ConnectionFailed(e.to_string()))?;
+    let version = version.version.unwrap_or_else(|| "unknown".to_string());
+    tracing::info!("Docker version on remote (TLS): {}", version);
+    Ok(version)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,16 +24,16 @@ pub use docker::{CONTAINER_NAME, DockerClient, DockerError, OPENCODE_WEB_PORT};
 
 // Re-export platform types
 pub use platform::{
-    InstallResult, ServiceConfig, ServiceManager, get_service_manager,
-    is_service_registration_supported,
+    InstallResult, ServiceConfig, ServiceManager, ServiceStatus, UninstallResult,
+    get_service_manager, is_service_registration_supported,
 };
 
 // Re-export host types
 pub use host::{
     DistroFamily, DistroInfo, HostConfig, HostError, HostsFile, SshConfigMatch, SshTunnel,
-    detect_distro, get_docker_install_commands, get_ssh_config_path, host_exists_in_ssh_config,
-    install_docker, load_hosts, query_ssh_config, save_hosts, test_connection,
-    verify_docker_installed, write_ssh_config_entry,
+    detect_distro, expand_home, get_docker_install_commands, get_ssh_config_path,
+    host_exists_in_ssh_config, install_docker, load_hosts, query_ssh_config, save_hosts,
+    test_connection, verify_docker_installed, write_ssh_config_entry,
 };
 
 // Re-export bollard to ensure all crates use the same version
@@ -11,7 +11,7 @@ use std::process::Command;
 use anyhow::{Result, anyhow};
 use serde::Serialize;
 
-use super::{InstallResult, ServiceConfig, ServiceManager};
+use super::{InstallResult, ServiceConfig, ServiceManager, ServiceStatus, UninstallResult};
 
 /// Service label used for launchd registration
 const SERVICE_LABEL: &str = "com.opencode-cloud.service";
@@ -123,6 +123,33 @@ fn get_user_id() -> Result<u32> {
         .map_err(|e| anyhow!("Failed to parse UID: {e}"))
 }
 
+/// Build a [`ServiceStatus`] from `launchctl print` output for a loaded service
+fn parse_launchctl_print(output: &str) -> ServiceStatus {
+    let field = |name: &str| {
+        output.lines().find_map(|line| {
+            let (key, value) = line.split_once(" = ")?;
+            (key.trim() == name).then(|| value.trim())
+        })
+    };
+
+    let state = field("state").unwrap_or("unknown").to_string();
+    let active = state == "running";
+    // e.g. "78: EX_CONFIG", or "(never exited)" while the first run is still going
+    let last_exit_status = field("last exit code")
+        .and_then(|code| code.split(':').next())
+        .and_then(|code| code.trim().parse().ok());
+
+    ServiceStatus {
+        active,
+        // Loaded services are started at boot/login (RunAtLoad)
+        enabled: true,
+        failed: !active && last_exit_status.is_some_and(|code: i32| code != 0),
+        state,
+        pid: field("pid").and_then(|pid| pid.parse().ok()),
+        last_exit_status,
+    }
+}
+
 impl ServiceManager for LaunchdManager {
     fn install(&self, config: &ServiceConfig) -> Result<InstallResult> {
         // Check permissions for system-level install
@@ -165,24 +192,50 @@ impl ServiceManager for LaunchdManager {
         })
     }
 
-    fn uninstall(&self) -> Result<()> {
+    fn uninstall(&self) -> Result<UninstallResult> {
         let plist_path = self.service_file_path();
 
         // Bootout service if running (ignore errors for idempotency)
         let _ = self.bootout();
 
         // Remove plist file if it exists
-        if plist_path.exists() {
+        let removed_file = plist_path.exists();
+        if removed_file {
             fs::remove_file(&plist_path)?;
         }
 
-        Ok(())
+        Ok(UninstallResult {
+            service_file_path: plist_path,
+            removed_file,
+        })
     }
 
     fn is_installed(&self) -> Result<bool> {
         Ok(self.service_file_path().exists())
     }
 
+    fn status(&self) -> Result<ServiceStatus> {
+        let service_target = if self.user_mode {
+            format!("gui/{}/{}", get_user_id()?, self.label())
+        } else {
+            format!("system/{}", self.label())
+        };
+        let output = Command::new("launchctl")
+            .args(["print", &service_target])
+            .output()?;
+
+        if !output.status.success() {
+            // Not bootstrapped: the plist will not run until loaded again
+            return Ok(ServiceStatus {
+                state: "not loaded".to_string(),
+                ..Default::default()
+            });
+        }
+        Ok(parse_launchctl_print(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
     fn service_file_path(&self) -> PathBuf {
         self.service_dir().join(format!("{}.plist", self.label()))
     }
@@ -360,4 +413,24 @@ mod tests {
         assert!(xml.contains("<key>KeepAlive</key>"));
         assert!(xml.contains("<key>ThrottleInterval</key>"));
     }
+
+    #[test]
+    fn test_parse_launchctl_print_running() {
+        let status = parse_launchctl_print(
+            "com.opencode-cloud.service = {\n\tactive count = 1\n\tstate = running\n\
+             \tpid = 812\n\tlast exit code = (never exited)\n}\n",
+        );
+        assert!(status.active && status.enabled && !status.failed);
+        assert_eq!(status.state, "running");
+        assert_eq!(status.pid, Some(812));
+        assert_eq!(status.last_exit_status, None);
+    }
+
+    #[test]
+    fn test_parse_launchctl_print_crashed() {
+        let status =
+            parse_launchctl_print("\tstate = not running\n\tlast exit code = 78: EX_CONFIG\n");
+        assert!(!status.active && status.failed);
+        assert_eq!(status.last_exit_status, Some(78));
+    }
 }
@@ -57,6 +57,38 @@ pub struct InstallResult {
     pub requires_root: bool,
 }
 
+/// Result of a service uninstallation operation
+#[derive(Debug, Clone)]
+pub struct UninstallResult {
+    /// Path to the service file that was registered
+    pub service_file_path: PathBuf,
+
+    /// Whether the service file existed and was removed
+    pub removed_file: bool,
+}
+
+/// Runtime state of the registered service as reported by the service manager
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceStatus {
+    /// Whether the service is currently running
+    pub active: bool,
+
+    /// Whether the service is set to start automatically on boot/login
+    pub enabled: bool,
+
+    /// Whether the service manager considers the service failed
+    pub failed: bool,
+
+    /// State as reported by the service manager (e.g. "active (running)")
+    pub state: String,
+
+    /// PID of the running service process
+    pub pid: Option<u32>,
+
+    /// Exit status of the most recent run, if it has exited
+    pub last_exit_status: Option<i32>,
+}
+
 /// Trait for platform-specific service managers
 ///
 /// Implementations handle the details of registering services with
@@ -71,11 +103,15 @@ pub trait ServiceManager: Send + Sync {
     /// Uninstall the service
     ///
     /// Stops the service if running and removes the registration.
-    fn uninstall(&self) -> Result<()>;
+    /// Succeeds without changes when the service is not installed.
+    fn uninstall(&self) -> Result<UninstallResult>;
 
     /// Check if the service is currently installed
     fn is_installed(&self) -> Result<bool>;
 
+    /// Query the service manager for the service's runtime state
+    fn status(&self) -> Result<ServiceStatus>;
+
     /// Get the path to the service file
     fn service_file_path(&self) -> PathBuf;
 
@@ -9,7 +9,7 @@ use std::process::{Command, Output};
 
 use anyhow::{Result, anyhow};
 
-use super::{InstallResult, ServiceConfig, ServiceManager};
+use super::{InstallResult, ServiceConfig, ServiceManager, ServiceStatus, UninstallResult};
 
 /// Service name used for systemd unit
 const SERVICE_NAME: &str = "opencode-cloud";
@@ -134,6 +134,41 @@ WantedBy=default.target
     }
 }
 
+/// Properties requested from `systemctl show` for [`ServiceManager::status`]
+const STATUS_PROPERTIES: &str =
+    "ActiveState,SubState,UnitFileState,Result,ExecMainPID,ExecMainStatus,ExecMainExitTimestamp";
+
+/// Build a [`ServiceStatus`] from `systemctl show --property=...` output
+fn parse_systemctl_show(output: &str) -> ServiceStatus {
+    let property = |name: &str| {
+        output
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix('='))
+            .unwrap_or("")
+            .trim()
+    };
+
+    let active_state = property("ActiveState");
+    let sub_state = property("SubState");
+    let state = match (active_state, sub_state) {
+        ("", _) => "unknown".to_string(),
+        (active, "") => active.to_string(),
+        (active, sub) => format!("{active} ({sub})"),
+    };
+    let has_exited = !property("ExecMainExitTimestamp").is_empty();
+
+    ServiceStatus {
+        active: active_state == "active",
+        enabled: property("UnitFileState").starts_with("enabled"),
+        failed: active_state == "failed" || !matches!(property("Result"), "" | "success"),
+        state,
+        pid: property("ExecMainPID").parse().ok().filter(|pid| *pid != 0),
+        last_exit_status: has_exited
+            .then(|| property("ExecMainStatus").parse().ok())
+            .flatten(),
+    }
+}
+
 /// Check if systemd is available on this system
 ///
 /// Returns true if /run/systemd/system exists, indicating systemd is running
@@ -222,7 +257,7 @@ impl ServiceManager for SystemdManager {
         })
     }
 
-    fn uninstall(&self) -> Result<()> {
+    fn uninstall(&self) -> Result<UninstallResult> {
         // 1. Stop the service (ignore error if not running)
         let _ = self.systemctl(&["stop", SERVICE_NAME]);
 
@@ -231,7 +266,8 @@ impl ServiceManager for SystemdManager {
 
         // 3. Remove the unit file
         let service_file = self.service_file_path();
-        if service_file.exists() {
+        let removed_file = service_file.exists();
+        if removed_file {
             fs::remove_file(&service_file).map_err(|e| {
                 anyhow!(
                     "Failed to remove service file {}: {}",
@@ -244,13 +280,28 @@ impl ServiceManager for SystemdManager {
         // 4. Reload daemon to reflect the removal
         self.systemctl_ok(&["daemon-reload"])?;
 
-        Ok(())
+        Ok(UninstallResult {
+            service_file_path: service_file,
+            removed_file,
+        })
     }
 
     fn is_installed(&self) -> Result<bool> {
         Ok(self.service_file_path().exists())
     }
 
+    fn status(&self) -> Result<ServiceStatus> {
+        let property_arg = format!("--property={STATUS_PROPERTIES}");
+        let output = self.systemctl(&["show", SERVICE_NAME, &property_arg])?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!("systemctl show failed: {}", stderr.trim()));
+        }
+        Ok(parse_systemctl_show(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
     fn service_file_path(&self) -> PathBuf {
         self.service_dir().join(format!("{SERVICE_NAME}.service"))
     }
@@ -382,4 +433,28 @@ mod tests {
         assert!(result.is_ok());
         // Can't assert false because the service might actually be installed on some systems
     }
+
+    #[test]
+    fn test_parse_systemctl_show_running() {
+        let status = parse_systemctl_show(
+            "ActiveState=active\nSubState=running\nUnitFileState=enabled\nResult=success\n\
+             ExecMainPID=4242\nExecMainStatus=0\nExecMainExitTimestamp=\n",
+        );
+        assert!(status.active && status.enabled && !status.failed);
+        assert_eq!(status.state, "active (running)");
+        assert_eq!(status.pid, Some(4242));
+        assert_eq!(status.last_exit_status, None);
+    }
+
+    #[test]
+    fn test_parse_systemctl_show_failed() {
+        let status = parse_systemctl_show(
+            "ActiveState=failed\nSubState=failed\nUnitFileState=disabled\nResult=exit-code\n\
+             ExecMainPID=0\nExecMainStatus=1\n\
+             ExecMainExitTimestamp=Thu 2026-01-01 10:00:00 UTC\n",
+        );
+        assert!(!status.active && !status.enabled && status.failed);
+        assert_eq!(status.pid, None);
+        assert_eq!(status.last_exit_status, Some(1));
+    }
 }